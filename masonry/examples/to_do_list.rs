@@ -50,11 +50,8 @@ impl AppDriver for Driver {
             });
         } else if action.is::<TextAction>() {
             let action = action.downcast::<TextAction>().unwrap();
-            match *action {
-                TextAction::Changed(new_text) => {
-                    self.next_task = new_text.clone();
-                }
-                TextAction::Entered(_) => {}
+            if let TextAction::Changed(new_text) = *action {
+                self.next_task = new_text.clone();
             }
         }
     }
@@ -0,0 +1,1202 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use accesskit::{HasPopup, Node, Role, Toggled};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Affine, Axis, BezPath, Cap, Insets, Join, Line, Point, Rect, Size, Stroke};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, ArcStr, ChildrenIds, EventCtx, HasProperty, Layer, LayoutCtx,
+    MeasureCtx, NewWidget, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent,
+    PointerUpdate, PrePaintProps, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetOptions, WidgetPod, paint_background,
+    paint_border, paint_box_shadow, pre_paint,
+};
+use crate::layout::{LayoutSize, LenDef, LenReq, SizeDef};
+use crate::properties::{
+    BorderWidth, CheckmarkColor, CheckmarkStrokeWidth, ContentColor, SelectionColor,
+};
+use crate::util::{fill_color, stroke};
+use crate::widgets::Label;
+
+/// Horizontal padding at both ends of a menu row.
+const ROW_PADDING_H: f64 = 8.;
+/// Vertical padding above and below the label of a menu row.
+const ROW_PADDING_V: f64 = 4.;
+/// Width of the column in which checkmarks are painted.
+const CHECK_COLUMN_WIDTH: f64 = 18.;
+/// Width of the column in which submenu arrows are painted.
+const ARROW_COLUMN_WIDTH: f64 = 18.;
+/// Height of a separator row.
+const SEPARATOR_HEIGHT: f64 = 9.;
+
+/// An entry in a [`Menu`].
+///
+/// An item is either a regular entry, a checkable entry, a separator,
+/// or an entry opening a submenu.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuItem {
+    label: ArcStr,
+    kind: MenuItemKind,
+    disabled: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum MenuItemKind {
+    Entry,
+    Checkable(bool),
+    Separator,
+    Submenu(Vec<MenuItem>),
+}
+
+impl MenuItem {
+    /// Creates a regular entry with the given label.
+    pub fn new(label: impl Into<ArcStr>) -> Self {
+        Self {
+            label: label.into(),
+            kind: MenuItemKind::Entry,
+            disabled: false,
+        }
+    }
+
+    /// Creates an entry which shows a checkmark when `checked` is true.
+    ///
+    /// Choosing it reports the toggled state in [`MenuItemSelected::checked`].
+    pub fn checkable(label: impl Into<ArcStr>, checked: bool) -> Self {
+        Self {
+            label: label.into(),
+            kind: MenuItemKind::Checkable(checked),
+            disabled: false,
+        }
+    }
+
+    /// Creates a horizontal line separating groups of entries.
+    pub fn separator() -> Self {
+        Self {
+            label: "".into(),
+            kind: MenuItemKind::Separator,
+            disabled: true,
+        }
+    }
+
+    /// Creates an entry which opens a nested menu with the given items.
+    pub fn submenu(label: impl Into<ArcStr>, items: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            label: label.into(),
+            kind: MenuItemKind::Submenu(items.into_iter().collect()),
+            disabled: false,
+        }
+    }
+
+    /// Builder-style method to set whether the item can be chosen.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the item's label.
+    ///
+    /// Separators have an empty label.
+    pub fn label(&self) -> &ArcStr {
+        &self.label
+    }
+
+    /// Returns `true` if the item is a separator.
+    pub fn is_separator(&self) -> bool {
+        matches!(self.kind, MenuItemKind::Separator)
+    }
+
+    /// Returns `true` if the item can't be chosen.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns the checked state of the item, or `None` if it isn't checkable.
+    pub fn checked(&self) -> Option<bool> {
+        match self.kind {
+            MenuItemKind::Checkable(checked) => Some(checked),
+            _ => None,
+        }
+    }
+
+    /// Returns the items of the submenu opened by this item.
+    ///
+    /// The slice is empty if this item doesn't open a submenu.
+    pub fn submenu_items(&self) -> &[Self] {
+        match &self.kind {
+            MenuItemKind::Submenu(items) => items,
+            _ => &[],
+        }
+    }
+
+    fn is_submenu(&self) -> bool {
+        matches!(self.kind, MenuItemKind::Submenu(_))
+    }
+
+    fn is_selectable(&self) -> bool {
+        !self.disabled && !self.is_separator()
+    }
+}
+
+/// An item of a [`Menu`] was chosen.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MenuItemSelected {
+    /// The indices leading to the chosen item.
+    ///
+    /// The first index is in the root menu's items, and each open submenu adds one index.
+    pub path: Vec<usize>,
+    /// The toggled state if the chosen item is [checkable](MenuItem::checkable).
+    pub checked: Option<bool>,
+}
+
+/// A [`Layer`] showing a list of [`MenuItem`]s, which can open nested submenus.
+///
+/// The menu takes [text focus] when added, and can be navigated with the arrow keys,
+/// `Home`/`End`, `Enter`/`Space` to choose and `Escape` to close.
+/// It is dismissed when an item is chosen, or when the user clicks outside of it.
+///
+/// Emits [`MenuItemSelected`] when an item is chosen.
+/// If an [action source](Self::with_action_source) is set, the action is submitted
+/// on behalf of that widget instead.
+///
+/// [text focus]: crate::doc::masonry_concepts#text-focus
+pub struct Menu {
+    items: Vec<MenuItem>,
+    action_source: Option<WidgetId>,
+    /// The rows of the root menu and of every submenu, in depth-first order.
+    ///
+    /// Rows of closed submenus are stashed.
+    submenus: Vec<Submenu>,
+    /// The open panels: the root menu first, then one per open submenu.
+    panels: Vec<Panel>,
+    /// The widget which had focus before the menu took it.
+    prev_focus: Option<WidgetId>,
+}
+
+/// The rows of the root menu or of a submenu.
+struct Submenu {
+    /// Indices of the submenu items leading to this submenu. Empty for the root menu.
+    path: Vec<usize>,
+    rows: Vec<WidgetPod<MenuRow>>,
+}
+
+/// An open level of the menu.
+struct Panel {
+    /// Index of the displayed submenu in [`Menu::submenus`].
+    submenu: usize,
+    highlighted: Option<usize>,
+    /// The panel's rect in the menu's content-box coordinate space.
+    rect: Rect,
+    /// The rect of each row in the menu's content-box coordinate space.
+    row_rects: Vec<Rect>,
+}
+
+impl Panel {
+    fn new(submenu: usize) -> Self {
+        Self {
+            submenu,
+            highlighted: None,
+            rect: Rect::ZERO,
+            row_rects: Vec::new(),
+        }
+    }
+}
+
+/// Creates the rows of `items` and of all their submenus, recursively.
+fn build_submenus(path: Vec<usize>, items: &[MenuItem], submenus: &mut Vec<Submenu>) {
+    let rows = items
+        .iter()
+        .map(|item| WidgetPod::new(MenuRow::new(item)))
+        .collect();
+    submenus.push(Submenu {
+        path: path.clone(),
+        rows,
+    });
+    for (idx, item) in items.iter().enumerate() {
+        if item.is_submenu() {
+            let mut path = path.clone();
+            path.push(idx);
+            build_submenus(path, item.submenu_items(), submenus);
+        }
+    }
+}
+
+// --- MARK: BUILDERS
+impl Menu {
+    /// Creates a new menu with the given items.
+    pub fn new(items: impl IntoIterator<Item = MenuItem>) -> Self {
+        let items: Vec<_> = items.into_iter().collect();
+        let mut submenus = Vec::new();
+        build_submenus(Vec::new(), &items, &mut submenus);
+        Self {
+            items,
+            action_source: None,
+            submenus,
+            panels: vec![Panel::new(0)],
+            prev_focus: None,
+        }
+    }
+
+    /// Builder-style method to submit [`MenuItemSelected`] on behalf of the given widget.
+    ///
+    /// This is usually the widget which created the menu's layer,
+    /// so that the app can handle the action like one from that widget.
+    pub fn with_action_source(mut self, source: WidgetId) -> Self {
+        self.action_source = Some(source);
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl Menu {
+    /// Returns the menu's items.
+    pub fn items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    /// Returns the index path of the highlighted item, if any.
+    pub fn highlighted_path(&self) -> Option<Vec<usize>> {
+        let panel = self.panels.last()?;
+        let mut path = self.panel_path(panel).to_vec();
+        path.push(panel.highlighted?);
+        Some(path)
+    }
+
+    fn panel_path(&self, panel: &Panel) -> &[usize] {
+        &self.submenus[panel.submenu].path
+    }
+
+    fn panel_items(&self, depth: usize) -> &[MenuItem] {
+        let mut items = &self.items[..];
+        for idx in self.panel_path(&self.panels[depth]) {
+            items = items[*idx].submenu_items();
+        }
+        items
+    }
+
+    fn item(&self, depth: usize, idx: usize) -> &MenuItem {
+        &self.panel_items(depth)[idx]
+    }
+
+    /// Returns `true` if the submenu of the item at `idx` in the panel at `depth` is open.
+    fn is_submenu_open(&self, depth: usize, idx: usize) -> bool {
+        self.panels
+            .get(depth + 1)
+            .is_some_and(|panel| self.panel_path(panel).last() == Some(&idx))
+    }
+
+    /// Closes all panels deeper than `depth`.
+    fn close_panels_after(&mut self, ctx: &mut EventCtx<'_>, depth: usize) {
+        if self.panels.len() <= depth + 1 {
+            return;
+        }
+        for panel in self.panels.drain(depth + 1..) {
+            for row in &mut self.submenus[panel.submenu].rows {
+                ctx.set_stashed(row, true);
+            }
+        }
+        ctx.request_layout();
+    }
+
+    /// Opens the submenu of the item at `idx` in the panel at `depth`.
+    fn open_submenu(&mut self, ctx: &mut EventCtx<'_>, depth: usize, idx: usize) {
+        self.close_panels_after(ctx, depth);
+        let mut path = self.panel_path(&self.panels[depth]).to_vec();
+        path.push(idx);
+        let Some(submenu) = self.submenus.iter().position(|s| s.path == path) else {
+            return;
+        };
+        for row in &mut self.submenus[submenu].rows {
+            ctx.set_stashed(row, false);
+        }
+        self.panels.push(Panel::new(submenu));
+        ctx.request_layout();
+    }
+
+    fn set_highlighted(&mut self, ctx: &mut EventCtx<'_>, depth: usize, idx: Option<usize>) {
+        if self.panels[depth].highlighted != idx {
+            self.panels[depth].highlighted = idx;
+            ctx.request_paint_only();
+        }
+    }
+
+    /// Moves the highlight of the deepest panel to the next selectable item.
+    fn move_highlight(&mut self, ctx: &mut EventCtx<'_>, forward: bool) {
+        let depth = self.panels.len() - 1;
+        let items = self.panel_items(depth);
+        let count = items.len();
+        if count == 0 {
+            return;
+        }
+        let mut idx = self.panels[depth].highlighted;
+        for _ in 0..count {
+            let next = match (idx, forward) {
+                (None, true) => 0,
+                (None, false) => count - 1,
+                (Some(idx), true) => (idx + 1) % count,
+                (Some(idx), false) => (idx + count - 1) % count,
+            };
+            idx = Some(next);
+            if items[next].is_selectable() {
+                self.set_highlighted(ctx, depth, idx);
+                return;
+            }
+        }
+    }
+
+    /// Highlights the first or last selectable item of the deepest panel.
+    fn highlight_edge(&mut self, ctx: &mut EventCtx<'_>, first: bool) {
+        let depth = self.panels.len() - 1;
+        self.panels[depth].highlighted = None;
+        self.move_highlight(ctx, first);
+        ctx.request_paint_only();
+    }
+
+    /// Chooses the item at `idx` in the panel at `depth`.
+    fn activate(&mut self, ctx: &mut EventCtx<'_>, depth: usize, idx: usize) {
+        let item = self.item(depth, idx);
+        if !item.is_selectable() {
+            return;
+        }
+        if item.is_submenu() {
+            if !self.is_submenu_open(depth, idx) {
+                self.open_submenu(ctx, depth, idx);
+            }
+            self.set_highlighted(ctx, depth, Some(idx));
+            self.move_highlight(ctx, true);
+            return;
+        }
+
+        let checked = item.checked().map(|checked| !checked);
+        let mut path = self.panel_path(&self.panels[depth]).to_vec();
+        path.push(idx);
+        let action = MenuItemSelected { path, checked };
+        match self.action_source {
+            Some(source) => ctx.submit_untyped_action_for(source, Box::new(action)),
+            None => ctx.submit_action::<MenuItemSelected>(action),
+        }
+        self.dismiss(ctx);
+    }
+
+    /// Removes the menu's layer, giving focus back to the widget which had it before.
+    fn dismiss(&mut self, ctx: &mut EventCtx<'_>) {
+        if ctx.has_focus_target()
+            && let Some(prev_focus) = self.prev_focus
+        {
+            ctx.set_focus(prev_focus);
+        }
+        ctx.remove_layer(ctx.widget_id());
+    }
+
+    /// Returns the panel depth and row index at `pos`, given in the content-box coordinate space.
+    ///
+    /// The row index is `None` if the position is inside a panel but not on a row.
+    fn hit_test(&self, pos: Point) -> Option<(usize, Option<usize>)> {
+        self.panels
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, panel)| panel.rect.contains(pos))
+            .map(|(depth, panel)| {
+                let row = panel.row_rects.iter().position(|rect| rect.contains(pos));
+                (depth, row)
+            })
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Menu {
+    /// Replaces the menu's items, closing any open submenu.
+    pub fn set_items(this: &mut WidgetMut<'_, Self>, items: impl IntoIterator<Item = MenuItem>) {
+        this.widget.items = items.into_iter().collect();
+        for submenu in this.widget.submenus.drain(..) {
+            for row in submenu.rows {
+                this.ctx.remove_child(row);
+            }
+        }
+        build_submenus(Vec::new(), &this.widget.items, &mut this.widget.submenus);
+        this.widget.panels = vec![Panel::new(0)];
+        this.ctx.children_changed();
+        this.ctx.request_layout();
+    }
+}
+
+impl HasProperty<SelectionColor> for Menu {}
+impl HasProperty<CheckmarkColor> for Menu {}
+impl HasProperty<CheckmarkStrokeWidth> for Menu {}
+impl HasProperty<ContentColor> for Menu {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Menu {
+    type Action = MenuItemSelected;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                let pos = ctx.local_position(current.position);
+                let Some((depth, Some(idx))) = self.hit_test(pos) else {
+                    return;
+                };
+                if !self.item(depth, idx).is_selectable() {
+                    self.set_highlighted(ctx, depth, None);
+                    return;
+                }
+                self.set_highlighted(ctx, depth, Some(idx));
+                if self.item(depth, idx).is_submenu() {
+                    if !self.is_submenu_open(depth, idx) {
+                        self.open_submenu(ctx, depth, idx);
+                    }
+                } else {
+                    self.close_panels_after(ctx, depth);
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let pos = ctx.local_position(state.position);
+                if let Some((depth, Some(idx))) = self.hit_test(pos) {
+                    self.activate(ctx, depth, idx);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        let depth = self.panels.len() - 1;
+        let highlighted = self.panels[depth].highlighted;
+        if key_event.state.is_up() {
+            // Like buttons, items are chosen when the key is released,
+            // so that the release doesn't reach the widget which gets focus back.
+            if matches!(&key_event.key, Key::Character(c) if c == " ")
+                || key_event.key == Key::Named(NamedKey::Enter)
+            {
+                if let Some(idx) = highlighted {
+                    self.activate(ctx, depth, idx);
+                }
+                ctx.set_handled();
+            }
+            return;
+        }
+        match &key_event.key {
+            Key::Named(NamedKey::ArrowDown) => self.move_highlight(ctx, true),
+            Key::Named(NamedKey::ArrowUp) => self.move_highlight(ctx, false),
+            Key::Named(NamedKey::Home) => self.highlight_edge(ctx, true),
+            Key::Named(NamedKey::End) => self.highlight_edge(ctx, false),
+            Key::Named(NamedKey::ArrowRight) => {
+                if let Some(idx) = highlighted
+                    && self.item(depth, idx).is_submenu()
+                {
+                    self.activate(ctx, depth, idx);
+                }
+            }
+            Key::Named(NamedKey::ArrowLeft) => {
+                if depth > 0 {
+                    self.close_panels_after(ctx, depth - 1);
+                }
+            }
+            Key::Named(NamedKey::Enter) => {}
+            Key::Character(c) if c == " " => {}
+            Key::Named(NamedKey::Escape) => self.dismiss(ctx),
+            _ => return,
+        }
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if event.action != accesskit::Action::Click {
+            return;
+        }
+        let target = ctx.target();
+        let hit = self.panels.iter().enumerate().find_map(|(depth, panel)| {
+            let rows = &self.submenus[panel.submenu].rows;
+            let idx = rows.iter().position(|row| row.id() == target)?;
+            Some((depth, idx))
+        });
+        if let Some((depth, idx)) = hit {
+            self.activate(ctx, depth, idx);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::WidgetAdded => {
+                for submenu in &mut self.submenus[1..] {
+                    for row in &mut submenu.rows {
+                        ctx.set_stashed(row, true);
+                    }
+                }
+                self.prev_focus = ctx.focus_target_id();
+                ctx.request_focus();
+            }
+            Update::FocusChanged(_) => ctx.request_paint_only(),
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for submenu in &mut self.submenus {
+            for row in &mut submenu.rows {
+                ctx.register_child(row);
+            }
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        SelectionColor::prop_changed(ctx, property_type);
+        CheckmarkColor::prop_changed(ctx, property_type);
+        CheckmarkStrokeWidth::prop_changed(ctx, property_type);
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        // The menu's own box is its root panel. Submenus are placed outside of it.
+        let rows = &mut self.submenus[0].rows;
+        let mut width: f64 = 0.;
+        for row in rows.iter_mut() {
+            let row_width = ctx.compute_length(
+                row,
+                LenDef::MaxContent,
+                LayoutSize::NONE,
+                Axis::Horizontal,
+                None,
+            );
+            width = width.max(row_width);
+        }
+        if axis == Axis::Horizontal {
+            return width;
+        }
+
+        let width = cross_length.unwrap_or(width);
+        let mut height = 0.;
+        for row in rows.iter_mut() {
+            height += ctx.compute_length(
+                row,
+                LenDef::MaxContent,
+                LayoutSize::NONE,
+                Axis::Vertical,
+                Some(width),
+            );
+        }
+        height
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, props: &PropertiesRef<'_>, size: Size) {
+        let border_width = props.get::<BorderWidth>().width;
+        let mut painted_area = size.to_rect();
+
+        // Only the rows of open panels are laid out, so the others must be stashed.
+        // The rows added by `set_items` can't be stashed until they are in the tree, so we do it here.
+        for (idx, submenu) in self.submenus.iter_mut().enumerate().skip(1) {
+            let is_open = self.panels.iter().any(|panel| panel.submenu == idx);
+            for row in &mut submenu.rows {
+                ctx.set_stashed(row, !is_open);
+            }
+        }
+
+        for depth in 0..self.panels.len() {
+            let origin = if depth == 0 {
+                Point::ORIGIN
+            } else {
+                // Submenus are placed to the right of the item which opened them.
+                let parent = &self.panels[depth - 1];
+                let opener_idx = *self.panel_path(&self.panels[depth]).last().unwrap();
+                let opener_rect = parent.row_rects[opener_idx];
+                Point::new(parent.rect.x1 + 2. * border_width, opener_rect.y0)
+            };
+
+            let panel = &mut self.panels[depth];
+            let rows = &mut self.submenus[panel.submenu].rows;
+            let width = if depth == 0 {
+                size.width
+            } else {
+                let mut width: f64 = 0.;
+                for row in rows.iter_mut() {
+                    let row_width = ctx.compute_length(
+                        row,
+                        LenDef::MaxContent,
+                        LayoutSize::NONE,
+                        Axis::Horizontal,
+                        None,
+                    );
+                    width = width.max(row_width);
+                }
+                width
+            };
+
+            panel.row_rects.clear();
+            let mut y = origin.y;
+            for row in rows.iter_mut() {
+                let row_size = ctx.compute_size(
+                    row,
+                    SizeDef::fixed(Size::new(width, 0.)).with_height(LenDef::MaxContent),
+                    LayoutSize::NONE,
+                );
+                let row_size = Size::new(width, row_size.height);
+                ctx.run_layout(row, row_size);
+                let row_origin = Point::new(origin.x, y);
+                ctx.place_child(row, row_origin);
+                panel
+                    .row_rects
+                    .push(Rect::from_origin_size(row_origin, row_size));
+                y += row_size.height;
+            }
+            panel.rect = Rect::new(origin.x, origin.y, origin.x + width, y);
+            painted_area = painted_area.union(panel.rect.inflate(border_width, border_width));
+        }
+
+        ctx.set_paint_insets(Insets::new(
+            -painted_area.x0,
+            -painted_area.y0,
+            painted_area.x1 - size.width,
+            painted_area.y1 - size.height,
+        ));
+    }
+
+    fn pre_paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        pre_paint(ctx, props, scene);
+
+        let p = PrePaintProps::fetch(ctx, props);
+        for panel in &self.panels[1..] {
+            let rect = panel
+                .rect
+                .inflate(p.border_width.width, p.border_width.width);
+            paint_box_shadow(scene, rect, p.box_shadow, &p.corner_radius);
+            paint_background(scene, rect, &p.background, p.border_width, &p.corner_radius);
+            paint_border(
+                scene,
+                rect,
+                &p.border_color,
+                p.border_width,
+                &p.corner_radius,
+            );
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let selection_color = props.get::<SelectionColor>().color;
+        let content_color = props.get::<ContentColor>().color;
+        let checkmark_color = props.get::<CheckmarkColor>().color;
+        let checkmark_width = props.get::<CheckmarkStrokeWidth>().width;
+        let is_focused = ctx.is_focus_target();
+
+        for (depth, panel) in self.panels.iter().enumerate() {
+            let items = self.panel_items(depth);
+            for (idx, (item, rect)) in items.iter().zip(&panel.row_rects).enumerate() {
+                if panel.highlighted == Some(idx) {
+                    let color = if is_focused {
+                        selection_color
+                    } else {
+                        selection_color.multiply_alpha(0.5)
+                    };
+                    fill_color(scene, rect, color);
+                }
+
+                let mid_y = rect.center().y;
+                match &item.kind {
+                    MenuItemKind::Separator => {
+                        let line = Line::new(
+                            (rect.x0 + ROW_PADDING_H, mid_y),
+                            (rect.x1 - ROW_PADDING_H, mid_y),
+                        );
+                        stroke(scene, &line, content_color.multiply_alpha(0.3), 1.);
+                    }
+                    MenuItemKind::Checkable(true) => {
+                        let x = rect.x0 + ROW_PADDING_H;
+                        let mut path = BezPath::new();
+                        path.move_to((x + 2., mid_y));
+                        path.line_to((x + 5., mid_y + 3.));
+                        path.line_to((x + 10., mid_y - 4.));
+                        let style = Stroke {
+                            width: checkmark_width,
+                            join: Join::Round,
+                            start_cap: Cap::Round,
+                            end_cap: Cap::Round,
+                            ..Default::default()
+                        };
+                        scene.stroke(&style, Affine::IDENTITY, checkmark_color, None, &path);
+                    }
+                    MenuItemKind::Submenu(_) => {
+                        let x = rect.x1 - ROW_PADDING_H - ARROW_COLUMN_WIDTH / 2.;
+                        let mut path = BezPath::new();
+                        path.move_to((x - 2., mid_y - 4.));
+                        path.line_to((x + 2., mid_y));
+                        path.line_to((x - 2., mid_y + 4.));
+                        stroke(scene, &path, content_color, 1.5);
+                    }
+                    MenuItemKind::Entry | MenuItemKind::Checkable(false) => {}
+                }
+            }
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Menu
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.submenus
+            .iter()
+            .flat_map(|submenu| submenu.rows.iter().map(|row| row.id()))
+            .collect()
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn as_layer(&mut self) -> Option<&mut dyn Layer> {
+        Some(self)
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Menu", id = id.trace())
+    }
+}
+
+// --- MARK: IMPL LAYER
+impl Layer for Menu {
+    fn capture_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        // Clicking anywhere outside of the menu dismisses it.
+        if let PointerEvent::Down(PointerButtonEvent { state, .. }) = event {
+            let pos = ctx.local_position(state.position);
+            if !ctx.border_box().contains(pos) && self.hit_test(pos).is_none() {
+                self.dismiss(ctx);
+            }
+        }
+    }
+}
+
+// --- MARK: MENU ROW
+/// A single row of a [`Menu`], which lays out the item's label
+/// and reports the item to accessibility.
+///
+/// Highlights, checkmarks and other decorations are painted by the menu.
+struct MenuRow {
+    label: Option<WidgetPod<Label>>,
+    role: Role,
+    checked: Option<bool>,
+    has_submenu: bool,
+    disabled: bool,
+}
+
+impl MenuRow {
+    fn new(item: &MenuItem) -> Self {
+        let role = match item.kind {
+            MenuItemKind::Entry | MenuItemKind::Submenu(_) => Role::MenuItem,
+            MenuItemKind::Checkable(_) => Role::MenuItemCheckBox,
+            MenuItemKind::Separator => Role::Splitter,
+        };
+        let label = (!item.is_separator()).then(|| {
+            let options = WidgetOptions {
+                disabled: item.disabled,
+                ..Default::default()
+            };
+            NewWidget::new_with_options(Label::new(item.label.clone()), options).to_pod()
+        });
+        Self {
+            label,
+            role,
+            checked: item.checked(),
+            has_submenu: item.is_submenu(),
+            disabled: item.disabled,
+        }
+    }
+}
+
+impl Widget for MenuRow {
+    type Action = ();
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        if let Some(label) = &mut self.label {
+            ctx.register_child(label);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let Some(label) = &mut self.label else {
+            return match axis {
+                Axis::Horizontal => 2. * ROW_PADDING_H,
+                Axis::Vertical => SEPARATOR_HEIGHT,
+            };
+        };
+        let chrome_width = 2. * ROW_PADDING_H + CHECK_COLUMN_WIDTH + ARROW_COLUMN_WIDTH;
+        match axis {
+            Axis::Horizontal => {
+                let label_width =
+                    ctx.compute_length(label, LenDef::MaxContent, LayoutSize::NONE, axis, None);
+                label_width + chrome_width
+            }
+            Axis::Vertical => {
+                let cross_length = cross_length.map(|width| (width - chrome_width).max(0.));
+                let label_height = ctx.compute_length(
+                    label,
+                    LenDef::MaxContent,
+                    LayoutSize::NONE,
+                    axis,
+                    cross_length,
+                );
+                label_height + 2. * ROW_PADDING_V
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let Some(label) = &mut self.label else {
+            return;
+        };
+        let label_space = Size::new(
+            (size.width - 2. * ROW_PADDING_H - CHECK_COLUMN_WIDTH - ARROW_COLUMN_WIDTH).max(0.),
+            (size.height - 2. * ROW_PADDING_V).max(0.),
+        );
+        let label_size = ctx.compute_size(label, SizeDef::fit(label_space), label_space.into());
+        ctx.run_layout(label, label_size);
+        let label_origin = Point::new(
+            ROW_PADDING_H + CHECK_COLUMN_WIDTH,
+            (size.height - label_size.height) * 0.5,
+        );
+        ctx.place_child(label, label_origin);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        self.role
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        if self.role == Role::Splitter {
+            return;
+        }
+        if self.disabled {
+            node.set_disabled();
+        } else {
+            node.add_action(accesskit::Action::Click);
+        }
+        if let Some(checked) = self.checked {
+            node.set_toggled(if checked {
+                Toggled::True
+            } else {
+                Toggled::False
+            });
+        }
+        if self.has_submenu {
+            node.set_has_popup(HasPopup::Menu);
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        match &self.label {
+            Some(label) => ChildrenIds::from_slice(&[label.id()]),
+            None => ChildrenIds::new(),
+        }
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("MenuRow", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::{Button, ContextMenuArea, Flex};
+
+    const AREA: WidgetTag<ContextMenuArea> = WidgetTag::named("area");
+    const BUTTON: WidgetTag<Button> = WidgetTag::named("button");
+
+    fn items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("Cut"),
+            MenuItem::new("Copy").disabled(true),
+            MenuItem::separator(),
+            MenuItem::checkable("Word wrap", false),
+            MenuItem::submenu("More", [MenuItem::new("First"), MenuItem::new("Second")]),
+        ]
+    }
+
+    /// Creates a harness with a button in a `ContextMenuArea`, and opens the menu
+    /// by right-clicking the button.
+    ///
+    /// Returns the harness and the id of the menu.
+    fn open_menu() -> (TestHarness<Flex>, WidgetId) {
+        let button = NewWidget::new_with_tag(Button::with_text("Target"), BUTTON);
+        let area = NewWidget::new_with_tag(ContextMenuArea::new(button, items()), AREA);
+        let root = Flex::column().with_fixed(area).with_auto_id();
+
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), root, Size::new(400., 400.));
+        let button_id = harness.get_widget(BUTTON).id();
+        harness.mouse_move_to(button_id);
+        harness.mouse_button_press(PointerButton::Secondary);
+        harness.mouse_button_release(PointerButton::Secondary);
+
+        // The menu takes focus when opened.
+        let menu_id = harness.focused_widget_id().unwrap();
+        assert!(
+            harness
+                .get_widget_with_id(menu_id)
+                .downcast::<Menu>()
+                .is_some()
+        );
+        (harness, menu_id)
+    }
+
+    fn highlighted_path(harness: &TestHarness<Flex>, menu_id: WidgetId) -> Option<Vec<usize>> {
+        let menu = harness.get_widget_with_id(menu_id);
+        menu.downcast::<Menu>().unwrap().inner().highlighted_path()
+    }
+
+    // `TestHarness::mouse_click_on` only finds widgets in the base layer.
+    fn click_on(harness: &mut TestHarness<Flex>, id: WidgetId) {
+        harness.mouse_move_to_unchecked(id);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+    }
+
+    fn key(harness: &mut TestHarness<Flex>, key: NamedKey) {
+        harness.process_text_event(TextEvent::key_down(Key::Named(key)));
+        harness.process_text_event(TextEvent::key_up(Key::Named(key)));
+    }
+
+    #[test]
+    fn secondary_click_opens_menu() {
+        let (harness, menu_id) = open_menu();
+        let menu = harness.get_widget_with_id(menu_id);
+        assert_eq!(
+            menu.downcast::<Menu>().unwrap().inner().items(),
+            &items()[..]
+        );
+
+        // The rows of the submenu are hidden until it's opened.
+        let children = menu.children();
+        assert_eq!(children.len(), items().len() + 2);
+        assert!(!children[0].ctx().is_stashed());
+        assert!(children[5].ctx().is_stashed());
+    }
+
+    #[test]
+    fn open_submenu_snapshot() {
+        let (mut harness, menu_id) = open_menu();
+        key(&mut harness, NamedKey::ArrowUp);
+        key(&mut harness, NamedKey::ArrowRight);
+        key(&mut harness, NamedKey::ArrowDown);
+        assert_eq!(highlighted_path(&harness, menu_id), Some(vec![4, 1]));
+
+        assert_render_snapshot!(harness, "menu_open_submenu");
+    }
+
+    #[test]
+    fn keyboard_navigation() {
+        let (mut harness, menu_id) = open_menu();
+        let area_id = harness.get_widget(AREA).id();
+        let button_id = harness.get_widget(BUTTON).id();
+
+        // "Copy" is disabled and the separator can't be highlighted.
+        key(&mut harness, NamedKey::ArrowDown);
+        key(&mut harness, NamedKey::ArrowDown);
+        assert_eq!(highlighted_path(&harness, menu_id), Some(vec![3]));
+
+        // Open the submenu, which highlights its first item.
+        key(&mut harness, NamedKey::ArrowDown);
+        key(&mut harness, NamedKey::ArrowRight);
+        assert_eq!(highlighted_path(&harness, menu_id), Some(vec![4, 0]));
+
+        // Close it again.
+        key(&mut harness, NamedKey::ArrowLeft);
+        assert_eq!(highlighted_path(&harness, menu_id), Some(vec![4]));
+
+        key(&mut harness, NamedKey::ArrowRight);
+        key(&mut harness, NamedKey::End);
+        key(&mut harness, NamedKey::Enter);
+        assert_eq!(
+            harness.pop_action::<MenuItemSelected>(),
+            Some((
+                MenuItemSelected {
+                    path: vec![4, 1],
+                    checked: None,
+                },
+                area_id
+            ))
+        );
+        assert!(harness.pop_action_erased().is_none());
+        assert!(harness.try_get_widget(menu_id).is_none());
+        assert_eq!(harness.focused_widget_id(), Some(button_id));
+    }
+
+    #[test]
+    fn click_checkable_item() {
+        let (mut harness, menu_id) = open_menu();
+        let area_id = harness.get_widget(AREA).id();
+        let row_id = harness.get_widget_with_id(menu_id).children()[3].id();
+
+        click_on(&mut harness, row_id);
+        assert_eq!(
+            harness.pop_action::<MenuItemSelected>(),
+            Some((
+                MenuItemSelected {
+                    path: vec![3],
+                    checked: Some(true),
+                },
+                area_id
+            ))
+        );
+        assert!(harness.try_get_widget(menu_id).is_none());
+    }
+
+    #[test]
+    fn click_disabled_item() {
+        let (mut harness, menu_id) = open_menu();
+        let row_id = harness.get_widget_with_id(menu_id).children()[1].id();
+
+        click_on(&mut harness, row_id);
+        assert!(harness.pop_action_erased().is_none());
+        assert!(harness.try_get_widget(menu_id).is_some());
+    }
+
+    #[test]
+    fn hover_opens_submenu() {
+        let (mut harness, menu_id) = open_menu();
+        let submenu_row_id = harness.get_widget_with_id(menu_id).children()[4].id();
+
+        harness.mouse_move_to_unchecked(submenu_row_id);
+        let menu = harness.get_widget_with_id(menu_id);
+        assert!(!menu.children()[5].ctx().is_stashed());
+        assert_eq!(highlighted_path(&harness, menu_id), None);
+
+        // Moving into the submenu highlights its items.
+        let first_row_id = menu.children()[5].id();
+        harness.mouse_move_to_unchecked(first_row_id);
+        assert_eq!(highlighted_path(&harness, menu_id), Some(vec![4, 0]));
+
+        // Moving back to a regular item closes the submenu.
+        let cut_row_id = harness.get_widget_with_id(menu_id).children()[0].id();
+        harness.mouse_move_to_unchecked(cut_row_id);
+        let menu = harness.get_widget_with_id(menu_id);
+        assert!(menu.children()[5].ctx().is_stashed());
+        assert_eq!(highlighted_path(&harness, menu_id), Some(vec![0]));
+    }
+
+    #[test]
+    fn set_items_with_submenu() {
+        let (mut harness, menu_id) = open_menu();
+        harness.edit_widget_with_id(menu_id, |mut menu| {
+            let mut menu = menu.downcast::<Menu>();
+            Menu::set_items(
+                &mut menu,
+                [
+                    MenuItem::new("Undo"),
+                    MenuItem::submenu(
+                        "Nested",
+                        [
+                            MenuItem::new("Inner"),
+                            MenuItem::submenu("Deeper", [MenuItem::new("Deepest")]),
+                        ],
+                    ),
+                ],
+            );
+        });
+        let _ = harness.render();
+
+        {
+            let menu = harness.get_widget_with_id(menu_id);
+            let children = menu.children();
+            assert_eq!(children.len(), 5);
+            assert!(!children[1].ctx().is_stashed());
+            assert!(children[2].ctx().is_stashed());
+            assert!(children[4].ctx().is_stashed());
+        }
+
+        // Open both submenus.
+        key(&mut harness, NamedKey::ArrowDown);
+        key(&mut harness, NamedKey::ArrowDown);
+        key(&mut harness, NamedKey::ArrowRight);
+        key(&mut harness, NamedKey::ArrowDown);
+        key(&mut harness, NamedKey::ArrowRight);
+        let _ = harness.render();
+        assert_eq!(highlighted_path(&harness, menu_id), Some(vec![1, 1, 0]));
+        let menu = harness.get_widget_with_id(menu_id);
+        assert!(!menu.children()[4].ctx().is_stashed());
+    }
+
+    #[test]
+    fn click_outside_dismisses() {
+        let (mut harness, menu_id) = open_menu();
+
+        harness.mouse_move((390., 390.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert!(harness.try_get_widget(menu_id).is_none());
+        assert!(harness.pop_action_erased().is_none());
+    }
+
+    #[test]
+    fn escape_dismisses() {
+        let (mut harness, menu_id) = open_menu();
+        let button_id = harness.get_widget(BUTTON).id();
+
+        key(&mut harness, NamedKey::Escape);
+        assert!(harness.try_get_widget(menu_id).is_none());
+        assert_eq!(harness.focused_widget_id(), Some(button_id));
+    }
+}
@@ -3,6 +3,8 @@
 
 //! A list of widgets implementing the [`Layer`](crate::core::Layer) trait.
 
+mod menu;
 mod tooltip;
 
+pub use menu::*;
 pub use tooltip::*;
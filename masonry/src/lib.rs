@@ -82,11 +82,8 @@
 //!             });
 //!         } else if action.is::<TextAction>() {
 //!             let action = action.downcast::<TextAction>().unwrap();
-//!             match *action {
-//!                 TextAction::Changed(new_text) => {
-//!                     self.next_task = new_text.clone();
-//!                 }
-//!                 TextAction::Entered(_) => {}
+//!             if let TextAction::Changed(new_text) = *action {
+//!                 self.next_task = new_text.clone();
 //!             }
 //!         }
 //!     }
@@ -222,6 +219,6 @@ pub use vello::peniko::color::palette;
 pub use vello::{kurbo, peniko};
 pub use {dpi, parley, vello};
 
-pub use masonry_core::{app, core, layout, ui_events, util};
+pub use masonry_core::{animation, app, core, layout, ui_events, util};
 #[cfg(any(feature = "testing", test))]
 pub use masonry_testing as testing;
@@ -17,6 +17,12 @@ use crate::layout::Length;
 /// As such, using a both a gap and a spacer may lead to counter-intuitive results.
 /// You should usually pick one or the other.
 ///
+/// ## Relative lengths
+///
+/// Font-relative and viewport-relative gaps are supported.
+/// Percentages are relative to the container's content-box length on the axis of the gap.
+/// Like in CSS, they are treated as zero while that length is being measured.
+///
 /// [gap]: https://developer.mozilla.org/en-US/docs/Web/CSS/gap
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Gap {
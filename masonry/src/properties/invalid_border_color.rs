@@ -0,0 +1,32 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::core::Property;
+use crate::peniko::color::AlphaColor;
+use crate::properties::BorderColor;
+
+/// The color of a widget's border when its content is invalid.
+///
+/// This is used by [`TextInput`] when it is [marked as invalid](crate::widgets::TextInput::set_invalid),
+/// and takes precedence over [`FocusedBorderColor`](crate::properties::FocusedBorderColor).
+///
+/// [`TextInput`]: crate::widgets::TextInput
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InvalidBorderColor(pub BorderColor);
+
+impl Property for InvalidBorderColor {
+    fn static_default() -> &'static Self {
+        static DEFAULT: InvalidBorderColor = InvalidBorderColor(BorderColor {
+            color: AlphaColor::TRANSPARENT,
+        });
+        &DEFAULT
+    }
+}
+
+// ---
+
+impl Default for InvalidBorderColor {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
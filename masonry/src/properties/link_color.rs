@@ -0,0 +1,48 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use crate::core::{Property, UpdateCtx};
+use crate::peniko::color::{AlphaColor, Srgb};
+
+/// The color of the links in a widget's [rich text](crate::core::RichText).
+#[expect(missing_docs, reason = "field names are self-descriptive")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinkColor {
+    pub color: AlphaColor<Srgb>,
+}
+
+impl Property for LinkColor {
+    fn static_default() -> &'static Self {
+        static DEFAULT: LinkColor = LinkColor {
+            color: AlphaColor::from_rgb8(0x3b, 0x7e, 0xe4),
+        };
+        &DEFAULT
+    }
+}
+
+impl LinkColor {
+    /// Creates new `LinkColor` with given value.
+    pub const fn new(color: AlphaColor<Srgb>) -> Self {
+        Self { color }
+    }
+}
+
+// ---
+
+impl Default for LinkColor {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl LinkColor {
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_paint_only();
+    }
+}
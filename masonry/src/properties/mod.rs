@@ -8,7 +8,9 @@
 mod checkmark;
 mod content_color;
 mod gap;
+mod invalid_border_color;
 mod line_breaking;
+mod link_color;
 mod object_fit;
 mod placeholder_color;
 mod progress_bar;
@@ -21,7 +23,9 @@ pub mod types;
 pub use checkmark::*;
 pub use content_color::*;
 pub use gap::*;
+pub use invalid_border_color::*;
 pub use line_breaking::*;
+pub use link_color::*;
 pub use object_fit::*;
 pub use placeholder_color::*;
 pub use progress_bar::*;
@@ -63,6 +63,45 @@ impl Property for UnfocusedSelectionColor {
     }
 }
 
+/// The background color of a [`TextArea`]'s search highlights.
+///
+/// Need to contrast the [`ContentColor`].
+///
+/// [`ContentColor`]: crate::properties::ContentColor
+/// [`TextArea`]: crate::widgets::TextArea
+#[expect(missing_docs, reason = "field names are self-descriptive")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HighlightColor {
+    pub color: AlphaColor<Srgb>,
+}
+
+impl Property for HighlightColor {
+    fn static_default() -> &'static Self {
+        static DEFAULT: HighlightColor = HighlightColor {
+            color: AlphaColor::from_rgb8(255, 230, 110),
+        };
+        &DEFAULT
+    }
+}
+
+/// The background color of a [`TextArea`]'s active search highlight.
+///
+/// Need to contrast the [`ContentColor`] and the [`HighlightColor`].
+///
+/// [`ContentColor`]: crate::properties::ContentColor
+/// [`TextArea`]: crate::widgets::TextArea
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ActiveHighlightColor(pub HighlightColor);
+
+impl Property for ActiveHighlightColor {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ActiveHighlightColor = ActiveHighlightColor(HighlightColor {
+            color: AlphaColor::from_rgb8(255, 150, 50),
+        });
+        &DEFAULT
+    }
+}
+
 // ---
 
 impl Default for CaretColor {
@@ -116,3 +155,39 @@ impl UnfocusedSelectionColor {
         ctx.request_paint_only();
     }
 }
+
+// ---
+
+impl Default for HighlightColor {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl HighlightColor {
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_paint_only();
+    }
+}
+
+// ---
+
+impl Default for ActiveHighlightColor {
+    fn default() -> Self {
+        *Self::static_default()
+    }
+}
+
+impl ActiveHighlightColor {
+    /// Helper function to be called in [`Widget::property_changed`](crate::core::Widget::property_changed).
+    pub fn prop_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        if property_type != TypeId::of::<Self>() {
+            return;
+        }
+        ctx.request_paint_only();
+    }
+}
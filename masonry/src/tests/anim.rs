@@ -4,8 +4,10 @@
 use assert_matches::assert_matches;
 use masonry_testing::{ModularWidget, Record, TestHarness, TestWidgetExt, assert_any, assert_none};
 
+use crate::animation::{Animation, AnimationStatus, Easing, Spring, Tween};
 use crate::core::{NewWidget, WidgetTag};
 use crate::theme::test_property_set;
+use crate::util::Duration;
 use crate::widgets::SizedBox;
 
 #[test]
@@ -36,3 +38,104 @@ fn needs_anim_flag() {
     // We didn't re-request an animation, so nothing should happen.
     assert_matches!(harness.take_records_of(parent_tag)[..], []);
 }
+
+#[test]
+fn animate_requests_frames_until_completed() {
+    let target_tag = WidgetTag::named("target");
+    let tween = Tween::stable(0.).with_easing(Easing::Linear);
+    let widget = ModularWidget::new(tween)
+        .anim_frame_fn(|tween, ctx, _props, interval| {
+            ctx.animate(tween, interval);
+        })
+        .record();
+    let mut harness = TestHarness::create(
+        test_property_set(),
+        NewWidget::new_with_tag(widget, target_tag),
+    );
+    harness.flush_records_of(target_tag);
+
+    harness.edit_widget(target_tag, |mut widget| {
+        let tween = &mut widget.widget.inner_mut().state;
+        tween.move_to(100., Duration::from_millis(100));
+        widget.ctx.request_anim_frame();
+    });
+
+    harness.animate_ms(40);
+    assert_eq!(
+        harness.get_widget(target_tag).inner().inner().state.value(),
+        40.
+    );
+    harness.animate_ms(40);
+    assert_eq!(
+        harness.get_widget(target_tag).inner().inner().state.value(),
+        80.
+    );
+    harness.animate_ms(40);
+    assert_eq!(
+        harness.get_widget(target_tag).inner().inner().state.value(),
+        100.
+    );
+    harness.flush_records_of(target_tag);
+
+    // The tween completed, so no other frame was requested.
+    harness.animate_ms(40);
+    assert_none(harness.take_records_of(target_tag), |r| {
+        matches!(r, Record::AnimFrame(_))
+    });
+}
+
+/// Advances `spring` by 60fps frames until it settles, returning the number of frames it took.
+fn settle(spring: &mut Spring, target: f64) -> usize {
+    let amplitude = (spring.value() - target).abs();
+    for frame in 1..=1000 {
+        let status = spring.advance(16_666_667);
+        assert!(
+            (spring.value() - target).abs() <= amplitude,
+            "spring diverged to {} on frame {frame}",
+            spring.value()
+        );
+        if status == AnimationStatus::Completed {
+            return frame;
+        }
+    }
+    panic!("spring didn't settle");
+}
+
+#[test]
+fn spring_settles_at_target() {
+    for damping in [10., Spring::DEFAULT_DAMPING, 2. * 170_f64.sqrt(), 100.] {
+        let mut spring = Spring::stable(0.).with_damping(damping);
+        spring.move_to(100.);
+        settle(&mut spring, 100.);
+        assert_eq!(spring.value(), 100.);
+        assert_eq!(spring.velocity(), 0.);
+    }
+}
+
+#[test]
+fn stiff_spring_is_stable() {
+    // Springs this stiff would make explicit integration with millisecond steps diverge.
+    for (stiffness, damping) in [(1e9, 2e4), (1e9, 2. * 1e9_f64.sqrt()), (1e9, 1e6)] {
+        let mut spring = Spring::stable(0.)
+            .with_stiffness(stiffness)
+            .with_damping(damping)
+            .with_mass(1e-2);
+        spring.move_to(100.);
+        settle(&mut spring, 100.);
+        assert_eq!(spring.value(), 100.);
+    }
+}
+
+#[test]
+fn spring_is_independent_of_frame_rate() {
+    let mut coarse = Spring::stable(0.);
+    coarse.move_to(100.);
+    let mut fine = coarse.clone();
+
+    coarse.advance(100_000_000);
+    for _ in 0..100 {
+        fine.advance(1_000_000);
+    }
+    assert!((coarse.value() - fine.value()).abs() < 1e-9);
+    assert!((coarse.velocity() - fine.velocity()).abs() < 1e-9);
+}
@@ -1,6 +1,9 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use accesskit::ActionRequest;
 use assert_matches::assert_matches;
 use dpi::PhysicalPosition;
@@ -8,8 +11,8 @@ use dpi::PhysicalPosition;
 use crate::core::keyboard::{Key, NamedKey};
 use crate::core::pointer::{PointerButton, PointerEvent, PointerInfo, PointerType};
 use crate::core::{
-    AccessEvent, NewWidget, PointerButtonEvent, PointerId, PointerState, PointerUpdate, TextEvent,
-    Update, Widget, WidgetId, WidgetTag,
+    AccessEvent, ClipboardData, DragEvent, NewWidget, PointerButtonEvent, PointerId, PointerState,
+    PointerUpdate, TextEvent, Update, Widget, WidgetId, WidgetTag,
 };
 use crate::kurbo::Point;
 use crate::layout::AsUnit;
@@ -75,6 +78,57 @@ fn pointer_event_bubbling() {
     assert_any(harness.take_records_of(grandparent_tag), is_pointer_down);
 }
 
+#[test]
+fn selection_scope_is_closest_scope_on_press() {
+    let leaf_tag = WidgetTag::named("leaf");
+    let inner_tag = WidgetTag::named("inner");
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    let leaf = {
+        let (seen, text_seen) = (seen.clone(), seen.clone());
+        ModularWidget::new(())
+            .accepts_focus(true)
+            .measure_fn(|_, _, _, _, _, _| 10.)
+            .pointer_event_fn(move |_, ctx, _, event| {
+                let kind = match event {
+                    PointerEvent::Down(..) => "down",
+                    PointerEvent::Move(..) => "move",
+                    PointerEvent::Up(..) => "up",
+                    _ => return,
+                };
+                seen.borrow_mut().push((kind, ctx.selection_scope()));
+            })
+            .text_event_fn(move |_, ctx, _, _| {
+                text_seen.borrow_mut().push(("text", ctx.selection_scope()));
+            })
+    };
+    let leaf = NewWidget::new_with_tag(leaf, leaf_tag);
+    let inner = NewWidget::new_with_tag(
+        ModularWidget::new_parent(leaf).is_selection_scope(true),
+        inner_tag,
+    );
+    let middle = NewWidget::new(ModularWidget::new_parent(inner));
+    let outer = NewWidget::new(ModularWidget::new_parent(middle).is_selection_scope(true));
+
+    let mut harness = TestHarness::create(test_property_set(), outer);
+    let leaf_id = harness.get_widget(leaf_tag).id();
+    let inner_id = harness.get_widget(inner_tag).id();
+
+    // Only presses, which can start a selection, find the closest scope.
+    harness.mouse_move_to(leaf_id);
+    harness.mouse_button_press(PointerButton::Primary);
+    harness.mouse_button_release(PointerButton::Primary);
+    assert_eq!(
+        seen.take(),
+        [("move", None), ("down", Some(inner_id)), ("up", None)]
+    );
+
+    // The scope isn't computed for the events sent to the focused widget either.
+    harness.focus_on(Some(leaf_id));
+    harness.keyboard_type_chars("a");
+    assert!(seen.take().iter().all(|(_, scope)| scope.is_none()));
+}
+
 #[test]
 fn pointer_capture_and_cancel() {
     let target_tag = WidgetTag::named("target");
@@ -481,6 +535,155 @@ fn multi_pointers_capture() {
     assert_matches!(harness.pop_action::<ButtonPress>(), Some((_, _)));
 }
 
+// DRAG AND DROP
+
+/// Creates a widget which starts a drag carrying `payload` when the pointer is pressed
+/// on it and then moves.
+fn create_drag_source(payload: u32, preview_tag: WidgetTag<SizedBox>) -> ModularWidget<()> {
+    ModularWidget::new(())
+        .pointer_event_fn(move |_, ctx, _, event| match event {
+            PointerEvent::Down { .. } => ctx.capture_pointer(),
+            PointerEvent::Move(_) if ctx.is_active() && !ctx.is_dragging() => {
+                let preview = SizedBox::empty().size(20.px(), 20.px());
+                ctx.start_drag(payload, NewWidget::new_with_tag(preview, preview_tag));
+            }
+            _ => {}
+        })
+        .measure_fn(|_, _, _, _, _, _| 40.)
+}
+
+/// Creates a widget which accepts dropped `u32` payloads, and stores the last one.
+fn create_drop_target() -> ModularWidget<Option<u32>> {
+    ModularWidget::new(None)
+        .accepts_drop(true)
+        .drag_event_fn(|dropped, ctx, _, event| {
+            if let DragEvent::Drop(_) = event
+                && let Some(payload) = ctx.drag_payload::<u32>()
+            {
+                *dropped = Some(*payload);
+                ctx.set_handled();
+            }
+        })
+        .measure_fn(|_, _, _, _, _, _| 40.)
+}
+
+#[test]
+fn drag_and_drop() {
+    let source_tag = WidgetTag::named("source");
+    let target_tag = WidgetTag::named("target");
+    let preview_tag = WidgetTag::named("preview");
+
+    let source = NewWidget::new_with_tag(create_drag_source(42, preview_tag).record(), source_tag);
+    let target = NewWidget::new_with_tag(create_drop_target().record(), target_tag);
+    let parent = Flex::column()
+        .with_fixed(source)
+        .with_fixed(target)
+        .with_auto_id();
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let source_id = harness.get_widget(source_tag).id();
+    let target_id = harness.get_widget(target_tag).id();
+
+    harness.mouse_move_to(source_id);
+    harness.mouse_button_press(PointerButton::Primary);
+    harness.mouse_move_to_unchecked(target_id);
+
+    // The preview is shown on its own layer, which doesn't hide the target.
+    let preview_id = harness.get_widget(preview_tag).id();
+    let records = harness.take_records_of(target_tag);
+    assert_any(
+        records.clone(),
+        |r| matches!(r, Record::DragEvent(DragEvent::Enter(update)) if update.source == source_id),
+    );
+    assert_any(records, |r| {
+        matches!(r, Record::DragEvent(DragEvent::Over(_)))
+    });
+
+    harness.mouse_button_release(PointerButton::Primary);
+    assert_any(harness.take_records_of(target_tag), |r| {
+        matches!(r, Record::DragEvent(DragEvent::Drop(_)))
+    });
+    assert_any(harness.take_records_of(source_tag), |r| {
+        matches!(r, Record::DragEvent(DragEvent::Ended(true)))
+    });
+    assert_eq!(
+        harness.get_widget(target_tag).inner().inner().state,
+        Some(42)
+    );
+    assert!(harness.try_get_widget(preview_id).is_none());
+}
+
+#[test]
+fn drop_rejected() {
+    let source_tag = WidgetTag::named("source");
+    let target_tag = WidgetTag::named("target");
+    let preview_tag = WidgetTag::named("preview");
+
+    // The target only accepts `u32` payloads.
+    let source = NewWidget::new_with_tag(create_drag_source(42, preview_tag).record(), source_tag);
+    let target = ModularWidget::new(())
+        .accepts_drop(true)
+        .measure_fn(|_, _, _, _, _, _| 40.);
+    let target = NewWidget::new_with_tag(target.record(), target_tag);
+    let parent = Flex::column()
+        .with_fixed(source)
+        .with_fixed(target)
+        .with_auto_id();
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let source_id = harness.get_widget(source_tag).id();
+    let target_id = harness.get_widget(target_tag).id();
+
+    harness.mouse_drag_and_drop(source_id, target_id);
+    assert_any(harness.take_records_of(target_tag), |r| {
+        matches!(r, Record::DragEvent(DragEvent::Drop(_)))
+    });
+    assert_any(harness.take_records_of(source_tag), |r| {
+        matches!(r, Record::DragEvent(DragEvent::Ended(false)))
+    });
+}
+
+#[test]
+fn drag_leave_and_cancel() {
+    let source_tag = WidgetTag::named("source");
+    let target_tag = WidgetTag::named("target");
+    let preview_tag = WidgetTag::named("preview");
+
+    let source = NewWidget::new_with_tag(create_drag_source(42, preview_tag).record(), source_tag);
+    let target = NewWidget::new_with_tag(create_drop_target().record(), target_tag);
+    let parent = Flex::column()
+        .with_fixed(source)
+        .with_fixed(target)
+        .with_auto_id();
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let source_id = harness.get_widget(source_tag).id();
+    let target_id = harness.get_widget(target_tag).id();
+
+    harness.mouse_move_to(source_id);
+    harness.mouse_button_press(PointerButton::Primary);
+    harness.mouse_move_to_unchecked(target_id);
+    harness.mouse_move_to_unchecked(source_id);
+    assert_any(harness.take_records_of(target_tag), |r| {
+        matches!(r, Record::DragEvent(DragEvent::Leave))
+    });
+
+    // Escape cancels the drag, without dropping the item.
+    let preview_id = harness.get_widget(preview_tag).id();
+    harness.mouse_move_to_unchecked(target_id);
+    harness.process_text_event(TextEvent::key_down(Key::Named(NamedKey::Escape)));
+    assert_any(harness.take_records_of(target_tag), |r| {
+        matches!(r, Record::DragEvent(DragEvent::Leave))
+    });
+    assert_any(harness.take_records_of(source_tag), |r| {
+        matches!(r, Record::DragEvent(DragEvent::Ended(false)))
+    });
+    assert!(harness.try_get_widget(preview_id).is_none());
+
+    harness.mouse_button_release(PointerButton::Primary);
+    assert_eq!(harness.get_widget(target_tag).inner().inner().state, None);
+}
+
 // TEXT EVENTS
 
 #[test]
@@ -567,6 +770,154 @@ fn text_event_fallback() {
     assert_matches!(harness.take_records_of(target_tag)[..], []);
 }
 
+#[test]
+fn clipboard_read_and_store() {
+    let writer_tag = WidgetTag::named("writer");
+    let reader_tag = WidgetTag::named("reader");
+    let focused_tag = WidgetTag::named("focused");
+
+    let writer = ModularWidget::new(())
+        .pointer_event_fn(|_, ctx, _, event| {
+            if matches!(event, PointerEvent::Down { .. }) {
+                ctx.set_clipboard_data(
+                    ClipboardData::from_text("hello")
+                        .with_html("<b>hello</b>")
+                        .with_format("application/x-custom", vec![1, 2, 3]),
+                );
+            }
+        })
+        .measure_fn(|_, _, _, _, _, _| 20.);
+    let reader = ModularWidget::new(None::<ClipboardData>)
+        .pointer_event_fn(|_, ctx, _, event| {
+            if matches!(event, PointerEvent::Down { .. }) {
+                ctx.request_clipboard();
+            }
+        })
+        .text_event_fn(|data, _, _, event| {
+            if let TextEvent::ClipboardData(received) = event {
+                *data = Some(received.clone());
+            }
+        })
+        .measure_fn(|_, _, _, _, _, _| 20.);
+    let parent = Flex::column()
+        .with_fixed(NewWidget::new_with_tag(writer, writer_tag))
+        .with_fixed(NewWidget::new_with_tag(reader.record(), reader_tag))
+        .with_fixed(NewWidget::new_with_tag(
+            TextArea::new_editable("").record(),
+            focused_tag,
+        ))
+        .with_auto_id();
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let writer_id = harness.get_widget(writer_tag).id();
+    let reader_id = harness.get_widget(reader_tag).id();
+    let focused_id = harness.get_widget(focused_tag).id();
+    harness.focus_on(Some(focused_id));
+    harness.flush_records_of(focused_tag);
+
+    harness.mouse_click_on(writer_id);
+    assert_eq!(harness.clipboard_contents(), "hello");
+    assert_eq!(harness.clipboard_data().html(), Some("<b>hello</b>"));
+
+    // The data goes to the widget which asked for it, and only bubbles up from there.
+    harness.mouse_click_on(reader_id);
+    let received = harness.get_widget(reader_tag).inner().inner().state.clone();
+    assert_eq!(received.as_ref(), Some(harness.clipboard_data()));
+    assert_eq!(
+        received.unwrap().get("application/x-custom"),
+        Some(&[1, 2, 3][..])
+    );
+    assert_any(harness.take_records_of(reader_tag), |r| {
+        matches!(r, Record::TextEvent(TextEvent::ClipboardData(_)))
+    });
+    assert_none(harness.take_records_of(focused_tag), |r| {
+        matches!(r, Record::TextEvent(TextEvent::ClipboardData(_)))
+    });
+
+    // The harness can fake any format.
+    harness.set_clipboard_data(ClipboardData::new().with_files(["/tmp/a.txt".into()]));
+    assert_eq!(harness.clipboard_contents(), "");
+    harness.mouse_click_on(reader_id);
+    let received = harness.get_widget(reader_tag).inner().inner().state.clone();
+    assert_eq!(received.unwrap().files(), Some(vec!["/tmp/a.txt".into()]));
+}
+
+#[test]
+fn clipboard_file_and_html_round_trip() {
+    let files: Vec<std::path::PathBuf> = vec![
+        "/tmp/with space.txt".into(),
+        "/tmp/#hash/100%.txt".into(),
+        "/tmp/café.txt".into(),
+    ];
+    let html = "<p>Caf\u{e9} &amp; <a href=\"#top\">more</a></p>";
+
+    let writer_tag = WidgetTag::named("writer");
+    let reader_tag = WidgetTag::named("reader");
+    let data = ClipboardData::from_text("caf\u{e9}")
+        .with_html(html)
+        .with_files(files.clone());
+    let writer = ModularWidget::new(data)
+        .pointer_event_fn(|data, ctx, _, event| {
+            if matches!(event, PointerEvent::Down { .. }) {
+                ctx.set_clipboard_data(data.clone());
+            }
+        })
+        .measure_fn(|_, _, _, _, _, _| 20.);
+    let reader = ModularWidget::new(None::<ClipboardData>)
+        .pointer_event_fn(|_, ctx, _, event| {
+            if matches!(event, PointerEvent::Down { .. }) {
+                ctx.request_clipboard();
+            }
+        })
+        .text_event_fn(|data, _, _, event| {
+            if let TextEvent::ClipboardData(received) = event {
+                *data = Some(received.clone());
+            }
+        })
+        .measure_fn(|_, _, _, _, _, _| 20.);
+    let parent = Flex::column()
+        .with_fixed(NewWidget::new_with_tag(writer, writer_tag))
+        .with_fixed(NewWidget::new_with_tag(reader, reader_tag))
+        .with_auto_id();
+
+    let mut harness = TestHarness::create(test_property_set(), parent);
+    let writer_id = harness.get_widget(writer_tag).id();
+    let reader_id = harness.get_widget(reader_tag).id();
+    harness.mouse_click_on(writer_id);
+    harness.mouse_click_on(reader_id);
+    let received = harness
+        .get_widget(reader_tag)
+        .inner()
+        .state
+        .clone()
+        .unwrap();
+
+    assert_eq!(received.html(), Some(html));
+    assert_eq!(received.files(), Some(files));
+    // The files are stored as percent-encoded URIs.
+    assert_eq!(
+        std::str::from_utf8(received.get(ClipboardData::FILE_LIST).unwrap()).unwrap(),
+        "file:///tmp/with%20space.txt\r\nfile:///tmp/%23hash/100%25.txt\r\nfile:///tmp/caf%C3%A9.txt"
+    );
+
+    // Lists from other applications can have comments and URIs of other kinds.
+    let list = "# A comment\r\nfile://localhost/tmp/a%20b\r\nhttps://example.com/\r\nfile:/tmp/c";
+    harness.set_clipboard_data(
+        ClipboardData::new().with_format(ClipboardData::FILE_LIST, list.as_bytes()),
+    );
+    harness.mouse_click_on(reader_id);
+    let received = harness
+        .get_widget(reader_tag)
+        .inner()
+        .state
+        .clone()
+        .unwrap();
+    assert_eq!(
+        received.files(),
+        Some(vec!["/tmp/a b".into(), "/tmp/c".into()])
+    );
+}
+
 #[test]
 fn tab_focus() {
     let child_1 = WidgetTag::named("child_1");
@@ -3,13 +3,18 @@
 
 use assert_matches::assert_matches;
 
-use crate::core::{NewWidget, Widget, WidgetOptions, WidgetTag};
+use crate::core::{
+    DefaultProperties, NewWidget, StyleProperty, Widget, WidgetOptions, WidgetTag, WindowEvent,
+};
+use crate::dpi::PhysicalSize;
 use crate::kurbo::{Insets, Point, Rect, Size};
 use crate::layout::{AsUnit, Length, SizeDef};
-use crate::properties::{BorderWidth, Dimensions, Padding};
+use crate::properties::{BorderWidth, Dimensions, Gap, Padding};
 use crate::testing::{ModularWidget, TestHarness, TestWidgetExt, assert_debug_panics};
 use crate::theme::test_property_set;
-use crate::widgets::{Button, ChildAlignment, Flex, Portal, SizedBox, ZStack};
+use crate::widgets::{
+    Button, ChildAlignment, Divider, Flex, Grid, GridParams, Label, Portal, SizedBox, Split, ZStack,
+};
 
 #[test]
 fn layout_simple() {
@@ -322,3 +327,229 @@ fn content_box() {
     assert_eq!(border_box, expected_border_box);
     assert_eq!(content_box, expected_content_box);
 }
+
+#[test]
+fn font_relative_units() {
+    let label_tag = WidgetTag::named("label");
+    let box_tag = WidgetTag::named("box");
+
+    let label = NewWidget::new_with(
+        Label::new("Hello").with_style(StyleProperty::FontSize(20.)),
+        Some(label_tag),
+        WidgetOptions::default(),
+        Dimensions::fixed(Length::em(2.), Length::rem(1.)),
+    );
+    // The box doesn't have a font size, so it inherits the root font size through the Flex.
+    let sized_box = NewWidget::new_with(
+        SizedBox::empty(),
+        Some(box_tag),
+        WidgetOptions::default(),
+        Dimensions::fixed(Length::em(1.), Length::em(0.5)),
+    );
+    let widget = Flex::column()
+        .with_fixed(label)
+        .with_fixed(sized_box)
+        .with_auto_id();
+
+    let mut harness = TestHarness::create(test_property_set(), widget);
+
+    let label_size =
+        |harness: &TestHarness<_>| harness.get_widget(label_tag).ctx().border_box_size();
+    let box_size = |harness: &TestHarness<_>| harness.get_widget(box_tag).ctx().border_box_size();
+    assert_eq!(label_size(&harness), Size::new(40., 16.));
+    assert_eq!(box_size(&harness), Size::new(16., 8.));
+
+    harness.set_root_font_size(20.);
+    assert_eq!(label_size(&harness), Size::new(40., 20.));
+    assert_eq!(box_size(&harness), Size::new(20., 10.));
+}
+
+#[test]
+fn viewport_relative_units() {
+    let tag = WidgetTag::named("box");
+
+    let sized_box = NewWidget::new_with(
+        SizedBox::empty(),
+        Some(tag),
+        WidgetOptions::default(),
+        Dimensions::fixed(Length::vw(50.), Length::vh(25.)),
+    );
+    // The box is nested in a fixed size container,
+    // so that it only gets laid out again if it's marked as viewport-relative.
+    let container = NewWidget::new_with_props(
+        Flex::row().with_fixed(sized_box),
+        Dimensions::fixed(300.px(), 300.px()),
+    );
+    let widget = Flex::column().with_fixed(container).with_auto_id();
+
+    let mut harness =
+        TestHarness::create_with_size(test_property_set(), widget, Size::new(400., 200.));
+    assert_eq!(
+        harness.get_widget(tag).ctx().border_box_size(),
+        Size::new(200., 50.)
+    );
+
+    harness.process_window_event(WindowEvent::Resize(PhysicalSize::new(200, 100)));
+    assert_eq!(
+        harness.get_widget(tag).ctx().border_box_size(),
+        Size::new(100., 25.)
+    );
+}
+
+#[test]
+fn percent_units() {
+    let tag = WidgetTag::named("box");
+
+    let sized_box = NewWidget::new_with(
+        SizedBox::empty(),
+        Some(tag),
+        WidgetOptions::default(),
+        Dimensions::fixed(Length::percent(50.), Length::percent(10.)),
+    );
+    let widget = NewWidget::new_with_props(
+        ZStack::new().with(sized_box, ChildAlignment::ParentAligned),
+        Dimensions::fixed(200.px(), 100.px()),
+    );
+
+    let harness = TestHarness::create(test_property_set(), widget);
+    assert_eq!(
+        harness.get_widget(tag).ctx().border_box_size(),
+        Size::new(100., 10.)
+    );
+}
+
+#[test]
+fn flex_relative_lengths() {
+    let first_tag = WidgetTag::named("first");
+    let second_tag = WidgetTag::named("second");
+
+    let flex = Flex::row()
+        .with_fixed(NewWidget::new_with_tag(
+            SizedBox::empty().width(10.px()),
+            first_tag,
+        ))
+        .with_fixed_spacer(Length::em(1.))
+        .with_fixed(NewWidget::new_with_tag(
+            SizedBox::empty().width(10.px()),
+            second_tag,
+        ));
+    let widget = NewWidget::new_with_props(
+        flex,
+        (
+            Dimensions::fixed(200.px(), 100.px()),
+            Gap::new(Length::percent(10.)),
+        ),
+    );
+
+    let harness = TestHarness::create(DefaultProperties::new(), widget);
+
+    // Both gaps are 10% of the main length, and the spacer is one root font size.
+    let first_x = harness.get_widget(first_tag).ctx().window_origin().x;
+    let second_x = harness.get_widget(second_tag).ctx().window_origin().x;
+    assert_eq!(second_x - first_x, 10. + 20. + 16. + 20.);
+}
+
+#[test]
+fn grid_relative_gap() {
+    let tag = WidgetTag::named("cell");
+
+    let grid = Grid::with_dimensions(2, 2).with(
+        NewWidget::new_with_tag(SizedBox::empty(), tag),
+        GridParams::new(1, 1, 1, 1),
+    );
+    let widget = NewWidget::new_with_props(
+        grid,
+        (
+            Dimensions::fixed(200.px(), 100.px()),
+            Gap::new(Length::percent(10.)),
+        ),
+    );
+
+    let mut harness = TestHarness::create(DefaultProperties::new(), widget);
+
+    // The gap is 10% of the grid's width horizontally, and of its height vertically.
+    let cell = |harness: &TestHarness<_>| {
+        let widget = harness.get_widget(tag);
+        (widget.ctx().window_origin(), widget.ctx().border_box_size())
+    };
+    assert_eq!(cell(&harness), (Point::new(110., 55.), Size::new(90., 45.)));
+
+    harness.edit_root_widget(|mut grid| {
+        grid.insert_prop(Gap::new(Length::em(1.)));
+    });
+    assert_eq!(cell(&harness), (Point::new(108., 58.), Size::new(92., 42.)));
+}
+
+#[test]
+fn sized_box_relative_lengths() {
+    let tag = WidgetTag::named("box");
+
+    let sized_box = SizedBox::empty()
+        .width(Length::em(2.))
+        .height(Length::percent(50.));
+    let widget = NewWidget::new_with_props(
+        ZStack::new().with(
+            NewWidget::new_with_tag(sized_box, tag),
+            ChildAlignment::ParentAligned,
+        ),
+        Dimensions::fixed(200.px(), 100.px()),
+    );
+
+    let harness = TestHarness::create(DefaultProperties::new(), widget);
+    assert_eq!(
+        harness.get_widget(tag).ctx().border_box_size(),
+        Size::new(32., 50.)
+    );
+}
+
+#[test]
+fn split_relative_lengths() {
+    let first_tag = WidgetTag::named("first");
+    let second_tag = WidgetTag::named("second");
+
+    let split = Split::new(
+        NewWidget::new_with_tag(SizedBox::empty(), first_tag),
+        NewWidget::new_with_tag(SizedBox::empty(), second_tag),
+    )
+    .bar_thickness(Length::em(0.5))
+    .min_bar_area(Length::em(0.5))
+    .split_point_from_start(Length::percent(25.));
+    let widget = NewWidget::new_with_props(split, Dimensions::fixed(208.px(), 100.px()));
+
+    let mut harness = TestHarness::create(DefaultProperties::new(), widget);
+
+    // The bar is 8px thick, leaving 200px to split between the children.
+    let first_width =
+        |harness: &TestHarness<_>| harness.get_widget(first_tag).ctx().border_box_size().width;
+    assert_eq!(first_width(&harness), 50.);
+    assert_eq!(harness.get_widget(second_tag).ctx().window_origin().x, 58.);
+
+    harness.edit_root_widget(|mut split| {
+        Split::set_min_lengths(&mut split, Length::em(4.), Length::ZERO);
+    });
+    assert_eq!(first_width(&harness), 64.);
+}
+
+#[test]
+fn divider_relative_thickness() {
+    let em_tag = WidgetTag::named("em");
+    let percent_tag = WidgetTag::named("percent");
+
+    let flex = Flex::column()
+        .with_fixed(NewWidget::new_with_tag(
+            Divider::horizontal().thickness(Length::em(0.25)),
+            em_tag,
+        ))
+        .with_fixed(NewWidget::new_with_tag(
+            Divider::horizontal().thickness(Length::percent(2.)),
+            percent_tag,
+        ));
+    let widget = NewWidget::new_with_props(flex, Dimensions::fixed(200.px(), 100.px()));
+
+    let harness = TestHarness::create(DefaultProperties::new(), widget);
+
+    let height = |tag: WidgetTag<Divider>| harness.get_widget(tag).ctx().border_box_size().height;
+    assert_eq!(height(em_tag), 4.);
+    // The percentage is relative to the divider's length.
+    assert_eq!(height(percent_tag), 4.);
+}
@@ -12,5 +12,8 @@ mod event;
 mod layout;
 mod mutate;
 mod paint;
+mod style;
+mod theme;
+mod transition;
 mod update;
 mod widget_tag;
@@ -0,0 +1,154 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use masonry_testing::{ModularWidget, Record, Recorder, TestHarness, TestWidgetExt};
+
+use crate::core::{
+    DefaultProperties, NewWidget, Selector, StyleClasses, Widget, WidgetOptions, WidgetTag,
+};
+use crate::palette::css::{BLUE, GREEN, RED, YELLOW};
+use crate::properties::{Background, CornerRadius};
+use crate::theme::test_property_set;
+use crate::widgets::Flex;
+
+type Target = Recorder<ModularWidget<()>>;
+
+fn target() -> Target {
+    ModularWidget::new(())
+        .accepts_pointer_interaction(true)
+        .record()
+}
+
+fn stylesheet() -> DefaultProperties {
+    let mut properties = test_property_set();
+    properties.insert::<Target, _>(Background::Color(RED));
+    properties.insert_rule::<Target, _>(Selector::class("primary"), Background::Color(BLUE));
+    properties.insert_rule::<Target, _>(Selector::new().hovered(), Background::Color(GREEN));
+    properties.insert_rule::<Target, _>(
+        Selector::class("primary").hovered(),
+        Background::Color(YELLOW),
+    );
+    properties
+}
+
+fn background(harness: &TestHarness<impl Widget>, tag: WidgetTag<Target>) -> Background {
+    harness.get_widget(tag).get_prop::<Background>().clone()
+}
+
+fn changed_background(records: &[Record]) -> bool {
+    records
+        .iter()
+        .any(|r| matches!(r, Record::PropertyChange(id) if *id == TypeId::of::<Background>()))
+}
+
+#[test]
+fn class_rules() {
+    let plain_tag = WidgetTag::named("plain");
+    let primary_tag = WidgetTag::named("primary");
+    let plain = NewWidget::new_with_tag(target(), plain_tag);
+    let primary = NewWidget::new_with(
+        target(),
+        Some(primary_tag),
+        WidgetOptions::default(),
+        StyleClasses::from("primary"),
+    );
+    let root = Flex::row().with_fixed(plain).with_fixed(primary);
+
+    let harness = TestHarness::create(stylesheet(), NewWidget::new(root));
+    assert_eq!(background(&harness, plain_tag), Background::Color(RED));
+    assert_eq!(background(&harness, primary_tag), Background::Color(BLUE));
+}
+
+#[test]
+fn local_properties_beat_rules() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with(
+        target(),
+        Some(target_tag),
+        WidgetOptions::default(),
+        (
+            StyleClasses::from("primary"),
+            Background::Color(GREEN),
+            CornerRadius { radius: 2. },
+        ),
+    );
+
+    let mut properties = stylesheet();
+    properties.insert_rule::<Target, _>(Selector::class("primary"), CornerRadius { radius: 8. });
+    let harness = TestHarness::create(properties, target);
+    assert_eq!(background(&harness, target_tag), Background::Color(GREEN));
+    assert_eq!(
+        harness
+            .get_widget(target_tag)
+            .get_prop::<CornerRadius>()
+            .radius,
+        2.
+    );
+}
+
+#[test]
+fn state_rules() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with_tag(target(), target_tag);
+
+    let mut harness = TestHarness::create(stylesheet(), target);
+    let _ = harness.render();
+    harness.flush_records_of(target_tag);
+    assert_eq!(background(&harness, target_tag), Background::Color(RED));
+
+    // Hovering makes the hovered rule apply, and notifies the widget.
+    let target_id = harness.get_widget(target_tag).id();
+    harness.mouse_move_to(target_id);
+    assert_eq!(background(&harness, target_tag), Background::Color(GREEN));
+    assert!(changed_background(&harness.take_records_of(target_tag)));
+
+    // The most specific rule wins.
+    harness.edit_widget(target_tag, |mut widget| {
+        widget.insert_prop(StyleClasses::from("primary"));
+    });
+    assert_eq!(background(&harness, target_tag), Background::Color(YELLOW));
+
+    harness.mouse_move((-10., -10.));
+    assert_eq!(background(&harness, target_tag), Background::Color(BLUE));
+    assert!(changed_background(&harness.take_records_of(target_tag)));
+}
+
+#[test]
+fn class_changes_notify_widget() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with_tag(target(), target_tag);
+
+    let mut harness = TestHarness::create(stylesheet(), target);
+    let _ = harness.render();
+    harness.flush_records_of(target_tag);
+
+    harness.edit_widget(target_tag, |mut widget| {
+        widget.insert_prop(StyleClasses::from("primary"));
+    });
+    assert_eq!(background(&harness, target_tag), Background::Color(BLUE));
+    assert!(changed_background(&harness.take_records_of(target_tag)));
+
+    harness.edit_widget(target_tag, |mut widget| {
+        widget.remove_prop::<StyleClasses>();
+    });
+    assert_eq!(background(&harness, target_tag), Background::Color(RED));
+    assert!(changed_background(&harness.take_records_of(target_tag)));
+}
+
+#[test]
+fn later_rules_win() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with(
+        target(),
+        Some(target_tag),
+        WidgetOptions::default(),
+        StyleClasses::from(["primary", "danger"]),
+    );
+
+    let mut properties = stylesheet();
+    properties.insert_rule::<Target, _>(Selector::class("danger"), Background::Color(GREEN));
+    let harness = TestHarness::create(properties, target);
+    assert_eq!(background(&harness, target_tag), Background::Color(GREEN));
+}
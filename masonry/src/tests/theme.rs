@@ -0,0 +1,130 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use assert_matches::assert_matches;
+
+use masonry_testing::{Record, Recorder, TestHarness, TestWidgetExt};
+
+use crate::app::ThemeProperties;
+use crate::core::{DefaultProperties, NewWidget, WidgetTag, WindowEvent, WindowTheme};
+use crate::palette::css::{BLUE, RED};
+use crate::properties::Background;
+use crate::theme::{Theme, test_property_set};
+use crate::widgets::SizedBox;
+
+fn background_props(background: Background) -> DefaultProperties {
+    let mut properties = test_property_set();
+    properties.insert::<Recorder<SizedBox>, _>(background);
+    properties
+}
+
+fn theme_properties() -> ThemeProperties {
+    ThemeProperties {
+        light: Arc::new(background_props(Background::Color(RED))),
+        dark: Arc::new(background_props(Background::Color(BLUE))),
+    }
+}
+
+#[test]
+fn swap_default_properties() {
+    let target_tag = WidgetTag::named("target");
+    let widget = NewWidget::new_with_tag(SizedBox::empty().record(), target_tag);
+
+    let mut harness = TestHarness::create(background_props(Background::Color(RED)), widget);
+    let _ = harness.render();
+    harness.flush_records_of(target_tag);
+    assert_eq!(
+        harness.get_widget(target_tag).get_prop::<Background>(),
+        &Background::Color(RED)
+    );
+
+    harness.set_default_properties(background_props(Background::Color(BLUE)));
+    let _ = harness.render();
+    assert_eq!(
+        harness.get_widget(target_tag).get_prop::<Background>(),
+        &Background::Color(BLUE)
+    );
+
+    // Every widget is laid out and painted again.
+    let records = harness.take_records_of(target_tag);
+    assert!(records.iter().any(|r| matches!(r, Record::Layout(_))));
+    assert!(records.iter().any(|r| matches!(r, Record::Paint)));
+}
+
+#[test]
+fn follow_window_theme() {
+    let target_tag = WidgetTag::named("target");
+    let widget = NewWidget::new_with_tag(SizedBox::empty().record(), target_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), widget);
+    harness.set_theme_properties(Some(theme_properties()));
+
+    harness.process_window_event(WindowEvent::ThemeChanged(WindowTheme::Light));
+    assert_eq!(
+        harness.get_widget(target_tag).get_prop::<Background>(),
+        &Background::Color(RED)
+    );
+
+    harness.process_window_event(WindowEvent::ThemeChanged(WindowTheme::Dark));
+    let _ = harness.render();
+    harness.flush_records_of(target_tag);
+    assert_eq!(
+        harness.get_widget(target_tag).get_prop::<Background>(),
+        &Background::Color(BLUE)
+    );
+
+    // Receiving the same theme again doesn't invalidate anything.
+    harness.process_window_event(WindowEvent::ThemeChanged(WindowTheme::Dark));
+    let _ = harness.render();
+    assert_matches!(harness.take_records_of(target_tag)[..], []);
+}
+
+#[test]
+fn theme_applied_when_opting_in() {
+    let target_tag = WidgetTag::named("target");
+    let widget = NewWidget::new_with_tag(SizedBox::empty().record(), target_tag);
+
+    let mut harness = TestHarness::create(test_property_set(), widget);
+    harness.process_window_event(WindowEvent::ThemeChanged(WindowTheme::Light));
+
+    // The window theme is already known, so it's applied immediately.
+    harness.set_theme_properties(Some(theme_properties()));
+    assert_eq!(
+        harness.get_widget(target_tag).get_prop::<Background>(),
+        &Background::Color(RED)
+    );
+}
+
+#[test]
+fn theme_changes_ignored_without_opt_in() {
+    let target_tag = WidgetTag::named("target");
+    let widget = NewWidget::new_with_tag(SizedBox::empty().record(), target_tag);
+
+    let mut harness = TestHarness::create(background_props(Background::Color(RED)), widget);
+    harness.process_window_event(WindowEvent::ThemeChanged(WindowTheme::Dark));
+    assert_eq!(
+        harness.get_widget(target_tag).get_prop::<Background>(),
+        &Background::Color(RED)
+    );
+
+    // Opting out keeps the current properties.
+    harness.set_theme_properties(Some(theme_properties()));
+    harness.set_theme_properties(None);
+    harness.process_window_event(WindowEvent::ThemeChanged(WindowTheme::Light));
+    assert_eq!(
+        harness.get_widget(target_tag).get_prop::<Background>(),
+        &Background::Color(BLUE)
+    );
+}
+
+#[test]
+fn shipped_themes() {
+    let light = Theme::light();
+    let dark = Theme::for_window_theme(WindowTheme::Dark);
+    assert_eq!(light.window_theme, WindowTheme::Light);
+    assert_eq!(dark.window_theme, WindowTheme::Dark);
+    assert_ne!(light.background_color, dark.background_color);
+    assert_eq!(dark.background_color, crate::theme::BACKGROUND_COLOR);
+}
@@ -0,0 +1,119 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use masonry_testing::{ModularWidget, TestHarness};
+
+use crate::animation::{Easing, Interpolate};
+use crate::core::{
+    DefaultProperties, NewWidget, PrePaintProps, Selector, Widget, WidgetOptions, WidgetTag,
+    pre_paint,
+};
+use crate::palette::css::{BLUE, GREEN, RED};
+use crate::properties::{Background, CornerRadius, Transition, Transitions};
+use crate::theme::test_property_set;
+use crate::util::Duration;
+
+/// A widget which stores the background it last painted.
+type Target = ModularWidget<Option<Background>>;
+
+fn target() -> Target {
+    ModularWidget::new(None)
+        .accepts_pointer_interaction(true)
+        .pre_paint_fn(|painted, ctx, props, scene| {
+            let p = PrePaintProps::fetch(ctx, props);
+            *painted = Some(p.background.into_owned());
+            pre_paint(ctx, props, scene);
+        })
+}
+
+fn linear(ms: u64) -> Transitions {
+    Transitions::new()
+        .with::<Background>(Transition::new(Duration::from_millis(ms), Easing::Linear))
+}
+
+fn painted(harness: &mut TestHarness<impl Widget>, tag: WidgetTag<Target>) -> Background {
+    let _ = harness.render();
+    harness.get_widget(tag).inner().state.clone().unwrap()
+}
+
+#[test]
+fn transition_on_property_change() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with(
+        target(),
+        Some(target_tag),
+        WidgetOptions::default(),
+        (Background::Color(RED), linear(100)),
+    );
+
+    let mut harness = TestHarness::create(test_property_set(), target);
+    assert_eq!(painted(&mut harness, target_tag), Background::Color(RED));
+
+    harness.edit_widget(target_tag, |mut target| {
+        target.insert_prop(Background::Color(BLUE));
+    });
+    assert_eq!(painted(&mut harness, target_tag), Background::Color(RED));
+
+    harness.animate_ms(25);
+    assert_eq!(
+        painted(&mut harness, target_tag),
+        Background::Color(RED.interpolate(&BLUE, 0.25))
+    );
+
+    harness.animate_ms(75);
+    assert_eq!(painted(&mut harness, target_tag), Background::Color(BLUE));
+}
+
+#[test]
+fn transition_on_state_change() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with_tag(target(), target_tag);
+
+    let mut properties = test_property_set();
+    properties.insert::<Target, _>(linear(100));
+    properties.insert::<Target, _>(Background::Color(RED));
+    properties.insert_rule::<Target, _>(Selector::new().hovered(), Background::Color(GREEN));
+
+    let mut harness = TestHarness::create(properties, target);
+    assert_eq!(painted(&mut harness, target_tag), Background::Color(RED));
+
+    let target_id = harness.get_widget(target_tag).id();
+    harness.mouse_move_to(target_id);
+    assert_eq!(painted(&mut harness, target_tag), Background::Color(RED));
+    harness.animate_ms(50);
+    assert_eq!(
+        painted(&mut harness, target_tag),
+        Background::Color(RED.interpolate(&GREEN, 0.5))
+    );
+
+    // Leaving mid-transition starts a new transition from the current value.
+    harness.mouse_move((-10., -10.));
+    assert_eq!(
+        painted(&mut harness, target_tag),
+        Background::Color(RED.interpolate(&GREEN, 0.5))
+    );
+    harness.animate_ms(100);
+    assert_eq!(painted(&mut harness, target_tag), Background::Color(RED));
+}
+
+#[test]
+fn no_transition_without_property() {
+    let target_tag = WidgetTag::named("target");
+    let target = NewWidget::new_with(
+        target(),
+        Some(target_tag),
+        WidgetOptions::default(),
+        (
+            Background::Color(RED),
+            Transitions::new().with::<CornerRadius>(Transition::ms(100)),
+        ),
+    );
+
+    let mut harness = TestHarness::create(DefaultProperties::new(), target);
+    let _ = harness.render();
+
+    harness.edit_widget(target_tag, |mut target| {
+        target.insert_prop(Background::Color(BLUE));
+    });
+    assert_eq!(painted(&mut harness, target_tag), Background::Color(BLUE));
+}
@@ -5,26 +5,35 @@
 
 #![allow(missing_docs, reason = "Names are self-explanatory.")]
 
+use std::sync::Arc;
+
 use parley::{GenericFamily, LineHeight};
 
-use crate::core::{DefaultProperties, StyleProperty, StyleSet};
+use crate::app::ThemeProperties;
+use crate::core::{DefaultProperties, StyleProperty, StyleSet, WindowTheme};
+use crate::layers::Menu;
 use crate::layout::Length;
 use crate::peniko::Color;
 use crate::properties::{
-    ActiveBackground, Background, BarColor, BorderColor, BorderWidth, CaretColor, CheckmarkColor,
-    CheckmarkStrokeWidth, ContentColor, CornerRadius, DisabledBackground, DisabledCheckmarkColor,
-    DisabledContentColor, FocusedBorderColor, Gap, HoveredBorderColor, Padding, PlaceholderColor,
+    ActiveBackground, ActiveHighlightColor, Background, BarColor, BorderColor, BorderWidth,
+    CaretColor, CheckmarkColor, CheckmarkStrokeWidth, ContentColor, CornerRadius,
+    DisabledBackground, DisabledCheckmarkColor, DisabledContentColor, FocusedBorderColor, Gap,
+    HighlightColor, HoveredBorderColor, InvalidBorderColor, LinkColor, Padding, PlaceholderColor,
     SelectionColor, ThumbColor, ThumbRadius, ToggledBackground, TrackThickness,
     UnfocusedSelectionColor,
 };
 use crate::widgets::{
-    Button, Checkbox, Divider, Flex, Grid, Label, ProgressBar, Spinner, Switch, TextArea, TextInput,
+    Button, Checkbox, CodeEditor, Divider, Dropdown, DropdownList, DropdownOption, Flex, Grid,
+    Label, ProgressBar, Spinner, Switch, Table, TableCell, TableHeader, TableHeaderCell, TableRow,
+    TextArea, TextInput, TreeRow, TreeView,
 };
 
 /// Default color for the app background.
 ///
 /// If the app driver does some kind beginning-of-frame clearing,
 /// it should clear with this color by default.
+///
+/// This is the background color of the dark theme; see [`Theme::background_color`].
 pub const BACKGROUND_COLOR: Color = Color::from_rgb8(0x1D, 0x1D, 0x1D);
 
 pub const BORDER_WIDTH: f64 = 1.;
@@ -58,7 +67,162 @@ pub const DEFAULT_GAP: Length = Length::const_px(10.0);
 pub const DEFAULT_SPACER_LEN: Length = Length::const_px(10.0);
 pub const WIDGET_CONTROL_COMPONENT_PADDING: Length = Length::const_px(4.0);
 
+/// Returns the default properties of the dark theme.
+///
+/// This is the same as the properties of [`Theme::dark`].
 pub fn default_property_set() -> DefaultProperties {
+    property_set(&Palette::DARK)
+}
+
+/// Returns the default properties of the light theme.
+///
+/// This is the same as the properties of [`Theme::light`].
+pub fn light_property_set() -> DefaultProperties {
+    property_set(&Palette::LIGHT)
+}
+
+/// Returns the default properties of both the light and dark themes.
+///
+/// Pass this to [`RenderRoot::set_theme_properties`] to follow the window's light/dark mode.
+///
+/// [`RenderRoot::set_theme_properties`]: crate::app::RenderRoot::set_theme_properties
+pub fn theme_properties() -> ThemeProperties {
+    ThemeProperties {
+        light: Theme::light().properties,
+        dark: Theme::dark().properties,
+    }
+}
+
+/// A full set of default properties, along with the matching window background color.
+///
+/// Masonry ships a [light](Self::light) and a [dark](Self::dark) theme.
+/// Themes can be applied at runtime with [`RenderRoot::set_default_properties`].
+///
+/// [`RenderRoot::set_default_properties`]: crate::app::RenderRoot::set_default_properties
+#[derive(Clone, Debug)]
+pub struct Theme {
+    /// Whether this is a light or dark theme.
+    pub window_theme: WindowTheme,
+    /// The color the app should clear the window with.
+    ///
+    /// Widgets don't paint the window background themselves,
+    /// so apps must set this as the base color of their windows.
+    pub background_color: Color,
+    /// The default properties of every widget.
+    pub properties: Arc<DefaultProperties>,
+}
+
+impl Theme {
+    /// Returns Masonry's default dark theme.
+    pub fn dark() -> Self {
+        Self {
+            window_theme: WindowTheme::Dark,
+            background_color: Palette::DARK.background,
+            properties: Arc::new(default_property_set()),
+        }
+    }
+
+    /// Returns Masonry's default light theme.
+    pub fn light() -> Self {
+        Self {
+            window_theme: WindowTheme::Light,
+            background_color: Palette::LIGHT.background,
+            properties: Arc::new(light_property_set()),
+        }
+    }
+
+    /// Returns the default theme matching `window_theme`.
+    pub fn for_window_theme(window_theme: WindowTheme) -> Self {
+        match window_theme {
+            WindowTheme::Light => Self::light(),
+            WindowTheme::Dark => Self::dark(),
+        }
+    }
+}
+
+/// The colors a theme is built from.
+struct Palette {
+    background: Color,
+    /// Background of menus, lists and tables.
+    surface: Color,
+    /// Background of buttons and other controls.
+    control: Color,
+    /// Background of pressed controls.
+    control_active: Color,
+    /// Background of pressed controls which already use `control_active`.
+    control_strong: Color,
+    border: Color,
+    strong_border: Color,
+    /// Hovered borders and dividers.
+    muted: Color,
+    disabled_background: Color,
+    accent: Color,
+    text: Color,
+    disabled_text: Color,
+    placeholder: Color,
+    text_background: Color,
+    focus: Color,
+    /// Borders of inputs with invalid content.
+    error: Color,
+    /// Links in rich text.
+    link: Color,
+    /// Search matches in text.
+    highlight: Color,
+    /// The current search match in text.
+    active_highlight: Color,
+    thumb: Color,
+}
+
+impl Palette {
+    const DARK: Self = Self {
+        background: BACKGROUND_COLOR,
+        surface: ZYNC_900,
+        control: ZYNC_800,
+        control_active: ZYNC_700,
+        control_strong: ZYNC_600,
+        border: ZYNC_700,
+        strong_border: ZYNC_600,
+        muted: ZYNC_500,
+        disabled_background: Color::BLACK,
+        accent: ACCENT_COLOR,
+        text: TEXT_COLOR,
+        disabled_text: DISABLED_TEXT_COLOR,
+        placeholder: PLACEHOLDER_COLOR,
+        text_background: TEXT_BACKGROUND_COLOR,
+        focus: FOCUS_COLOR,
+        error: Color::from_rgb8(0xf8, 0x71, 0x71),
+        link: Color::from_rgb8(0x60, 0xa5, 0xfa),
+        highlight: Color::from_rgb8(0x71, 0x3f, 0x12),
+        active_highlight: Color::from_rgb8(0xc2, 0x41, 0x0c),
+        thumb: Color::WHITE,
+    };
+
+    // Zync color variations from https://tailwindcss.com/docs/colors
+    const LIGHT: Self = Self {
+        background: Color::from_rgb8(0xfa, 0xfa, 0xfa),
+        surface: Color::WHITE,
+        control: Color::from_rgb8(0xf4, 0xf4, 0xf5),
+        control_active: Color::from_rgb8(0xe4, 0xe4, 0xe7),
+        control_strong: Color::from_rgb8(0xd4, 0xd4, 0xd8),
+        border: Color::from_rgb8(0xd4, 0xd4, 0xd8),
+        strong_border: Color::from_rgb8(0xa1, 0xa1, 0xaa),
+        muted: ZYNC_500,
+        disabled_background: Color::from_rgb8(0xe4, 0xe4, 0xe7),
+        accent: ACCENT_COLOR,
+        text: ZYNC_900,
+        disabled_text: Color::from_rgb8(0x9f, 0x9f, 0xa6),
+        placeholder: Color::from_rgba8(0x00, 0x00, 0x00, 0x8F),
+        text_background: Color::WHITE,
+        focus: ZYNC_800,
+        error: Color::from_rgb8(0xdc, 0x26, 0x26),
+        link: Color::from_rgb8(0x25, 0x63, 0xeb),
+        highlight: Color::from_rgb8(0xfe, 0xf0, 0x8a),
+        active_highlight: Color::from_rgb8(0xfd, 0xba, 0x74),
+        thumb: Color::WHITE,
+    };
+}
+
+fn property_set(palette: &Palette) -> DefaultProperties {
     let mut properties = DefaultProperties::new();
 
     // Button
@@ -68,12 +232,20 @@ pub fn default_property_set() -> DefaultProperties {
         width: BORDER_WIDTH,
     });
 
-    properties.insert::<Button, _>(Background::Color(ZYNC_800));
-    properties.insert::<Button, _>(ActiveBackground(Background::Color(ZYNC_700)));
-    properties.insert::<Button, _>(DisabledBackground(Background::Color(Color::BLACK)));
-    properties.insert::<Button, _>(BorderColor { color: ZYNC_700 });
-    properties.insert::<Button, _>(HoveredBorderColor(BorderColor { color: ZYNC_500 }));
-    properties.insert::<Button, _>(FocusedBorderColor(BorderColor { color: FOCUS_COLOR }));
+    properties.insert::<Button, _>(Background::Color(palette.control));
+    properties.insert::<Button, _>(ActiveBackground(Background::Color(palette.control_active)));
+    properties.insert::<Button, _>(DisabledBackground(Background::Color(
+        palette.disabled_background,
+    )));
+    properties.insert::<Button, _>(BorderColor {
+        color: palette.border,
+    });
+    properties.insert::<Button, _>(HoveredBorderColor(BorderColor {
+        color: palette.muted,
+    }));
+    properties.insert::<Button, _>(FocusedBorderColor(BorderColor {
+        color: palette.focus,
+    }));
 
     // Checkbox
     properties.insert::<Checkbox, _>(CornerRadius { radius: 4. });
@@ -81,21 +253,31 @@ pub fn default_property_set() -> DefaultProperties {
         width: BORDER_WIDTH,
     });
 
-    properties.insert::<Checkbox, _>(Background::Color(ZYNC_800));
-    properties.insert::<Checkbox, _>(ActiveBackground(Background::Color(ZYNC_700)));
-    properties.insert::<Checkbox, _>(DisabledBackground(Background::Color(Color::BLACK)));
-    properties.insert::<Checkbox, _>(BorderColor { color: ZYNC_700 });
-    properties.insert::<Checkbox, _>(HoveredBorderColor(BorderColor { color: ZYNC_500 }));
-    properties.insert::<Checkbox, _>(FocusedBorderColor(BorderColor { color: FOCUS_COLOR }));
+    properties.insert::<Checkbox, _>(Background::Color(palette.control));
+    properties.insert::<Checkbox, _>(ActiveBackground(Background::Color(palette.control_active)));
+    properties.insert::<Checkbox, _>(DisabledBackground(Background::Color(
+        palette.disabled_background,
+    )));
+    properties.insert::<Checkbox, _>(BorderColor {
+        color: palette.border,
+    });
+    properties.insert::<Checkbox, _>(HoveredBorderColor(BorderColor {
+        color: palette.muted,
+    }));
+    properties.insert::<Checkbox, _>(FocusedBorderColor(BorderColor {
+        color: palette.focus,
+    }));
 
     properties.insert::<Checkbox, _>(CheckmarkStrokeWidth { width: 2.0 });
-    properties.insert::<Checkbox, _>(CheckmarkColor { color: TEXT_COLOR });
+    properties.insert::<Checkbox, _>(CheckmarkColor {
+        color: palette.text,
+    });
     properties.insert::<Checkbox, _>(DisabledCheckmarkColor(CheckmarkColor {
-        color: DISABLED_TEXT_COLOR,
+        color: palette.disabled_text,
     }));
 
     // Divider
-    properties.insert::<Divider, _>(ContentColor::new(ZYNC_500));
+    properties.insert::<Divider, _>(ContentColor::new(palette.muted));
 
     // Switch
     properties.insert::<Switch, _>(CornerRadius { radius: 10. }); // Full pill shape
@@ -103,14 +285,22 @@ pub fn default_property_set() -> DefaultProperties {
         width: BORDER_WIDTH,
     });
 
-    properties.insert::<Switch, _>(Background::Color(ZYNC_700));
-    properties.insert::<Switch, _>(ActiveBackground(Background::Color(ZYNC_600)));
-    properties.insert::<Switch, _>(DisabledBackground(Background::Color(Color::BLACK)));
-    properties.insert::<Switch, _>(ToggledBackground(Background::Color(ACCENT_COLOR)));
-    properties.insert::<Switch, _>(BorderColor { color: ZYNC_700 });
-    properties.insert::<Switch, _>(HoveredBorderColor(BorderColor { color: ZYNC_500 }));
-    properties.insert::<Switch, _>(FocusedBorderColor(BorderColor { color: FOCUS_COLOR }));
-    properties.insert::<Switch, _>(ThumbColor(Color::WHITE));
+    properties.insert::<Switch, _>(Background::Color(palette.control_active));
+    properties.insert::<Switch, _>(ActiveBackground(Background::Color(palette.control_strong)));
+    properties.insert::<Switch, _>(DisabledBackground(Background::Color(
+        palette.disabled_background,
+    )));
+    properties.insert::<Switch, _>(ToggledBackground(Background::Color(palette.accent)));
+    properties.insert::<Switch, _>(BorderColor {
+        color: palette.border,
+    });
+    properties.insert::<Switch, _>(HoveredBorderColor(BorderColor {
+        color: palette.muted,
+    }));
+    properties.insert::<Switch, _>(FocusedBorderColor(BorderColor {
+        color: palette.focus,
+    }));
+    properties.insert::<Switch, _>(ThumbColor(palette.thumb));
     properties.insert::<Switch, _>(ThumbRadius(8.0));
     properties.insert::<Switch, _>(TrackThickness(20.0));
 
@@ -126,44 +316,148 @@ pub fn default_property_set() -> DefaultProperties {
     properties.insert::<TextInput, _>(BorderWidth {
         width: BORDER_WIDTH,
     });
-    properties.insert::<TextInput, _>(BorderColor { color: ZYNC_600 });
-    properties.insert::<TextInput, _>(FocusedBorderColor(BorderColor { color: FOCUS_COLOR }));
-    properties.insert::<TextInput, _>(PlaceholderColor::new(PLACEHOLDER_COLOR));
-    properties.insert::<TextInput, _>(CaretColor { color: TEXT_COLOR });
+    properties.insert::<TextInput, _>(BorderColor {
+        color: palette.strong_border,
+    });
+    properties.insert::<TextInput, _>(FocusedBorderColor(BorderColor {
+        color: palette.focus,
+    }));
+    properties.insert::<TextInput, _>(InvalidBorderColor(BorderColor {
+        color: palette.error,
+    }));
+    properties.insert::<TextInput, _>(PlaceholderColor::new(palette.placeholder));
+    properties.insert::<TextInput, _>(CaretColor {
+        color: palette.text,
+    });
     properties.insert::<TextInput, _>(SelectionColor {
-        color: ACCENT_COLOR,
+        color: palette.accent,
     });
     properties.insert::<TextInput, _>(UnfocusedSelectionColor(SelectionColor {
-        color: DISABLED_TEXT_COLOR,
+        color: palette.disabled_text,
     }));
-    properties.insert::<TextInput, _>(Background::Color(TEXT_BACKGROUND_COLOR));
-    properties.insert::<TextInput, _>(DisabledBackground(Background::Color(TEXT_BACKGROUND_COLOR)));
+    properties.insert::<TextInput, _>(Background::Color(palette.text_background));
+    properties.insert::<TextInput, _>(DisabledBackground(Background::Color(
+        palette.text_background,
+    )));
 
     // TextArea
-    properties.insert::<TextArea<false>, _>(ContentColor::new(TEXT_COLOR));
-    properties
-        .insert::<TextArea<false>, _>(DisabledContentColor(ContentColor::new(DISABLED_TEXT_COLOR)));
-    properties.insert::<TextArea<false>, _>(CaretColor { color: TEXT_COLOR });
+    properties.insert::<TextArea<false>, _>(ContentColor::new(palette.text));
+    properties.insert::<TextArea<false>, _>(DisabledContentColor(ContentColor::new(
+        palette.disabled_text,
+    )));
+    properties.insert::<TextArea<false>, _>(CaretColor {
+        color: palette.text,
+    });
     properties.insert::<TextArea<false>, _>(SelectionColor {
-        color: ACCENT_COLOR,
+        color: palette.accent,
     });
     properties.insert::<TextArea<false>, _>(UnfocusedSelectionColor(SelectionColor {
-        color: DISABLED_TEXT_COLOR,
+        color: palette.disabled_text,
     }));
-    properties.insert::<TextArea<true>, _>(ContentColor::new(TEXT_COLOR));
-    properties
-        .insert::<TextArea<true>, _>(DisabledContentColor(ContentColor::new(DISABLED_TEXT_COLOR)));
-    properties.insert::<TextArea<true>, _>(CaretColor { color: TEXT_COLOR });
+    properties.insert::<TextArea<false>, _>(HighlightColor {
+        color: palette.highlight,
+    });
+    properties.insert::<TextArea<false>, _>(ActiveHighlightColor(HighlightColor {
+        color: palette.active_highlight,
+    }));
+    properties.insert::<TextArea<false>, _>(LinkColor::new(palette.link));
+    properties.insert::<TextArea<true>, _>(ContentColor::new(palette.text));
+    properties.insert::<TextArea<true>, _>(LinkColor::new(palette.link));
+    properties.insert::<TextArea<true>, _>(DisabledContentColor(ContentColor::new(
+        palette.disabled_text,
+    )));
+    properties.insert::<TextArea<true>, _>(CaretColor {
+        color: palette.text,
+    });
     properties.insert::<TextArea<true>, _>(SelectionColor {
-        color: ACCENT_COLOR,
+        color: palette.accent,
     });
     properties.insert::<TextArea<true>, _>(UnfocusedSelectionColor(SelectionColor {
-        color: DISABLED_TEXT_COLOR,
+        color: palette.disabled_text,
+    }));
+    properties.insert::<TextArea<true>, _>(HighlightColor {
+        color: palette.highlight,
+    });
+    properties.insert::<TextArea<true>, _>(ActiveHighlightColor(HighlightColor {
+        color: palette.active_highlight,
     }));
 
+    // CodeEditor
+    properties.insert::<CodeEditor, _>(ContentColor::new(palette.text));
+    properties.insert::<CodeEditor, _>(CaretColor {
+        color: palette.text,
+    });
+    properties.insert::<CodeEditor, _>(SelectionColor {
+        color: palette.accent,
+    });
+    properties.insert::<CodeEditor, _>(HighlightColor {
+        color: palette.highlight,
+    });
+    properties.insert::<CodeEditor, _>(LinkColor::new(palette.link));
+    properties.insert::<CodeEditor, _>(Background::Color(palette.text_background));
+
+    // Dropdown
+    properties.insert::<Dropdown, _>(Padding::from_vh(6., 8.));
+    properties.insert::<Dropdown, _>(CornerRadius { radius: 6. });
+    properties.insert::<Dropdown, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<Dropdown, _>(Background::Color(palette.control));
+    properties.insert::<Dropdown, _>(DisabledBackground(Background::Color(
+        palette.disabled_background,
+    )));
+    properties.insert::<Dropdown, _>(BorderColor {
+        color: palette.border,
+    });
+    properties.insert::<Dropdown, _>(HoveredBorderColor(BorderColor {
+        color: palette.muted,
+    }));
+    properties.insert::<Dropdown, _>(FocusedBorderColor(BorderColor {
+        color: palette.focus,
+    }));
+    properties.insert::<Dropdown, _>(ContentColor::new(palette.text));
+
+    properties.insert::<DropdownList, _>(CornerRadius { radius: 4. });
+    properties.insert::<DropdownList, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<DropdownList, _>(Background::Color(palette.surface));
+    properties.insert::<DropdownList, _>(BorderColor {
+        color: palette.border,
+    });
+
+    properties.insert::<DropdownOption, _>(Padding::from_vh(4., 8.));
+    properties.insert::<DropdownOption, _>(SelectionColor {
+        color: palette.accent,
+    });
+
     // Label
-    properties.insert::<Label, _>(ContentColor::new(TEXT_COLOR));
-    properties.insert::<Label, _>(DisabledContentColor(ContentColor::new(DISABLED_TEXT_COLOR)));
+    properties.insert::<Label, _>(ContentColor::new(palette.text));
+    properties.insert::<Label, _>(DisabledContentColor(ContentColor::new(
+        palette.disabled_text,
+    )));
+    properties.insert::<Label, _>(LinkColor::new(palette.link));
+    properties.insert::<Label, _>(SelectionColor {
+        color: palette.accent,
+    });
+
+    // Menu
+    properties.insert::<Menu, _>(CornerRadius { radius: 4. });
+    properties.insert::<Menu, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<Menu, _>(Background::Color(palette.surface));
+    properties.insert::<Menu, _>(BorderColor {
+        color: palette.border,
+    });
+    properties.insert::<Menu, _>(SelectionColor {
+        color: palette.accent,
+    });
+    properties.insert::<Menu, _>(ContentColor::new(palette.text));
+    properties.insert::<Menu, _>(CheckmarkStrokeWidth { width: 2.0 });
+    properties.insert::<Menu, _>(CheckmarkColor {
+        color: palette.text,
+    });
 
     // ProgressBar
     properties.insert::<ProgressBar, _>(CornerRadius { radius: 2. });
@@ -171,12 +465,56 @@ pub fn default_property_set() -> DefaultProperties {
         width: BORDER_WIDTH,
     });
 
-    properties.insert::<ProgressBar, _>(Background::Color(ZYNC_900));
-    properties.insert::<ProgressBar, _>(BorderColor { color: ZYNC_800 });
-    properties.insert::<ProgressBar, _>(BarColor(ACCENT_COLOR));
+    properties.insert::<ProgressBar, _>(Background::Color(palette.surface));
+    properties.insert::<ProgressBar, _>(BorderColor {
+        color: palette.control,
+    });
+    properties.insert::<ProgressBar, _>(BarColor(palette.accent));
 
     // Spinner
-    properties.insert::<Spinner, _>(ContentColor::new(TEXT_COLOR));
+    properties.insert::<Spinner, _>(ContentColor::new(palette.text));
+
+    // Table
+    properties.insert::<Table, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<Table, _>(BorderColor {
+        color: palette.border,
+    });
+    properties.insert::<Table, _>(FocusedBorderColor(BorderColor {
+        color: palette.focus,
+    }));
+    properties.insert::<Table, _>(Background::Color(palette.surface));
+
+    properties.insert::<TableHeader, _>(Background::Color(palette.control));
+    properties.insert::<TableHeaderCell, _>(Padding::from_vh(4., 6.));
+    properties.insert::<TableHeaderCell, _>(ContentColor::new(palette.text));
+
+    properties.insert::<TableRow, _>(SelectionColor {
+        color: palette.accent,
+    });
+    properties.insert::<TableRow, _>(UnfocusedSelectionColor(SelectionColor {
+        color: palette.control_active,
+    }));
+    properties.insert::<TableCell, _>(Padding::from_vh(0., 6.));
+
+    // TreeView
+    properties.insert::<TreeView, _>(BorderWidth {
+        width: BORDER_WIDTH,
+    });
+    properties.insert::<TreeView, _>(BorderColor {
+        color: palette.border,
+    });
+    properties.insert::<TreeView, _>(FocusedBorderColor(BorderColor {
+        color: palette.focus,
+    }));
+    properties.insert::<TreeView, _>(Background::Color(palette.surface));
+
+    properties.insert::<TreeRow, _>(Padding::from_vh(2., 4.));
+    properties.insert::<TreeRow, _>(ContentColor::new(palette.text));
+    properties.insert::<TreeRow, _>(SelectionColor {
+        color: palette.accent,
+    });
 
     properties
 }
@@ -0,0 +1,199 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PropertiesMut,
+    PropertiesRef, RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::LenReq;
+
+/// A widget which sends an [`AnimationFrame`] action on every animation frame while it's running.
+///
+/// This lets code outside the widget tree drive animations,
+/// for example to animate a value in the app state.
+/// Widgets which animate themselves should instead advance their animations
+/// in [`Widget::on_anim_frame`], using [`UpdateCtx::animate`].
+///
+/// It reports the child's length as its own in [`measure`], syncing its size with the child's.
+///
+/// Ensure that `Animator` has [`Dimensions`] set via props to [`Dimensions::MAX`].
+///
+/// [`measure`]: Widget::measure
+/// [`Dimensions`]: crate::properties::Dimensions
+/// [`Dimensions::MAX`]: crate::properties::Dimensions::MAX
+pub struct Animator {
+    child: WidgetPod<dyn Widget>,
+    running: bool,
+}
+
+/// The [action](Widget::Action) sent by [`Animator`] on every animation frame while it's running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnimationFrame {
+    /// The time since the previous animation frame, in nanoseconds.
+    pub elapsed_ns: u64,
+}
+
+// --- MARK: BUILDERS
+impl Animator {
+    /// Creates a new animator, which starts stopped.
+    pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            running: false,
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl Animator {
+    /// Returns `true` if this animator is sending animation frames.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Animator {
+    /// Replaces the child widget with a new one.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        let old_child = std::mem::replace(&mut this.widget.child, child.erased().to_pod());
+        this.ctx.remove_child(old_child);
+    }
+
+    /// Returns mutable reference to the child widget.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Starts sending an [`AnimationFrame`] on every animation frame.
+    pub fn start(this: &mut WidgetMut<'_, Self>) {
+        this.widget.running = true;
+        this.ctx.request_anim_frame();
+    }
+
+    /// Stops sending animation frames.
+    pub fn stop(this: &mut WidgetMut<'_, Self>) {
+        this.widget.running = false;
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Animator {
+    type Action = AnimationFrame;
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        false
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn on_anim_frame(
+        &mut self,
+        ctx: &mut UpdateCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        interval: u64,
+    ) {
+        if !self.running {
+            return;
+        }
+        ctx.submit_action::<Self::Action>(AnimationFrame {
+            elapsed_ns: interval,
+        });
+        ctx.request_anim_frame();
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+
+        let child_baseline = ctx.child_baseline_offset(&self.child);
+        ctx.set_baseline_offset(child_baseline);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Animator", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::properties::Dimensions;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::SizedBox;
+
+    #[test]
+    fn sends_frames_while_running() {
+        let widget = Animator::new(SizedBox::empty().with_auto_id()).with_props(Dimensions::MAX);
+        let animator_id = widget.id();
+        let mut harness = TestHarness::create(test_property_set(), widget);
+
+        // The animator starts stopped.
+        harness.animate_ms(16);
+        assert_eq!(harness.pop_action::<AnimationFrame>(), None);
+
+        harness.edit_root_widget(|mut animator| Animator::start(&mut animator));
+        harness.animate_ms(16);
+        assert_eq!(
+            harness.pop_action::<AnimationFrame>(),
+            Some((
+                AnimationFrame {
+                    elapsed_ns: 16_000_000
+                },
+                animator_id
+            ))
+        );
+        harness.animate_ms(8);
+        assert_eq!(
+            harness.pop_action::<AnimationFrame>(),
+            Some((
+                AnimationFrame {
+                    elapsed_ns: 8_000_000
+                },
+                animator_id
+            ))
+        );
+
+        harness.edit_root_widget(|mut animator| Animator::stop(&mut animator));
+        harness.animate_ms(16);
+        assert_eq!(harness.pop_action::<AnimationFrame>(), None);
+    }
+}
@@ -259,8 +259,8 @@ impl Widget for Checkbox {
         let bbox = ctx.border_box();
         let p = PrePaintProps::fetch(ctx, props);
 
-        paint_box_shadow(scene, bbox, p.box_shadow, p.corner_radius);
-        paint_background(scene, bbox, p.background, p.border_width, p.corner_radius);
+        paint_box_shadow(scene, bbox, p.box_shadow, &p.corner_radius);
+        paint_background(scene, bbox, &p.background, p.border_width, &p.corner_radius);
 
         // Paint focus indicator around the entire widget (box + label)
         if ctx.is_focus_target() || ctx.is_hovered() {
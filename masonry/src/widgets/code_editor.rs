@@ -0,0 +1,1581 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use accesskit::{Node, NodeId, Role, TextSelection};
+use dpi::PhysicalPosition;
+use parley::editing::Cursor;
+use parley::{Affinity, FontContext, GenericFamily, Layout, LayoutContext, LineHeight};
+use tracing::{Span, trace_span};
+use unicode_segmentation::UnicodeSegmentation;
+use vello::Scene;
+
+use crate::core::keyboard::{Key, KeyState, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, BrushIndex, ChildrenIds, CursorIcon, EventCtx, Ime, LayoutCtx,
+    MeasureCtx, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerScrollEvent,
+    PointerUpdate, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, RichText, StyleProperty,
+    TextEvent, TextSpan, Update, UpdateCtx, Widget, WidgetId, WidgetMut, render_text,
+};
+use crate::kurbo::{Affine, Axis, Point, Rect, Size, Vec2};
+use crate::layout::LenReq;
+use crate::peniko::Fill;
+use crate::properties::{CaretColor, ContentColor, HighlightColor, LinkColor, SelectionColor};
+use crate::theme;
+
+/// The number of columns and lines the editor prefers to show, when it isn't given a size.
+const PREFERRED_COLUMNS: f64 = 80.;
+const PREFERRED_LINES: f64 = 24.;
+/// The space between the gutter and the text.
+const GUTTER_PADDING: f64 = 8.;
+/// How many lines are searched for the bracket matching the one at the caret.
+const MAX_BRACKET_SEARCH_LINES: usize = 1000;
+
+/// A function which supplies the styles of a line of a [`CodeEditor`], given its index and text.
+type Highlighter = dyn Fn(usize, &str) -> Vec<TextSpan>;
+
+/// A widget for editing source code.
+///
+/// The text is shown in a monospace font, next to a gutter with line numbers.
+/// It is stored as a list of lines, and only the lines which are visible are laid out
+/// and painted, so that the editor stays responsive for files with many lines.
+/// The editor scrolls its content itself, so it shouldn't be put in a [`Portal`](super::Portal),
+/// and it should be given a size, as it only prefers a size of 80 columns by 24 lines.
+///
+/// Besides the usual caret movement and selection, the editor supports:
+/// - Inserting indentation with <kbd>Tab</kbd>, as configured with
+///   [`with_tab_insertion`](Self::with_tab_insertion), and removing it with
+///   <kbd>Shift</kbd>+<kbd>Tab</kbd>. Either indents all selected lines.
+/// - Keeping the indentation of the previous line when <kbd>Enter</kbd> is pressed,
+///   and indenting further after an opening bracket.
+///   This can be disabled with [`with_auto_indent`](Self::with_auto_indent).
+/// - Highlighting the bracket which matches the one next to the caret,
+///   with the [`HighlightColor`].
+/// - Syntax highlighting, with a [highlighter](Self::with_highlighter) which supplies
+///   styles for each line.
+///
+/// Lines are not wrapped, the preedit of an input method isn't shown,
+/// and there is no undo history yet.
+///
+/// This widget emits [`CodeEditorAction::Changed`] whenever the user edits the text.
+pub struct CodeEditor {
+    /// The text, without line endings.
+    ///
+    /// This always has at least one line.
+    lines: Vec<String>,
+    /// The position of the caret, which is the end of the selection which moves.
+    caret: TextPosition,
+    /// The end of the selection which stays fixed when the selection is extended.
+    anchor: TextPosition,
+    /// The column the caret returns to when moving up and down through shorter lines, in chars.
+    preferred_column: Option<usize>,
+
+    /// What to insert when the user presses Tab.
+    tab_insertion: TabInsertion,
+    /// Whether new lines keep the indentation of the line before them.
+    auto_indent: bool,
+    /// Supplies the styles of each line, given its index and text.
+    highlighter: Option<Box<Highlighter>>,
+    font_size: f32,
+
+    /// The offset of the visible part of the text.
+    scroll: Vec2,
+    /// The content-box size of the editor, as of the last layout.
+    size: Size,
+    /// The advance of a character and the height of a line, once they have been measured.
+    cell_size: Option<Size>,
+    /// The layouts of the lines which have been laid out, by index.
+    ///
+    /// Only the visible lines are kept when painting.
+    line_layouts: HashMap<usize, LineLayout>,
+    /// The ids of the accessibility nodes of the lines exposed to accessibility technology, by index.
+    access_ids: HashMap<usize, NodeId>,
+}
+
+/// The cached layout of a line of a [`CodeEditor`].
+struct LineLayout {
+    text: RichText,
+    layout: Layout<BrushIndex>,
+    number: Layout<BrushIndex>,
+}
+
+/// A position in the text of a [`CodeEditor`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextPosition {
+    /// The index of the line.
+    pub line: usize,
+    /// The byte offset in the line.
+    pub column: usize,
+}
+
+impl TextPosition {
+    /// Creates a position from the index of a line and a byte offset in it.
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+/// What a [`CodeEditor`] inserts when the user presses <kbd>Tab</kbd>.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TabInsertion {
+    /// Insert spaces up to the next multiple of the given number of columns.
+    Spaces(usize),
+    /// Insert a tab character.
+    Tab,
+    /// Never insert anything, so that <kbd>Tab</kbd> moves the focus, as with other widgets.
+    Never,
+}
+
+impl Default for TabInsertion {
+    fn default() -> Self {
+        Self::Spaces(4)
+    }
+}
+
+/// The text of a [`CodeEditor`] has been edited by the user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CodeEditorAction {
+    /// Some lines have been replaced.
+    ///
+    /// The `removed` lines starting at `first_line` were replaced by `inserted` lines.
+    /// This is enough to update a per-line model of the text, such as syntax highlighting state.
+    Changed {
+        /// The index of the first line which changed.
+        first_line: usize,
+        /// The number of lines which were replaced.
+        removed: usize,
+        /// The number of lines which replaced them.
+        inserted: usize,
+    },
+}
+
+// --- MARK: BUILDERS
+impl CodeEditor {
+    /// Creates a new code editor with the given text.
+    ///
+    /// Both `\n` and `\r\n` are treated as line endings.
+    pub fn new(text: &str) -> Self {
+        Self {
+            lines: split_lines(text),
+            caret: TextPosition::default(),
+            anchor: TextPosition::default(),
+            preferred_column: None,
+            tab_insertion: TabInsertion::default(),
+            auto_indent: true,
+            highlighter: None,
+            font_size: theme::TEXT_SIZE_NORMAL,
+            scroll: Vec2::ZERO,
+            size: Size::ZERO,
+            cell_size: None,
+            line_layouts: HashMap::new(),
+            access_ids: HashMap::new(),
+        }
+    }
+
+    /// Sets what to insert when the user presses <kbd>Tab</kbd>.
+    ///
+    /// The default is four spaces.
+    ///
+    /// To modify this on an active editor, use [`set_tab_insertion`](Self::set_tab_insertion).
+    pub fn with_tab_insertion(mut self, tab_insertion: TabInsertion) -> Self {
+        self.tab_insertion = tab_insertion;
+        self
+    }
+
+    /// Sets whether new lines keep the indentation of the line before them.
+    ///
+    /// The default is true.
+    ///
+    /// To modify this on an active editor, use [`set_auto_indent`](Self::set_auto_indent).
+    pub fn with_auto_indent(mut self, auto_indent: bool) -> Self {
+        self.auto_indent = auto_indent;
+        self
+    }
+
+    /// Sets a function which supplies the styles of each line, given its index and text.
+    ///
+    /// The ranges of the spans are byte ranges of the line.
+    /// The function is called when a line is first shown, and again after it is edited.
+    /// If the styles depend on other lines, such as in a multi-line comment, use
+    /// [`refresh_highlighting`](Self::refresh_highlighting) when they change.
+    ///
+    /// To set this on an active editor, use [`set_highlighter`](Self::set_highlighter).
+    pub fn with_highlighter(
+        mut self,
+        highlighter: impl Fn(usize, &str) -> Vec<TextSpan> + 'static,
+    ) -> Self {
+        self.highlighter = Some(Box::new(highlighter));
+        self
+    }
+
+    /// Sets the font size of the text.
+    ///
+    /// To modify this on an active editor, use [`set_font_size`](Self::set_font_size).
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl CodeEditor {
+    /// Returns the lines of the text, without line endings.
+    ///
+    /// There is always at least one line.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns the text, with lines separated by `\n`.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns the anchor and the caret of the selection.
+    ///
+    /// They are equal when nothing is selected.
+    pub fn selection(&self) -> (TextPosition, TextPosition) {
+        (self.anchor, self.caret)
+    }
+
+    /// Returns the selected text, with lines separated by `\n`.
+    pub fn selected_text(&self) -> String {
+        let (start, end) = self.ordered_selection();
+        if start.line == end.line {
+            return self.lines[start.line][start.column..end.column].to_string();
+        }
+        let mut text = self.lines[start.line][start.column..].to_string();
+        for line in &self.lines[start.line + 1..end.line] {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text.push('\n');
+        text.push_str(&self.lines[end.line][..end.column]);
+        text
+    }
+
+    /// Returns the positions of the bracket next to the caret and of the bracket matching it.
+    ///
+    /// The bracket after the caret is preferred over the one before it.
+    pub fn matching_brackets(&self) -> Option<(TextPosition, TextPosition)> {
+        let line = &self.lines[self.caret.line];
+        let after = line[self.caret.column..].chars().next();
+        let before = line[..self.caret.column].chars().next_back();
+        let bracket = match (after, before) {
+            (Some(c), _) if bracket_pair(c).is_some() => self.caret,
+            (_, Some(c)) if bracket_pair(c).is_some() => {
+                TextPosition::new(self.caret.line, self.caret.column - c.len_utf8())
+            }
+            _ => return None,
+        };
+        let matching = self.find_matching_bracket(bracket)?;
+        Some((bracket, matching))
+    }
+
+    /// Returns the selection, with the start first.
+    fn ordered_selection(&self) -> (TextPosition, TextPosition) {
+        if self.anchor <= self.caret {
+            (self.anchor, self.caret)
+        } else {
+            (self.caret, self.anchor)
+        }
+    }
+
+    /// Returns the nearest valid position to `position`.
+    fn clamp(&self, position: TextPosition) -> TextPosition {
+        let line = position.line.min(self.lines.len() - 1);
+        let text = &self.lines[line];
+        let mut column = position.column.min(text.len());
+        while !text.is_char_boundary(column) {
+            column -= 1;
+        }
+        TextPosition::new(line, column)
+    }
+
+    /// Returns the string inserted to indent by one level.
+    fn indent_unit(&self) -> String {
+        match self.tab_insertion {
+            TabInsertion::Spaces(width) => " ".repeat(width.max(1)),
+            TabInsertion::Tab => "\t".into(),
+            TabInsertion::Never => " ".repeat(4),
+        }
+    }
+
+    /// Finds the bracket matching the one at `position`, searching a limited number of lines.
+    fn find_matching_bracket(&self, position: TextPosition) -> Option<TextPosition> {
+        let line = &self.lines[position.line];
+        let bracket = line[position.column..].chars().next()?;
+        let (open, close) = bracket_pair(bracket)?;
+        let mut depth = 0_usize;
+        if bracket == open {
+            let last_line = (position.line + MAX_BRACKET_SEARCH_LINES).min(self.lines.len() - 1);
+            for index in position.line..=last_line {
+                let start = if index == position.line {
+                    position.column
+                } else {
+                    0
+                };
+                for (column, c) in self.lines[index][start..].char_indices() {
+                    if c == open {
+                        depth += 1;
+                    } else if c == close {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(TextPosition::new(index, start + column));
+                        }
+                    }
+                }
+            }
+        } else {
+            let first_line = position.line.saturating_sub(MAX_BRACKET_SEARCH_LINES);
+            for index in (first_line..=position.line).rev() {
+                let end = if index == position.line {
+                    position.column + close.len_utf8()
+                } else {
+                    self.lines[index].len()
+                };
+                for (column, c) in self.lines[index][..end].char_indices().rev() {
+                    if c == close {
+                        depth += 1;
+                    } else if c == open {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(TextPosition::new(index, column));
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Replaces the text in `range` with `text`, and returns the end of the inserted text.
+    ///
+    /// The selection isn't changed, so may be invalid afterwards.
+    fn replace(
+        &mut self,
+        range: Range<TextPosition>,
+        text: &str,
+    ) -> (TextPosition, CodeEditorAction) {
+        let (start, end) = (range.start, range.end);
+        let mut new_lines = split_lines(text);
+        new_lines[0].insert_str(0, &self.lines[start.line][..start.column]);
+        let last = new_lines.len() - 1;
+        let text_end = TextPosition::new(start.line + last, new_lines[last].len());
+        new_lines[last].push_str(&self.lines[end.line][end.column..]);
+
+        let action = CodeEditorAction::Changed {
+            first_line: start.line,
+            removed: end.line - start.line + 1,
+            inserted: new_lines.len(),
+        };
+        self.lines.splice(start.line..=end.line, new_lines);
+        // Lines after the edit may have moved, so all layouts are invalidated.
+        self.line_layouts.clear();
+        (text_end, action)
+    }
+
+    /// Replaces the selection with `text`, and moves the caret to the end of it.
+    fn insert(&mut self, text: &str) -> CodeEditorAction {
+        let (start, end) = self.ordered_selection();
+        let (text_end, action) = self.replace(start..end, text);
+        self.caret = text_end;
+        self.anchor = text_end;
+        self.preferred_column = None;
+        action
+    }
+
+    /// Inserts a line break, indenting the new line if auto-indent is enabled.
+    fn insert_newline(&mut self) -> CodeEditorAction {
+        let mut text = String::from("\n");
+        if self.auto_indent {
+            let (start, _) = self.ordered_selection();
+            let line = &self.lines[start.line][..start.column];
+            let indent_len = line.len() - line.trim_start().len();
+            text.push_str(&line[..indent_len]);
+            if line
+                .trim_end()
+                .ends_with(|c| bracket_pair(c).is_some_and(|(open, _)| c == open))
+            {
+                text.push_str(&self.indent_unit());
+            }
+        }
+        self.insert(&text)
+    }
+
+    /// Handles the Tab key, returning `None` if it should move the focus instead.
+    fn insert_tab(&mut self, shift: bool) -> Option<Vec<CodeEditorAction>> {
+        if self.tab_insertion == TabInsertion::Never {
+            return None;
+        }
+        let (start, end) = self.ordered_selection();
+        if shift || start.line != end.line {
+            // A selection which ends at the start of a line doesn't include it.
+            let last_line = if end.line > start.line && end.column == 0 {
+                end.line - 1
+            } else {
+                end.line
+            };
+            let actions = (start.line..=last_line)
+                .filter_map(|line| {
+                    if shift {
+                        self.dedent_line(line)
+                    } else {
+                        Some(self.indent_line(line))
+                    }
+                })
+                .collect();
+            return Some(actions);
+        }
+        let text = match self.tab_insertion {
+            TabInsertion::Spaces(width) => {
+                let width = width.max(1);
+                let column = self.lines[start.line][..start.column].chars().count();
+                " ".repeat(width - column % width)
+            }
+            _ => "\t".into(),
+        };
+        Some(vec![self.insert(&text)])
+    }
+
+    /// Inserts one level of indentation at the start of `line`.
+    fn indent_line(&mut self, line: usize) -> CodeEditorAction {
+        let unit = self.indent_unit();
+        let position = TextPosition::new(line, 0);
+        let (_, action) = self.replace(position..position, &unit);
+        for selection_end in [&mut self.anchor, &mut self.caret] {
+            if selection_end.line == line && selection_end.column > 0 {
+                selection_end.column += unit.len();
+            }
+        }
+        action
+    }
+
+    /// Removes up to one level of indentation from the start of `line`.
+    fn dedent_line(&mut self, line: usize) -> Option<CodeEditorAction> {
+        let text = &self.lines[line];
+        let len = if text.starts_with('\t') {
+            1
+        } else {
+            let width = self.indent_unit().len();
+            text.bytes().take(width).take_while(|b| *b == b' ').count()
+        };
+        if len == 0 {
+            return None;
+        }
+        let (_, action) =
+            self.replace(TextPosition::new(line, 0)..TextPosition::new(line, len), "");
+        for selection_end in [&mut self.anchor, &mut self.caret] {
+            if selection_end.line == line {
+                selection_end.column = selection_end.column.saturating_sub(len);
+            }
+        }
+        Some(action)
+    }
+
+    /// Deletes the selection, or the character before or after the caret.
+    fn delete(&mut self, forward: bool) -> Option<CodeEditorAction> {
+        if self.anchor == self.caret {
+            let target = if forward {
+                self.next_position(self.caret)
+            } else {
+                self.delete_back_target()
+            };
+            if target == self.caret {
+                return None;
+            }
+            self.anchor = target;
+        }
+        Some(self.insert(""))
+    }
+
+    /// Returns where Backspace deletes back to, which is the previous tab stop in indentation.
+    fn delete_back_target(&self) -> TextPosition {
+        let line = &self.lines[self.caret.line][..self.caret.column];
+        if let TabInsertion::Spaces(width) = self.tab_insertion
+            && !line.is_empty()
+            && line.bytes().all(|b| b == b' ')
+        {
+            let width = width.max(1);
+            let len = (line.len() - 1) % width + 1;
+            return TextPosition::new(self.caret.line, line.len() - len);
+        }
+        self.previous_position(self.caret)
+    }
+
+    /// Returns the position one character before `position`, which may be on the previous line.
+    fn previous_position(&self, position: TextPosition) -> TextPosition {
+        match self.lines[position.line][..position.column]
+            .chars()
+            .next_back()
+        {
+            Some(c) => TextPosition::new(position.line, position.column - c.len_utf8()),
+            None if position.line > 0 => {
+                TextPosition::new(position.line - 1, self.lines[position.line - 1].len())
+            }
+            None => position,
+        }
+    }
+
+    /// Returns the position one character after `position`, which may be on the next line.
+    fn next_position(&self, position: TextPosition) -> TextPosition {
+        match self.lines[position.line][position.column..].chars().next() {
+            Some(c) => TextPosition::new(position.line, position.column + c.len_utf8()),
+            None if position.line + 1 < self.lines.len() => TextPosition::new(position.line + 1, 0),
+            None => position,
+        }
+    }
+
+    /// Returns the position on `line` at the preferred column of the caret.
+    fn position_on_line(&mut self, line: usize) -> TextPosition {
+        let column = *self.preferred_column.get_or_insert_with(|| {
+            self.lines[self.caret.line][..self.caret.column]
+                .chars()
+                .count()
+        });
+        let text = &self.lines[line];
+        let column = text
+            .char_indices()
+            .nth(column)
+            .map_or(text.len(), |(i, _)| i);
+        TextPosition::new(line, column)
+    }
+
+    /// Moves the caret, extending the selection if `extend` is true.
+    fn move_caret(&mut self, position: TextPosition, extend: bool) {
+        self.caret = position;
+        if !extend {
+            self.anchor = position;
+        }
+    }
+
+    /// Returns the range of the word at `position`, or of the character there if it isn't a word.
+    fn word_at(&self, position: TextPosition) -> Range<TextPosition> {
+        let line = &self.lines[position.line];
+        let is_word = |c: char| c.is_alphanumeric() || c == '_';
+        let start = line[..position.column]
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| is_word(*c))
+            .last()
+            .map_or(position.column, |(i, _)| i);
+        let end = line[position.column..]
+            .char_indices()
+            .find(|(_, c)| !is_word(*c))
+            .map_or(line.len(), |(i, _)| position.column + i);
+        let end = if start == end {
+            self.next_position(position).column.max(end)
+        } else {
+            end
+        };
+        TextPosition::new(position.line, start)..TextPosition::new(position.line, end)
+    }
+
+    /// Returns the number of lines which fit in the editor.
+    fn visible_line_count(&self) -> usize {
+        let line_height = self.cell_size.map_or(1., |cell| cell.height);
+        ((self.size.height / line_height).floor() as usize).max(1)
+    }
+
+    /// Returns the range of lines which are at least partly visible.
+    fn visible_lines(&self) -> Range<usize> {
+        let line_height = self.cell_size.map_or(1., |cell| cell.height);
+        let first = ((self.scroll.y / line_height) as usize).min(self.lines.len());
+        first..(first + self.visible_line_count() + 1).min(self.lines.len())
+    }
+
+    /// Returns the width of the gutter, including the space after it.
+    fn gutter_width(&self) -> f64 {
+        let char_width = self.cell_size.map_or(0., |cell| cell.width);
+        let digits = self.lines.len().max(10).ilog10() as f64 + 1.;
+        (digits + 1.) * char_width + GUTTER_PADDING
+    }
+
+    /// Returns the largest valid scroll offset.
+    fn max_scroll(&self) -> Vec2 {
+        let cell = self.cell_size.unwrap_or(Size::ZERO);
+        let first = (self.scroll.y / cell.height.max(1.)) as usize;
+        let visible = first..(first + self.visible_line_count() + 1).min(self.lines.len());
+        let columns = self.lines[visible.start.min(self.lines.len())..visible.end]
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let text_width = self.size.width - self.gutter_width();
+        Vec2::new(
+            (columns as f64 * cell.width + cell.width - text_width).max(0.),
+            (self.lines.len() as f64 * cell.height - self.size.height).max(0.),
+        )
+    }
+
+    /// Scrolls by `delta`, returning `true` if the scroll offset changed.
+    fn scroll_by(&mut self, delta: Vec2) -> bool {
+        let old = self.scroll;
+        let max = self.max_scroll();
+        self.scroll = Vec2::new(
+            (self.scroll.x + delta.x).clamp(0., max.x),
+            (self.scroll.y + delta.y).clamp(0., max.y),
+        );
+        self.scroll != old
+    }
+
+    /// Scrolls so that the caret is visible.
+    fn reveal_caret(&mut self) {
+        let Some(cell) = self.cell_size else {
+            return;
+        };
+        let top = self.caret.line as f64 * cell.height;
+        if top < self.scroll.y {
+            self.scroll.y = top;
+        } else if top + cell.height > self.scroll.y + self.size.height {
+            self.scroll.y = (top + cell.height - self.size.height).max(0.);
+        }
+        let columns = self.lines[self.caret.line][..self.caret.column]
+            .chars()
+            .count();
+        let x = columns as f64 * cell.width;
+        let text_width = (self.size.width - self.gutter_width() - cell.width).max(0.);
+        if x < self.scroll.x {
+            self.scroll.x = x;
+        } else if x > self.scroll.x + text_width {
+            self.scroll.x = x - text_width;
+        }
+    }
+
+    /// Measures the size of a character and of a line, if that hasn't been done yet.
+    fn ensure_cell_size(&mut self, fctx: &mut FontContext, lctx: &mut LayoutContext<BrushIndex>) {
+        if self.cell_size.is_none() {
+            let layout = self.build_layout(fctx, lctx, &RichText::new("0"));
+            self.cell_size = Some(Size::new(layout.width().into(), layout.height().into()));
+        }
+    }
+
+    /// Lays out `line` if it isn't already.
+    fn ensure_line_layout(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        line: usize,
+    ) {
+        if self.line_layouts.contains_key(&line) {
+            return;
+        }
+        let text = &self.lines[line];
+        let mut rich_text = RichText::new(text.as_str());
+        if let Some(highlighter) = &self.highlighter {
+            for span in highlighter(line, text) {
+                rich_text = rich_text.with_span(span.range, span.style);
+            }
+        }
+        let layout = self.build_layout(fctx, lctx, &rich_text);
+        let number = self.build_layout(fctx, lctx, &RichText::new((line + 1).to_string()));
+        let line_layout = LineLayout {
+            text: rich_text,
+            layout,
+            number,
+        };
+        self.line_layouts.insert(line, line_layout);
+    }
+
+    /// Lays out a single line of monospace text.
+    fn build_layout(
+        &self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        text: &RichText,
+    ) -> Layout<BrushIndex> {
+        // TODO: Should we use a different scale?
+        // See https://github.com/linebender/xilem/issues/1264
+        let mut builder = lctx.ranged_builder(fctx, text.text(), 1.0, true);
+        builder.push_default(StyleProperty::FontSize(self.font_size));
+        builder.push_default(StyleProperty::LineHeight(LineHeight::FontSizeRelative(1.2)));
+        builder.push_default(GenericFamily::Monospace);
+        text.push_styles(&mut builder);
+        let mut layout = builder.build(text.text());
+        layout.break_all_lines(None);
+        layout
+    }
+
+    /// Returns the text position at `pos`, in the editor's content-box coordinates.
+    fn position_at_point(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        pos: Point,
+    ) -> TextPosition {
+        self.ensure_cell_size(fctx, lctx);
+        let cell = self.cell_size.unwrap();
+        let line = ((pos.y + self.scroll.y) / cell.height).max(0.) as usize;
+        let line = line.min(self.lines.len() - 1);
+        self.ensure_line_layout(fctx, lctx, line);
+        let layout = &self.line_layouts[&line].layout;
+        let x = pos.x - self.gutter_width() + self.scroll.x;
+        let cursor = Cursor::from_point(layout, x as f32, (cell.height / 2.) as f32);
+        TextPosition::new(line, cursor.index())
+    }
+
+    /// Returns the rectangle of the caret, in the editor's content-box coordinates.
+    fn caret_rect(&self) -> Option<Rect> {
+        let cell = self.cell_size?;
+        let x = match self.line_layouts.get(&self.caret.line) {
+            Some(line) => column_x(&line.layout, self.caret.column),
+            None => {
+                let text = &self.lines[self.caret.line][..self.caret.column];
+                text.chars().count() as f64 * cell.width
+            }
+        };
+        let x = x + self.gutter_width() - self.scroll.x;
+        let y = self.caret.line as f64 * cell.height - self.scroll.y;
+        Some(Rect::new(x, y, x + 1.5, y + cell.height))
+    }
+
+    /// Reports the edits, and updates the view after the text or selection changed.
+    fn finish_edit(&mut self, ctx: &mut EventCtx<'_>, actions: Vec<CodeEditorAction>) {
+        for action in actions {
+            ctx.submit_action::<CodeEditorAction>(action);
+        }
+        self.reveal_caret();
+        if let Some(rect) = self.caret_rect() {
+            ctx.set_ime_area(rect);
+        }
+        ctx.request_render();
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl CodeEditor {
+    /// Replaces the text, and moves the caret to the start.
+    ///
+    /// This doesn't emit [`CodeEditorAction::Changed`].
+    pub fn set_text(this: &mut WidgetMut<'_, Self>, text: &str) {
+        this.widget.lines = split_lines(text);
+        this.widget.line_layouts.clear();
+        this.widget.caret = TextPosition::default();
+        this.widget.anchor = TextPosition::default();
+        this.widget.preferred_column = None;
+        this.widget.scroll = Vec2::ZERO;
+        this.ctx.request_render();
+    }
+
+    /// Replaces the text in `range` with `text`, as if the user had edited it.
+    ///
+    /// The positions are clamped to the text.
+    /// The selection is moved to the end of the inserted text.
+    /// This doesn't emit [`CodeEditorAction::Changed`].
+    pub fn replace_range(this: &mut WidgetMut<'_, Self>, range: Range<TextPosition>, text: &str) {
+        let start = this.widget.clamp(range.start);
+        let end = this.widget.clamp(range.end).max(start);
+        this.widget.anchor = start;
+        this.widget.caret = end;
+        this.widget.insert(text);
+        this.widget.reveal_caret();
+        this.ctx.request_render();
+    }
+
+    /// Sets the selection, and scrolls the caret into view.
+    ///
+    /// The positions are clamped to the text.
+    pub fn set_selection(
+        this: &mut WidgetMut<'_, Self>,
+        anchor: TextPosition,
+        caret: TextPosition,
+    ) {
+        this.widget.anchor = this.widget.clamp(anchor);
+        this.widget.caret = this.widget.clamp(caret);
+        this.widget.preferred_column = None;
+        this.widget.reveal_caret();
+        this.ctx.request_render();
+    }
+
+    /// Sets what to insert when the user presses <kbd>Tab</kbd>.
+    ///
+    /// The runtime equivalent of [`with_tab_insertion`](Self::with_tab_insertion).
+    pub fn set_tab_insertion(this: &mut WidgetMut<'_, Self>, tab_insertion: TabInsertion) {
+        this.widget.tab_insertion = tab_insertion;
+    }
+
+    /// Sets whether new lines keep the indentation of the line before them.
+    ///
+    /// The runtime equivalent of [`with_auto_indent`](Self::with_auto_indent).
+    pub fn set_auto_indent(this: &mut WidgetMut<'_, Self>, auto_indent: bool) {
+        this.widget.auto_indent = auto_indent;
+    }
+
+    /// Sets a function which supplies the styles of each line, given its index and text.
+    ///
+    /// The runtime equivalent of [`with_highlighter`](Self::with_highlighter).
+    /// For full documentation, see that method.
+    pub fn set_highlighter(
+        this: &mut WidgetMut<'_, Self>,
+        highlighter: impl Fn(usize, &str) -> Vec<TextSpan> + 'static,
+    ) {
+        this.widget.highlighter = Some(Box::new(highlighter));
+        Self::refresh_highlighting(this);
+    }
+
+    /// Removes the highlighter, so that the text has no styles.
+    pub fn clear_highlighter(this: &mut WidgetMut<'_, Self>) {
+        this.widget.highlighter = None;
+        Self::refresh_highlighting(this);
+    }
+
+    /// Calls the highlighter again for the visible lines.
+    ///
+    /// This is needed when the styles of lines change without them being edited.
+    pub fn refresh_highlighting(this: &mut WidgetMut<'_, Self>) {
+        this.widget.line_layouts.clear();
+        this.ctx.request_render();
+    }
+
+    /// Sets the font size of the text.
+    ///
+    /// The runtime equivalent of [`with_font_size`](Self::with_font_size).
+    pub fn set_font_size(this: &mut WidgetMut<'_, Self>, font_size: f32) {
+        this.widget.font_size = font_size;
+        this.widget.cell_size = None;
+        this.widget.line_layouts.clear();
+        this.ctx.request_layout();
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for CodeEditor {
+    type Action = CodeEditorAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let pos = ctx.local_position(state.position);
+                let (fctx, lctx) = ctx.text_contexts();
+                let position = self.position_at_point(fctx, lctx, pos);
+                if state.count == 2 {
+                    let word = self.word_at(position);
+                    self.anchor = word.start;
+                    self.caret = word.end;
+                } else {
+                    self.move_caret(position, state.modifiers.shift());
+                }
+                self.preferred_column = None;
+                ctx.request_focus();
+                ctx.capture_pointer();
+                self.finish_edit(ctx, Vec::new());
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                if ctx.is_active() {
+                    let pos = ctx.local_position(current.position);
+                    let (fctx, lctx) = ctx.text_contexts();
+                    let position = self.position_at_point(fctx, lctx, pos);
+                    if position != self.caret {
+                        self.move_caret(position, true);
+                        self.preferred_column = None;
+                        self.finish_edit(ctx, Vec::new());
+                    }
+                }
+            }
+            PointerEvent::Scroll(PointerScrollEvent { delta, .. }) => {
+                // TODO - Remove reference to scale factor.
+                // See https://github.com/linebender/xilem/issues/1264
+                let scale_factor = ctx.get_scale_factor();
+                let line_height = self.cell_size.map_or(20., |cell| cell.height);
+                let line_px = PhysicalPosition {
+                    x: 3. * line_height * scale_factor,
+                    y: 3. * line_height * scale_factor,
+                };
+                let page_px = PhysicalPosition {
+                    x: self.size.width * scale_factor,
+                    y: self.size.height * scale_factor,
+                };
+                let delta_px = delta.to_pixel_delta(line_px, page_px);
+                let dpi::LogicalPosition { x, y } = delta_px.to_logical::<f64>(scale_factor);
+                if self.scroll_by(-Vec2::new(x, y)) {
+                    ctx.request_render();
+                    ctx.set_handled();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let actions = match event {
+            TextEvent::Keyboard(key_event) => {
+                if key_event.state != KeyState::Down {
+                    return;
+                }
+                let (shift, action_mod) = (
+                    key_event.modifiers.shift(),
+                    if cfg!(target_os = "macos") {
+                        key_event.modifiers.meta()
+                    } else {
+                        key_event.modifiers.ctrl()
+                    },
+                );
+                let mut actions = Vec::new();
+                match &key_event.key {
+                    Key::Character(a) if action_mod && a.as_str().eq_ignore_ascii_case("a") => {
+                        let last = self.lines.len() - 1;
+                        self.anchor = TextPosition::default();
+                        self.caret = TextPosition::new(last, self.lines[last].len());
+                    }
+                    Key::Character(c) if action_mod && c.as_str().eq_ignore_ascii_case("c") => {
+                        if self.anchor != self.caret {
+                            ctx.set_clipboard(self.selected_text());
+                        }
+                    }
+                    Key::Character(x) if action_mod && x.as_str().eq_ignore_ascii_case("x") => {
+                        if self.anchor != self.caret {
+                            ctx.set_clipboard(self.selected_text());
+                            actions.push(self.insert(""));
+                        }
+                    }
+                    Key::Character(text) if !action_mod => {
+                        actions.push(self.insert(text));
+                    }
+                    Key::Named(NamedKey::Enter) => {
+                        actions.push(self.insert_newline());
+                    }
+                    Key::Named(NamedKey::Tab) => match self.insert_tab(shift) {
+                        Some(tab_actions) => actions = tab_actions,
+                        // Let the focus move.
+                        None => return,
+                    },
+                    Key::Named(NamedKey::Backspace) => actions.extend(self.delete(false)),
+                    Key::Named(NamedKey::Delete) => actions.extend(self.delete(true)),
+                    Key::Named(NamedKey::ArrowLeft) => {
+                        let (start, _) = self.ordered_selection();
+                        let position = if self.anchor != self.caret && !shift {
+                            start
+                        } else {
+                            self.previous_position(self.caret)
+                        };
+                        self.move_caret(position, shift);
+                        self.preferred_column = None;
+                    }
+                    Key::Named(NamedKey::ArrowRight) => {
+                        let (_, end) = self.ordered_selection();
+                        let position = if self.anchor != self.caret && !shift {
+                            end
+                        } else {
+                            self.next_position(self.caret)
+                        };
+                        self.move_caret(position, shift);
+                        self.preferred_column = None;
+                    }
+                    Key::Named(
+                        key @ (NamedKey::ArrowUp
+                        | NamedKey::ArrowDown
+                        | NamedKey::PageUp
+                        | NamedKey::PageDown),
+                    ) => {
+                        let lines = match key {
+                            NamedKey::PageUp | NamedKey::PageDown => self.visible_line_count(),
+                            _ => 1,
+                        };
+                        let line = match key {
+                            NamedKey::ArrowUp | NamedKey::PageUp => {
+                                self.caret.line.saturating_sub(lines)
+                            }
+                            _ => (self.caret.line + lines).min(self.lines.len() - 1),
+                        };
+                        let position = self.position_on_line(line);
+                        self.move_caret(position, shift);
+                    }
+                    Key::Named(NamedKey::Home) => {
+                        let position = if action_mod {
+                            TextPosition::default()
+                        } else {
+                            // Go to the start of the indentation, or the start of the line if
+                            // the caret is already there.
+                            let line = &self.lines[self.caret.line];
+                            let indent = line.len() - line.trim_start().len();
+                            let column = if self.caret.column == indent {
+                                0
+                            } else {
+                                indent
+                            };
+                            TextPosition::new(self.caret.line, column)
+                        };
+                        self.move_caret(position, shift);
+                        self.preferred_column = None;
+                    }
+                    Key::Named(NamedKey::End) => {
+                        let line = if action_mod {
+                            self.lines.len() - 1
+                        } else {
+                            self.caret.line
+                        };
+                        self.move_caret(TextPosition::new(line, self.lines[line].len()), shift);
+                        self.preferred_column = None;
+                    }
+                    _ => return,
+                }
+                actions
+            }
+            TextEvent::Ime(Ime::Commit(text)) => vec![self.insert(text)],
+            TextEvent::ClipboardPaste(text) => vec![self.insert(text)],
+            _ => return,
+        };
+        ctx.set_handled();
+        self.finish_edit(ctx, actions);
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn accepts_text_input(&self) -> bool {
+        true
+    }
+
+    fn font_size(&self) -> Option<f64> {
+        Some(self.font_size.into())
+    }
+
+    fn on_access_event(
+        &mut self,
+        _ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        _event: &AccessEvent,
+    ) {
+    }
+
+    fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::FocusChanged(_) = event {
+            ctx.request_render();
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        CaretColor::prop_changed(ctx, property_type);
+        ContentColor::prop_changed(ctx, property_type);
+        HighlightColor::prop_changed(ctx, property_type);
+        LinkColor::prop_changed(ctx, property_type);
+        SelectionColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        let (fctx, lctx) = ctx.text_contexts();
+        self.ensure_cell_size(fctx, lctx);
+        let cell = self.cell_size.unwrap();
+        match (len_req, axis) {
+            (LenReq::FitContent(space), _) => space,
+            (_, Axis::Horizontal) => self.gutter_width() + PREFERRED_COLUMNS * cell.width,
+            (_, Axis::Vertical) => PREFERRED_LINES * cell.height,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        if ctx.fonts_changed() {
+            self.cell_size = None;
+            self.line_layouts.clear();
+        }
+        let (fctx, lctx) = ctx.text_contexts();
+        self.ensure_cell_size(fctx, lctx);
+        self.size = size;
+        // Keep the scroll offset valid when the editor is resized.
+        self.scroll_by(Vec2::ZERO);
+        ctx.set_clip_path(size.to_rect());
+        if let Some(rect) = self.caret_rect() {
+            ctx.set_ime_area(rect);
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let (fctx, lctx) = ctx.text_contexts();
+        self.ensure_cell_size(fctx, lctx);
+        let cell = self.cell_size.unwrap();
+        let visible = self.visible_lines();
+        // Only the visible lines are kept laid out.
+        self.line_layouts.retain(|line, _| visible.contains(line));
+        for line in visible.clone() {
+            self.ensure_line_layout(fctx, lctx, line);
+        }
+
+        let text_color = props.get::<ContentColor>().color;
+        let link_color = props.get::<LinkColor>().color;
+        let selection_color = props.get::<SelectionColor>().color;
+        let highlight_color = props.get::<HighlightColor>().color;
+        let gutter_width = self.gutter_width();
+        let line_top = |line: usize| line as f64 * cell.height - self.scroll.y;
+        let text_x = gutter_width - self.scroll.x;
+
+        let text_area = Rect::new(gutter_width, 0., self.size.width, self.size.height);
+        scene.push_clip_layer(Fill::NonZero, Affine::IDENTITY, &text_area);
+
+        let (start, end) = self.ordered_selection();
+        for line in visible.start.max(start.line)..visible.end.min(end.line + 1) {
+            let layout = &self.line_layouts[&line].layout;
+            let x0 = if line == start.line {
+                column_x(layout, start.column)
+            } else {
+                0.
+            };
+            let x1 = if line == end.line {
+                column_x(layout, end.column)
+            } else {
+                // Show that the line break is selected.
+                column_x(layout, self.lines[line].len()) + cell.width / 2.
+            };
+            let y = line_top(line);
+            let rect = Rect::new(text_x + x0, y, text_x + x1, y + cell.height);
+            scene.fill(
+                Fill::NonZero,
+                Affine::IDENTITY,
+                selection_color,
+                None,
+                &rect,
+            );
+        }
+
+        if ctx.is_focus_target()
+            && let Some((bracket, matching)) = self.matching_brackets()
+        {
+            for position in [bracket, matching] {
+                let Some(line) = self.line_layouts.get(&position.line) else {
+                    continue;
+                };
+                let next = self.next_position(position).column;
+                let x0 = column_x(&line.layout, position.column);
+                let x1 = column_x(&line.layout, next.max(position.column + 1));
+                let y = line_top(position.line);
+                let rect = Rect::new(text_x + x0, y, text_x + x1, y + cell.height);
+                scene.fill(
+                    Fill::NonZero,
+                    Affine::IDENTITY,
+                    highlight_color,
+                    None,
+                    &rect,
+                );
+            }
+        }
+
+        for line in visible.clone() {
+            let line_layout = &self.line_layouts[&line];
+            let brushes = line_layout.text.brushes(text_color, link_color);
+            let transform = Affine::translate((text_x, line_top(line)));
+            render_text(scene, transform, &line_layout.layout, &brushes, true);
+        }
+
+        if ctx.is_focus_target()
+            && ctx.is_window_focused()
+            && let Some(caret) = self.caret_rect()
+        {
+            let caret_color = props.get::<CaretColor>().color;
+            scene.fill(Fill::NonZero, Affine::IDENTITY, caret_color, None, &caret);
+        }
+        scene.pop_layer();
+
+        // The line numbers, right-aligned in the gutter.
+        let number_color = text_color.multiply_alpha(0.5);
+        for line in visible {
+            let number = &self.line_layouts[&line].number;
+            let color = if line == self.caret.line {
+                text_color
+            } else {
+                number_color
+            };
+            let x = gutter_width - GUTTER_PADDING - f64::from(number.width());
+            let transform = Affine::translate((x, line_top(line)));
+            render_text(scene, transform, number, &[color.into()], true);
+        }
+    }
+
+    fn get_cursor(&self, ctx: &QueryCtx<'_>, pos: Point) -> CursorIcon {
+        let local_pos = ctx.window_transform().inverse() * pos;
+        if local_pos.x < self.gutter_width() {
+            CursorIcon::Default
+        } else {
+            CursorIcon::Text
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::MultilineTextInput
+    }
+
+    fn accessibility(
+        &mut self,
+        ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_value(self.text());
+
+        // As with painting, only the visible lines (and the lines with the ends of
+        // the selection) get a text run, so that the tree stays small for large files.
+        let mut lines: Vec<usize> = self.visible_lines().collect();
+        for line in [self.anchor.line, self.caret.line] {
+            if !lines.contains(&line) {
+                lines.push(line);
+            }
+        }
+        lines.sort_unstable();
+        self.access_ids.retain(|line, _| lines.contains(line));
+        for line in lines {
+            let id = *self
+                .access_ids
+                .entry(line)
+                .or_insert_with(AccessCtx::next_node_id);
+            let mut text = self.lines[line].clone();
+            if line + 1 < self.lines.len() {
+                text.push('\n');
+            }
+            let mut run = Node::new(Role::TextRun);
+            run.set_character_lengths(
+                text.graphemes(true)
+                    .map(|grapheme| u8::try_from(grapheme.len()).unwrap_or(u8::MAX))
+                    .collect::<Vec<_>>(),
+            );
+            run.set_value(text);
+            ctx.tree_update().nodes.push((id, run));
+            node.push_child(id);
+        }
+
+        let access_position = |position: TextPosition| accesskit::TextPosition {
+            node: self.access_ids[&position.line],
+            character_index: self.lines[position.line][..position.column]
+                .graphemes(true)
+                .count(),
+        };
+        node.set_text_selection(TextSelection {
+            anchor: access_position(self.anchor),
+            focus: access_position(self.caret),
+        });
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::new()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("CodeEditor", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(format!(
+            "{} lines, caret at {}:{}",
+            self.lines.len(),
+            self.caret.line + 1,
+            self.caret.column + 1
+        ))
+    }
+}
+
+/// Splits `text` into lines, treating both `\n` and `\r\n` as line endings.
+fn split_lines(text: &str) -> Vec<String> {
+    text.split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).to_string())
+        .collect()
+}
+
+/// Returns the opening and closing bracket of the pair `c` is part of, if any.
+fn bracket_pair(c: char) -> Option<(char, char)> {
+    match c {
+        '(' | ')' => Some(('(', ')')),
+        '[' | ']' => Some(('[', ']')),
+        '{' | '}' => Some(('{', '}')),
+        _ => None,
+    }
+}
+
+/// Returns the horizontal offset of the byte `column` in a laid out line.
+fn column_x(layout: &Layout<BrushIndex>, column: usize) -> f64 {
+    Cursor::from_byte_index(layout, column, Affinity::Downstream)
+        .geometry(layout, 0.)
+        .x0
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::core::{KeyboardEvent, Modifiers, NewWidget, SpanStyle};
+    use crate::palette;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+
+    fn press(harness: &mut TestHarness<CodeEditor>, key: Key, modifiers: Modifiers) {
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            key,
+            modifiers,
+            ..Default::default()
+        }));
+    }
+
+    fn create_harness(editor: CodeEditor) -> TestHarness<CodeEditor> {
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            NewWidget::new(editor),
+            Size::new(400.0, 200.0),
+        );
+        harness.focus_on(Some(harness.root_id()));
+        harness
+    }
+
+    #[test]
+    fn typing_and_auto_indent() {
+        let mut harness = create_harness(CodeEditor::new(""));
+        let enter = Key::Named(NamedKey::Enter);
+
+        harness.keyboard_type_chars("fn main() {");
+        press(&mut harness, enter.clone(), Modifiers::default());
+        harness.keyboard_type_chars("call(");
+        press(&mut harness, enter.clone(), Modifiers::default());
+        harness.keyboard_type_chars("x");
+        assert_eq!(
+            harness.root_widget().lines(),
+            ["fn main() {", "    call(", "        x"]
+        );
+        assert_eq!(
+            harness.pop_action::<CodeEditorAction>(),
+            Some((
+                CodeEditorAction::Changed {
+                    first_line: 0,
+                    removed: 1,
+                    inserted: 1,
+                },
+                harness.root_id()
+            ))
+        );
+
+        // Backspace in the indentation removes a whole level, then joins lines.
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        assert_eq!(harness.root_widget().lines()[2], "    ");
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        assert_eq!(harness.root_widget().lines(), ["fn main() {", "    call("]);
+
+        // Without auto-indent, new lines start at the first column.
+        harness.edit_root_widget(|mut editor| CodeEditor::set_auto_indent(&mut editor, false));
+        press(&mut harness, enter, Modifiers::default());
+        assert_eq!(
+            harness.root_widget().lines(),
+            ["fn main() {", "    call(", ""]
+        );
+    }
+
+    #[test]
+    fn tab_insertion() {
+        let mut harness = create_harness(CodeEditor::new("ab\ncd\nef"));
+        let tab = Key::Named(NamedKey::Tab);
+
+        // Spaces are inserted up to the next tab stop.
+        harness.edit_root_widget(|mut editor| {
+            CodeEditor::set_selection(
+                &mut editor,
+                TextPosition::new(0, 1),
+                TextPosition::new(0, 1),
+            );
+        });
+        press(&mut harness, tab.clone(), Modifiers::default());
+        assert_eq!(harness.root_widget().lines()[0], "a   b");
+
+        // With several lines selected, they are all indented and dedented.
+        harness.edit_root_widget(|mut editor| {
+            CodeEditor::set_selection(
+                &mut editor,
+                TextPosition::new(1, 1),
+                TextPosition::new(2, 0),
+            );
+        });
+        press(&mut harness, tab.clone(), Modifiers::default());
+        assert_eq!(harness.root_widget().lines(), ["a   b", "    cd", "ef"]);
+        assert_eq!(
+            harness.root_widget().selection(),
+            (TextPosition::new(1, 5), TextPosition::new(2, 0))
+        );
+        press(&mut harness, tab.clone(), Modifiers::SHIFT);
+        assert_eq!(harness.root_widget().lines(), ["a   b", "cd", "ef"]);
+
+        harness.edit_root_widget(|mut editor| {
+            CodeEditor::set_tab_insertion(&mut editor, TabInsertion::Tab);
+        });
+        press(&mut harness, tab.clone(), Modifiers::default());
+        assert_eq!(harness.root_widget().lines(), ["a   b", "\tcd", "ef"]);
+
+        // Tab is left to move the focus when the editor doesn't insert anything.
+        harness.edit_root_widget(|mut editor| {
+            CodeEditor::set_tab_insertion(&mut editor, TabInsertion::Never);
+        });
+        press(&mut harness, tab, Modifiers::default());
+        assert_eq!(harness.root_widget().lines(), ["a   b", "\tcd", "ef"]);
+    }
+
+    #[test]
+    fn dedent_short_lines() {
+        let mut harness = create_harness(CodeEditor::new("\n \n  x\n      y"));
+        harness.edit_root_widget(|mut editor| {
+            CodeEditor::set_selection(
+                &mut editor,
+                TextPosition::new(0, 0),
+                TextPosition::new(3, 1),
+            );
+        });
+        press(&mut harness, Key::Named(NamedKey::Tab), Modifiers::SHIFT);
+        assert_eq!(harness.root_widget().lines(), ["", "", "x", "  y"]);
+
+        // Dedenting the empty line on its own does nothing.
+        harness.edit_root_widget(|mut editor| {
+            CodeEditor::set_selection(
+                &mut editor,
+                TextPosition::new(0, 0),
+                TextPosition::new(0, 0),
+            );
+        });
+        press(&mut harness, Key::Named(NamedKey::Tab), Modifiers::SHIFT);
+        assert_eq!(harness.root_widget().lines(), ["", "", "x", "  y"]);
+    }
+
+    #[test]
+    fn accessibility_exposes_text_and_selection() {
+        let mut harness = create_harness(CodeEditor::new("fn main() {\n    é\n}"));
+        harness.edit_root_widget(|mut editor| {
+            CodeEditor::set_selection(
+                &mut editor,
+                TextPosition::new(0, 3),
+                TextPosition::new(1, 6),
+            );
+        });
+        // The accessibility tree is only updated when rendering.
+        let _ = harness.render();
+
+        let node = harness.access_node(harness.root_id()).unwrap();
+        assert_eq!(node.value().as_deref(), Some("fn main() {\n    é\n}"));
+        assert_eq!(node.document_range().text(), "fn main() {\n    é\n}");
+        let selection = node.text_selection().unwrap();
+        assert_eq!(selection.text(), "main() {\n    é");
+    }
+
+    #[test]
+    fn bracket_matching() {
+        let mut editor = CodeEditor::new("f(a[0], {\n  b(c)\n})");
+        editor.caret = TextPosition::new(0, 1);
+        assert_eq!(
+            editor.matching_brackets(),
+            Some((TextPosition::new(0, 1), TextPosition::new(2, 1)))
+        );
+        // The bracket before the caret is used if there is none after it.
+        editor.caret = TextPosition::new(0, 6);
+        assert_eq!(
+            editor.matching_brackets(),
+            Some((TextPosition::new(0, 5), TextPosition::new(0, 3)))
+        );
+        editor.caret = TextPosition::new(2, 0);
+        assert_eq!(
+            editor.matching_brackets(),
+            Some((TextPosition::new(2, 0), TextPosition::new(0, 8)))
+        );
+        editor.caret = TextPosition::new(1, 0);
+        assert_eq!(editor.matching_brackets(), None);
+        // Unbalanced brackets have no match.
+        let mut editor = CodeEditor::new("((a)");
+        editor.caret = TextPosition::new(0, 0);
+        assert_eq!(editor.matching_brackets(), None);
+    }
+
+    #[test]
+    fn only_visible_lines_are_laid_out() {
+        let text = (0..100_000)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>();
+        let highlighted = Rc::new(Cell::new(0));
+        let editor = CodeEditor::new(&text.join("\n")).with_highlighter({
+            let highlighted = highlighted.clone();
+            move |_, line| {
+                highlighted.set(highlighted.get() + 1);
+                vec![TextSpan {
+                    range: 0..4.min(line.len()),
+                    style: SpanStyle::Color(palette::css::RED),
+                }]
+            }
+        });
+        let mut harness = create_harness(editor);
+        harness.render();
+        let visible = harness.root_widget().line_layouts.len();
+        assert!(visible > 0 && visible < 20);
+        assert_eq!(highlighted.get(), visible);
+
+        // Moving to the end scrolls there, and only lays out the lines there.
+        press(&mut harness, Key::Named(NamedKey::End), Modifiers::CONTROL);
+        harness.render();
+        let editor = harness.root_widget();
+        assert_eq!(editor.selection().1, TextPosition::new(99_999, 10));
+        assert!(editor.scroll.y > 0.);
+        assert!(editor.line_layouts.contains_key(&99_999));
+        assert!(!editor.line_layouts.contains_key(&0));
+        assert!(editor.line_layouts.len() <= visible + 1);
+    }
+
+    #[test]
+    fn copy_paste_lines() {
+        let mut harness = create_harness(CodeEditor::new("one\ntwo"));
+        harness.edit_root_widget(|mut editor| {
+            CodeEditor::set_selection(
+                &mut editor,
+                TextPosition::new(0, 1),
+                TextPosition::new(1, 1),
+            );
+        });
+        assert_eq!(harness.root_widget().selected_text(), "ne\nt");
+
+        harness.process_text_event(TextEvent::ClipboardPaste("1\r\n2\n3".into()));
+        assert_eq!(harness.root_widget().lines(), ["o1", "2", "3wo"]);
+        assert_eq!(
+            harness.pop_action::<CodeEditorAction>(),
+            Some((
+                CodeEditorAction::Changed {
+                    first_line: 0,
+                    removed: 2,
+                    inserted: 3,
+                },
+                harness.root_id()
+            ))
+        );
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{HasPopup, Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, ChildrenIds, EventCtx, LayerType, LayoutCtx, MeasureCtx, NewWidget, PaintCtx,
+    PointerButton, PointerButtonEvent, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx,
+    TextEvent, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layers::{Menu, MenuItem, MenuItemSelected};
+use crate::layout::LenReq;
+
+/// A container which opens a context [`Menu`] for its child.
+///
+/// The menu is opened at the pointer with a secondary click anywhere on the child,
+/// or below the container when the `ContextMenu` key or `Shift+F10` is pressed
+/// while the child (or one of its descendants) has focus.
+///
+/// Emits [`MenuItemSelected`] when an item of the menu is chosen.
+pub struct ContextMenuArea {
+    child: WidgetPod<dyn Widget>,
+    items: Vec<MenuItem>,
+}
+
+// --- MARK: BUILDERS
+impl ContextMenuArea {
+    /// Creates a new `ContextMenuArea` showing the given menu items for `child`.
+    pub fn new(
+        child: NewWidget<impl Widget + ?Sized>,
+        items: impl IntoIterator<Item = MenuItem>,
+    ) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            items: items.into_iter().collect(),
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl ContextMenuArea {
+    /// Returns the items of the menu opened by this widget.
+    pub fn items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    fn open_menu(&self, ctx: &mut EventCtx<'_>, position: Point) {
+        if self.items.is_empty() {
+            return;
+        }
+        let menu = Menu::new(self.items.iter().cloned()).with_action_source(ctx.widget_id());
+        ctx.create_layer(LayerType::ContextMenu, NewWidget::new(menu), position);
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl ContextMenuArea {
+    /// Replaces the child widget with a new one.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        let old = std::mem::replace(&mut this.widget.child, child.erased().to_pod());
+        this.ctx.remove_child(old);
+    }
+
+    /// Returns a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Sets the items of the menu.
+    ///
+    /// This only affects menus opened after this call.
+    pub fn set_items(this: &mut WidgetMut<'_, Self>, items: impl IntoIterator<Item = MenuItem>) {
+        this.widget.items = items.into_iter().collect();
+        this.ctx.request_accessibility_update();
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for ContextMenuArea {
+    type Action = MenuItemSelected;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if let PointerEvent::Down(PointerButtonEvent {
+            button: Some(PointerButton::Secondary),
+            state,
+            ..
+        }) = event
+        {
+            self.open_menu(ctx, state.logical_point());
+            ctx.set_handled();
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if key_event.state.is_up() {
+            return;
+        }
+        let is_menu_key = match &key_event.key {
+            Key::Named(NamedKey::ContextMenu) => true,
+            Key::Named(NamedKey::F10) => key_event.modifiers.shift(),
+            _ => false,
+        };
+        if is_menu_key {
+            let position = ctx.to_window(Point::new(0., ctx.content_box_size().height));
+            self.open_menu(ctx, position);
+            ctx.set_handled();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+
+        let child_baseline = ctx.child_baseline_offset(&self.child);
+        ctx.set_baseline_offset(child_baseline);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        if !self.items.is_empty() {
+            node.set_has_popup(HasPopup::Menu);
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("ContextMenuArea", id = id.trace())
+    }
+}
@@ -54,6 +54,8 @@ pub struct Divider {
     content: Option<WidgetPod<dyn Widget>>,
     pad: Length,
     lines: SmallVec<[LineLayout; 2]>,
+    /// The thickness resolved during layout, if one is set.
+    thickness_resolved: Option<f64>,
 }
 
 /// Describes the strategy how to display a dashed divider.
@@ -158,6 +160,7 @@ impl Divider {
             content: None,
             pad: Length::const_px(5.),
             lines: SmallVec::default(),
+            thickness_resolved: None,
         }
     }
 
@@ -172,6 +175,8 @@ impl Divider {
     }
 
     /// Returns `self` with the given line `thickness`.
+    ///
+    /// Percentages are relative to the divider's length along its axis.
     pub fn thickness(mut self, thickness: Length) -> Self {
         self.thickness = Some(thickness);
         self
@@ -197,6 +202,7 @@ impl Divider {
     /// * `10, 5, 20, 30` - 10 px dash, 5px gap, 20px dash, 30px gap
     ///
     /// The pattern can be even longer and in any case will repeat to fill the whole divider space.
+    /// Percentages are relative to the divider's length along its axis.
     ///
     /// The pattern must contain an even number of lengths. With exceptions for zero and one, where
     /// zero lengths means a solid line and one length will be used for both the dash and the gap.
@@ -291,6 +297,7 @@ impl Divider {
     ///
     /// This `pad` determines the amount of space between the divider line and the content.
     /// It does nothing when there is no content.
+    /// Percentages are relative to the divider's length along its axis.
     ///
     /// The default value is 5px.
     pub fn pad(mut self, pad: Length) -> Self {
@@ -483,7 +490,6 @@ impl Widget for Divider {
         let scale = 1.0;
 
         const DEFAULT_LENGTH: f64 = 100.;
-        let thickness = self.thickness.map(|t| t.dp(scale)).unwrap_or(1.);
 
         let content_length = if let Some(content) = &mut self.content {
             let auto_length = len_req.into();
@@ -500,6 +506,11 @@ impl Widget for Divider {
                 LenReq::FitContent(space) => space.max(content_length),
             }
         } else {
+            // Percentages are relative to our length along our axis, which is the cross length.
+            let thickness = self
+                .thickness
+                .map(|t| ctx.resolve_length(t, cross_length).unwrap_or(0.) * scale)
+                .unwrap_or(1.);
             thickness.max(content_length)
         }
     }
@@ -533,10 +544,15 @@ impl Widget for Divider {
             });
         }
 
-        let thickness = self.thickness.map(|t| t.dp(scale)).unwrap_or(1.);
-        let cross_pos = size.get_coord(self.axis.cross()) * 0.5;
+        // Percentages are relative to our length along our axis.
+        let length = size.get_coord(self.axis);
+        let mut resolve = |l: Length| ctx.resolve_length(l, Some(length)).unwrap_or(0.) * scale;
+        self.thickness_resolved = self.thickness.map(&mut resolve);
         let mut dashes: SmallVec<[f64; 4]> =
-            self.dash_pattern.iter().map(|l| l.dp(scale)).collect();
+            self.dash_pattern.iter().map(|&l| resolve(l)).collect();
+        let pad = resolve(self.pad);
+        let thickness = self.thickness_resolved.unwrap_or(1.);
+        let cross_pos = size.get_coord(self.axis.cross()) * 0.5;
 
         if let Some(content) = &mut self.content {
             let content_size = ctx.compute_size(content, SizeDef::fit(size), size.into());
@@ -561,7 +577,6 @@ impl Widget for Divider {
             let bottom_gap = size.height - content_bottom;
             ctx.set_baseline_offset(content_baseline + bottom_gap);
 
-            let pad = self.pad.dp(scale);
             let mut line_space = size.get_coord(self.axis)
                 - self.total_cap_overhang(thickness)
                 - content_size.get_coord(self.axis)
@@ -613,15 +628,11 @@ impl Widget for Divider {
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
-        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
-        //       https://github.com/linebender/xilem/issues/1264
-        let scale = 1.0;
-
         // TODO: Remove HACK: After scale factor rework this can be a simple 1.
         let one_dp = 1. / ctx.get_scale_factor();
 
         let color = props.get::<ContentColor>();
-        let thickness = self.thickness.map(|t| t.dp(scale)).unwrap_or(one_dp);
+        let thickness = self.thickness_resolved.unwrap_or(one_dp);
 
         for line in &self.lines {
             let style = Stroke {
@@ -0,0 +1,1082 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use accesskit::{HasPopup, Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Axis, BezPath, Point, Rect, Size};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, ArcStr, ChildrenIds, ComposeCtx, EventCtx, HasProperty, Layer,
+    LayerType, LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PointerButton, PointerButtonEvent,
+    PointerEvent, PointerUpdate, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update,
+    UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::layout::{LayoutSize, LenDef, LenReq, SizeDef};
+use crate::properties::{ContentColor, SelectionColor};
+use crate::theme;
+use crate::util::{Duration, Instant, fill_color, stroke};
+use crate::widgets::{Label, Portal};
+
+/// Width of the column in which the dropdown arrow is painted.
+const ARROW_COLUMN_WIDTH: f64 = 20.;
+/// Maximum height of the option list before it starts scrolling.
+const MAX_LIST_HEIGHT: f64 = 300.;
+/// Time after which typed characters start a new typeahead search.
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// A button showing the selected option out of a list, which opens the list
+/// of options in a [layer] when clicked.
+///
+/// While closed, the selection can be changed with the arrow keys, or by typing
+/// the first characters of an option.
+/// The list is opened with a click, `Enter`, `Space`, `F4` or `Alt+ArrowDown`.
+///
+/// Emits [`DropdownSelected`] when an option is chosen.
+/// Note that the selected option does not change automatically, and so one of
+/// the responses to a `DropdownSelected` is to call [`Dropdown::set_selected`]
+/// on the originating widget.
+///
+/// [layer]: crate::doc::masonry_concepts#layers
+pub struct Dropdown {
+    options: Vec<ArcStr>,
+    selected: Option<usize>,
+    label: WidgetPod<Label>,
+    placeholder: ArcStr,
+    /// The root of the option list's layer, and whether that list is still open.
+    list: Option<(WidgetId, Arc<AtomicBool>)>,
+    typeahead: Typeahead,
+}
+
+/// The action type emitted by [`Dropdown`] when an option is chosen.
+///
+/// The field is the index of the chosen option.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DropdownSelected(pub usize);
+
+// --- MARK: BUILDERS
+impl Dropdown {
+    /// Creates a new `Dropdown` with the given options and selected option.
+    pub fn new(
+        options: impl IntoIterator<Item = impl Into<ArcStr>>,
+        selected: Option<usize>,
+    ) -> Self {
+        let options: Vec<ArcStr> = options.into_iter().map(Into::into).collect();
+        let selected = selected.filter(|idx| *idx < options.len());
+        let placeholder = ArcStr::from("");
+        let text = selected.map_or(placeholder.clone(), |idx| options[idx].clone());
+        Self {
+            options,
+            selected,
+            label: WidgetPod::new(Label::new(text)),
+            placeholder,
+            list: None,
+            typeahead: Typeahead::default(),
+        }
+    }
+
+    /// Builder-style method to set the text shown when no option is selected.
+    pub fn with_placeholder(mut self, placeholder: impl Into<ArcStr>) -> Self {
+        self.placeholder = placeholder.into();
+        if self.selected.is_none() {
+            self.label = WidgetPod::new(Label::new(self.placeholder.clone()));
+        }
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl Dropdown {
+    /// Returns the options of the dropdown.
+    pub fn options(&self) -> &[ArcStr] {
+        &self.options
+    }
+
+    /// Returns the index of the selected option.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Returns `true` if the option list is open.
+    pub fn is_open(&self) -> bool {
+        self.list
+            .as_ref()
+            .is_some_and(|(_, open)| open.load(Ordering::Relaxed))
+    }
+
+    fn label_text(&self) -> ArcStr {
+        self.selected
+            .map_or(self.placeholder.clone(), |idx| self.options[idx].clone())
+    }
+
+    fn open_list(&mut self, ctx: &mut EventCtx<'_>) {
+        if self.options.is_empty() {
+            return;
+        }
+        let open = Arc::new(AtomicBool::new(true));
+        let list = DropdownList::new(
+            &self.options,
+            self.selected,
+            ctx.widget_id(),
+            ctx.border_box_size(),
+            open.clone(),
+        );
+        let list = NewWidget::new(list);
+        let position = self.list_position(ctx);
+        self.list = Some((list.id(), open));
+        ctx.create_layer(LayerType::Dropdown, list, position);
+        ctx.request_accessibility_update();
+    }
+
+    fn close_list(&mut self, ctx: &mut EventCtx<'_>) {
+        if let Some((list_id, open)) = self.list.take()
+            && open.swap(false, Ordering::Relaxed)
+        {
+            ctx.remove_layer(list_id);
+        }
+        ctx.request_accessibility_update();
+    }
+
+    /// Returns the position of the option list, in the window's coordinate space.
+    fn list_position(&self, ctx: &EventCtx<'_>) -> Point {
+        let border_box = ctx.border_box();
+        ctx.to_window(Point::new(border_box.x0, border_box.y1))
+    }
+
+    /// Submits a selection change, unless `idx` is already selected.
+    fn select(&mut self, ctx: &mut EventCtx<'_>, idx: usize) {
+        if self.selected != Some(idx) {
+            ctx.submit_action::<<Self as Widget>::Action>(DropdownSelected(idx));
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Dropdown {
+    /// Sets the selected option.
+    ///
+    /// Indices out of bounds clear the selection.
+    pub fn set_selected(this: &mut WidgetMut<'_, Self>, selected: Option<usize>) {
+        this.widget.selected = selected.filter(|idx| *idx < this.widget.options.len());
+        let text = this.widget.label_text();
+        Label::set_text(&mut this.ctx.get_mut(&mut this.widget.label), text);
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Replaces the options of the dropdown, keeping the selected index if it's still valid.
+    ///
+    /// This only affects option lists opened after this call.
+    pub fn set_options(
+        this: &mut WidgetMut<'_, Self>,
+        options: impl IntoIterator<Item = impl Into<ArcStr>>,
+    ) {
+        this.widget.options = options.into_iter().map(Into::into).collect();
+        let selected = this.widget.selected;
+        Self::set_selected(this, selected);
+    }
+
+    /// Sets the text shown when no option is selected.
+    pub fn set_placeholder(this: &mut WidgetMut<'_, Self>, placeholder: impl Into<ArcStr>) {
+        this.widget.placeholder = placeholder.into();
+        let selected = this.widget.selected;
+        Self::set_selected(this, selected);
+    }
+
+    /// Returns a mutable reference to the label showing the selected option.
+    pub fn label_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Label> {
+        this.ctx.get_mut(&mut this.widget.label)
+    }
+}
+
+impl HasProperty<ContentColor> for Dropdown {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Dropdown {
+    type Action = DropdownSelected;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if let PointerEvent::Down(PointerButtonEvent {
+            button: Some(PointerButton::Primary),
+            ..
+        }) = event
+        {
+            ctx.request_focus();
+            if self.is_open() {
+                self.close_list(ctx);
+            } else {
+                self.open_list(ctx);
+            }
+            ctx.request_paint_only();
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if key_event.state.is_up() {
+            return;
+        }
+        let last = self.options.len().checked_sub(1);
+        match &key_event.key {
+            Key::Named(NamedKey::ArrowDown) if key_event.modifiers.alt() => self.open_list(ctx),
+            Key::Named(NamedKey::Enter | NamedKey::F4) => self.open_list(ctx),
+            Key::Character(c) if c == " " && self.typeahead.is_empty() => self.open_list(ctx),
+            Key::Named(NamedKey::ArrowDown) => {
+                let next = self.selected.map_or(0, |idx| idx + 1);
+                if let Some(last) = last {
+                    self.select(ctx, next.min(last));
+                }
+            }
+            Key::Named(NamedKey::ArrowUp) => {
+                let prev = self.selected.map_or(0, |idx| idx.saturating_sub(1));
+                if last.is_some() {
+                    self.select(ctx, prev);
+                }
+            }
+            Key::Named(NamedKey::Home) if last.is_some() => self.select(ctx, 0),
+            Key::Named(NamedKey::End) => {
+                if let Some(last) = last {
+                    self.select(ctx, last);
+                }
+            }
+            Key::Character(c) => {
+                if let Some(idx) = self.typeahead.search(c, &self.options, self.selected) {
+                    self.select(ctx, idx);
+                }
+            }
+            _ => return,
+        }
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        match event.action {
+            accesskit::Action::Click | accesskit::Action::Expand if !self.is_open() => {
+                self.open_list(ctx);
+            }
+            accesskit::Action::Collapse if self.is_open() => self.close_list(ctx),
+            _ => {}
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::HoveredChanged(_) | Update::ActiveChanged(_) | Update::DisabledChanged(_) => {
+                ctx.request_paint_only();
+            }
+            Update::FocusChanged(_) => {
+                // The option list gives focus back when it closes.
+                ctx.request_render();
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        let arrow_length = match axis {
+            Axis::Horizontal => ARROW_COLUMN_WIDTH,
+            Axis::Vertical => 0.,
+        };
+        let cross_space = cross_length.map(|cross_length| match axis {
+            Axis::Horizontal => cross_length,
+            Axis::Vertical => (cross_length - ARROW_COLUMN_WIDTH).max(0.),
+        });
+
+        let label_length = ctx.compute_length(
+            &mut self.label,
+            len_req.reduce(arrow_length).into(),
+            LayoutSize::maybe(axis.cross(), cross_space),
+            axis,
+            cross_space,
+        );
+
+        match axis {
+            Axis::Horizontal => label_length + arrow_length,
+            Axis::Vertical => label_length.max(theme::BASIC_WIDGET_HEIGHT.dp(scale)),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let space = Size::new((size.width - ARROW_COLUMN_WIDTH).max(0.), size.height);
+        let label_size = ctx.compute_size(&mut self.label, SizeDef::fit(space), space.into());
+        ctx.run_layout(&mut self.label, label_size);
+
+        let label_origin = Point::new(0., (size.height - label_size.height) * 0.5);
+        ctx.place_child(&mut self.label, label_origin);
+
+        let label_baseline = ctx.child_baseline_offset(&self.label);
+        let label_bottom = label_origin.y + label_size.height;
+        ctx.set_baseline_offset(label_baseline + size.height - label_bottom);
+    }
+
+    fn compose(&mut self, ctx: &mut ComposeCtx<'_>) {
+        // Keep the option list attached to the dropdown when it moves.
+        if let Some((list_id, open)) = &self.list
+            && open.load(Ordering::Relaxed)
+        {
+            let border_box = ctx.border_box();
+            let position = ctx.to_window(Point::new(border_box.x0, border_box.y1));
+            ctx.reposition_layer(*list_id, position);
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let color = props.get::<ContentColor>().color;
+        let size = ctx.content_box_size();
+        let center = Point::new(size.width - ARROW_COLUMN_WIDTH / 2., size.height / 2.);
+
+        let mut arrow = BezPath::new();
+        arrow.move_to((center.x - 4., center.y - 2.));
+        arrow.line_to((center.x, center.y + 2.));
+        arrow.line_to((center.x + 4., center.y - 2.));
+        stroke(scene, &arrow, color, 1.5);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ComboBox
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_has_popup(HasPopup::Listbox);
+        node.set_expanded(self.is_open());
+        if let Some(idx) = self.selected {
+            node.set_value(&*self.options[idx]);
+        }
+        node.add_action(accesskit::Action::Click);
+        if self.is_open() {
+            node.add_action(accesskit::Action::Collapse);
+        } else {
+            node.add_action(accesskit::Action::Expand);
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Dropdown", id = id.trace())
+    }
+}
+
+// --- MARK: TYPEAHEAD
+/// Characters typed in quick succession, used to find an option by its first characters.
+#[derive(Default)]
+struct Typeahead {
+    text: String,
+    last_input: Option<Instant>,
+}
+
+impl Typeahead {
+    fn is_empty(&self) -> bool {
+        self.last_input
+            .is_none_or(|last_input| last_input.elapsed() > TYPEAHEAD_TIMEOUT)
+    }
+
+    /// Adds `c` to the search text, and returns the index of the matching option.
+    ///
+    /// Typing the same character repeatedly cycles through the options starting with it.
+    fn search(&mut self, c: &str, options: &[ArcStr], current: Option<usize>) -> Option<usize> {
+        if self.is_empty() {
+            self.text.clear();
+        }
+        self.last_input = Some(Instant::now());
+        self.text.push_str(&c.to_lowercase());
+
+        let mut chars = self.text.chars();
+        let first = chars.next()?;
+        let repeated = chars.all(|c| c == first);
+        let (query, start) = if repeated {
+            // Cycle through the options starting with that character.
+            (first.to_string(), current.map_or(0, |idx| idx + 1))
+        } else {
+            (self.text.clone(), current.unwrap_or(0))
+        };
+
+        (0..options.len())
+            .map(|offset| (start + offset) % options.len())
+            .find(|idx| options[*idx].to_lowercase().starts_with(&query))
+    }
+}
+
+// --- MARK: OPTION LIST
+/// The layer showing the options of a [`Dropdown`].
+pub(crate) struct DropdownList {
+    options: Vec<ArcStr>,
+    highlighted: Option<usize>,
+    portal: WidgetPod<Portal<OptionColumn>>,
+    row_ids: Vec<WidgetId>,
+    /// The dropdown which opened this list.
+    source: WidgetId,
+    source_size: Size,
+    open: Arc<AtomicBool>,
+    typeahead: Typeahead,
+}
+
+impl DropdownList {
+    fn new(
+        options: &[ArcStr],
+        selected: Option<usize>,
+        source: WidgetId,
+        source_size: Size,
+        open: Arc<AtomicBool>,
+    ) -> Self {
+        let rows: Vec<_> = options
+            .iter()
+            .enumerate()
+            .map(|(idx, option)| {
+                NewWidget::new(DropdownOption::new(option.clone(), selected == Some(idx)))
+            })
+            .collect();
+        let row_ids = rows.iter().map(|row| row.id()).collect();
+        let column = OptionColumn {
+            rows: rows.into_iter().map(NewWidget::to_pod).collect(),
+            row_rects: Vec::new(),
+        };
+        let portal = Portal::new(NewWidget::new(column)).constrain_horizontal(true);
+        Self {
+            options: options.to_vec(),
+            highlighted: selected,
+            portal: WidgetPod::new(portal),
+            row_ids,
+            source,
+            source_size,
+            open,
+            typeahead: Typeahead::default(),
+        }
+    }
+
+    fn set_highlighted(&mut self, ctx: &mut EventCtx<'_>, idx: Option<usize>) {
+        if self.highlighted == idx {
+            return;
+        }
+        let prev = self.highlighted;
+        self.highlighted = idx;
+        ctx.mutate_later(&mut self.portal, move |mut portal| {
+            let row_rect = {
+                let mut column = Portal::child_mut(&mut portal);
+                if let Some(prev) = prev {
+                    OptionColumn::set_highlighted(&mut column, prev, false);
+                }
+                let Some(idx) = idx else {
+                    return;
+                };
+                OptionColumn::set_highlighted(&mut column, idx, true);
+                column.widget.row_rects.get(idx).copied()
+            };
+            if let Some(row_rect) = row_rect {
+                Portal::pan_viewport_to(&mut portal, row_rect);
+            }
+        });
+    }
+
+    fn move_highlight(&mut self, ctx: &mut EventCtx<'_>, delta: isize) {
+        let Some(last) = self.options.len().checked_sub(1) else {
+            return;
+        };
+        let idx = match self.highlighted {
+            Some(idx) => idx.saturating_add_signed(delta).min(last),
+            None if delta < 0 => last,
+            None => 0,
+        };
+        self.set_highlighted(ctx, Some(idx));
+    }
+
+    /// Chooses the option at `idx` and closes the list.
+    fn choose(&mut self, ctx: &mut EventCtx<'_>, idx: usize) {
+        ctx.submit_untyped_action_for(self.source, Box::new(DropdownSelected(idx)));
+        self.dismiss(ctx);
+    }
+
+    /// Closes the list, giving focus back to the dropdown.
+    fn dismiss(&mut self, ctx: &mut EventCtx<'_>) {
+        if self.open.swap(false, Ordering::Relaxed) {
+            if ctx.has_focus_target() {
+                ctx.set_focus(self.source);
+            }
+            ctx.remove_layer(ctx.widget_id());
+        }
+    }
+}
+
+impl Widget for DropdownList {
+    type Action = DropdownSelected;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        let target = ctx.target();
+        let row = self.row_ids.iter().position(|id| *id == target);
+        match event {
+            PointerEvent::Move(PointerUpdate { .. }) => {
+                if row.is_some() {
+                    self.set_highlighted(ctx, row);
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                ..
+            }) => {
+                if let Some(idx) = row {
+                    self.choose(ctx, idx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if key_event.state.is_up() {
+            // Like buttons, options are chosen when the key is released,
+            // so that the release doesn't reach the dropdown which gets focus back.
+            let is_space = matches!(&key_event.key, Key::Character(c) if c == " ");
+            if key_event.key == Key::Named(NamedKey::Enter)
+                || (is_space && self.typeahead.is_empty())
+            {
+                if let Some(idx) = self.highlighted {
+                    self.choose(ctx, idx);
+                }
+                ctx.set_handled();
+            }
+            return;
+        }
+        let page = (MAX_LIST_HEIGHT / theme::BORDERED_WIDGET_HEIGHT) as isize;
+        match &key_event.key {
+            Key::Named(NamedKey::ArrowDown) => self.move_highlight(ctx, 1),
+            Key::Named(NamedKey::ArrowUp) if key_event.modifiers.alt() => self.dismiss(ctx),
+            Key::Named(NamedKey::ArrowUp) => self.move_highlight(ctx, -1),
+            Key::Named(NamedKey::PageDown) => self.move_highlight(ctx, page),
+            Key::Named(NamedKey::PageUp) => self.move_highlight(ctx, -page),
+            Key::Named(NamedKey::Home) => self.move_highlight(ctx, isize::MIN),
+            Key::Named(NamedKey::End) => self.move_highlight(ctx, isize::MAX),
+            Key::Named(NamedKey::Escape | NamedKey::Tab | NamedKey::F4) => self.dismiss(ctx),
+            Key::Named(NamedKey::Enter) => {}
+            Key::Character(c) if c == " " && self.typeahead.is_empty() => {}
+            Key::Character(c) => {
+                let found = self.typeahead.search(c, &self.options, self.highlighted);
+                if found.is_some() {
+                    self.set_highlighted(ctx, found);
+                }
+            }
+            _ => return,
+        }
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if event.action == accesskit::Action::Click {
+            let target = ctx.target();
+            if let Some(idx) = self.row_ids.iter().position(|id| *id == target) {
+                self.choose(ctx, idx);
+            }
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::WidgetAdded = event {
+            ctx.request_focus();
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.portal);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        // The list is at least as wide as the dropdown, and scrolls if it's too tall.
+        match axis {
+            Axis::Horizontal => {
+                let width = ctx.compute_length(
+                    &mut self.portal,
+                    LenDef::MaxContent,
+                    LayoutSize::NONE,
+                    axis,
+                    None,
+                );
+                width.max(self.source_size.width)
+            }
+            Axis::Vertical => {
+                let height = ctx.compute_length(
+                    &mut self.portal,
+                    LenDef::MaxContent,
+                    LayoutSize::NONE,
+                    axis,
+                    cross_length,
+                );
+                height.min(MAX_LIST_HEIGHT)
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.portal, size);
+        ctx.place_child(&mut self.portal, Point::ORIGIN);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::ListBox
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.portal.id()])
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn as_layer(&mut self) -> Option<&mut dyn Layer> {
+        Some(self)
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("DropdownList", id = id.trace())
+    }
+}
+
+impl Layer for DropdownList {
+    fn capture_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        // Clicking outside of the list closes it.
+        // Clicks on the dropdown itself are left for the dropdown to handle.
+        if let PointerEvent::Down(PointerButtonEvent { state, .. }) = event {
+            let pos = ctx.local_position(state.position);
+            let border_box = ctx.border_box();
+            let source_rect = Rect::from_origin_size(
+                (border_box.x0, border_box.y0 - self.source_size.height),
+                self.source_size,
+            );
+            if !border_box.contains(pos) && !source_rect.contains(pos) {
+                self.dismiss(ctx);
+            }
+        }
+    }
+}
+
+/// The vertical list of options inside the [`Portal`] of a [`DropdownList`].
+struct OptionColumn {
+    rows: Vec<WidgetPod<DropdownOption>>,
+    /// The rect of each row in this widget's content-box coordinate space.
+    row_rects: Vec<Rect>,
+}
+
+impl OptionColumn {
+    fn set_highlighted(this: &mut WidgetMut<'_, Self>, idx: usize, highlighted: bool) {
+        if let Some(row) = this.widget.rows.get_mut(idx) {
+            let mut row = this.ctx.get_mut(row);
+            row.widget.highlighted = highlighted;
+            row.ctx.request_render();
+        }
+    }
+}
+
+impl Widget for OptionColumn {
+    type Action = ();
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for row in &mut self.rows {
+            ctx.register_child(row);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let lengths = self
+            .rows
+            .iter_mut()
+            .map(|row| ctx.compute_length(row, auto_length, context_size, axis, cross_length));
+        match axis {
+            Axis::Horizontal => lengths.fold(0., f64::max),
+            Axis::Vertical => lengths.sum(),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        self.row_rects.clear();
+        let mut y = 0.;
+        for row in &mut self.rows {
+            let row_size = ctx.compute_size(
+                row,
+                SizeDef::fixed(Size::new(size.width, 0.)).with_height(LenDef::MaxContent),
+                size.into(),
+            );
+            let row_size = Size::new(size.width, row_size.height);
+            ctx.run_layout(row, row_size);
+            let origin = Point::new(0., y);
+            ctx.place_child(row, origin);
+            self.row_rects
+                .push(Rect::from_origin_size(origin, row_size));
+            y += row_size.height;
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.rows.iter().map(|row| row.id()).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("OptionColumn", id = id.trace())
+    }
+}
+
+/// A single option of a [`DropdownList`].
+pub(crate) struct DropdownOption {
+    label: WidgetPod<Label>,
+    selected: bool,
+    highlighted: bool,
+}
+
+impl DropdownOption {
+    fn new(text: ArcStr, selected: bool) -> Self {
+        Self {
+            label: WidgetPod::new(Label::new(text)),
+            selected,
+            highlighted: selected,
+        }
+    }
+}
+
+impl HasProperty<SelectionColor> for DropdownOption {}
+
+impl Widget for DropdownOption {
+    type Action = ();
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        SelectionColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let auto_length = len_req.into();
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        ctx.compute_length(
+            &mut self.label,
+            auto_length,
+            context_size,
+            axis,
+            cross_length,
+        )
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let label_size = ctx.compute_size(&mut self.label, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.label, label_size);
+        let origin = Point::new(0., (size.height - label_size.height) * 0.5);
+        ctx.place_child(&mut self.label, origin);
+    }
+
+    fn pre_paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        if self.highlighted {
+            let color = props.get::<SelectionColor>().color;
+            fill_color(scene, &ctx.border_box(), color);
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::ListBoxOption
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_selected(self.selected);
+        node.add_action(accesskit::Action::Click);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("DropdownOption", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::WidgetTag;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::Flex;
+
+    const DROPDOWN: WidgetTag<Dropdown> = WidgetTag::named("dropdown");
+
+    const FRUITS: [&str; 5] = ["Apple", "Banana", "Blueberry", "Cherry", "Date"];
+
+    fn harness(selected: Option<usize>) -> TestHarness<Flex> {
+        let dropdown = NewWidget::new_with_tag(Dropdown::new(FRUITS, selected), DROPDOWN);
+        let root = Flex::column().with_fixed(dropdown).with_auto_id();
+        TestHarness::create_with_size(test_property_set(), root, Size::new(200., 250.))
+    }
+
+    fn open_list(harness: &mut TestHarness<Flex>) -> WidgetId {
+        let dropdown_id = harness.get_widget(DROPDOWN).id();
+        harness.mouse_click_on(dropdown_id);
+
+        // The list takes focus when opened.
+        let list_id = harness.focused_widget_id().unwrap();
+        assert!(
+            harness
+                .get_widget_with_id(list_id)
+                .downcast::<DropdownList>()
+                .is_some()
+        );
+        list_id
+    }
+
+    fn key(harness: &mut TestHarness<Flex>, key: Key) {
+        harness.process_text_event(TextEvent::key_down(key.clone()));
+        harness.process_text_event(TextEvent::key_up(key));
+    }
+
+    fn highlighted(harness: &TestHarness<Flex>, list_id: WidgetId) -> Option<usize> {
+        let list = harness.get_widget_with_id(list_id);
+        list.downcast::<DropdownList>().unwrap().inner().highlighted
+    }
+
+    #[test]
+    fn simple_dropdown() {
+        let mut harness = harness(Some(0));
+        assert_render_snapshot!(harness, "dropdown_closed");
+
+        let list_id = open_list(&mut harness);
+        assert!(harness.get_widget(DROPDOWN).inner().is_open());
+        assert_eq!(highlighted(&harness, list_id), Some(0));
+        assert_render_snapshot!(harness, "dropdown_open");
+    }
+
+    #[test]
+    fn click_option() {
+        let mut harness = harness(None);
+        let list_id = open_list(&mut harness);
+
+        let option_id = harness
+            .get_widget_with_id(list_id)
+            .downcast::<DropdownList>()
+            .unwrap()
+            .inner()
+            .row_ids[3];
+        harness.mouse_move_to_unchecked(option_id);
+        assert_eq!(highlighted(&harness, list_id), Some(3));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+
+        let dropdown_id = harness.get_widget(DROPDOWN).id();
+        assert_eq!(
+            harness.pop_action::<DropdownSelected>(),
+            Some((DropdownSelected(3), dropdown_id))
+        );
+        assert!(!harness.get_widget(DROPDOWN).inner().is_open());
+        assert_eq!(harness.focused_widget_id(), Some(dropdown_id));
+    }
+
+    #[test]
+    fn keyboard_selection_in_list() {
+        let mut harness = harness(Some(1));
+        let list_id = open_list(&mut harness);
+
+        key(&mut harness, Key::Named(NamedKey::ArrowDown));
+        assert_eq!(highlighted(&harness, list_id), Some(2));
+        key(&mut harness, Key::Named(NamedKey::End));
+        assert_eq!(highlighted(&harness, list_id), Some(4));
+        key(&mut harness, Key::Named(NamedKey::Home));
+        assert_eq!(highlighted(&harness, list_id), Some(0));
+        key(&mut harness, Key::Character("c".into()));
+        assert_eq!(highlighted(&harness, list_id), Some(3));
+
+        key(&mut harness, Key::Named(NamedKey::Enter));
+        let dropdown_id = harness.get_widget(DROPDOWN).id();
+        assert_eq!(
+            harness.pop_action::<DropdownSelected>(),
+            Some((DropdownSelected(3), dropdown_id))
+        );
+        assert!(!harness.get_widget(DROPDOWN).inner().is_open());
+    }
+
+    #[test]
+    fn keyboard_selection_when_closed() {
+        let mut harness = harness(None);
+        let dropdown_id = harness.get_widget(DROPDOWN).id();
+        harness.focus_on(Some(dropdown_id));
+
+        key(&mut harness, Key::Named(NamedKey::ArrowDown));
+        assert_eq!(
+            harness.pop_action::<DropdownSelected>(),
+            Some((DropdownSelected(0), dropdown_id))
+        );
+
+        // Typing the same letter cycles through the options starting with it.
+        harness.edit_widget(DROPDOWN, |mut dropdown| {
+            Dropdown::set_selected(&mut dropdown, Some(0));
+        });
+        key(&mut harness, Key::Character("b".into()));
+        assert_eq!(
+            harness.pop_action::<DropdownSelected>(),
+            Some((DropdownSelected(1), dropdown_id))
+        );
+        harness.edit_widget(DROPDOWN, |mut dropdown| {
+            Dropdown::set_selected(&mut dropdown, Some(1));
+        });
+        key(&mut harness, Key::Character("b".into()));
+        assert_eq!(
+            harness.pop_action::<DropdownSelected>(),
+            Some((DropdownSelected(2), dropdown_id))
+        );
+        assert!(!harness.get_widget(DROPDOWN).inner().is_open());
+    }
+
+    #[test]
+    fn escape_and_click_outside_dismiss() {
+        let mut harness = harness(Some(0));
+        open_list(&mut harness);
+        key(&mut harness, Key::Named(NamedKey::Escape));
+        assert!(!harness.get_widget(DROPDOWN).inner().is_open());
+        assert_eq!(
+            harness.focused_widget_id(),
+            Some(harness.get_widget(DROPDOWN).id())
+        );
+
+        open_list(&mut harness);
+        harness.mouse_move((190., 240.));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert!(!harness.get_widget(DROPDOWN).inner().is_open());
+        assert!(harness.pop_action_erased().is_none());
+    }
+
+    #[test]
+    fn clicking_dropdown_toggles_list() {
+        let mut harness = harness(Some(0));
+        open_list(&mut harness);
+
+        let dropdown_id = harness.get_widget(DROPDOWN).id();
+        harness.mouse_click_on(dropdown_id);
+        assert!(!harness.get_widget(DROPDOWN).inner().is_open());
+        assert!(harness.pop_action_erased().is_none());
+    }
+}
@@ -197,6 +197,7 @@ impl Flex {
     /// Builder-style method for adding a fixed-size spacer child to the container.
     ///
     /// A good default is [`DEFAULT_SPACER_LEN`](crate::theme::DEFAULT_SPACER_LEN).
+    /// Percentages are relative to the container's length on its main axis.
     pub fn with_fixed_spacer(mut self, len: Length) -> Self {
         let new_child = Child::Spacer {
             flex: 0.,
@@ -527,6 +528,17 @@ impl CollectionWidget<FlexParams> for Flex {
         this.ctx.children_changed();
     }
 
+    /// Moves the child at index `from` to index `to`, shifting the children in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+    }
+
     /// Removes the child at the given index.
     ///
     /// # Panics
@@ -717,16 +729,26 @@ impl Widget for Flex {
         let main = self.direction;
         let cross = main.cross();
 
-        let gap = props.get::<Gap>();
-
-        let gap_length = gap.gap.dp(scale);
-        let gap_count = self.children.len().saturating_sub(1);
-
         let (main_space, cross_space) = if perp == main {
             (perp_length, None)
         } else {
             (None, perp_length)
         };
+        // Percentages of gaps and spacers are resolved against our main length.
+        // When we're measuring it, that's only known if we're asked to fit some space.
+        // Otherwise they're treated as zero, like cyclic percentages in CSS.
+        let main_context_length = match len_req {
+            LenReq::FitContent(space) if measure_axis == main => Some(space),
+            _ => main_space,
+        };
+
+        let gap = props.get::<Gap>();
+
+        let gap_length = ctx
+            .resolve_length(gap.gap, main_context_length)
+            .unwrap_or(0.)
+            * scale;
+        let gap_count = self.children.len().saturating_sub(1);
         let context_size = LayoutSize::maybe(perp, perp_length);
 
         let (len_req, min_result) = match len_req {
@@ -774,7 +796,10 @@ impl Widget for Flex {
                         basis_resolved,
                         ..
                     } => {
-                        *basis_resolved = basis.dp(scale);
+                        *basis_resolved = ctx
+                            .resolve_length(*basis, main_context_length)
+                            .unwrap_or(0.)
+                            * scale;
                     }
                 }
             }
@@ -928,15 +953,17 @@ impl Widget for Flex {
         //       https://github.com/linebender/xilem/issues/1264
         let scale = 1.0;
 
-        let gap = props.get::<Gap>();
-        let gap_length = gap.gap.dp(scale);
-        let gap_count = self.children.len().saturating_sub(1);
-
         let main = self.direction;
         let cross = main.cross();
         let cross_space = size.get_coord(cross);
+        // Percentages of gaps and spacers are resolved against our main length.
+        let main_length = size.get_coord(main);
+
+        let gap = props.get::<Gap>();
+        let gap_length = ctx.resolve_length(gap.gap, Some(main_length)).unwrap_or(0.) * scale;
+        let gap_count = self.children.len().saturating_sub(1);
 
-        let mut main_space = size.get_coord(main) - gap_count as f64 * gap_length;
+        let mut main_space = main_length - gap_count as f64 * gap_length;
         let mut flex_sum = 0.;
         let mut max_ascent: f64 = 0.;
         let mut lowest_baseline: f64 = f64::INFINITY;
@@ -1037,7 +1064,8 @@ impl Widget for Flex {
                     basis_resolved,
                     length_resolved,
                 } => {
-                    *basis_resolved = basis.dp(scale);
+                    *basis_resolved =
+                        ctx.resolve_length(*basis, Some(main_length)).unwrap_or(0.) * scale;
                     main_space -= *basis_resolved;
 
                     if *flex == 0. {
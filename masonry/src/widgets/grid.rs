@@ -263,6 +263,19 @@ impl CollectionWidget<GridParams> for Grid {
         this.ctx.children_changed();
     }
 
+    /// Moves the child at index `from` to index `to`, shifting the children in between.
+    ///
+    /// Unlike [`swap`](Self::swap), this keeps the [`GridParams`] of every child.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+    }
+
     /// Removes the child at the given index.
     ///
     /// # Panics
@@ -315,13 +328,21 @@ impl Widget for Grid {
 
         let gap = props.get::<Gap>();
 
-        let gap_length = gap.gap.dp(scale);
+        // Percentage gaps are resolved against our length on the axis of the gap.
+        // When we're measuring it, that's only known if we're asked to fit some space.
+        // Otherwise they're treated as zero, like cyclic percentages in CSS.
+        let context_length = match len_req {
+            LenReq::FitContent(space) => Some(space),
+            LenReq::MinContent | LenReq::MaxContent => None,
+        };
+        let gap_length = ctx.resolve_length(gap.gap, context_length).unwrap_or(0.) * scale;
+        let cross_gap_length = ctx.resolve_length(gap.gap, cross_length).unwrap_or(0.) * scale;
 
         let cross = axis.cross();
         let cross_track_cells = self.track_cells(cross) as f64;
         let cross_cell_length = cross_length
             .filter(|_| cross_track_cells > 0.) // Guard against div by zero
-            .map(|cross_length| (cross_length + gap_length) / cross_track_cells);
+            .map(|cross_length| (cross_length + cross_gap_length) / cross_track_cells);
 
         let (len_req, min_result) = match len_req {
             LenReq::MinContent | LenReq::MaxContent => (len_req, 0.),
@@ -337,7 +358,7 @@ impl Widget for Grid {
                 let area_cells = child.area_cells(axis) as f64;
                 let cross_area_length = cross_cell_length.map(|cross_cell_length| {
                     let cross_area_cells = child.area_cells(cross) as f64;
-                    let length = cross_area_cells * cross_cell_length - gap_length;
+                    let length = cross_area_cells * cross_cell_length - cross_gap_length;
                     // Guard against the derived area length becoming negative,
                     // which can happen if total space can't fit all cells and gaps.
                     length.max(0.)
@@ -372,15 +393,17 @@ impl Widget for Grid {
 
         let gap = props.get::<Gap>();
 
-        let gap_length = gap.gap.dp(scale);
+        // Percentage gaps are resolved against our length on the axis of the gap.
+        let gap_width = ctx.resolve_length(gap.gap, Some(size.width)).unwrap_or(0.) * scale;
+        let gap_height = ctx.resolve_length(gap.gap, Some(size.height)).unwrap_or(0.) * scale;
 
-        let cell_width = (size.width + gap_length) / self.grid_column_count as f64;
-        let cell_height = (size.height + gap_length) / self.grid_row_count as f64;
+        let cell_width = (size.width + gap_width) / self.grid_column_count as f64;
+        let cell_height = (size.height + gap_height) / self.grid_row_count as f64;
 
         for child in &mut self.children {
             let area = Size::new(
-                child.width as f64 * cell_width - gap_length,
-                child.height as f64 * cell_height - gap_length,
+                child.width as f64 * cell_width - gap_width,
+                child.height as f64 * cell_height - gap_height,
             )
             // Guard against the derived area becoming negative,
             // which can happen if total space can't fit all cells and gaps.
@@ -184,6 +184,19 @@ impl CollectionWidget<()> for IndexedStack {
         this.ctx.children_changed();
     }
 
+    /// Moves the child at index `from` to index `to`, shifting the children in between.
+    ///
+    /// Like [`swap`](Self::swap), this doesn't change the index of the active child.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+    }
+
     /// Removes the child at the given index.
     ///
     /// If the active child is removed, the first child in the stack will be selected as active.
@@ -3,23 +3,30 @@
 
 use std::any::TypeId;
 use std::mem::Discriminant;
+use std::ops::Range;
 
 use accesskit::{Node, Role};
 use include_doc_path::include_doc_path;
-use parley::{FontContext, Layout, LayoutAccessibility, LayoutContext};
+use parley::editing::Cursor;
+use parley::{Cluster, FontContext, Layout, LayoutAccessibility, LayoutContext};
 use tracing::{Span, trace_span};
 use vello::Scene;
 
 use crate::core::{
-    AccessCtx, ArcStr, BrushIndex, ChildrenIds, HasProperty, LayoutCtx, MeasureCtx, NoAction,
-    PaintCtx, PropertiesMut, PropertiesRef, RegisterCtx, StyleProperty, StyleSet, Update,
-    UpdateCtx, Widget, WidgetId, WidgetMut, render_text,
+    AccessCtx, ArcStr, BrushIndex, ChildrenIds, CursorIcon, EventCtx, HasProperty, LayoutCtx,
+    MeasureCtx, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PropertiesMut,
+    PropertiesRef, QueryCtx, RegisterCtx, RichText, StyleProperty, StyleSet, Update, UpdateCtx,
+    Widget, WidgetId, WidgetMut, WidgetRef, find_widget_under_pointer, render_text,
 };
 use crate::kurbo::{Affine, Axis, Point, Size};
 use crate::layout::LenReq;
-use crate::properties::{ContentColor, DisabledContentColor, LineBreaking};
+use crate::peniko::Fill;
+use crate::properties::{
+    ContentColor, DisabledContentColor, LineBreaking, LinkColor, SelectionColor,
+};
 use crate::theme::default_text_styles;
 use crate::util::debug_panic;
+use crate::widgets::text_area::highlight_rects;
 use crate::{TextAlign, TextAlignOptions, theme};
 
 /// A widget displaying non-interactive text.
@@ -28,7 +35,14 @@ use crate::{TextAlign, TextAlignOptions, theme};
 /// need support for displaying text, such as a button.
 ///
 /// You can customize the look of this label with the
-/// [`LineBreaking`], [`ContentColor`] and [`DisabledContentColor`] properties.
+/// [`LineBreaking`], [`ContentColor`], [`DisabledContentColor`] and [`LinkColor`] properties.
+///
+/// A label can also display [rich text](RichText), with styles applied to ranges of the text.
+/// Clicking a link in the text emits a [`LinkClicked`] action.
+/// Apart from its links, a label is transparent to the pointer.
+///
+/// Inside a [`SelectionScope`](super::SelectionScope), the text of a label can be selected,
+/// and the selection is painted with the [`SelectionColor`] property.
 ///
 #[doc = concat!(
     "![Styled label](",
@@ -40,7 +54,7 @@ pub struct Label {
     measure_text_layout: TextLayout,
     accessibility: LayoutAccessibility,
 
-    text: ArcStr,
+    text: RichText,
     styles: StyleSet,
     /// Whether `text` or `styles` has been updated since `text_layout` was created.
     ///
@@ -57,6 +71,18 @@ pub struct Label {
     /// Should be disabled whilst an animation involving this label is ongoing.
     // TODO: What classes of animations?
     hint: bool,
+
+    /// The target of the link the pointer was pressed on, if any.
+    pressed_link: Option<ArcStr>,
+    /// The part of the text selected by the enclosing selection scope, if any.
+    scoped_selection: Option<Range<usize>>,
+}
+
+/// A link in the text of a [`Label`] was clicked.
+#[derive(PartialEq, Debug)]
+pub struct LinkClicked {
+    /// The target of the link, which is usually a URL.
+    pub target: ArcStr,
 }
 
 struct TextLayout {
@@ -88,18 +114,27 @@ impl Label {
     // This is written out fully to appease rust-analyzer; StyleProperty is imported but not recognised.
     /// To change the font size, use `with_style`, setting [`StyleProperty::FontSize`](parley::StyleProperty::FontSize).
     pub fn new(text: impl Into<ArcStr>) -> Self {
+        Self::from_rich_text(RichText::new(text))
+    }
+
+    /// Creates a new label with the given rich text.
+    ///
+    /// The styles of the rich text apply on top of the styles of the label.
+    pub fn from_rich_text(text: RichText) -> Self {
         let mut styles = StyleSet::new(theme::TEXT_SIZE_NORMAL);
         default_text_styles(&mut styles);
         Self {
             text_layout: TextLayout::new(),
             measure_text_layout: TextLayout::new(),
             accessibility: LayoutAccessibility::default(),
-            text: text.into(),
+            text,
             styles,
             styles_changed: true,
             text_alignment: TextAlign::Start,
             last_inline_space: 0.,
             hint: true,
+            pressed_link: None,
+            scoped_selection: None,
         }
     }
 
@@ -173,8 +208,25 @@ impl Label {
     ///
     /// To update the text of an active label, use [`set_text`](Self::set_text).
     pub fn text(&self) -> &ArcStr {
+        self.text.text()
+    }
+
+    /// Returns a reference to the current text of this label, with its styles.
+    ///
+    /// To update the rich text of an active label, use [`set_rich_text`](Self::set_rich_text).
+    pub fn rich_text(&self) -> &RichText {
         &self.text
     }
+
+    /// Returns the target of the link at `pos`, in the label's content-box coordinates.
+    fn link_at_point(&self, pos: Point) -> Option<&ArcStr> {
+        if !self.text.has_links() {
+            return None;
+        }
+        let (cluster, _) =
+            Cluster::from_point_exact(&self.text_layout.layout, pos.x as f32, pos.y as f32)?;
+        self.text.link_at(cluster.text_range().start)
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -230,8 +282,22 @@ impl Label {
     }
 
     /// Replaces the text of this widget.
+    ///
+    /// This removes the styles of any previous [rich text](Self::set_rich_text).
     pub fn set_text(this: &mut WidgetMut<'_, Self>, new_text: impl Into<ArcStr>) {
-        this.widget.text = new_text.into();
+        Self::set_rich_text(this, RichText::new(new_text));
+    }
+
+    /// Replaces the text of this widget with rich text.
+    ///
+    /// The runtime equivalent of [`from_rich_text`](Self::from_rich_text).
+    pub fn set_rich_text(this: &mut WidgetMut<'_, Self>, new_text: RichText) {
+        if this.widget.text == new_text {
+            return;
+        }
+        this.widget.text = new_text;
+        this.widget.pressed_link = None;
+        this.widget.scoped_selection = None;
 
         this.widget.styles_changed = true;
         this.ctx.request_layout();
@@ -274,20 +340,22 @@ impl Label {
             {
                 // TODO: Should we use a different scale?
                 // See https://github.com/linebender/xilem/issues/1264
-                let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, 1.0, true);
+                let mut builder = layout_ctx.ranged_builder(font_ctx, self.text.text(), 1.0, true);
                 for prop in self.styles.inner().values() {
                     builder.push_default(prop.to_owned());
                 }
-                builder.build_into(&mut self.measure_text_layout.layout, &self.text);
+                self.text.push_styles(&mut builder);
+                builder.build_into(&mut self.measure_text_layout.layout, self.text.text());
             }
             if commit {
                 // TODO: Should we use a different scale?
                 // See https://github.com/linebender/xilem/issues/1264
-                let mut builder = layout_ctx.ranged_builder(font_ctx, &self.text, 1.0, true);
+                let mut builder = layout_ctx.ranged_builder(font_ctx, self.text.text(), 1.0, true);
                 for prop in self.styles.inner().values() {
                     builder.push_default(prop.to_owned());
                 }
-                builder.build_into(&mut self.text_layout.layout, &self.text);
+                self.text.push_styles(&mut builder);
+                builder.build_into(&mut self.text_layout.layout, self.text.text());
                 self.styles_changed = false;
             }
         }
@@ -310,13 +378,52 @@ impl Label {
 impl HasProperty<ContentColor> for Label {}
 impl HasProperty<DisabledContentColor> for Label {}
 impl HasProperty<LineBreaking> for Label {}
+impl HasProperty<LinkColor> for Label {}
 
 // --- MARK: IMPL WIDGET
 impl Widget for Label {
-    type Action = NoAction;
+    type Action = LinkClicked;
 
-    fn accepts_pointer_interaction(&self) -> bool {
-        false
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let pos = ctx.local_position(state.position);
+                if let Some(target) = self.link_at_point(pos) {
+                    self.pressed_link = Some(target.clone());
+                    ctx.capture_pointer();
+                    ctx.set_handled();
+                }
+            }
+            PointerEvent::Up(PointerButtonEvent { state, .. }) => {
+                if let Some(pressed) = self.pressed_link.take() {
+                    let pos = ctx.local_position(state.position);
+                    if ctx.is_active() && self.link_at_point(pos) == Some(&pressed) {
+                        ctx.submit_action::<Self::Action>(LinkClicked { target: pressed });
+                    }
+                    ctx.set_handled();
+                }
+            }
+            PointerEvent::Cancel(..) => {
+                self.pressed_link = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn font_size(&self) -> Option<f64> {
+        self.styles.inner().values().find_map(|style| match style {
+            StyleProperty::FontSize(font_size) => Some((*font_size).into()),
+            _ => None,
+        })
     }
 
     fn register_children(&mut self, _ctx: &mut RegisterCtx<'_>) {}
@@ -325,6 +432,8 @@ impl Widget for Label {
         LineBreaking::prop_changed(ctx, property_type);
         ContentColor::prop_changed(ctx, property_type);
         DisabledContentColor::prop_changed(ctx, property_type);
+        LinkColor::prop_changed(ctx, property_type);
+        SelectionColor::prop_changed(ctx, property_type);
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
@@ -447,15 +556,39 @@ impl Widget for Label {
             props.get::<ContentColor>()
         };
 
+        let link_color = props.get::<LinkColor>().color;
+
+        if let Some(selection) = &self.scoped_selection {
+            let selection_color = props.get::<SelectionColor>().color;
+            for rect in highlight_rects(&self.text_layout.layout, selection.clone()) {
+                scene.fill(
+                    Fill::NonZero,
+                    Affine::IDENTITY,
+                    selection_color,
+                    None,
+                    &rect,
+                );
+            }
+        }
+
         render_text(
             scene,
             Affine::IDENTITY,
             &self.text_layout.layout,
-            &[text_color.color.into()],
+            &self.text.brushes(text_color.color, link_color),
             self.hint,
         );
     }
 
+    fn get_cursor(&self, ctx: &QueryCtx<'_>, pos: Point) -> CursorIcon {
+        let local_pos = ctx.window_transform().inverse() * pos;
+        if self.link_at_point(local_pos).is_some() {
+            CursorIcon::Pointer
+        } else {
+            CursorIcon::Default
+        }
+    }
+
     fn accessibility_role(&self) -> Role {
         Role::Label
     }
@@ -469,7 +602,7 @@ impl Widget for Label {
         let text_origin_in_border_box_space = Point::ORIGIN + ctx.border_box_translation();
 
         self.accessibility.build_nodes(
-            self.text.as_ref(),
+            self.text.text(),
             &self.text_layout.layout,
             ctx.tree_update(),
             node,
@@ -483,12 +616,38 @@ impl Widget for Label {
         ChildrenIds::new()
     }
 
+    fn selectable_text(&self) -> Option<&str> {
+        Some(self.text.text())
+    }
+
+    fn selectable_text_index(&self, pos: Point) -> usize {
+        Cursor::from_point(&self.text_layout.layout, pos.x as f32, pos.y as f32).index()
+    }
+
+    fn set_scoped_selection(&mut self, selection: Option<Range<usize>>) {
+        self.scoped_selection = selection.filter(|range| {
+            let text = self.text.text();
+            text.is_char_boundary(range.start) && text.is_char_boundary(range.end)
+        });
+    }
+
+    fn find_widget_under_pointer<'c>(
+        &'c self,
+        ctx: QueryCtx<'c>,
+        pos: Point,
+    ) -> Option<WidgetRef<'c, dyn Widget>> {
+        // Labels are transparent to the pointer, except for their links.
+        let local_pos = ctx.window_transform().inverse() * pos;
+        self.link_at_point(local_pos)?;
+        find_widget_under_pointer(self, ctx, pos)
+    }
+
     fn make_trace_span(&self, id: WidgetId) -> Span {
         trace_span!("Label", id = id.trace())
     }
 
     fn get_debug_text(&self) -> Option<String> {
-        Some(self.text.to_string())
+        Some(self.text.text().to_string())
     }
 }
 
@@ -672,4 +831,69 @@ mod tests {
         // We don't use assert_eq because we don't want rich assert
         assert!(image_1 == image_2);
     }
+
+    #[test]
+    fn rich_text_spans_change_layout() {
+        let plain = Label::new("Hello world").with_auto_id();
+        let rich = Label::from_rich_text(
+            RichText::new("Hello world").with_span(6..11, StyleProperty::FontSize(40.0)),
+        )
+        .with_auto_id();
+        let window_size = Size::new(400.0, 100.0);
+        let mut plain = TestHarness::create_with_size(test_property_set(), plain, window_size);
+        let mut rich = TestHarness::create_with_size(test_property_set(), rich, window_size);
+
+        let plain_width = plain.root_widget().text_layout.layout.full_width();
+        let rich_width = rich.root_widget().text_layout.layout.full_width();
+        assert!(rich_width > plain_width);
+
+        rich.edit_root_widget(|mut label| Label::set_text(&mut label, "Hello world"));
+        let _ = rich.render();
+        let _ = plain.render();
+        assert_eq!(
+            rich.root_widget().text_layout.layout.full_width(),
+            plain_width
+        );
+    }
+
+    #[test]
+    fn link_click() {
+        let mut builder = RichText::builder();
+        builder
+            .push_link("docs", "https://docs.rs")
+            .push(" and some more text");
+        let label = Label::from_rich_text(builder.build()).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), label, Size::new(400.0, 40.0));
+        let label_id = harness.root_id();
+
+        // Clicking the link emits its target.
+        harness.mouse_move((5.0, 8.0));
+        assert_eq!(harness.cursor_icon(), CursorIcon::Pointer);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action::<LinkClicked>(),
+            Some((
+                LinkClicked {
+                    target: "https://docs.rs".into()
+                },
+                label_id
+            ))
+        );
+
+        // Clicking elsewhere in the text doesn't.
+        harness.mouse_move((150.0, 8.0));
+        assert_eq!(harness.cursor_icon(), CursorIcon::Default);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action::<LinkClicked>(), None);
+
+        // Releasing the pointer away from the link cancels the click.
+        harness.mouse_move((5.0, 8.0));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((150.0, 8.0));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action::<LinkClicked>(), None);
+    }
 }
@@ -4,10 +4,14 @@
 //! Common widgets.
 
 mod align;
+mod animator;
 mod button;
 mod canvas;
 mod checkbox;
+mod code_editor;
+mod context_menu_area;
 mod divider;
+mod dropdown;
 mod flex;
 mod grid;
 mod image;
@@ -19,13 +23,17 @@ mod progress_bar;
 mod prose;
 mod resize_observer;
 mod scroll_bar;
+mod selection_scope;
 mod sized_box;
 mod slider;
+mod spin_box;
 mod spinner;
 mod split;
 mod switch;
+mod table;
 mod text_area;
 mod text_input;
+mod tree_view;
 mod variable_label;
 mod virtual_scroll;
 mod zstack;
@@ -34,10 +42,14 @@ mod zstack;
 // (e.g. actions, param types)
 
 pub use self::align::*;
+pub use self::animator::*;
 pub use self::button::*;
 pub use self::canvas::*;
 pub use self::checkbox::*;
+pub use self::code_editor::*;
+pub use self::context_menu_area::*;
 pub use self::divider::*;
+pub use self::dropdown::*;
 pub use self::flex::*;
 pub use self::grid::*;
 pub use self::image::*;
@@ -49,13 +61,17 @@ pub use self::progress_bar::*;
 pub use self::prose::*;
 pub use self::resize_observer::*;
 pub use self::scroll_bar::*;
+pub use self::selection_scope::*;
 pub use self::sized_box::*;
 pub use self::slider::*;
+pub use self::spin_box::*;
 pub use self::spinner::*;
 pub use self::split::*;
 pub use self::switch::*;
+pub use self::table::*;
 pub use self::text_area::*;
 pub use self::text_input::*;
+pub use self::tree_view::*;
 pub use self::variable_label::*;
 pub use self::virtual_scroll::*;
 pub use self::zstack::*;
@@ -6,14 +6,15 @@ use include_doc_path::include_doc_path;
 use tracing::{Span, trace_span};
 use vello::Scene;
 
+use crate::core::keyboard::{Key, KeyState, NamedKey};
 use crate::core::{
     AccessCtx, AccessEvent, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget, NoAction,
-    PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, TextEvent, Update,
+    PaintCtx, PointerEvent, PropertiesMut, PropertiesRef, RegisterCtx, RichText, TextEvent, Update,
     UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::kurbo::{Axis, Point, Size};
-use crate::layout::LenReq;
-use crate::widgets::TextArea;
+use crate::layout::{LayoutSize, LenDef, LenReq};
+use crate::widgets::{Label, TextArea, TextInput};
 
 /// The prose widget displays immutable text which can be
 /// selected within.
@@ -28,6 +29,17 @@ use crate::widgets::TextArea;
 /// as it enables users to copy/paste from the text.
 ///
 /// This widget has no actions.
+/// When the text is [rich text](RichText), its text area emits
+/// [`TextAction::LinkClicked`](super::TextAction::LinkClicked) when a link is clicked.
+/// The text area's ID can be accessed using [`text_area_pod`](Self::text_area_pod).
+///
+/// Ranges of the text can be highlighted using [`TextArea::set_highlights`].
+/// Prose can also have a built-in find bar, enabled with [`with_find_bar`](Self::with_find_bar).
+/// When the text has focus, <kbd>Ctrl</kbd>+<kbd>F</kbd> opens the bar above the text,
+/// and focuses its query field.
+/// Typing then highlights the matches of the query, <kbd>Enter</kbd> and
+/// <kbd>Shift</kbd>+<kbd>Enter</kbd> scroll to the next and previous match,
+/// and <kbd>Escape</kbd> closes the bar.
 ///
 #[doc = concat!(
     "![Multiple lines with different alignments](",
@@ -39,6 +51,17 @@ pub struct Prose {
 
     /// Whether to clip the contained text.
     clip: bool,
+
+    /// The query field of the find bar.
+    find_input: WidgetPod<TextInput>,
+    /// The ID of the query field's text area.
+    find_area_id: WidgetId,
+    /// Shows the number of matches next to the query field.
+    find_status: WidgetPod<Label>,
+    /// Whether <kbd>Ctrl</kbd>+<kbd>F</kbd> opens the find bar.
+    find_bar_enabled: bool,
+    /// The search query, if the find bar is open.
+    find_query: Option<String>,
 }
 
 // --- MARK: BUILDERS
@@ -50,11 +73,34 @@ impl Prose {
         Self::from_text_area(TextArea::new_immutable(text).with_auto_id())
     }
 
+    /// Creates a new `Prose` with the given rich text.
+    ///
+    /// See [`TextArea::with_rich_text`] for which styles are supported.
+    pub fn from_rich_text(text: RichText) -> Self {
+        Self::from_text_area(
+            TextArea::new_immutable("")
+                .with_rich_text(text)
+                .with_auto_id(),
+        )
+    }
+
     /// Creates a new `Prose` from a styled text area.
     pub fn from_text_area(text: NewWidget<TextArea<false>>) -> Self {
+        let find_area = TextArea::new_editable("")
+            .with_search_field()
+            .with_auto_id();
+        let find_area_id = find_area.id();
         Self {
             text: text.to_pod(),
             clip: false,
+            find_input: TextInput::from_text_area(find_area)
+                .with_placeholder("Find")
+                .with_auto_id()
+                .to_pod(),
+            find_area_id,
+            find_status: Label::new("").with_auto_id().to_pod(),
+            find_bar_enabled: false,
+            find_query: None,
         }
     }
 
@@ -68,6 +114,16 @@ impl Prose {
         self.clip = clip;
         self
     }
+
+    /// Whether the user can open a find bar with <kbd>Ctrl</kbd>+<kbd>F</kbd>.
+    ///
+    /// See the [type docs](Self) for details.
+    ///
+    /// To modify this on active prose, use [`set_find_bar`](Self::set_find_bar).
+    pub fn with_find_bar(mut self, find_bar: bool) -> Self {
+        self.find_bar_enabled = find_bar;
+        self
+    }
 }
 
 // --- MARK: METHODS
@@ -78,6 +134,11 @@ impl Prose {
     pub fn text_area_pod(&self) -> &WidgetPod<TextArea<false>> {
         &self.text
     }
+
+    /// Returns the query of the find bar, or `None` if the find bar isn't open.
+    pub fn find_query(&self) -> Option<&str> {
+        self.find_query.as_deref()
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -99,6 +160,106 @@ impl Prose {
         this.widget.clip = clip;
         this.ctx.request_layout();
     }
+
+    /// Whether the user can open a find bar with <kbd>Ctrl</kbd>+<kbd>F</kbd>.
+    ///
+    /// Disabling the find bar closes it.
+    ///
+    /// The runtime equivalent of [`with_find_bar`](Self::with_find_bar).
+    pub fn set_find_bar(this: &mut WidgetMut<'_, Self>, find_bar: bool) {
+        this.widget.find_bar_enabled = find_bar;
+        if !find_bar {
+            Self::close_find_bar(this);
+        }
+    }
+
+    /// Opens the find bar with the given query, and highlights its matches in the text.
+    ///
+    /// The first match becomes the active highlight, and is scrolled into view.
+    /// If the find bar wasn't open, its query field gets focus.
+    /// This works even if the find bar isn't [enabled](Self::set_find_bar) for the user.
+    pub fn set_find_query(this: &mut WidgetMut<'_, Self>, query: &str) {
+        if this.widget.find_query.is_none() {
+            this.ctx.request_layout();
+        }
+        this.widget.find_query = Some(query.into());
+        {
+            let mut input = this.ctx.get_mut(&mut this.widget.find_input);
+            let mut area = TextInput::text_mut(&mut input);
+            if area.widget.text() != query {
+                TextArea::reset_text(&mut area, query);
+            }
+        }
+        {
+            let mut text = Self::text_mut(this);
+            let matches = text.widget.find(query, false);
+            TextArea::set_highlights(&mut text, matches);
+            TextArea::next_highlight(&mut text);
+        }
+        Self::update_find_bar(this);
+    }
+
+    /// Makes the next match of the find bar's query active, and scrolls it into view.
+    ///
+    /// No-op if the find bar isn't open.
+    pub fn find_next(this: &mut WidgetMut<'_, Self>) {
+        if this.widget.find_query.is_some() {
+            TextArea::next_highlight(&mut Self::text_mut(this));
+            Self::update_find_bar(this);
+        }
+    }
+
+    /// Makes the previous match of the find bar's query active, and scrolls it into view.
+    ///
+    /// No-op if the find bar isn't open.
+    pub fn find_previous(this: &mut WidgetMut<'_, Self>) {
+        if this.widget.find_query.is_some() {
+            TextArea::previous_highlight(&mut Self::text_mut(this));
+            Self::update_find_bar(this);
+        }
+    }
+
+    /// Closes the find bar, and removes the highlights from the text.
+    ///
+    /// No-op if the find bar isn't open.
+    pub fn close_find_bar(this: &mut WidgetMut<'_, Self>) {
+        if this.widget.find_query.take().is_some() {
+            TextArea::set_highlights(&mut Self::text_mut(this), Vec::new());
+            this.ctx.request_layout();
+        }
+    }
+
+    /// Highlights the matches of the query which the user typed into the find bar.
+    fn sync_find_query(this: &mut WidgetMut<'_, Self>) {
+        let query = {
+            let mut input = this.ctx.get_mut(&mut this.widget.find_input);
+            TextInput::text_mut(&mut input).widget.text().to_string()
+        };
+        if this
+            .widget
+            .find_query
+            .as_ref()
+            .is_some_and(|old| *old != query)
+        {
+            Self::set_find_query(this, &query);
+        }
+    }
+
+    /// Shows the number of matches and the active one in the find bar.
+    fn update_find_bar(this: &mut WidgetMut<'_, Self>) {
+        let (count, active) = {
+            let text = Self::text_mut(this);
+            (
+                text.widget.highlights().len(),
+                text.widget.active_highlight(),
+            )
+        };
+        let status = match active {
+            Some(active) => format!("{} of {count}", active + 1),
+            None => "No matches".into(),
+        };
+        Label::set_text(&mut this.ctx.get_mut(&mut this.widget.find_status), status);
+    }
 }
 
 // --- MARK: IMPL WIDGET
@@ -115,10 +276,53 @@ impl Widget for Prose {
 
     fn on_text_event(
         &mut self,
-        _ctx: &mut EventCtx<'_>,
+        ctx: &mut EventCtx<'_>,
         _props: &mut PropertiesMut<'_>,
-        _event: &TextEvent,
+        event: &TextEvent,
     ) {
+        // Key presses which the text area or the query field didn't handle bubble up to here.
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if !self.find_bar_enabled || key_event.state != KeyState::Down {
+            return;
+        }
+        let (shift, action_mod) = (
+            key_event.modifiers.shift(),
+            if cfg!(target_os = "macos") {
+                key_event.modifiers.meta()
+            } else {
+                key_event.modifiers.ctrl()
+            },
+        );
+        let open = self.find_query.is_some();
+        match &key_event.key {
+            Key::Character(f) if action_mod && f.as_str().eq_ignore_ascii_case("f") => {
+                if open {
+                    ctx.set_focus(self.find_area_id);
+                } else {
+                    // The query field takes focus once it is shown.
+                    ctx.mutate_self_later(|mut this| {
+                        Self::set_find_query(&mut this.downcast(), "");
+                    });
+                }
+            }
+            Key::Named(NamedKey::Enter) if open => {
+                ctx.mutate_self_later(move |mut this| {
+                    if shift {
+                        Self::find_previous(&mut this.downcast());
+                    } else {
+                        Self::find_next(&mut this.downcast());
+                    }
+                });
+            }
+            Key::Named(NamedKey::Escape) if open => {
+                ctx.mutate_self_later(|mut this| Self::close_find_bar(&mut this.downcast()));
+                ctx.set_focus(self.text.id());
+            }
+            _ => return,
+        }
+        ctx.set_handled();
     }
 
     fn on_access_event(
@@ -131,6 +335,8 @@ impl Widget for Prose {
 
     fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
         ctx.register_child(&mut self.text);
+        ctx.register_child(&mut self.find_input);
+        ctx.register_child(&mut self.find_status);
     }
 
     fn update(
@@ -146,15 +352,98 @@ impl Widget for Prose {
         ctx: &mut MeasureCtx<'_>,
         _props: &PropertiesRef<'_>,
         axis: Axis,
-        _len_req: LenReq,
+        len_req: LenReq,
         cross_length: Option<f64>,
     ) -> f64 {
-        ctx.redirect_measurement(&mut self.text, axis, cross_length)
+        if self.find_query.is_none() {
+            return ctx.redirect_measurement(&mut self.text, axis, cross_length);
+        }
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        let input_length = ctx.compute_length(
+            &mut self.find_input,
+            len_req.into(),
+            context_size,
+            axis,
+            cross_length,
+        );
+        let status_length = ctx.compute_length(
+            &mut self.find_status,
+            LenDef::MaxContent,
+            context_size,
+            axis,
+            None,
+        );
+        match axis {
+            Axis::Horizontal => {
+                let bar_length = input_length + status_length;
+                let text_length = ctx.compute_length(
+                    &mut self.text,
+                    len_req.into(),
+                    context_size,
+                    axis,
+                    cross_length,
+                );
+                text_length.max(bar_length)
+            }
+            Axis::Vertical => {
+                let bar_length = input_length.max(status_length);
+                let text_length = ctx.compute_length(
+                    &mut self.text,
+                    len_req.reduce(bar_length).into(),
+                    context_size,
+                    axis,
+                    cross_length,
+                );
+                bar_length + text_length
+            }
+        }
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
-        ctx.run_layout(&mut self.text, size);
-        ctx.place_child(&mut self.text, Point::ORIGIN);
+        ctx.set_stashed(&mut self.find_input, self.find_query.is_none());
+        ctx.set_stashed(&mut self.find_status, self.find_query.is_none());
+        let bar_height = if self.find_query.is_some() {
+            // The query field doesn't report edits to us, but they lay it out again.
+            ctx.mutate_self_later(|mut this| Self::sync_find_query(&mut this.downcast()));
+
+            let status_width = ctx.compute_length(
+                &mut self.find_status,
+                LenDef::MaxContent,
+                size.into(),
+                Axis::Horizontal,
+                None,
+            );
+            let input_width = (size.width - status_width).max(0.);
+            let input_height = ctx.compute_length(
+                &mut self.find_input,
+                LenDef::FitContent(size.height),
+                size.into(),
+                Axis::Vertical,
+                Some(input_width),
+            );
+            let status_height = ctx.compute_length(
+                &mut self.find_status,
+                LenDef::FitContent(size.height),
+                size.into(),
+                Axis::Vertical,
+                Some(status_width),
+            );
+            let bar_height = input_height.max(status_height);
+            ctx.run_layout(&mut self.find_input, Size::new(input_width, bar_height));
+            ctx.place_child(&mut self.find_input, Point::ORIGIN);
+            ctx.run_layout(
+                &mut self.find_status,
+                Size::new(status_width, status_height),
+            );
+            let status_y = (bar_height - status_height) / 2.;
+            ctx.place_child(&mut self.find_status, Point::new(input_width, status_y));
+            bar_height
+        } else {
+            0.
+        };
+        let text_size = Size::new(size.width, (size.height - bar_height).max(0.));
+        ctx.run_layout(&mut self.text, text_size);
+        ctx.place_child(&mut self.text, Point::new(0., bar_height));
 
         if self.clip {
             let border_box = size.to_rect() + ctx.border_box_insets();
@@ -181,7 +470,7 @@ impl Widget for Prose {
     }
 
     fn children_ids(&self) -> ChildrenIds {
-        ChildrenIds::from_slice(&[self.text.id()])
+        ChildrenIds::from_slice(&[self.text.id(), self.find_input.id(), self.find_status.id()])
     }
 
     fn make_trace_span(&self, id: WidgetId) -> Span {
@@ -189,7 +478,12 @@ impl Widget for Prose {
     }
 
     fn get_debug_text(&self) -> Option<String> {
-        self.clip.then(|| "(clip)".into())
+        match (&self.find_query, self.clip) {
+            (Some(query), true) => Some(format!("(clip) find: {query}")),
+            (Some(query), false) => Some(format!("find: {query}")),
+            (None, true) => Some("(clip)".into()),
+            (None, false) => None,
+        }
     }
 }
 
@@ -201,7 +495,7 @@ mod tests {
 
     use super::*;
     use crate::TextAlign;
-    use crate::core::Properties;
+    use crate::core::{KeyboardEvent, Modifiers, Properties};
     use crate::kurbo::Size;
     use crate::layout::AsUnit;
     use crate::properties::Gap;
@@ -267,4 +561,95 @@ mod tests {
 
         assert_render_snapshot!(harness, "prose_alignment_flex");
     }
+
+    #[test]
+    fn find_bar() {
+        let prose = Prose::new("One fish, two fish, red fish")
+            .with_find_bar(true)
+            .with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), prose, Size::new(400.0, 100.0));
+        let text_id = harness.root_widget().text_area_pod().id();
+        harness.focus_on(Some(text_id));
+
+        let press = |harness: &mut TestHarness<Prose>, key: Key, modifiers: Modifiers| {
+            harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+                key,
+                modifiers,
+                ..Default::default()
+            }));
+        };
+        let action_mod = if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+        let highlights = |harness: &TestHarness<Prose>| {
+            let area = harness.get_widget_with_id(text_id);
+            let area = area.downcast::<TextArea<false>>().unwrap();
+            (area.highlights().to_vec(), area.active_highlight())
+        };
+
+        // Shortcuts do nothing until the find bar is enabled.
+        harness.edit_root_widget(|mut prose| Prose::set_find_bar(&mut prose, false));
+        press(&mut harness, Key::Character("f".into()), action_mod);
+        assert_eq!(harness.root_widget().find_query(), None);
+        harness.edit_root_widget(|mut prose| Prose::set_find_bar(&mut prose, true));
+
+        press(&mut harness, Key::Character("f".into()), action_mod);
+        assert_eq!(harness.root_widget().find_query(), Some(""));
+        let find_area_id = harness.root_widget().find_area_id;
+        assert_eq!(harness.focused_widget_id(), Some(find_area_id));
+        assert!(harness.has_ime_session());
+
+        // The query field takes text through the IME.
+        harness.keyboard_type_chars("FISH");
+        assert_eq!(harness.root_widget().find_query(), Some("FISH"));
+        assert_eq!(highlights(&harness), (vec![4..8, 14..18, 24..28], Some(0)));
+
+        // It is exposed to accessibility technology as a search field.
+        let _ = harness.render();
+        let node = harness.access_node(find_area_id).unwrap();
+        assert_eq!(node.role(), Role::SearchInput);
+        assert_eq!(node.value().as_deref(), Some("FISH"));
+
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Enter),
+            Modifiers::default(),
+        );
+        assert_eq!(highlights(&harness).1, Some(1));
+        press(&mut harness, Key::Named(NamedKey::Enter), Modifiers::SHIFT);
+        press(&mut harness, Key::Named(NamedKey::Enter), Modifiers::SHIFT);
+        assert_eq!(highlights(&harness).1, Some(2));
+
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        assert_eq!(harness.root_widget().find_query(), Some("FIS"));
+        assert_eq!(highlights(&harness).1, Some(0));
+
+        // Setting the query programmatically updates the query field.
+        harness.edit_root_widget(|mut prose| Prose::set_find_query(&mut prose, "ish"));
+        let _ = harness.render();
+        let node = harness.access_node(find_area_id).unwrap();
+        assert_eq!(node.value().as_deref(), Some("ish"));
+        assert_eq!(highlights(&harness), (vec![5..8, 15..18, 25..28], Some(0)));
+
+        // Pressing the shortcut again goes back to the query field.
+        harness.focus_on(Some(text_id));
+        press(&mut harness, Key::Character("f".into()), action_mod);
+        assert_eq!(harness.focused_widget_id(), Some(find_area_id));
+
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Escape),
+            Modifiers::default(),
+        );
+        assert_eq!(harness.root_widget().find_query(), None);
+        assert_eq!(highlights(&harness), (vec![], None));
+        assert_eq!(harness.focused_widget_id(), Some(text_id));
+    }
 }
@@ -0,0 +1,589 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Range;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::keyboard::{Key, KeyState, NamedKey};
+use crate::core::{
+    AccessCtx, ChildrenIds, EventCtx, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate, PropertiesMut, PropertiesRef,
+    RegisterCtx, TextEvent, Widget, WidgetId, WidgetMut, WidgetPod, WidgetRef,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::LenReq;
+
+/// A container which lets the user select text across the read-only text widgets inside it.
+///
+/// Pressing the pointer in the scope and dragging it selects the text between the two points,
+/// across every [`Label`](super::Label) and [`Prose`](super::Prose) between them in tree order.
+/// Other widgets can take part by implementing [`Widget::selectable_text`] and the methods
+/// after it.
+/// Each widget paints its own part of the selection.
+///
+/// The scope takes focus when the pointer is pressed in it, so that
+/// <kbd>Ctrl</kbd>+<kbd>C</kbd> copies the selected text, with the text of each widget on
+/// its own line, <kbd>Ctrl</kbd>+<kbd>A</kbd> selects all the text, and
+/// <kbd>Escape</kbd> clears the selection.
+///
+/// Links in the text can still be clicked.
+/// Widgets which handle pointer presses themselves, such as buttons, keep doing so.
+/// A scope nested in another one has its own selection, which the outer scope doesn't include.
+pub struct SelectionScope {
+    child: WidgetPod<dyn Widget>,
+    /// The end of the selection where the pointer was pressed.
+    anchor: Option<SelectionPoint>,
+    /// The end of the selection which follows the pointer.
+    focus: Option<SelectionPoint>,
+    /// The widgets which have some of their text selected, and the selected ranges.
+    selected: Vec<(WidgetId, Range<usize>)>,
+}
+
+/// A position in the text of a widget in a [`SelectionScope`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct SelectionPoint {
+    widget: WidgetId,
+    index: usize,
+}
+
+// --- MARK: BUILDERS
+impl SelectionScope {
+    /// Creates a new `SelectionScope` around `child`.
+    pub fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+            anchor: None,
+            focus: None,
+            selected: Vec::new(),
+        }
+    }
+}
+
+// --- MARK: METHODS
+impl SelectionScope {
+    /// Returns the widgets which have some of their text selected, and the selected byte ranges,
+    /// in tree order.
+    pub fn selection(&self) -> &[(WidgetId, Range<usize>)] {
+        &self.selected
+    }
+
+    /// Returns the point in the text closest to `pos`, which is in window coordinates.
+    ///
+    /// If the pointer isn't over any text, this is the end of the text before it, in tree order.
+    fn point_at(&self, ctx: &EventCtx<'_>, pos: Point) -> Option<SelectionPoint> {
+        let participants = participants(ctx.get(self.child.id()));
+        let point_in = |widget: &WidgetRef<'_, dyn Widget>| SelectionPoint {
+            widget: widget.id(),
+            index: widget
+                .inner()
+                .selectable_text_index(widget.ctx().to_local(pos)),
+        };
+        if let Some(widget) = participants
+            .iter()
+            .find(|widget| widget.ctx().bounding_box().contains(pos))
+        {
+            return Some(point_in(widget));
+        }
+        let mut before = None;
+        for widget in &participants {
+            let rect = widget.ctx().bounding_box();
+            if pos.y < rect.y0 {
+                break;
+            }
+            if pos.y <= rect.y1 {
+                return Some(point_in(widget));
+            }
+            before = Some(widget);
+        }
+        match before {
+            Some(widget) => Some(SelectionPoint {
+                widget: widget.id(),
+                index: widget.inner().selectable_text().unwrap_or_default().len(),
+            }),
+            None => participants.first().map(|widget| SelectionPoint {
+                widget: widget.id(),
+                index: 0,
+            }),
+        }
+    }
+
+    /// Sets the selection, and updates the selected range of each widget in the scope.
+    fn select(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        anchor: Option<SelectionPoint>,
+        focus: Option<SelectionPoint>,
+    ) {
+        self.anchor = anchor;
+        self.focus = focus;
+        let participants = participants(ctx.get(self.child.id()))
+            .iter()
+            .map(|widget| {
+                let len = widget.inner().selectable_text().unwrap_or_default().len();
+                (widget.id(), len)
+            })
+            .collect::<Vec<_>>();
+        let selected = match (anchor, focus) {
+            (Some(anchor), Some(focus)) => selected_ranges(&participants, anchor, focus),
+            _ => Vec::new(),
+        };
+
+        let old = std::mem::replace(&mut self.selected, selected);
+        for (id, _) in &old {
+            // Widgets which have been removed since they were selected are skipped.
+            let is_participant = participants.iter().any(|(other_id, _)| other_id == id);
+            let is_selected = self.selected.iter().any(|(other_id, _)| other_id == id);
+            if is_participant && !is_selected {
+                ctx.mutate_descendant_later(*id, |mut widget| {
+                    widget.widget.set_scoped_selection(None);
+                    widget.ctx.request_paint_only();
+                });
+            }
+        }
+        for (id, range) in &self.selected {
+            if !old.contains(&(*id, range.clone())) {
+                let range = range.clone();
+                ctx.mutate_descendant_later(*id, |mut widget| {
+                    widget.widget.set_scoped_selection(Some(range));
+                    widget.ctx.request_paint_only();
+                });
+            }
+        }
+    }
+
+    /// Returns the selected text, with the text of each widget on its own line.
+    fn selected_text(&self, ctx: &EventCtx<'_>) -> String {
+        let child = ctx.get(self.child.id());
+        let texts = self.selected.iter().filter_map(|(id, range)| {
+            let widget = child.find_widget_by_id(*id)?;
+            widget.inner().selectable_text()?.get(range.clone())
+        });
+        texts.collect::<Vec<_>>().join("\n")
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl SelectionScope {
+    /// Replaces the child widget with a new one.
+    ///
+    /// This clears the selection.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<impl Widget + ?Sized>) {
+        let old = std::mem::replace(&mut this.widget.child, child.erased().to_pod());
+        this.ctx.remove_child(old);
+        this.widget.anchor = None;
+        this.widget.focus = None;
+        this.widget.selected.clear();
+    }
+
+    /// Returns a mutable reference to the child.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for SelectionScope {
+    type Action = NoAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: None | Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                if ctx.pointer_capture_target_id().is_some() {
+                    // A descendant, such as a button or a text input, handles this press.
+                    return;
+                }
+                let focus = self.point_at(ctx, state.logical_point());
+                let anchor = if state.modifiers.shift() && self.anchor.is_some() {
+                    self.anchor
+                } else {
+                    focus
+                };
+                self.select(ctx, anchor, focus);
+                ctx.request_focus();
+                ctx.capture_pointer();
+                ctx.set_handled();
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                if ctx.is_active() {
+                    let focus = self.point_at(ctx, current.logical_point());
+                    if focus != self.focus {
+                        self.select(ctx, self.anchor, focus);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if key_event.state != KeyState::Down {
+            return;
+        }
+        let action_mod = if cfg!(target_os = "macos") {
+            key_event.modifiers.meta()
+        } else {
+            key_event.modifiers.ctrl()
+        };
+        match &key_event.key {
+            Key::Character(c) if action_mod && c.as_str().eq_ignore_ascii_case("c") => {
+                if !self.selected.is_empty() {
+                    ctx.set_clipboard(self.selected_text(ctx));
+                }
+            }
+            Key::Character(a) if action_mod && a.as_str().eq_ignore_ascii_case("a") => {
+                let participants = participants(ctx.get(self.child.id()))
+                    .iter()
+                    .map(|widget| {
+                        let len = widget.inner().selectable_text().unwrap_or_default().len();
+                        SelectionPoint {
+                            widget: widget.id(),
+                            index: len,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let anchor = participants.first().map(|point| SelectionPoint {
+                    widget: point.widget,
+                    index: 0,
+                });
+                self.select(ctx, anchor, participants.last().copied());
+            }
+            Key::Named(NamedKey::Escape) if !self.selected.is_empty() => {
+                self.select(ctx, None, None);
+            }
+            _ => return,
+        }
+        ctx.set_handled();
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn is_selection_scope(&self) -> bool {
+        true
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+
+        let child_baseline = ctx.child_baseline_offset(&self.child);
+        ctx.set_baseline_offset(child_baseline);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("SelectionScope", id = id.trace())
+    }
+}
+
+/// Returns the widgets with selectable text in the tree of `widget`, in tree order.
+///
+/// Stashed widgets and nested selection scopes are skipped.
+fn participants<'w>(widget: WidgetRef<'w, dyn Widget>) -> Vec<WidgetRef<'w, dyn Widget>> {
+    let mut participants = Vec::new();
+    let mut stack = vec![widget];
+    while let Some(widget) = stack.pop() {
+        // Nested scopes handle the selection of their own descendants.
+        if widget.ctx().is_stashed() || widget.inner().is_selection_scope() {
+            continue;
+        }
+        if widget.inner().selectable_text().is_some() {
+            participants.push(widget);
+        }
+        stack.extend(widget.children().into_iter().rev());
+    }
+    participants
+}
+
+/// Returns the ranges selected between `anchor` and `focus` in each of `participants`,
+/// which are given as ids and text lengths, in tree order.
+fn selected_ranges(
+    participants: &[(WidgetId, usize)],
+    anchor: SelectionPoint,
+    focus: SelectionPoint,
+) -> Vec<(WidgetId, Range<usize>)> {
+    let position = |point: SelectionPoint| {
+        let index = participants
+            .iter()
+            .position(|(id, _)| *id == point.widget)?;
+        Some((index, point.index))
+    };
+    let (Some(anchor), Some(focus)) = (position(anchor), position(focus)) else {
+        return Vec::new();
+    };
+    let (start, end) = if anchor <= focus {
+        (anchor, focus)
+    } else {
+        (focus, anchor)
+    };
+    participants[start.0..=end.0]
+        .iter()
+        .enumerate()
+        .filter_map(|(offset, &(id, len))| {
+            let index = start.0 + offset;
+            let range_start = if index == start.0 { start.1 } else { 0 };
+            let range_end = if index == end.0 { end.1 } else { len };
+            (range_start < range_end).then_some((id, range_start..range_end))
+        })
+        .collect()
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{KeyboardEvent, Modifiers};
+    use crate::kurbo::Rect;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::{Flex, Label, Prose, TextInput};
+
+    #[test]
+    fn select_across_widgets() {
+        let first = Label::new("First post").with_auto_id();
+        let second = Prose::new("Second post").with_auto_id();
+        let third = Label::new("Third post").with_auto_id();
+        let (first_id, prose_id, third_id) = (first.id(), second.id(), third.id());
+        let column = Flex::column()
+            .with_fixed(first)
+            .with_fixed(second)
+            .with_fixed(third)
+            .with_auto_id();
+        let scope = SelectionScope::new(column).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), scope, Size::new(300.0, 200.0));
+        let second_id = {
+            let prose = harness.get_widget_with_id(prose_id);
+            prose
+                .downcast::<Prose>()
+                .unwrap()
+                .inner()
+                .text_area_pod()
+                .id()
+        };
+        let rect = |harness: &TestHarness<SelectionScope>, id| -> Rect {
+            harness.get_widget_with_id(id).ctx().bounding_box()
+        };
+        let selection =
+            |harness: &TestHarness<SelectionScope>| harness.root_widget().selection().to_vec();
+
+        // Dragging from the start of the first label to the start of the last one
+        // selects the first two widgets.
+        let first_rect = rect(&harness, first_id);
+        let third_rect = rect(&harness, third_id);
+        harness.mouse_move((first_rect.x0 + 1., first_rect.center().y));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((third_rect.x0 + 1., third_rect.center().y));
+        assert_eq!(
+            selection(&harness),
+            vec![(first_id, 0..10), (second_id, 0..11)]
+        );
+
+        // Dragging past the end of the text selects all of it.
+        harness.mouse_move((third_rect.x0 + 1., 190.));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            selection(&harness),
+            vec![(first_id, 0..10), (second_id, 0..11), (third_id, 0..10)]
+        );
+        assert_eq!(harness.focused_widget_id(), Some(harness.root_id()));
+
+        let press = |harness: &mut TestHarness<SelectionScope>, key: Key, modifiers| {
+            harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+                key,
+                modifiers,
+                ..Default::default()
+            }));
+        };
+        let action_mod = if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+        press(&mut harness, Key::Character("c".into()), action_mod);
+        assert_eq!(
+            harness.clipboard_contents(),
+            "First post\nSecond post\nThird post"
+        );
+
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Escape),
+            Modifiers::default(),
+        );
+        assert!(selection(&harness).is_empty());
+
+        // Selecting backwards from the middle of a widget.
+        let second_rect = rect(&harness, second_id);
+        let end = Point::new(second_rect.x1 - 1., second_rect.center().y);
+        let end_index = {
+            let text_area = harness.get_widget_with_id(second_id);
+            let local = text_area.ctx().to_local(end);
+            text_area.inner().selectable_text_index(local)
+        };
+        harness.mouse_move(end);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((first_rect.x0 + 1., first_rect.center().y));
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            selection(&harness),
+            vec![(first_id, 0..10), (second_id, 0..end_index)]
+        );
+
+        press(&mut harness, Key::Character("a".into()), action_mod);
+        assert_eq!(selection(&harness).len(), 3);
+    }
+
+    fn action_mod() -> Modifiers {
+        if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        }
+    }
+
+    fn press_key(harness: &mut TestHarness<SelectionScope>, key: &str, modifiers: Modifiers) {
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            key: Key::Character(key.into()),
+            modifiers,
+            ..Default::default()
+        }));
+    }
+
+    fn drag_across(harness: &mut TestHarness<SelectionScope>, id: WidgetId) {
+        let rect = harness.get_widget_with_id(id).ctx().bounding_box();
+        harness.mouse_move((rect.x0 + 1., rect.center().y));
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((rect.x1 + 10., rect.center().y));
+        harness.mouse_button_release(PointerButton::Primary);
+    }
+
+    #[test]
+    fn nested_scopes() {
+        let outer_label = Label::new("Outer").with_auto_id();
+        let inner_label = Label::new("Inner").with_auto_id();
+        let (outer_label_id, inner_label_id) = (outer_label.id(), inner_label.id());
+        let inner = SelectionScope::new(inner_label).with_auto_id();
+        let inner_id = inner.id();
+        let column = Flex::column()
+            .with_fixed(outer_label)
+            .with_fixed(inner)
+            .with_auto_id();
+        let scope = SelectionScope::new(column).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), scope, Size::new(300.0, 200.0));
+        let inner_selection = |harness: &TestHarness<SelectionScope>| {
+            let inner = harness.get_widget_with_id(inner_id);
+            inner
+                .downcast::<SelectionScope>()
+                .unwrap()
+                .selection()
+                .to_vec()
+        };
+
+        // Selecting in the inner scope doesn't select anything in the outer one.
+        drag_across(&mut harness, inner_label_id);
+        assert_eq!(inner_selection(&harness), vec![(inner_label_id, 0..5)]);
+        assert!(harness.root_widget().selection().is_empty());
+        assert_eq!(harness.focused_widget_id(), Some(inner_id));
+
+        // The outer scope doesn't include the text of the inner one.
+        drag_across(&mut harness, outer_label_id);
+        assert_eq!(harness.focused_widget_id(), Some(harness.root_id()));
+        press_key(&mut harness, "a", action_mod());
+        assert_eq!(harness.root_widget().selection(), [(outer_label_id, 0..5)]);
+        assert_eq!(inner_selection(&harness), vec![(inner_label_id, 0..5)]);
+    }
+
+    #[test]
+    fn focus_moves_to_text_input() {
+        let label = Label::new("Some text").with_auto_id();
+        let input = TextInput::new("Input").with_auto_id();
+        let (label_id, input_id) = (label.id(), input.id());
+        let column = Flex::column()
+            .with_fixed(label)
+            .with_fixed(input)
+            .with_auto_id();
+        let scope = SelectionScope::new(column).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), scope, Size::new(300.0, 200.0));
+
+        drag_across(&mut harness, label_id);
+        assert_eq!(harness.root_widget().selection(), [(label_id, 0..9)]);
+        assert_eq!(harness.focused_widget_id(), Some(harness.root_id()));
+
+        // Text inputs handle presses themselves, so they take the focus from the scope,
+        // and the shortcuts go to them.
+        let input_area = harness
+            .get_widget_with_id(input_id)
+            .downcast::<TextInput>()
+            .unwrap()
+            .inner()
+            .area_pod()
+            .id();
+        harness.mouse_click_on(input_area);
+        assert_eq!(harness.focused_widget_id(), Some(input_area));
+        assert_eq!(harness.root_widget().selection(), [(label_id, 0..9)]);
+        press_key(&mut harness, "a", action_mod());
+        assert_eq!(harness.root_widget().selection(), [(label_id, 0..9)]);
+
+        // Pressing in the label gives the focus back to the scope.
+        drag_across(&mut harness, label_id);
+        assert_eq!(harness.focused_widget_id(), Some(harness.root_id()));
+        press_key(&mut harness, "c", action_mod());
+        assert_eq!(harness.clipboard_contents(), "Some text");
+    }
+}
@@ -13,7 +13,7 @@ use crate::core::{
     RegisterCtx, UpdateCtx, Widget, WidgetId, WidgetMut, WidgetPod,
 };
 use crate::kurbo::{Axis, Point, Size};
-use crate::layout::{LayoutSize, LenReq, Length};
+use crate::layout::{LayoutSize, LenReq, Length, LengthUnit};
 use crate::properties::{BorderWidth, Padding};
 
 /// A widget with bi-directional size enforcement.
@@ -26,6 +26,9 @@ use crate::properties::{BorderWidth, Padding};
 /// 1. [`Dimensions`] properties work as usual and take precedence over anything else.
 /// 2. There are methods to configure the inner fields for width and height.
 ///
+/// The width and height can be relative lengths. Percentages are relative to the
+/// context size given by the parent, and are ignored if it doesn't give one.
+///
 /// ## Adopted size
 ///
 /// If there is no explicit size and the parent widget chooses to measure `SizedBox`,
@@ -131,6 +134,20 @@ impl SizedBox {
             Axis::Vertical => self.height,
         }
     }
+
+    /// Resolves the length of the given `axis` into logical pixels.
+    ///
+    /// Percentages are relative to the context size, and are ignored if it's unknown.
+    fn resolve_length(&self, ctx: &mut MeasureCtx<'_>, axis: Axis) -> Option<f64> {
+        let length = self.length(axis)?;
+        // Only ask for the context size when it's used, as that disables measurement caching.
+        let context_length = if length.unit() == LengthUnit::Percent {
+            ctx.context_size().length(axis)
+        } else {
+            None
+        };
+        ctx.resolve_length(length, context_length)
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -242,27 +259,21 @@ impl Widget for SizedBox {
         let padding_length = padding.length(axis).dp(scale);
 
         // First see if we have an explicitly defined length
-        if let Some(length) = self.length(axis) {
-            return (length.dp(scale) - border_length - padding_length).max(0.);
+        if let Some(length) = self.resolve_length(ctx, axis) {
+            return (length * scale - border_length - padding_length).max(0.);
         }
 
         // Otherwise measure the child
+        let cross = axis.cross();
+        let cross_length = match cross_length {
+            Some(cross_length) => Some(cross_length),
+            None => self.resolve_length(ctx, cross).map(|length| {
+                let cross_border_length = border.length(cross).dp(scale);
+                let cross_padding_length = padding.length(cross).dp(scale);
+                (length * scale - cross_border_length - cross_padding_length).max(0.)
+            }),
+        };
         if let Some(child) = self.child.as_mut() {
-            let cross = axis.cross();
-            let cross_length = cross_length.or_else(|| {
-                // Can't use self.length() due to borrow checker stupidity,
-                // so we need to manually inline that method.
-                let length = match cross {
-                    Axis::Horizontal => self.width,
-                    Axis::Vertical => self.height,
-                };
-                length.map(|length| {
-                    let cross_border_length = border.length(cross).dp(scale);
-                    let cross_padding_length = padding.length(cross).dp(scale);
-                    (length.dp(scale) - cross_border_length - cross_padding_length).max(0.)
-                })
-            });
-
             let auto_length = len_req.into();
             let context_size = LayoutSize::maybe(cross, cross_length);
 
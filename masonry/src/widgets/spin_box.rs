@@ -0,0 +1,240 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+
+use crate::core::{
+    AccessCtx, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx, PropertiesRef,
+    RegisterCtx, Widget, WidgetId, WidgetMut, WidgetPod,
+};
+use crate::kurbo::{Axis, Point, Size};
+use crate::layout::{LayoutSize, LenReq};
+use crate::properties::Padding;
+use crate::widgets::{Button, TextInput};
+
+/// The width of each of the step buttons.
+const BUTTON_WIDTH: f64 = 28.;
+
+/// A text input with buttons to decrement and increment its value.
+///
+/// This widget only arranges its children, and doesn't interpret the text.
+/// The text area of the input emits [`TextAction`](super::TextAction)s, and the buttons emit
+/// [`ButtonPress`](super::ButtonPress)es when pressed.
+/// It is up to the app to parse and validate the text, and to update it when a button is pressed.
+/// Their IDs can be accessed using [`input_pod`](Self::input_pod),
+/// [`decrement_pod`](Self::decrement_pod) and [`increment_pod`](Self::increment_pod).
+///
+/// The text input is usually configured to only accept numbers,
+/// using [`TextArea::with_filter`](super::TextArea::with_filter).
+pub struct SpinBox {
+    input: WidgetPod<TextInput>,
+    decrement: WidgetPod<Button>,
+    increment: WidgetPod<Button>,
+    /// Whether to show the step buttons.
+    show_buttons: bool,
+}
+
+// --- MARK: BUILDERS
+impl SpinBox {
+    /// Creates a new spin box around the given text input.
+    pub fn new(input: NewWidget<TextInput>) -> Self {
+        Self {
+            input: input.to_pod(),
+            decrement: Button::with_text("−").with_props(Padding::ZERO).to_pod(),
+            increment: Button::with_text("+").with_props(Padding::ZERO).to_pod(),
+            show_buttons: true,
+        }
+    }
+
+    /// Sets whether to show the decrement and increment buttons.
+    ///
+    /// The default is true.
+    ///
+    /// To modify this on an active spin box, use [`set_show_buttons`](Self::set_show_buttons).
+    pub fn with_show_buttons(mut self, show_buttons: bool) -> Self {
+        self.show_buttons = show_buttons;
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl SpinBox {
+    /// Reads the text input.
+    ///
+    /// Useful for getting the ID of its text area, which sends the text actions.
+    pub fn input_pod(&self) -> &WidgetPod<TextInput> {
+        &self.input
+    }
+
+    /// Reads the decrement button.
+    pub fn decrement_pod(&self) -> &WidgetPod<Button> {
+        &self.decrement
+    }
+
+    /// Reads the increment button.
+    pub fn increment_pod(&self) -> &WidgetPod<Button> {
+        &self.increment
+    }
+
+    fn buttons_width(&self) -> f64 {
+        if self.show_buttons {
+            2. * BUTTON_WIDTH
+        } else {
+            0.
+        }
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl SpinBox {
+    /// Edits the text input.
+    pub fn input_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, TextInput> {
+        this.ctx.get_mut(&mut this.widget.input)
+    }
+
+    /// Edits the decrement button.
+    pub fn decrement_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Button> {
+        this.ctx.get_mut(&mut this.widget.decrement)
+    }
+
+    /// Edits the increment button.
+    pub fn increment_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, Button> {
+        this.ctx.get_mut(&mut this.widget.increment)
+    }
+
+    /// Sets whether to show the decrement and increment buttons.
+    ///
+    /// The runtime equivalent of [`with_show_buttons`](Self::with_show_buttons).
+    pub fn set_show_buttons(this: &mut WidgetMut<'_, Self>, show_buttons: bool) {
+        this.widget.show_buttons = show_buttons;
+        this.ctx.request_layout();
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for SpinBox {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.input);
+        ctx.register_child(&mut self.decrement);
+        ctx.register_child(&mut self.increment);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let buttons_width = self.buttons_width();
+        match axis {
+            Axis::Horizontal => {
+                let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+                let input_width = ctx.compute_length(
+                    &mut self.input,
+                    len_req.reduce(buttons_width).into(),
+                    context_size,
+                    axis,
+                    cross_length,
+                );
+                input_width + buttons_width
+            }
+            Axis::Vertical => {
+                let input_width = cross_length.map(|width| (width - buttons_width).max(0.));
+                let context_size = LayoutSize::maybe(axis.cross(), input_width);
+                ctx.compute_length(
+                    &mut self.input,
+                    len_req.into(),
+                    context_size,
+                    axis,
+                    input_width,
+                )
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let buttons_width = self.buttons_width().min(size.width);
+        let input_size = Size::new(size.width - buttons_width, size.height);
+        ctx.run_layout(&mut self.input, input_size);
+        ctx.place_child(&mut self.input, Point::ORIGIN);
+
+        let input_baseline = ctx.child_baseline_offset(&self.input);
+        ctx.set_baseline_offset(input_baseline);
+
+        ctx.set_stashed(&mut self.decrement, !self.show_buttons);
+        ctx.set_stashed(&mut self.increment, !self.show_buttons);
+        if self.show_buttons {
+            let button_size = Size::new(buttons_width / 2., size.height);
+            ctx.run_layout(&mut self.decrement, button_size);
+            ctx.place_child(&mut self.decrement, Point::new(input_size.width, 0.));
+            ctx.run_layout(&mut self.increment, button_size);
+            ctx.place_child(
+                &mut self.increment,
+                Point::new(input_size.width + button_size.width, 0.),
+            );
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        _node: &mut Node,
+    ) {
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.input.id(), self.decrement.id(), self.increment.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("SpinBox", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use crate::theme::test_property_set;
+    use crate::widgets::ButtonPress;
+
+    #[test]
+    fn buttons_send_presses() {
+        let widget = SpinBox::new(TextInput::new("1").with_auto_id()).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), widget, Size::new(200., 40.));
+        let increment_id = harness.root_widget().increment_pod().id();
+        let decrement_id = harness.root_widget().decrement_pod().id();
+
+        harness.mouse_click_on(increment_id);
+        assert_eq!(
+            harness.pop_action::<ButtonPress>().map(|(_, id)| id),
+            Some(increment_id)
+        );
+        harness.mouse_click_on(decrement_id);
+        assert_eq!(
+            harness.pop_action::<ButtonPress>().map(|(_, id)| id),
+            Some(decrement_id)
+        );
+
+        // Without buttons, the input takes the whole width.
+        harness.edit_root_widget(|mut spin_box| SpinBox::set_show_buttons(&mut spin_box, false));
+        let input_id = harness.root_widget().input_pod().id();
+        let input = harness.get_widget_with_id(input_id);
+        assert_eq!(input.ctx().border_box_size().width, 200.);
+    }
+}
@@ -14,7 +14,7 @@ use crate::core::{
     WidgetId, WidgetMut, WidgetPod,
 };
 use crate::kurbo::{Axis, Line, Point, Size};
-use crate::layout::{AsUnit, LayoutSize, LenReq, Length};
+use crate::layout::{AsUnit, LayoutSize, LenReq, Length, LengthUnit};
 use crate::peniko::Color;
 use crate::theme;
 use crate::util::{fill_color, stroke};
@@ -31,8 +31,12 @@ pub enum SplitPoint {
     /// Values outside `0.0..=1.0` are clamped when set.
     Fraction(f64),
     /// Split by an absolute distance from the start.
+    ///
+    /// Percentages are relative to the available space.
     FromStart(Length),
     /// Split by an absolute distance from the end.
+    ///
+    /// Percentages are relative to the available space.
     FromEnd(Length),
 }
 
@@ -54,6 +58,8 @@ where
     min_lengths: (Length, Length),
     bar_thickness: Length,
     min_bar_area: Length,
+    /// The lengths resolved during layout.
+    resolved: ResolvedLengths,
     solid: bool,
     draggable: bool,
     /// Offset from the bar center to the actual position where the bar was clicked.
@@ -64,6 +70,16 @@ where
     child2: WidgetPod<ChildB>,
 }
 
+/// The lengths of a [`Split`], resolved into device pixels.
+#[derive(Clone, Copy, Debug, Default)]
+struct ResolvedLengths {
+    bar_thickness: f64,
+    min_bar_area: f64,
+    min_lengths: (f64, f64),
+    /// The length of a [`SplitPoint::FromStart`] or [`SplitPoint::FromEnd`] split point.
+    split_point: f64,
+}
+
 // --- MARK: BUILDERS
 impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
     /// Creates a new split panel.
@@ -75,6 +91,7 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
             min_lengths: (Length::ZERO, Length::ZERO),
             bar_thickness: 6.px(),
             min_bar_area: 6.px(),
+            resolved: ResolvedLengths::default(),
             solid: false,
             draggable: true,
             click_offset: 0.0,
@@ -134,6 +151,8 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
     }
 
     /// Builder-style method to set the minimum length for both sides of the split axis.
+    ///
+    /// Percentages are relative to the available space.
     pub fn min_lengths(mut self, first: Length, second: Length) -> Self {
         self.min_lengths = (first, second);
         self
@@ -141,6 +160,8 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
 
     /// Builder-style method to set the thickness of the splitter bar.
     ///
+    /// Percentages are relative to the widget's length along the split axis.
+    ///
     /// The default splitter bar thickness is `6.0`.
     pub fn bar_thickness(mut self, bar_thickness: Length) -> Self {
         self.bar_thickness = bar_thickness;
@@ -180,22 +201,50 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
 
 // --- MARK: METHODS
 impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
+    /// Resolves the lengths into device pixels, and stores them for use outside of layout.
+    ///
+    /// `length` is our length along the split axis, if it's known.
+    /// Percentages of the bar thickness are relative to it, and those of the
+    /// split point and minimum lengths to the space available to the children.
+    fn resolve_lengths(
+        &mut self,
+        resolve: &mut dyn FnMut(Length, Option<f64>) -> Option<f64>,
+        length: Option<f64>,
+        scale: f64,
+    ) {
+        let mut resolve = |l, context_length| resolve(l, context_length).unwrap_or(0.) * scale;
+        let bar_thickness = resolve(self.bar_thickness, length);
+        let split_space = length.map(|length| (length - bar_thickness).max(0.));
+        self.resolved = ResolvedLengths {
+            bar_thickness,
+            min_bar_area: resolve(self.min_bar_area, length),
+            min_lengths: (
+                resolve(self.min_lengths.0, split_space),
+                resolve(self.min_lengths.1, split_space),
+            ),
+            split_point: match self.split_point_chosen {
+                SplitPoint::Fraction(_) => 0.,
+                SplitPoint::FromStart(len) | SplitPoint::FromEnd(len) => resolve(len, split_space),
+            },
+        };
+    }
+
     /// Returns the thickness of the splitter bar area.
     #[inline]
-    fn bar_area(&self, scale: f64) -> f64 {
-        self.bar_thickness.max(self.min_bar_area).dp(scale)
+    fn bar_area(&self) -> f64 {
+        self.resolved.bar_thickness.max(self.resolved.min_bar_area)
     }
 
     /// Returns the splitter bar center point.
-    fn bar_center(&self, length: f64, scale: f64) -> f64 {
-        let (edge1, edge2) = self.bar_edges(length, scale);
+    fn bar_center(&self, length: f64) -> f64 {
+        let (edge1, edge2) = self.bar_edges(length);
         (edge1 + edge2) * 0.5
     }
 
     /// Returns the location of the edges of the splitter bar,
     /// given the specified total length.
-    fn bar_edges(&self, length: f64, scale: f64) -> (f64, f64) {
-        let bar_thickness = self.bar_thickness.dp(scale);
+    fn bar_edges(&self, length: f64) -> (f64, f64) {
+        let bar_thickness = self.resolved.bar_thickness;
         let reduced_length = length - bar_thickness;
         let edge = reduced_length * self.split_point_effective;
         (edge, edge + bar_thickness)
@@ -203,10 +252,10 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
 
     /// Returns the location of the edges of the splitter bar area,
     /// given the specified total length.
-    fn bar_area_edges(&self, length: f64, scale: f64) -> (f64, f64) {
-        let (edge1, edge2) = self.bar_edges(length, scale);
+    fn bar_area_edges(&self, length: f64) -> (f64, f64) {
+        let (edge1, edge2) = self.bar_edges(length);
         let (space1, space2) = (edge1.max(0.), (length - edge2).max(0.));
-        let padding = self.bar_area(scale) - self.bar_thickness.dp(scale);
+        let padding = self.bar_area() - self.resolved.bar_thickness;
 
         // Half the padding to the first edge
         let pad1 = (0.5 * padding).min(space1);
@@ -219,16 +268,16 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
     }
 
     /// Returns `true` if the provided position is on the splitter bar area.
-    fn bar_area_hit_test(&self, length: f64, pos: f64, scale: f64) -> bool {
-        let (edge1, edge2) = self.bar_area_edges(length, scale);
+    fn bar_area_hit_test(&self, length: f64, pos: f64) -> bool {
+        let (edge1, edge2) = self.bar_area_edges(length);
         pos >= edge1 && pos <= edge2
     }
 
     /// Returns the minimum and maximum split coordinate of the provided length.
-    fn split_side_limits(&self, length: f64, scale: f64) -> (f64, f64) {
-        let (min_child1, min_child2) = self.min_lengths;
-        let mut min_limit = min_child1.dp(scale);
-        let mut max_limit = (length - min_child2.dp(scale)).max(0.0);
+    fn split_side_limits(&self, length: f64) -> (f64, f64) {
+        let (min_child1, min_child2) = self.resolved.min_lengths;
+        let mut min_limit = min_child1;
+        let mut max_limit = (length - min_child2).max(0.0);
 
         if min_limit > max_limit {
             min_limit = 0.5 * (min_limit + max_limit);
@@ -238,22 +287,22 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
         (min_limit, max_limit)
     }
 
-    fn calc_effective_split_point(&self, length: f64, scale: f64) -> f64 {
-        let (min_limit, max_limit) = self.split_side_limits(length, scale);
+    fn calc_effective_split_point(&self, length: f64) -> f64 {
+        let (min_limit, max_limit) = self.split_side_limits(length);
         if length <= f64::EPSILON {
             0.5
         } else {
             let child1_len = match self.split_point_chosen {
                 SplitPoint::Fraction(frac) => length * frac,
-                SplitPoint::FromStart(len) => len.dp(scale),
-                SplitPoint::FromEnd(len) => length - len.dp(scale),
+                SplitPoint::FromStart(_) => self.resolved.split_point,
+                SplitPoint::FromEnd(_) => length - self.resolved.split_point,
             };
             (child1_len / length).clamp(min_limit / length, max_limit / length)
         }
     }
 
     fn set_chosen_from_child1_len(&mut self, length: f64, child1_len: f64, scale: f64) {
-        let (min_limit, max_limit) = self.split_side_limits(length, scale);
+        let (min_limit, max_limit) = self.split_side_limits(length);
         let child1_len = child1_len.clamp(min_limit, max_limit);
 
         match self.split_point_chosen {
@@ -264,14 +313,14 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
                     child1_len / length
                 });
             }
-            SplitPoint::FromStart(_) => {
-                let logical = child1_len / scale;
-                self.split_point_chosen = SplitPoint::FromStart(Length::px(logical));
+            SplitPoint::FromStart(len) => {
+                let len = length_in_unit_of(len, child1_len, length, scale);
+                self.split_point_chosen = SplitPoint::FromStart(len);
             }
-            SplitPoint::FromEnd(_) => {
+            SplitPoint::FromEnd(len) => {
                 let child2_len = (length - child1_len).max(0.0);
-                let logical = child2_len / scale;
-                self.split_point_chosen = SplitPoint::FromEnd(Length::px(logical));
+                let len = length_in_unit_of(len, child2_len, length, scale);
+                self.split_point_chosen = SplitPoint::FromEnd(len);
             }
         }
     }
@@ -282,7 +331,7 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
         bar_center: f64,
         scale: f64,
     ) {
-        let bar_thickness = self.bar_thickness.dp(scale);
+        let bar_thickness = self.resolved.bar_thickness;
         let split_space = (total_length - bar_thickness).max(0.0);
         let child1_len = bar_center - bar_thickness * 0.5;
         self.set_chosen_from_child1_len(split_space, child1_len, scale);
@@ -302,7 +351,7 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
 
     fn paint_focus_bar(&mut self, ctx: &mut PaintCtx<'_>, scene: &mut Scene, scale: f64) {
         let length = ctx.content_box_size().get_coord(self.split_axis);
-        let (edge1, edge2) = self.bar_edges(length, scale);
+        let (edge1, edge2) = self.bar_edges(length);
 
         let mut rect = ctx.border_box();
         rect.set_coords(self.split_axis, edge1, edge2);
@@ -313,15 +362,9 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
         stroke(scene, &rect, focus_color, 1.0 * scale);
     }
 
-    fn paint_solid_bar(
-        &mut self,
-        ctx: &mut PaintCtx<'_>,
-        scene: &mut Scene,
-        scale: f64,
-        color: Color,
-    ) {
+    fn paint_solid_bar(&mut self, ctx: &mut PaintCtx<'_>, scene: &mut Scene, color: Color) {
         let length = ctx.content_box_size().get_coord(self.split_axis);
-        let (edge1, edge2) = self.bar_edges(length, scale);
+        let (edge1, edge2) = self.bar_edges(length);
 
         let mut rect = ctx.border_box();
         rect.set_coords(self.split_axis, edge1, edge2);
@@ -329,19 +372,13 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
         fill_color(scene, &rect, color);
     }
 
-    fn paint_stroked_bar(
-        &mut self,
-        ctx: &mut PaintCtx<'_>,
-        scene: &mut Scene,
-        scale: f64,
-        color: Color,
-    ) {
+    fn paint_stroked_bar(&mut self, ctx: &mut PaintCtx<'_>, scene: &mut Scene, color: Color) {
         let length = ctx.content_box_size().get_coord(self.split_axis);
         // Set the line width to a third of the splitter bar thickness,
         // because we'll paint two equal lines at the edges.
-        let line_width = self.bar_thickness.dp(scale) / 3.0;
+        let line_width = self.resolved.bar_thickness / 3.0;
         let line_midpoint = line_width / 2.0;
-        let (edge1, edge2) = self.bar_edges(length, scale);
+        let (edge1, edge2) = self.bar_edges(length);
 
         let edge1_line_pos = edge1 + line_midpoint;
         let edge2_line_pos = edge2 - line_midpoint;
@@ -362,6 +399,17 @@ impl<ChildA: Widget + ?Sized, ChildB: Widget + ?Sized> Split<ChildA, ChildB> {
     }
 }
 
+/// Creates a length of `value` device pixels, in the same unit as `len` if it's a percentage.
+///
+/// Percentages are relative to `length`.
+fn length_in_unit_of(len: Length, value: f64, length: f64, scale: f64) -> Length {
+    if len.unit() == LengthUnit::Percent && length > f64::EPSILON {
+        Length::percent(value / length * 100.)
+    } else {
+        Length::px(value / scale)
+    }
+}
+
 // --- MARK: WIDGETMUT
 impl<ChildA, ChildB> Split<ChildA, ChildB>
 where
@@ -485,12 +533,12 @@ where
                         .local_position(state.position)
                         .get_coord(self.split_axis);
                     let length = ctx.content_box_size().get_coord(self.split_axis);
-                    if self.bar_area_hit_test(length, pos, scale) {
+                    if self.bar_area_hit_test(length, pos) {
                         ctx.set_handled();
                         ctx.capture_pointer();
                         ctx.request_focus();
                         // Save the delta between the click position and the bar center.
-                        self.click_offset = pos - self.bar_center(length, scale);
+                        self.click_offset = pos - self.bar_center(length);
                     }
                 }
                 PointerEvent::Move(PointerUpdate { current, .. }) => {
@@ -535,8 +583,7 @@ where
         let scale = 1.0;
 
         let length = ctx.content_box_size().get_coord(self.split_axis);
-        let bar_thickness = self.bar_thickness.dp(scale);
-        let split_space = (length - bar_thickness).max(0.0);
+        let split_space = (length - self.resolved.bar_thickness).max(0.0);
         if split_space <= f64::EPSILON {
             return;
         }
@@ -564,10 +611,10 @@ where
                 child1_len += delta;
             }
             Key::Named(NamedKey::Home) => {
-                child1_len = self.split_side_limits(split_space, scale).0;
+                child1_len = self.split_side_limits(split_space).0;
             }
             Key::Named(NamedKey::End) => {
-                child1_len = self.split_side_limits(split_space, scale).1;
+                child1_len = self.split_side_limits(split_space).1;
             }
             _ => return,
         }
@@ -591,8 +638,7 @@ where
         let scale = 1.0;
 
         let length = ctx.content_box_size().get_coord(self.split_axis);
-        let bar_thickness = self.bar_thickness.dp(scale);
-        let split_space = (length - bar_thickness).max(0.0);
+        let split_space = (length - self.resolved.bar_thickness).max(0.0);
         if split_space <= f64::EPSILON {
             return;
         }
@@ -648,7 +694,17 @@ where
         //       https://github.com/linebender/xilem/issues/1264
         let scale = 1.0;
 
-        let bar_thickness = self.bar_thickness.dp(scale);
+        // Our length along the split axis, if it's known.
+        let length = if axis == self.split_axis {
+            match len_req {
+                LenReq::FitContent(space) => Some(space),
+                LenReq::MinContent | LenReq::MaxContent => None,
+            }
+        } else {
+            cross_length
+        };
+        self.resolve_lengths(&mut |l, c| ctx.resolve_length(l, c), length, scale);
+        let bar_thickness = self.resolved.bar_thickness;
 
         if let LenReq::FitContent(space) = len_req {
             // We always want to use up all offered space
@@ -667,7 +723,7 @@ where
                 // We need to split the cross length if it's our split axis
                 if cross == self.split_axis {
                     let cross_space = (cross_length - bar_thickness).max(0.);
-                    let split_point = self.calc_effective_split_point(cross_space, scale);
+                    let split_point = self.calc_effective_split_point(cross_space);
                     let child1_cross_space = cross_space * split_point;
                     (child1_cross_space, cross_space - child1_cross_space)
                 } else {
@@ -705,12 +761,14 @@ where
         //       https://github.com/linebender/xilem/issues/1264
         let scale = 1.0;
 
-        let bar_thickness = self.bar_thickness.dp(scale);
-        let split_space = (size.get_coord(self.split_axis) - bar_thickness).max(0.);
+        let length = size.get_coord(self.split_axis);
+        self.resolve_lengths(&mut |l, c| ctx.resolve_length(l, c), Some(length), scale);
+        let bar_thickness = self.resolved.bar_thickness;
+        let split_space = (length - bar_thickness).max(0.);
         let cross_space = size.get_coord(self.split_axis.cross());
 
         // Update our effective split point to respect our size
-        self.split_point_effective = self.calc_effective_split_point(split_space, scale);
+        self.split_point_effective = self.calc_effective_split_point(split_space);
 
         let child1_split_space = (split_space * self.split_point_effective).max(0.);
         let child2_split_space = (split_space - child1_split_space).max(0.);
@@ -738,9 +796,9 @@ where
         // TODO - Paint differently if the bar is draggable and hovered.
         let bar_color = self.bar_color(ctx);
         if self.solid {
-            self.paint_solid_bar(ctx, scene, scale, bar_color);
+            self.paint_solid_bar(ctx, scene, bar_color);
         } else {
-            self.paint_stroked_bar(ctx, scene, scale, bar_color);
+            self.paint_stroked_bar(ctx, scene, bar_color);
         }
 
         if ctx.is_focus_target() && self.draggable && !ctx.is_disabled() {
@@ -752,13 +810,9 @@ where
     }
 
     fn get_cursor(&self, ctx: &QueryCtx<'_>, pos: Point) -> CursorIcon {
-        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
-        //       https://github.com/linebender/xilem/issues/1264
-        let scale = 1.0;
-
         let length = ctx.content_box_size().get_coord(self.split_axis);
         let local_pos = ctx.to_local(pos).get_coord(self.split_axis);
-        let is_bar_area_hovered = self.bar_area_hit_test(length, local_pos, scale);
+        let is_bar_area_hovered = self.bar_area_hit_test(length, local_pos);
 
         if self.draggable && (ctx.is_active() || is_bar_area_hovered) {
             match self.split_axis {
@@ -780,14 +834,9 @@ where
         _props: &PropertiesRef<'_>,
         node: &mut Node,
     ) {
-        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
-        //       https://github.com/linebender/xilem/issues/1264
-        let scale = 1.0;
-
         let length = ctx.content_box_size().get_coord(self.split_axis);
-        let bar_thickness = self.bar_thickness.dp(scale);
-        let split_space = (length - bar_thickness).max(0.0);
-        let (min_limit, max_limit) = self.split_side_limits(split_space, scale);
+        let split_space = (length - self.resolved.bar_thickness).max(0.0);
+        let (min_limit, max_limit) = self.split_side_limits(split_space);
         let child1_len = split_space * self.split_point_effective;
 
         node.set_orientation(match self.split_axis {
@@ -0,0 +1,1903 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+
+use accesskit::{Node, Role};
+use dpi::PhysicalPosition;
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Axis, BezPath, Point, Rect, Size};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, AllowRawMut, ArcStr, ChildrenIds, CursorIcon, EventCtx, HasProperty,
+    LayoutCtx, MeasureCtx, Modifiers, NewWidget, NoAction, PaintCtx, PointerButton,
+    PointerButtonEvent, PointerEvent, PointerScrollEvent, PointerUpdate, PropertiesMut,
+    PropertiesRef, QueryCtx, RawCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId,
+    WidgetMut, WidgetPod, WidgetRef,
+};
+use crate::layout::{LayoutSize, LenDef, LenReq, SizeDef};
+use crate::properties::{ContentColor, SelectionColor, UnfocusedSelectionColor};
+use crate::util::{debug_panic, fill_color, stroke};
+use crate::widgets::Label;
+
+/// Height of a row if none is given with [`Table::with_row_height`].
+const DEFAULT_ROW_HEIGHT: f64 = 24.;
+/// Number of rows loaded above and below the visible rows.
+const OVERSCAN_ROWS: usize = 4;
+/// Distance from a column's right edge within which dragging its header resizes the column.
+const RESIZE_HANDLE_WIDTH: f64 = 4.;
+/// Distance the pointer must move before dragging a column header starts reordering columns.
+const REORDER_THRESHOLD: f64 = 4.;
+/// Width of the area at the end of a column header in which the sort indicator is painted.
+const SORT_INDICATOR_WIDTH: f64 = 16.;
+
+/// A column of a [`Table`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableColumn {
+    /// The text shown in the column's header.
+    pub title: ArcStr,
+    /// The width of the column, in logical pixels.
+    pub width: f64,
+    /// The width below which the user can't shrink the column.
+    pub min_width: f64,
+    /// Whether clicking the column's header sorts the table.
+    pub sortable: bool,
+}
+
+/// The direction in which a [`Table`] is sorted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// Smallest values first.
+    Ascending,
+    /// Largest values first.
+    Descending,
+}
+
+/// How many rows of a [`Table`] the user can select.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SelectionMode {
+    /// Rows can't be selected.
+    None,
+    /// At most one row can be selected.
+    Single,
+    /// Any number of rows can be selected, using `Shift` and `Ctrl` (or `Cmd` on macOS).
+    #[default]
+    Multiple,
+}
+
+/// The action type emitted by [`Table`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableAction {
+    /// The set of rows which should be loaded has changed.
+    ///
+    /// See [`Table`] for how to handle this action.
+    LoadRows(TableLoadRows),
+    /// The user clicked the header of a sortable column.
+    ///
+    /// The sort indicator has already been updated; the rows should be
+    /// re-sorted by the driver.
+    Sort {
+        /// The index of the column, in the order the columns were given.
+        column: usize,
+        /// The requested sort direction.
+        direction: SortDirection,
+    },
+    /// The set of selected rows changed, and is now the given sorted list.
+    SelectionChanged(Vec<usize>),
+    /// The user finished resizing a column.
+    ColumnResized {
+        /// The index of the column, in the order the columns were given.
+        column: usize,
+        /// The new width of the column.
+        width: f64,
+    },
+    /// The user finished dragging a column to a new position.
+    ///
+    /// The field is the index of each column, in display order.
+    ColumnsReordered(Vec<usize>),
+}
+
+/// A request from a [`Table`] to change which rows are loaded.
+///
+/// Before handling this request, you must call [`Table::will_handle_load`] with it.
+/// Then, rows which are in `old_active` but not in `target` should be
+/// [removed](Table::remove_row), and rows which are in `target` but not in
+/// `old_active` should be [added](Table::add_row).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableLoadRows {
+    /// The range of rows which were loaded before this change.
+    pub old_active: Range<usize>,
+    /// The range of rows which should now be loaded.
+    pub target: Range<usize>,
+}
+
+/// A table of rows with resizable, reorderable and sortable columns.
+///
+/// Like [`VirtualScroll`](crate::widgets::VirtualScroll), only the rows near
+/// the visible area are loaded, and the driver is responsible for providing them.
+/// Unlike `VirtualScroll`, all rows have the same height, which is set with
+/// [`with_row_height`](Self::with_row_height), so the table always knows the full
+/// extent of its content.
+///
+/// The column headers stay at the top of the table while its rows are scrolled.
+/// Dragging the right edge of a header resizes its column, dragging the rest of
+/// a header moves the column, and clicking the header of a sortable column
+/// toggles its sort direction.
+///
+/// # Usage
+///
+/// The table emits [`TableAction::LoadRows`] whenever the range of rows which
+/// should be loaded changes.
+/// To handle it, the driver calls [`will_handle_load`](Self::will_handle_load),
+/// then [removes](Self::remove_row) the rows which are no longer needed,
+/// and [adds](Self::add_row) the ones which are newly needed, with one cell widget
+/// per column.
+///
+/// Sorting doesn't change any rows by itself: on [`TableAction::Sort`], the driver
+/// should sort its data and update the loaded rows through [`row_mut`](Self::row_mut).
+///
+/// # Selection
+///
+/// Clicking a row selects it.
+/// In [`SelectionMode::Multiple`], `Ctrl`-clicking (`Cmd`-clicking on macOS) a row
+/// toggles it, and `Shift`-clicking selects the range from the last clicked row.
+/// The arrow keys, `PageUp`, `PageDown`, `Home` and `End` move the selection, and
+/// extend it when `Shift` is held.
+///
+/// Emits [`TableAction::SelectionChanged`] when the selection changes through user input.
+pub struct Table {
+    columns: Vec<TableColumn>,
+    /// The indices of the columns, in display order.
+    order: Vec<usize>,
+    header: WidgetPod<TableHeader>,
+    rows: HashMap<usize, WidgetPod<TableRow>>,
+    row_count: usize,
+    row_height: f64,
+
+    /// The range of rows which the driver has been asked to load.
+    active_range: Range<usize>,
+    /// Whether the most recent [`TableAction::LoadRows`] was handled.
+    action_handled: bool,
+    /// Whether all rows must be unloaded, because the number of columns changed.
+    needs_reload: bool,
+    /// The amount the rows are scrolled by, in logical pixels.
+    scroll_offset: f64,
+    header_height: f64,
+    body_height: f64,
+
+    selection_mode: SelectionMode,
+    selection: BTreeSet<usize>,
+    /// The row from which `Shift` selections extend.
+    anchor: Option<usize>,
+    /// The row moved by the keyboard.
+    cursor: Option<usize>,
+    sort: Option<(usize, SortDirection)>,
+    header_drag: Option<HeaderDrag>,
+}
+
+/// An interaction with the column headers, in progress.
+#[derive(Clone, Copy, Debug)]
+enum HeaderDrag {
+    /// A header was pressed, but the pointer hasn't moved enough to start a reorder.
+    Pressed {
+        column: usize,
+        start_x: f64,
+    },
+    Reordering {
+        column: usize,
+        start_order: usize,
+    },
+    Resizing {
+        column: usize,
+        start_x: f64,
+        start_width: f64,
+    },
+}
+
+/// The position of one cell in a row.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct CellSlot {
+    x: f64,
+    width: f64,
+    /// The position of the cell's column in display order.
+    index: usize,
+}
+
+impl std::fmt::Debug for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Table")
+            .field("columns", &self.columns)
+            .field("order", &self.order)
+            .field("rows", &self.rows.keys().collect::<Vec<_>>())
+            .field("row_count", &self.row_count)
+            .field("active_range", &self.active_range)
+            .field("action_handled", &self.action_handled)
+            .field("scroll_offset", &self.scroll_offset)
+            .field("selection", &self.selection)
+            .field("sort", &self.sort)
+            .finish_non_exhaustive()
+    }
+}
+
+// --- MARK: BUILDERS
+impl TableColumn {
+    /// Creates a new sortable column with the given header text and width.
+    pub fn new(title: impl Into<ArcStr>, width: f64) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            min_width: 20.,
+            sortable: true,
+        }
+    }
+
+    /// Builder-style method to set the width below which the user can't shrink the column.
+    pub fn with_min_width(mut self, min_width: f64) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Builder-style method to set whether clicking the column's header sorts the table.
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+impl Table {
+    /// Creates a new table with the given columns and number of rows.
+    ///
+    /// Note that rows can't be added before the widget is "live", as the set of
+    /// rows which should be loaded has not yet been determined.
+    pub fn new(columns: impl IntoIterator<Item = TableColumn>, row_count: usize) -> Self {
+        let columns: Vec<TableColumn> = columns.into_iter().collect();
+        Self {
+            order: (0..columns.len()).collect(),
+            header: WidgetPod::new(TableHeader::new(&columns, None)),
+            columns,
+            rows: HashMap::new(),
+            row_count,
+            row_height: DEFAULT_ROW_HEIGHT,
+            active_range: 0..0,
+            action_handled: true,
+            needs_reload: false,
+            scroll_offset: 0.,
+            header_height: 0.,
+            body_height: 0.,
+            selection_mode: SelectionMode::default(),
+            selection: BTreeSet::new(),
+            anchor: None,
+            cursor: None,
+            sort: None,
+            header_drag: None,
+        }
+    }
+
+    /// Builder-style method to set the height of every row, in logical pixels.
+    pub fn with_row_height(mut self, row_height: f64) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Builder-style method to set how many rows the user can select.
+    pub fn with_selection_mode(mut self, selection_mode: SelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    /// Builder-style method to set the column whose header shows a sort indicator.
+    pub fn with_sort(mut self, sort: Option<(usize, SortDirection)>) -> Self {
+        self.sort = sort;
+        self.header = WidgetPod::new(TableHeader::new(&self.columns, sort));
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl SortDirection {
+    /// Returns the opposite direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+impl Table {
+    /// Returns the columns of the table, in the order they were given.
+    pub fn columns(&self) -> &[TableColumn] {
+        &self.columns
+    }
+
+    /// Returns the index of each column, in display order.
+    pub fn column_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns the number of rows in the table.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Returns the indices of the selected rows.
+    pub fn selection(&self) -> &BTreeSet<usize> {
+        &self.selection
+    }
+
+    /// Returns the column whose header shows a sort indicator, and its direction.
+    pub fn sort(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    /// The number of currently loaded rows.
+    ///
+    /// This is intended for sanity-checking of drivers.
+    #[expect(
+        clippy::len_without_is_empty,
+        reason = "This isn't the length of the table, see `row_count`."
+    )]
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Computes the position of each column's cells, indexed by column.
+    fn cell_slots(&self) -> Vec<CellSlot> {
+        let mut slots = vec![CellSlot::default(); self.columns.len()];
+        let mut x = 0.;
+        for (index, &column) in self.order.iter().enumerate() {
+            let width = self.columns[column].width;
+            slots[column] = CellSlot { x, width, index };
+            x += width;
+        }
+        slots
+    }
+
+    fn total_width(&self) -> f64 {
+        self.columns.iter().map(|column| column.width).sum()
+    }
+
+    fn max_scroll(&self) -> f64 {
+        (self.row_count as f64 * self.row_height - self.body_height).max(0.)
+    }
+
+    fn visible_row_count(&self) -> usize {
+        ((self.body_height / self.row_height).floor() as usize).max(1)
+    }
+
+    /// Returns the range of rows which should be loaded for the current scroll position.
+    fn target_range(&self) -> Range<usize> {
+        if self.needs_reload || self.row_height <= 0. {
+            return 0..0;
+        }
+        let first = (self.scroll_offset / self.row_height).floor() as usize;
+        let last = ((self.scroll_offset + self.body_height) / self.row_height).ceil() as usize;
+        let start = first.saturating_sub(OVERSCAN_ROWS).min(self.row_count);
+        let end = (last + OVERSCAN_ROWS).min(self.row_count);
+        start..end
+    }
+
+    /// Returns the row at the given position, in the table's content-box coordinate space.
+    fn row_at(&self, pos: Point) -> Option<usize> {
+        if pos.y < self.header_height {
+            return None;
+        }
+        let row = ((pos.y - self.header_height + self.scroll_offset) / self.row_height).floor();
+        let row = row as usize;
+        (row < self.row_count).then_some(row)
+    }
+
+    /// Returns the column whose header right edge is near `x`, if any.
+    fn resize_handle_at(&self, x: f64) -> Option<usize> {
+        let mut right = 0.;
+        for &column in &self.order {
+            right += self.columns[column].width;
+            if (x - right).abs() <= RESIZE_HANDLE_WIDTH {
+                return Some(column);
+            }
+        }
+        None
+    }
+
+    /// Returns the position in display order of the column at `x`.
+    fn display_index_at(&self, x: f64) -> Option<usize> {
+        let mut right = 0.;
+        for (index, &column) in self.order.iter().enumerate() {
+            right += self.columns[column].width;
+            if x < right {
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    /// Scrolls by `delta`, returning `true` if the scroll position changed.
+    fn scroll_by(&mut self, delta: f64) -> bool {
+        let old = self.scroll_offset;
+        self.scroll_offset = (self.scroll_offset + delta).clamp(0., self.max_scroll());
+        old != self.scroll_offset
+    }
+
+    /// Scrolls the minimum amount needed for `row` to be fully visible.
+    fn scroll_row_into_view(&mut self, row: usize) -> bool {
+        let top = row as f64 * self.row_height;
+        let bottom = top + self.row_height;
+        if top < self.scroll_offset {
+            self.scroll_by(top - self.scroll_offset)
+        } else if bottom > self.scroll_offset + self.body_height {
+            self.scroll_by(bottom - self.scroll_offset - self.body_height)
+        } else {
+            false
+        }
+    }
+
+    /// Updates the selection for the user choosing `row`.
+    ///
+    /// If `extend` is true, the rows between the anchor and `row` are selected.
+    /// If `toggle` is true, the previous selection is kept.
+    ///
+    /// Returns `true` if the selection changed.
+    fn select(&mut self, row: usize, extend: bool, toggle: bool) -> bool {
+        self.cursor = Some(row);
+        let old_selection = self.selection.clone();
+        match self.selection_mode {
+            SelectionMode::None => return false,
+            SelectionMode::Single => {
+                self.selection.clear();
+                self.selection.insert(row);
+                self.anchor = Some(row);
+            }
+            SelectionMode::Multiple => {
+                if !toggle {
+                    self.selection.clear();
+                }
+                if extend {
+                    let anchor = *self.anchor.get_or_insert(row);
+                    self.selection.extend(anchor.min(row)..=anchor.max(row));
+                } else {
+                    if toggle && self.selection.contains(&row) {
+                        self.selection.remove(&row);
+                    } else {
+                        self.selection.insert(row);
+                    }
+                    self.anchor = Some(row);
+                }
+            }
+        }
+        self.selection != old_selection
+    }
+
+    /// Pushes the selection and keyboard cursor to the loaded rows.
+    fn update_rows(&mut self, ctx: &mut EventCtx<'_>) {
+        let focused = ctx.has_focus_target();
+        for (idx, row) in &mut self.rows {
+            let (row, mut row_ctx) = ctx.get_raw_mut(row);
+            row.set_state(
+                &mut row_ctx,
+                self.selection.contains(idx),
+                focused && self.cursor == Some(*idx),
+                focused,
+            );
+        }
+    }
+
+    fn selection_changed(&mut self, ctx: &mut EventCtx<'_>) {
+        self.update_rows(ctx);
+        let selection = self.selection.iter().copied().collect();
+        ctx.submit_action::<<Self as Widget>::Action>(TableAction::SelectionChanged(selection));
+    }
+
+    fn set_sort_from_header(&mut self, ctx: &mut EventCtx<'_>, column: usize) {
+        let direction = match self.sort {
+            Some((sorted, direction)) if sorted == column => direction.reversed(),
+            _ => SortDirection::Ascending,
+        };
+        let sort = Some((column, direction));
+        self.sort = sort;
+        ctx.mutate_later(&mut self.header, move |mut header| {
+            TableHeader::set_sort(&mut header, sort);
+        });
+        ctx.submit_action::<<Self as Widget>::Action>(TableAction::Sort { column, direction });
+    }
+
+    fn on_header_down(&mut self, ctx: &mut EventCtx<'_>, pos: Point) {
+        if let Some(column) = self.resize_handle_at(pos.x) {
+            self.header_drag = Some(HeaderDrag::Resizing {
+                column,
+                start_x: pos.x,
+                start_width: self.columns[column].width,
+            });
+        } else if let Some(index) = self.display_index_at(pos.x) {
+            self.header_drag = Some(HeaderDrag::Pressed {
+                column: self.order[index],
+                start_x: pos.x,
+            });
+        } else {
+            return;
+        }
+        ctx.capture_pointer();
+        ctx.set_handled();
+    }
+
+    fn on_header_move(&mut self, ctx: &mut EventCtx<'_>, pos: Point) {
+        match self.header_drag {
+            Some(HeaderDrag::Resizing {
+                column,
+                start_x,
+                start_width,
+            }) => {
+                let min_width = self.columns[column].min_width;
+                self.columns[column].width = (start_width + pos.x - start_x).max(min_width);
+                ctx.request_layout();
+            }
+            Some(HeaderDrag::Pressed { column, start_x }) => {
+                if (pos.x - start_x).abs() > REORDER_THRESHOLD {
+                    let start_order = self.order.iter().position(|c| *c == column).unwrap();
+                    self.header_drag = Some(HeaderDrag::Reordering {
+                        column,
+                        start_order,
+                    });
+                    self.on_header_move(ctx, pos);
+                }
+            }
+            Some(HeaderDrag::Reordering { column, .. }) => {
+                let current = self.order.iter().position(|c| *c == column).unwrap();
+                let target = self
+                    .display_index_at(pos.x.max(0.))
+                    .unwrap_or(self.order.len() - 1);
+                if target != current {
+                    self.order.remove(current);
+                    self.order.insert(target, column);
+                    ctx.request_layout();
+                    ctx.request_accessibility_update();
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn on_header_up(&mut self, ctx: &mut EventCtx<'_>) {
+        match self.header_drag.take() {
+            Some(HeaderDrag::Resizing {
+                column,
+                start_width,
+                ..
+            }) => {
+                let width = self.columns[column].width;
+                if width != start_width {
+                    ctx.submit_action::<<Self as Widget>::Action>(TableAction::ColumnResized {
+                        column,
+                        width,
+                    });
+                }
+            }
+            Some(HeaderDrag::Pressed { column, .. }) => {
+                if self.columns[column].sortable {
+                    self.set_sort_from_header(ctx, column);
+                }
+            }
+            Some(HeaderDrag::Reordering {
+                column,
+                start_order,
+            }) => {
+                if self.order[start_order] != column {
+                    ctx.submit_action::<<Self as Widget>::Action>(TableAction::ColumnsReordered(
+                        self.order.clone(),
+                    ));
+                }
+            }
+            None => {}
+        }
+        ctx.request_cursor_icon_change();
+    }
+}
+
+fn action_mod(modifiers: Modifiers) -> bool {
+    if cfg!(target_os = "macos") {
+        modifiers.meta()
+    } else {
+        modifiers.ctrl()
+    }
+}
+
+// --- MARK: WIDGETMUT
+impl Table {
+    /// Indicates that `load` is about to be handled by the driver (which is calling this method).
+    ///
+    /// See [`VirtualScroll::will_handle_action`](crate::widgets::VirtualScroll::will_handle_action)
+    /// for why this is needed.
+    pub fn will_handle_load(this: &mut WidgetMut<'_, Self>, load: &TableLoadRows) {
+        if this.widget.active_range != load.old_active {
+            debug_panic!(
+                "Handling a TableLoadRows with the wrong range; got {:?}, expected {:?} for widget {}.",
+                load.old_active,
+                this.widget.active_range,
+                this.ctx.widget_id(),
+            );
+        }
+        this.widget.action_handled = true;
+        if load.target.is_empty() {
+            this.widget.needs_reload = false;
+        }
+        this.widget.active_range = load.target.clone();
+        this.ctx.request_layout();
+    }
+
+    /// Adds the row at index `idx`, with one cell per column.
+    ///
+    /// This should be done only in the handling of a [`TableAction::LoadRows`].
+    /// This must be called after [`Table::will_handle_load`].
+    #[track_caller]
+    pub fn add_row(
+        this: &mut WidgetMut<'_, Self>,
+        idx: usize,
+        cells: impl IntoIterator<Item = NewWidget<dyn Widget>>,
+    ) {
+        debug_assert!(
+            this.widget.action_handled,
+            "You must call `will_handle_load` before `add_row`."
+        );
+        debug_assert!(
+            this.widget.active_range.contains(&idx),
+            "`add_row` should only be called with an index requested by the table."
+        );
+        let cells: Vec<_> = cells.into_iter().collect();
+        if cells.len() != this.widget.columns.len() {
+            debug_panic!(
+                "Row {idx} has {} cells, but the table has {} columns.",
+                cells.len(),
+                this.widget.columns.len()
+            );
+        }
+        let selected = this.widget.selection.contains(&idx);
+        let row = TableRow::new(idx, cells, selected);
+        if let Some(old_row) = this.widget.rows.insert(idx, WidgetPod::new(row)) {
+            tracing::warn!("Tried to add row {idx} twice to Table");
+            this.ctx.remove_child(old_row);
+        }
+        this.ctx.children_changed();
+    }
+
+    /// Removes the row at index `idx`.
+    ///
+    /// This should be done only in the handling of a [`TableAction::LoadRows`].
+    /// This must be called after [`Table::will_handle_load`].
+    #[track_caller]
+    pub fn remove_row(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        debug_assert!(
+            this.widget.action_handled,
+            "You must call `will_handle_load` before `remove_row`."
+        );
+        if let Some(row) = this.widget.rows.remove(&idx) {
+            this.ctx.remove_child(row);
+        } else {
+            tracing::error!("Tried to remove row {idx} which was never added to Table.");
+        }
+    }
+
+    /// Returns a mutable reference to the row at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// If the row at `idx` is not loaded.
+    #[track_caller]
+    pub fn row_mut<'t>(this: &'t mut WidgetMut<'_, Self>, idx: usize) -> WidgetMut<'t, TableRow> {
+        let row = this.widget.rows.get_mut(&idx).unwrap_or_else(|| {
+            panic!(
+                "`Table::row_mut` called with non-loaded row {idx}.\n\
+                Active range is {:?}.",
+                &this.widget.active_range
+            )
+        });
+        this.ctx.get_mut(row)
+    }
+
+    /// Sets the number of rows in the table.
+    ///
+    /// Selected rows past the new end are deselected.
+    /// Loaded rows past the new end are removed by a later [`TableAction::LoadRows`].
+    pub fn set_row_count(this: &mut WidgetMut<'_, Self>, row_count: usize) {
+        this.widget.row_count = row_count;
+        this.widget.selection.retain(|row| *row < row_count);
+        this.widget.cursor = this.widget.cursor.filter(|row| *row < row_count);
+        this.widget.anchor = this.widget.anchor.filter(|row| *row < row_count);
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Replaces the columns of the table.
+    ///
+    /// This resets the column order.
+    /// If the number of columns changes, all loaded rows are removed and loaded again
+    /// through [`TableAction::LoadRows`].
+    pub fn set_columns(
+        this: &mut WidgetMut<'_, Self>,
+        columns: impl IntoIterator<Item = TableColumn>,
+    ) {
+        let columns: Vec<TableColumn> = columns.into_iter().collect();
+        if columns.len() != this.widget.columns.len() {
+            this.widget.needs_reload = true;
+        }
+        if this
+            .widget
+            .sort
+            .is_some_and(|(column, _)| column >= columns.len())
+        {
+            this.widget.sort = None;
+        }
+        this.widget.order = (0..columns.len()).collect();
+        let header = TableHeader::new(&columns, this.widget.sort);
+        let old_header = std::mem::replace(&mut this.widget.header, WidgetPod::new(header));
+        this.ctx.remove_child(old_header);
+        this.widget.columns = columns;
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the height of every row, in logical pixels.
+    pub fn set_row_height(this: &mut WidgetMut<'_, Self>, row_height: f64) {
+        this.widget.row_height = row_height;
+        this.ctx.request_layout();
+    }
+
+    /// Sets how many rows the user can select.
+    ///
+    /// This doesn't change the current selection.
+    pub fn set_selection_mode(this: &mut WidgetMut<'_, Self>, selection_mode: SelectionMode) {
+        this.widget.selection_mode = selection_mode;
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets the selected rows.
+    ///
+    /// This doesn't emit [`TableAction::SelectionChanged`].
+    pub fn set_selection(
+        this: &mut WidgetMut<'_, Self>,
+        selection: impl IntoIterator<Item = usize>,
+    ) {
+        let row_count = this.widget.row_count;
+        this.widget.selection = selection
+            .into_iter()
+            .filter(|row| *row < row_count)
+            .collect();
+        let focused = this.ctx.has_focus_target();
+        let widget = &mut *this.widget;
+        for (idx, row) in &mut widget.rows {
+            let (row, mut row_ctx) = this.ctx.get_raw_mut(row);
+            row.set_state(
+                &mut row_ctx,
+                widget.selection.contains(idx),
+                focused && widget.cursor == Some(*idx),
+                focused,
+            );
+        }
+    }
+
+    /// Sets the column whose header shows a sort indicator.
+    ///
+    /// This doesn't emit [`TableAction::Sort`].
+    pub fn set_sort(this: &mut WidgetMut<'_, Self>, sort: Option<(usize, SortDirection)>) {
+        this.widget.sort = sort;
+        let mut header = this.ctx.get_mut(&mut this.widget.header);
+        TableHeader::set_sort(&mut header, sort);
+    }
+
+    /// Scrolls the table so that the row at `idx` is fully visible.
+    pub fn scroll_to_row(this: &mut WidgetMut<'_, Self>, idx: usize) {
+        if this.widget.scroll_row_into_view(idx) {
+            this.ctx.request_layout();
+        }
+    }
+}
+
+impl HasProperty<SelectionColor> for TableRow {}
+impl HasProperty<UnfocusedSelectionColor> for TableRow {}
+impl HasProperty<ContentColor> for TableHeaderCell {}
+
+// --- MARK: IMPL WIDGET
+impl Widget for Table {
+    type Action = TableAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        match event {
+            PointerEvent::Down(PointerButtonEvent {
+                button: Some(PointerButton::Primary),
+                state,
+                ..
+            }) => {
+                let pos = ctx.local_position(state.position);
+                if pos.y < self.header_height {
+                    self.on_header_down(ctx, pos);
+                    return;
+                }
+                ctx.request_focus();
+                if let Some(row) = self.row_at(pos) {
+                    let modifiers = state.modifiers;
+                    if self.select(row, modifiers.shift(), action_mod(modifiers)) {
+                        self.selection_changed(ctx);
+                    } else {
+                        self.update_rows(ctx);
+                    }
+                }
+            }
+            PointerEvent::Move(PointerUpdate { current, .. }) => {
+                if ctx.is_active() && self.header_drag.is_some() {
+                    let pos = ctx.local_position(current.position);
+                    self.on_header_move(ctx, pos);
+                }
+            }
+            PointerEvent::Up(..) => {
+                if self.header_drag.is_some() {
+                    self.on_header_up(ctx);
+                }
+            }
+            PointerEvent::Cancel(..) => {
+                self.header_drag = None;
+            }
+            PointerEvent::Scroll(PointerScrollEvent { delta, .. }) => {
+                // TODO - Remove reference to scale factor.
+                // See https://github.com/linebender/xilem/issues/1264
+                let scale_factor = ctx.get_scale_factor();
+                let line_px = PhysicalPosition {
+                    x: self.row_height * 3. * scale_factor,
+                    y: self.row_height * 3. * scale_factor,
+                };
+                let page_px = PhysicalPosition {
+                    x: ctx.content_box_size().width * scale_factor,
+                    y: self.body_height * scale_factor,
+                };
+                let delta_px = delta.to_pixel_delta(line_px, page_px);
+                let delta = -delta_px.to_logical::<f64>(scale_factor).y;
+                if self.scroll_by(delta) {
+                    ctx.request_layout();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if ctx.is_disabled() || !ctx.is_focus_target() {
+            return;
+        }
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if !key_event.state.is_down() || self.row_count == 0 {
+            return;
+        }
+        let shift = key_event.modifiers.shift();
+        let action_mod = action_mod(key_event.modifiers);
+        let last = self.row_count - 1;
+        let page = self.visible_row_count();
+        let target = match &key_event.key {
+            Key::Named(NamedKey::ArrowDown) => self.cursor.map_or(0, |row| (row + 1).min(last)),
+            Key::Named(NamedKey::ArrowUp) => self.cursor.map_or(0, |row| row.saturating_sub(1)),
+            Key::Named(NamedKey::PageDown) => self.cursor.map_or(0, |row| (row + page).min(last)),
+            Key::Named(NamedKey::PageUp) => self.cursor.map_or(0, |row| row.saturating_sub(page)),
+            Key::Named(NamedKey::Home) => 0,
+            Key::Named(NamedKey::End) => last,
+            Key::Character(c) if c == " " => {
+                let row = self.cursor.unwrap_or(0);
+                if self.select(row, shift, action_mod) {
+                    self.selection_changed(ctx);
+                }
+                ctx.set_handled();
+                return;
+            }
+            Key::Character(c)
+                if action_mod
+                    && c.eq_ignore_ascii_case("a")
+                    && self.selection_mode == SelectionMode::Multiple =>
+            {
+                if self.selection.len() != self.row_count {
+                    self.selection = (0..self.row_count).collect();
+                    self.selection_changed(ctx);
+                }
+                ctx.set_handled();
+                return;
+            }
+            _ => return,
+        };
+
+        if action_mod && !shift {
+            // Move the cursor without changing the selection.
+            self.cursor = Some(target);
+            self.update_rows(ctx);
+        } else if self.select(target, shift, false) {
+            self.selection_changed(ctx);
+        } else {
+            self.update_rows(ctx);
+        }
+        if self.scroll_row_into_view(target) {
+            ctx.request_layout();
+        }
+        ctx.set_handled();
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        if matches!(
+            event.action,
+            accesskit::Action::ScrollUp | accesskit::Action::ScrollDown
+        ) {
+            let unit = if let Some(accesskit::ActionData::ScrollUnit(unit)) = &event.data {
+                *unit
+            } else {
+                accesskit::ScrollUnit::Item
+            };
+            let amount = match unit {
+                accesskit::ScrollUnit::Item => self.row_height,
+                accesskit::ScrollUnit::Page => self.body_height,
+            };
+            let delta = if event.action == accesskit::Action::ScrollUp {
+                -amount
+            } else {
+                amount
+            };
+            if self.scroll_by(delta) {
+                ctx.request_layout();
+            }
+            ctx.set_handled();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        match event {
+            Update::FocusChanged(_) | Update::ChildFocusChanged(_) => {
+                let focused = ctx.has_focus_target();
+                for (idx, row) in &mut self.rows {
+                    let (row, mut row_ctx) = ctx.get_raw_mut(row);
+                    row.set_state(
+                        &mut row_ctx,
+                        self.selection.contains(idx),
+                        focused && self.cursor == Some(*idx),
+                        focused,
+                    );
+                }
+            }
+            Update::RequestPanToChild(target) => {
+                let body = self.header_height..self.header_height + self.body_height;
+                let new_pos_y =
+                    super::portal::compute_pan_range(body, target.min_y()..target.max_y()).start;
+                if self.scroll_by(new_pos_y - self.header_height) {
+                    ctx.request_layout();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for row in self.rows.values_mut() {
+            ctx.register_child(row);
+        }
+        ctx.register_child(&mut self.header);
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        // As with `VirtualScroll`, we don't base our preferred height on the rows,
+        // which might not be loaded yet.
+        const DEFAULT_HEIGHT: f64 = 200.;
+
+        // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
+        //       https://github.com/linebender/xilem/issues/1264
+        let scale = 1.0;
+
+        match (axis, len_req) {
+            (_, LenReq::FitContent(space)) => space,
+            (Axis::Horizontal, _) => self.total_width() * scale,
+            (Axis::Vertical, _) => DEFAULT_HEIGHT * scale,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.set_clip_path(size.to_rect());
+
+        let slots = self.cell_slots();
+        let total_width = self.total_width();
+
+        let (header, mut header_ctx) = ctx.get_raw_mut(&mut self.header);
+        if header.slots != slots {
+            header.slots.clone_from(&slots);
+            header_ctx.request_layout();
+        }
+        drop(header_ctx);
+        let header_height = ctx.compute_length(
+            &mut self.header,
+            LenDef::MaxContent,
+            size.into(),
+            Axis::Vertical,
+            Some(total_width),
+        );
+        ctx.run_layout(&mut self.header, Size::new(total_width, header_height));
+        ctx.place_child(&mut self.header, Point::ORIGIN);
+
+        self.header_height = header_height;
+        self.body_height = (size.height - header_height).max(0.);
+        self.scroll_offset = self.scroll_offset.clamp(0., self.max_scroll());
+
+        for (&idx, row) in &mut self.rows {
+            let y = header_height + idx as f64 * self.row_height - self.scroll_offset;
+            // Rows scrolled under the header are clipped, so that they can't be
+            // seen or clicked through it.
+            let clip_top = (header_height - y).clamp(0., self.row_height);
+            let (row_widget, mut row_ctx) = ctx.get_raw_mut(row);
+            if row_widget.slots != slots || row_widget.clip_top != clip_top {
+                row_widget.slots.clone_from(&slots);
+                row_widget.clip_top = clip_top;
+                row_ctx.request_layout();
+            }
+            drop(row_ctx);
+            ctx.run_layout(row, Size::new(total_width, self.row_height));
+            ctx.place_child(row, Point::new(0., y));
+        }
+
+        // We only send an updated request if the driver has actioned the previous request.
+        if self.action_handled {
+            let target = self.target_range();
+            if target != self.active_range {
+                ctx.submit_action::<Self::Action>(TableAction::LoadRows(TableLoadRows {
+                    old_active: self.active_range.clone(),
+                    target,
+                }));
+                self.action_handled = false;
+            }
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn get_cursor(&self, ctx: &QueryCtx<'_>, pos: Point) -> CursorIcon {
+        let local_pos = ctx.to_local(pos);
+        let resizing = matches!(self.header_drag, Some(HeaderDrag::Resizing { .. }));
+        if resizing
+            || (local_pos.y < self.header_height && self.resize_handle_at(local_pos.x).is_some())
+        {
+            CursorIcon::EwResize
+        } else {
+            CursorIcon::Default
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Table
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_clips_children();
+        // The header row is the first row.
+        node.set_row_count(self.row_count + 1);
+        node.set_column_count(self.columns.len());
+        if self.selection_mode == SelectionMode::Multiple {
+            node.set_multiselectable();
+        }
+        node.set_scroll_y_min(0.);
+        node.set_scroll_y_max(self.max_scroll());
+        node.set_scroll_y(self.scroll_offset);
+        node.add_action(accesskit::Action::ScrollUp);
+        node.add_action(accesskit::Action::ScrollDown);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        let mut rows: Vec<_> = self.rows.iter().collect();
+        rows.sort_unstable_by_key(|(idx, _)| **idx);
+        // The header is last so that it's painted on top of the rows.
+        rows.into_iter()
+            .map(|(_, row)| row.id())
+            .chain([self.header.id()])
+            .collect()
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("Table", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(format!("{self:#?}"))
+    }
+}
+
+// --- MARK: TABLE HEADER
+/// The row of column headers of a [`Table`].
+pub(crate) struct TableHeader {
+    cells: Vec<WidgetPod<TableHeaderCell>>,
+    slots: Vec<CellSlot>,
+}
+
+impl TableHeader {
+    fn new(columns: &[TableColumn], sort: Option<(usize, SortDirection)>) -> Self {
+        let cells = columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| {
+                let sort = sort.filter(|(sorted, _)| *sorted == idx).map(|(_, d)| d);
+                WidgetPod::new(TableHeaderCell::new(column.title.clone(), sort))
+            })
+            .collect();
+        Self {
+            cells,
+            slots: Vec::new(),
+        }
+    }
+
+    fn set_sort(this: &mut WidgetMut<'_, Self>, sort: Option<(usize, SortDirection)>) {
+        for (idx, cell) in this.widget.cells.iter_mut().enumerate() {
+            let direction = sort.filter(|(sorted, _)| *sorted == idx).map(|(_, d)| d);
+            let mut cell = this.ctx.get_mut(cell);
+            if cell.widget.sort != direction {
+                cell.widget.sort = direction;
+                cell.ctx.request_render();
+            }
+        }
+    }
+}
+
+impl AllowRawMut for TableHeader {}
+
+impl Widget for TableHeader {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for cell in &mut self.cells {
+            ctx.register_child(cell);
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        match axis {
+            Axis::Horizontal => self.slots.iter().map(|slot| slot.width).sum(),
+            Axis::Vertical => {
+                let mut height: f64 = 0.;
+                for (cell, slot) in self.cells.iter_mut().zip(&self.slots) {
+                    let cell_height = ctx.compute_length(
+                        cell,
+                        len_req.into(),
+                        LayoutSize::maybe(Axis::Horizontal, Some(slot.width)),
+                        Axis::Vertical,
+                        Some(slot.width),
+                    );
+                    height = height.max(cell_height);
+                }
+                height
+            }
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        for (cell, slot) in self.cells.iter_mut().zip(&self.slots) {
+            let (cell_widget, mut cell_ctx) = ctx.get_raw_mut(cell);
+            if cell_widget.column_index != slot.index {
+                cell_widget.column_index = slot.index;
+                cell_ctx.request_accessibility_update();
+            }
+            drop(cell_ctx);
+            ctx.run_layout(cell, Size::new(slot.width, size.height));
+            ctx.place_child(cell, Point::new(slot.x, 0.));
+        }
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::Row
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_row_index(0);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.cells.iter().map(|cell| cell.id()).collect()
+    }
+
+    fn find_widget_under_pointer<'c>(
+        &'c self,
+        _ctx: QueryCtx<'c>,
+        _pos: Point,
+    ) -> Option<WidgetRef<'c, dyn Widget>> {
+        // Pointer interactions with the headers are handled by the table.
+        None
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TableHeader", id = id.trace())
+    }
+}
+
+/// The header of one column of a [`Table`].
+pub(crate) struct TableHeaderCell {
+    label: WidgetPod<Label>,
+    sort: Option<SortDirection>,
+    column_index: usize,
+}
+
+impl TableHeaderCell {
+    fn new(title: ArcStr, sort: Option<SortDirection>) -> Self {
+        Self {
+            label: WidgetPod::new(Label::new(title)),
+            sort,
+            column_index: 0,
+        }
+    }
+}
+
+impl AllowRawMut for TableHeaderCell {}
+
+impl Widget for TableHeaderCell {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.label);
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        ContentColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let indicator_length = match axis {
+            Axis::Horizontal => SORT_INDICATOR_WIDTH,
+            Axis::Vertical => 0.,
+        };
+        let cross_space = cross_length.map(|cross_length| match axis {
+            Axis::Horizontal => cross_length,
+            Axis::Vertical => (cross_length - SORT_INDICATOR_WIDTH).max(0.),
+        });
+        let label_length = ctx.compute_length(
+            &mut self.label,
+            len_req.reduce(indicator_length).into(),
+            LayoutSize::maybe(axis.cross(), cross_space),
+            axis,
+            cross_space,
+        );
+        label_length + indicator_length
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.set_clip_path(size.to_rect());
+        let space = Size::new((size.width - SORT_INDICATOR_WIDTH).max(0.), size.height);
+        let label_size = ctx.compute_size(&mut self.label, SizeDef::fit(space), space.into());
+        ctx.run_layout(&mut self.label, label_size);
+        let label_origin = Point::new(0., (size.height - label_size.height) * 0.5);
+        ctx.place_child(&mut self.label, label_origin);
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let Some(sort) = self.sort else {
+            return;
+        };
+        let color = props.get::<ContentColor>().color;
+        let size = ctx.content_box_size();
+        let center = Point::new(size.width - SORT_INDICATOR_WIDTH / 2., size.height / 2.);
+        // Ascending is shown as an arrow pointing up, and descending as one pointing down.
+        let dy = match sort {
+            SortDirection::Ascending => -2.,
+            SortDirection::Descending => 2.,
+        };
+        let mut arrow = BezPath::new();
+        arrow.move_to((center.x - 4., center.y - dy));
+        arrow.line_to((center.x, center.y + dy));
+        arrow.line_to((center.x + 4., center.y - dy));
+        stroke(scene, &arrow, color, 1.5);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::ColumnHeader
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_column_index(self.column_index);
+        match self.sort {
+            Some(SortDirection::Ascending) => {
+                node.set_sort_direction(accesskit::SortDirection::Ascending);
+            }
+            Some(SortDirection::Descending) => {
+                node.set_sort_direction(accesskit::SortDirection::Descending);
+            }
+            None => {}
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.label.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TableHeaderCell", id = id.trace())
+    }
+}
+
+// --- MARK: TABLE ROW
+/// A loaded row of a [`Table`].
+///
+/// Each cell of the row is wrapped in a [`TableCell`].
+pub struct TableRow {
+    index: usize,
+    cells: Vec<WidgetPod<TableCell>>,
+    slots: Vec<CellSlot>,
+    /// How much of the top of the row is hidden under the table's header.
+    clip_top: f64,
+    selected: bool,
+    /// Whether this is the row moved by the keyboard, and the table is focused.
+    current: bool,
+    table_focused: bool,
+}
+
+impl TableRow {
+    fn new(index: usize, cells: Vec<NewWidget<dyn Widget>>, selected: bool) -> Self {
+        Self {
+            index,
+            cells: cells
+                .into_iter()
+                .map(|cell| WidgetPod::new(TableCell::new(cell)))
+                .collect(),
+            slots: Vec::new(),
+            clip_top: 0.,
+            selected,
+            current: false,
+            table_focused: false,
+        }
+    }
+
+    /// Returns the index of the row.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    fn set_state(
+        &mut self,
+        ctx: &mut RawCtx<'_>,
+        selected: bool,
+        current: bool,
+        table_focused: bool,
+    ) {
+        if (self.selected, self.current, self.table_focused) != (selected, current, table_focused) {
+            if self.selected != selected {
+                ctx.request_accessibility_update();
+            }
+            self.selected = selected;
+            self.current = current;
+            self.table_focused = table_focused;
+            ctx.request_paint_only();
+        }
+    }
+}
+
+impl TableRow {
+    /// Returns a mutable reference to the cell of the given column.
+    ///
+    /// `column` is the index of the column in the order the columns were given,
+    /// regardless of the order they are displayed in.
+    ///
+    /// # Panics
+    ///
+    /// If `column` is out of bounds.
+    pub fn cell_mut<'t>(
+        this: &'t mut WidgetMut<'_, Self>,
+        column: usize,
+    ) -> WidgetMut<'t, TableCell> {
+        this.ctx.get_mut(&mut this.widget.cells[column])
+    }
+}
+
+impl AllowRawMut for TableRow {}
+
+impl Widget for TableRow {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        for cell in &mut self.cells {
+            ctx.register_child(cell);
+        }
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        SelectionColor::prop_changed(ctx, property_type);
+        UnfocusedSelectionColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        _ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        _cross_length: Option<f64>,
+    ) -> f64 {
+        // The table always gives rows an explicit size.
+        match (axis, len_req) {
+            (_, LenReq::FitContent(space)) => space,
+            (Axis::Horizontal, _) => self.slots.iter().map(|slot| slot.width).sum(),
+            (Axis::Vertical, _) => DEFAULT_ROW_HEIGHT,
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.set_clip_path(Rect::new(0., self.clip_top, size.width, size.height));
+        for (column, cell) in self.cells.iter_mut().enumerate() {
+            // A row can briefly have the wrong number of cells after the columns change.
+            let slot = self.slots.get(column).copied().unwrap_or_default();
+            let (cell_widget, mut cell_ctx) = ctx.get_raw_mut(cell);
+            if cell_widget.column_index != slot.index {
+                cell_widget.column_index = slot.index;
+                cell_ctx.request_accessibility_update();
+            }
+            drop(cell_ctx);
+            ctx.run_layout(cell, Size::new(slot.width, size.height));
+            ctx.place_child(cell, Point::new(slot.x, 0.));
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let rect = ctx.content_box_size().to_rect();
+        if self.selected {
+            let color = if self.table_focused {
+                props.get::<SelectionColor>().color
+            } else {
+                props.get::<UnfocusedSelectionColor>().0.color
+            };
+            fill_color(scene, &rect, color);
+        }
+        if self.current {
+            let color = props.get::<SelectionColor>().color;
+            stroke(scene, &rect.inset(-0.5), color.with_alpha(0.8), 1.);
+        }
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::Row
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        // The header row is the first row.
+        node.set_row_index(self.index + 1);
+        node.set_selected(self.selected);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        self.cells.iter().map(|cell| cell.id()).collect()
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TableRow", id = id.trace(), index = self.index)
+    }
+}
+
+// --- MARK: TABLE CELL
+/// A cell of a [`TableRow`], containing a widget provided by the driver.
+pub struct TableCell {
+    child: WidgetPod<dyn Widget>,
+    column_index: usize,
+}
+
+impl TableCell {
+    fn new(child: NewWidget<dyn Widget>) -> Self {
+        Self {
+            child: child.to_pod(),
+            column_index: 0,
+        }
+    }
+
+    /// Returns a mutable reference to the widget in the cell.
+    pub fn child_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.child)
+    }
+
+    /// Replaces the widget in the cell.
+    pub fn set_child(this: &mut WidgetMut<'_, Self>, child: NewWidget<dyn Widget>) {
+        let old_child = std::mem::replace(&mut this.widget.child, child.to_pod());
+        this.ctx.remove_child(old_child);
+    }
+}
+
+impl AllowRawMut for TableCell {}
+
+impl Widget for TableCell {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.set_clip_path(size.to_rect());
+        let child_size = ctx.compute_size(&mut self.child, SizeDef::fit(size), size.into());
+        ctx.run_layout(&mut self.child, child_size);
+        let child_origin = Point::new(0., (size.height - child_size.height) * 0.5);
+        ctx.place_child(&mut self.child, child_origin);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::Cell
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_column_index(self.column_index);
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TableCell", id = id.trace())
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kurbo::Vec2;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+
+    const ROW_COUNT: usize = 100;
+
+    fn harness() -> TestHarness<Table> {
+        let columns = [
+            TableColumn::new("Name", 100.),
+            TableColumn::new("Size", 80.),
+            TableColumn::new("Kind", 80.).with_sortable(false),
+        ];
+        let table = Table::new(columns, ROW_COUNT).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), table, Size::new(300., 200.));
+        drive(&mut harness);
+        harness
+    }
+
+    /// Handles row loading, and returns the other actions emitted by the table.
+    fn drive(harness: &mut TestHarness<Table>) -> Vec<TableAction> {
+        let mut actions = Vec::new();
+        while let Some((action, _)) = harness.pop_action::<TableAction>() {
+            let TableAction::LoadRows(load) = action else {
+                actions.push(action);
+                continue;
+            };
+            harness.edit_root_widget(|mut table| {
+                Table::will_handle_load(&mut table, &load);
+                for idx in load.old_active.clone() {
+                    if !load.target.contains(&idx) {
+                        Table::remove_row(&mut table, idx);
+                    }
+                }
+                for idx in load.target.clone() {
+                    if !load.old_active.contains(&idx) {
+                        let cells = (0..3).map(|column| {
+                            NewWidget::new(Label::new(format!("{idx}:{column}"))).erased()
+                        });
+                        Table::add_row(&mut table, idx, cells);
+                    }
+                }
+            });
+        }
+        actions
+    }
+
+    /// Returns a point in the middle of the given column, at the given height
+    /// in the table's content-box.
+    fn table_point(harness: &TestHarness<Table>, display_index: usize, y: f64) -> Point {
+        let table = harness.root_widget();
+        let x = table.inner().order[..display_index]
+            .iter()
+            .map(|column| table.inner().columns[*column].width)
+            .sum::<f64>()
+            + 10.;
+        table.ctx().window_transform() * Point::new(x, y)
+    }
+
+    fn header_point(harness: &TestHarness<Table>, display_index: usize) -> Point {
+        let header_height = harness.root_widget().inner().header_height;
+        table_point(harness, display_index, header_height / 2.)
+    }
+
+    fn row_point(harness: &TestHarness<Table>, row: usize) -> Point {
+        let table = harness.root_widget();
+        let table = table.inner();
+        let y = table.header_height + (row as f64 + 0.5) * table.row_height - table.scroll_offset;
+        table_point(harness, 0, y)
+    }
+
+    fn click(harness: &mut TestHarness<Table>, pos: Point, modifiers: Modifiers) {
+        harness.set_mouse_modifiers(modifiers);
+        harness.mouse_move(pos);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        harness.set_mouse_modifiers(Modifiers::empty());
+    }
+
+    fn key(harness: &mut TestHarness<Table>, key: Key, modifiers: Modifiers) {
+        let mut event = TextEvent::key_down(key);
+        if let TextEvent::Keyboard(key_event) = &mut event {
+            key_event.modifiers = modifiers;
+        }
+        harness.process_text_event(event);
+    }
+
+    fn selection(harness: &TestHarness<Table>) -> Vec<usize> {
+        harness
+            .root_widget()
+            .inner()
+            .selection()
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn simple_table() {
+        let mut harness = harness();
+
+        let table = harness.root_widget();
+        let table = table.inner();
+        // The visible rows, and a few more below.
+        assert_eq!(table.active_range, 0..12);
+        assert_eq!(table.len(), 12);
+
+        assert_render_snapshot!(harness, "table_simple");
+    }
+
+    #[test]
+    fn scrolling_loads_rows() {
+        let mut harness = harness();
+
+        harness.edit_root_widget(|mut table| Table::scroll_to_row(&mut table, 50));
+        drive(&mut harness);
+        let table = harness.root_widget();
+        assert!(table.inner().active_range.contains(&50));
+        assert!(!table.inner().active_range.contains(&0));
+        assert_eq!(table.inner().len(), table.inner().active_range.len());
+
+        // Scrolling never goes past the last row.
+        let pos = row_point(&harness, 50);
+        harness.mouse_move(pos);
+        harness.mouse_wheel(Vec2::new(0., -100_000.));
+        drive(&mut harness);
+        let table = harness.root_widget();
+        assert_eq!(table.inner().active_range.end, ROW_COUNT);
+        assert_eq!(table.inner().scroll_offset, table.inner().max_scroll());
+    }
+
+    #[test]
+    fn row_selection() {
+        let mut harness = harness();
+
+        let pos = row_point(&harness, 2);
+        click(&mut harness, pos, Modifiers::empty());
+        assert_eq!(
+            drive(&mut harness),
+            [TableAction::SelectionChanged(vec![2])]
+        );
+
+        let pos = row_point(&harness, 4);
+        click(&mut harness, pos, Modifiers::CONTROL);
+        assert_eq!(
+            drive(&mut harness),
+            [TableAction::SelectionChanged(vec![2, 4])]
+        );
+
+        // Shift-click replaces the selection with the range from the last clicked row.
+        let pos = row_point(&harness, 6);
+        click(&mut harness, pos, Modifiers::SHIFT);
+        assert_eq!(selection(&harness), [4, 5, 6]);
+        drive(&mut harness);
+
+        // The keyboard extends the selection from the same anchor.
+        key(
+            &mut harness,
+            Key::Named(NamedKey::ArrowDown),
+            Modifiers::SHIFT,
+        );
+        assert_eq!(selection(&harness), [4, 5, 6, 7]);
+        key(
+            &mut harness,
+            Key::Named(NamedKey::ArrowUp),
+            Modifiers::empty(),
+        );
+        assert_eq!(
+            drive(&mut harness),
+            [
+                TableAction::SelectionChanged(vec![4, 5, 6, 7]),
+                TableAction::SelectionChanged(vec![6]),
+            ]
+        );
+
+        // Moving past the visible rows scrolls the table.
+        key(&mut harness, Key::Named(NamedKey::End), Modifiers::empty());
+        drive(&mut harness);
+        assert_eq!(selection(&harness), [ROW_COUNT - 1]);
+        assert_eq!(harness.root_widget().inner().active_range.end, ROW_COUNT);
+    }
+
+    #[test]
+    fn single_selection_mode() {
+        let mut harness = harness();
+        harness.edit_root_widget(|mut table| {
+            Table::set_selection_mode(&mut table, SelectionMode::Single);
+        });
+
+        let pos = row_point(&harness, 1);
+        click(&mut harness, pos, Modifiers::empty());
+        let pos = row_point(&harness, 3);
+        click(&mut harness, pos, Modifiers::SHIFT);
+        assert_eq!(selection(&harness), [3]);
+    }
+
+    #[test]
+    fn sort_by_header() {
+        let mut harness = harness();
+
+        let pos = header_point(&harness, 1);
+        click(&mut harness, pos, Modifiers::empty());
+        click(&mut harness, pos, Modifiers::empty());
+        assert_eq!(
+            drive(&mut harness),
+            [
+                TableAction::Sort {
+                    column: 1,
+                    direction: SortDirection::Ascending
+                },
+                TableAction::Sort {
+                    column: 1,
+                    direction: SortDirection::Descending
+                },
+            ]
+        );
+        assert_eq!(
+            harness.root_widget().inner().sort(),
+            Some((1, SortDirection::Descending))
+        );
+        assert_render_snapshot!(harness, "table_sorted");
+
+        // The third column isn't sortable.
+        let pos = header_point(&harness, 2);
+        click(&mut harness, pos, Modifiers::empty());
+        assert_eq!(drive(&mut harness), []);
+    }
+
+    #[test]
+    fn resize_and_reorder_columns() {
+        let mut harness = harness();
+        let header_height = harness.root_widget().inner().header_height;
+
+        // Drag the right edge of the first column.
+        let edge = table_point(&harness, 1, header_height / 2.) - Vec2::new(10., 0.);
+        harness.mouse_drag(edge, edge + Vec2::new(50., 0.));
+        assert_eq!(
+            drive(&mut harness),
+            [TableAction::ColumnResized {
+                column: 0,
+                width: 150.
+            }]
+        );
+        assert_eq!(harness.root_widget().inner().columns()[0].width, 150.);
+
+        // Drag the first column to the end.
+        let start = header_point(&harness, 0);
+        let end = header_point(&harness, 2);
+        harness.mouse_drag(start, end);
+        assert_eq!(
+            drive(&mut harness),
+            [TableAction::ColumnsReordered(vec![1, 2, 0])]
+        );
+        // Cells follow their column.
+        let row = harness.root_widget().inner().rows[&0].id();
+        let row = harness.get_widget_with_id(row);
+        let cell = row.downcast::<TableRow>().unwrap().inner().cells[0].id();
+        let cell = harness.get_widget_with_id(cell);
+        assert_eq!(cell.ctx().window_origin().x, 80. + 80. + 1.);
+    }
+
+    #[test]
+    fn accessibility_roles() {
+        let mut harness = harness();
+        // The accessibility tree is only updated when rendering.
+        let _ = harness.render();
+        let table = harness.root_widget();
+        let header_id = table.inner().header.id();
+        let row_id = table.inner().rows[&3].id();
+        let table_id = table.id();
+
+        let node = harness.access_node(table_id).unwrap();
+        assert_eq!(node.role(), Role::Table);
+        assert_eq!(node.data().row_count(), Some(ROW_COUNT + 1));
+        assert_eq!(node.data().column_count(), Some(3));
+
+        let header = harness.access_node(header_id).unwrap();
+        assert_eq!(header.role(), Role::Row);
+        let column_header = header.children().next().unwrap();
+        assert_eq!(column_header.role(), Role::ColumnHeader);
+
+        let row = harness.access_node(row_id).unwrap();
+        assert_eq!(row.role(), Role::Row);
+        assert_eq!(row.data().row_index(), Some(4));
+        let cell = row.children().nth(1).unwrap();
+        assert_eq!(cell.role(), Role::Cell);
+        assert_eq!(cell.data().column_index(), Some(1));
+    }
+}
@@ -2,26 +2,30 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::any::TypeId;
+use std::collections::VecDeque;
 use std::mem::Discriminant;
+use std::ops::Range;
 
 use accesskit::{Node, Role};
-use parley::PlainEditor;
-use parley::editing::{Generation, SplitString};
+use parley::editing::{Cursor, Generation, Selection, SplitString};
+use parley::{Affinity, Cluster, FontContext, Layout, LayoutContext, PlainEditor};
 use tracing::{Span, trace_span};
+use unicode_segmentation::UnicodeSegmentation;
 use vello::Scene;
 
 use crate::core::keyboard::{Key, KeyState, NamedKey};
 use crate::core::{
-    AccessCtx, AccessEvent, BrushIndex, ChildrenIds, CursorIcon, EventCtx, Ime, LayoutCtx,
+    AccessCtx, AccessEvent, ArcStr, BrushIndex, ChildrenIds, CursorIcon, EventCtx, Ime, LayoutCtx,
     MeasureCtx, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PointerUpdate,
-    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, StyleProperty, TextEvent, Update,
-    UpdateCtx, Widget, WidgetId, WidgetMut, render_text,
+    PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, RichText, StyleProperty, TextEvent,
+    Update, UpdateCtx, Widget, WidgetId, WidgetMut, render_text, render_text_with_paint_spans,
 };
-use crate::kurbo::{Affine, Axis, Point, Rect, Size};
+use crate::kurbo::{Affine, Axis, BezPath, Line, Point, Rect, Size, Stroke};
 use crate::layout::LenReq;
-use crate::peniko::Fill;
+use crate::peniko::{Color, Fill};
 use crate::properties::{
-    CaretColor, ContentColor, DisabledContentColor, SelectionColor, UnfocusedSelectionColor,
+    ActiveHighlightColor, CaretColor, ContentColor, DisabledContentColor, HighlightColor,
+    LinkColor, SelectionColor, UnfocusedSelectionColor,
 };
 use crate::theme::default_text_styles;
 use crate::util::bounding_box_to_rect;
@@ -41,12 +45,27 @@ use crate::{TextAlign, theme};
 /// edited by the user of the app.
 /// This is true for `TextInput` and false for `Prose`.
 ///
-/// This widget emits [`TextAction`] only when `USER_EDITABLE` is true.
+/// This widget emits [`TextAction`] only when `USER_EDITABLE` is true, except for
+/// [`TextAction::LinkClicked`], which is only emitted when it is false,
+/// and [`TextAction::DecorationHovered`], which is emitted in both cases.
+///
+/// The text can be given [rich text](RichText) spans with [`with_rich_text`](Self::with_rich_text).
+/// As the layout is shared with the editor, only spans which don't change the layout are shown:
+/// colors, links, underlines and strikethroughs.
+/// The spans are dropped as soon as the text changes.
+///
+/// Ranges of the text can also be highlighted, for example to show search matches,
+/// using [`with_highlights`](Self::with_highlights).
+/// These are painted with the [`HighlightColor`], and the [active](Self::set_active_highlight)
+/// one with the [`ActiveHighlightColor`].
+///
+/// [Decorations](TextDecoration), such as squiggly underlines for spelling mistakes, can be
+/// drawn under ranges of the text using [`set_decorations`](Self::set_decorations).
+/// Unlike the other styles, decorations follow the text they decorate as it is edited.
 ///
 /// The exact semantics of how much horizontal space this widget takes up has not been determined.
 /// In particular, this has consequences when the text alignment is set.
 // TODO: RichTextInput 👀
-// TODO: Support for links - https://github.com/linebender/xilem/issues/360
 pub struct TextArea<const USER_EDITABLE: bool> {
     // TODO: Placeholder text?
     /// The underlying `PlainEditor`, which provides a high-level interface for us to dispatch into.
@@ -61,6 +80,8 @@ pub struct TextArea<const USER_EDITABLE: bool> {
     /// Note that if clipping is desired, that should be added by the parent widget.
     /// Can be set using [`set_word_wrap`](Self::set_word_wrap).
     word_wrap: bool,
+    /// The font size set in the editor's styles.
+    font_size: f32,
     /// The value of `max_advance` when this layout was last calculated.
     ///
     /// If it has changed, we need to re-perform line-breaking.
@@ -76,6 +97,11 @@ pub struct TextArea<const USER_EDITABLE: bool> {
     /// What key combination should trigger a newline insertion.
     /// If this is set to `InsertNewline::OnEnter` then `Enter` will insert a newline and _not_ trigger a [`TextAction::Entered`] event.
     insert_newline: InsertNewline,
+    /// Whether this is the query field of a search, such as the find bar of [`Prose`](super::Prose).
+    ///
+    /// Search fields take focus when they are shown, and leave <kbd>Enter</kbd> to their
+    /// ancestors, which use it to go to the next match.
+    search_field: bool,
 
     /// Whether to show the cursor, used for the blink animation.
     anim_cursor_visible: bool,
@@ -85,8 +111,70 @@ pub struct TextArea<const USER_EDITABLE: bool> {
 
     /// Time elapsed (ms) to calculate the timeout of the cursor's blink animation.
     anim_elapsed: u64,
+
+    /// The undo and redo history of user edits.
+    ///
+    /// This is only recorded when the text area is editable and not secure.
+    history: EditHistory,
+
+    /// Whether the text is a secret, such as a password.
+    ///
+    /// Can be set using [`set_secure`](Self::set_secure).
+    secure: bool,
+    /// Whether the text of a secure text area is shown instead of being masked.
+    ///
+    /// Can be set using [`set_revealed`](Self::set_revealed).
+    revealed: bool,
+    /// The actual text of a secure text area whilst it is masked.
+    ///
+    /// The editor then holds one [`MASK_CHAR`] per grapheme of the text instead.
+    /// This is a `PlainEditor` rather than a `String` so that [`text`](Self::text) can return it.
+    secret: Option<PlainEditor<BrushIndex>>,
+
+    /// A check of the text the user is about to enter, which rejects the edit if it fails.
+    ///
+    /// Can be set using [`set_filter`](Self::set_filter).
+    filter: Option<Box<dyn Fn(&str) -> bool>>,
+    /// The maximum number of graphemes the user can enter.
+    ///
+    /// Can be set using [`set_max_length`](Self::set_max_length).
+    max_length: Option<usize>,
+    /// Whether the text has been marked as invalid.
+    ///
+    /// Can be set using [`set_invalid`](Self::set_invalid).
+    invalid: bool,
+
+    /// Paint-only styles for ranges of the text.
+    ///
+    /// Can be set using [`set_rich_text`](Self::set_rich_text).
+    rich_text: Option<RichText>,
+    /// The target of the link the pointer was pressed on, if any.
+    pressed_link: Option<ArcStr>,
+    /// The part of the text selected by the enclosing selection scope, if any.
+    ///
+    /// This is only used when the text area isn't editable.
+    scoped_selection: Option<Range<usize>>,
+
+    /// Byte ranges of the text to highlight, such as search matches.
+    ///
+    /// Can be set using [`set_highlights`](Self::set_highlights).
+    highlights: Vec<Range<usize>>,
+    /// The index of the highlight to paint as active.
+    ///
+    /// Can be set using [`set_active_highlight`](Self::set_active_highlight).
+    active_highlight: Option<usize>,
+
+    /// Decorations drawn under ranges of the text, such as diagnostics.
+    ///
+    /// Can be set using [`set_decorations`](Self::set_decorations).
+    decorations: Vec<TextDecoration>,
+    /// The index of the decoration under the pointer, if any.
+    hovered_decoration: Option<usize>,
 }
 
+/// The character shown in place of each grapheme of a masked text area.
+const MASK_CHAR: char = '•';
+
 // --- MARK: BUILDERS
 impl TextArea<true> {
     /// Creates a new `TextArea` which can be edited.
@@ -123,15 +211,43 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
             editor,
             rendered_generation: Generation::default(),
             word_wrap: true,
+            font_size: theme::TEXT_SIZE_NORMAL,
             last_max_advance: None,
             hint: true,
             insert_newline: InsertNewline::default(),
+            search_field: false,
             anim_cursor_visible: true,
             anim_prev_interval: 0,
             anim_elapsed: 0,
+            history: EditHistory::default(),
+            secure: false,
+            revealed: false,
+            secret: None,
+            filter: None,
+            max_length: None,
+            invalid: false,
+            rich_text: None,
+            pressed_link: None,
+            highlights: Vec::new(),
+            active_highlight: None,
+            scoped_selection: None,
+            decorations: Vec::new(),
+            hovered_decoration: None,
         }
     }
 
+    /// Replaces the text with rich text.
+    ///
+    /// Only the spans which don't change the layout of the text are shown.
+    /// See the [type docs](Self) for details.
+    ///
+    /// To set the rich text of an active text area, use [`set_rich_text`](Self::set_rich_text).
+    pub fn with_rich_text(mut self, text: RichText) -> Self {
+        self.set_text(text.text());
+        self.rich_text = (!text.is_plain()).then_some(text);
+        self
+    }
+
     /// Sets a style property for the new text area.
     ///
     /// Style properties set by this method include [text size](parley::StyleProperty::FontSize),
@@ -212,6 +328,111 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         self
     }
 
+    /// Makes this text area the query field of a search.
+    ///
+    /// It is reported to accessibility technology as a search input, takes focus
+    /// when it stops being stashed, and doesn't handle <kbd>Enter</kbd>.
+    pub(crate) fn with_search_field(mut self) -> Self {
+        self.search_field = true;
+        self
+    }
+
+    /// Sets whether the text is a secret, such as a password.
+    ///
+    /// The text of a secure text area is masked, showing `•` for each grapheme,
+    /// unless it is [revealed](Self::with_revealed).
+    /// It can't be copied or cut to the clipboard, isn't recorded in the undo history,
+    /// and is reported to accessibility technology as a password input.
+    ///
+    /// To modify this on an active text area, use [`set_secure`](Self::set_secure).
+    pub fn with_secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self.update_mask(None);
+        self
+    }
+
+    /// Sets whether the text of a [secure](Self::with_secure) text area is shown instead of masked.
+    ///
+    /// The default is false.
+    ///
+    /// To modify this on an active text area, use [`set_revealed`](Self::set_revealed).
+    pub fn with_revealed(mut self, revealed: bool) -> Self {
+        self.revealed = revealed;
+        self.update_mask(None);
+        self
+    }
+
+    /// Sets a filter for the text the user enters.
+    ///
+    /// Before the user types, pastes or commits IME text, `filter` is called with
+    /// the whole text as it would be after the edit.
+    /// If it returns false, the edit is ignored.
+    /// The filter isn't applied to deletions, nor to [programmatic changes](Self::reset_text).
+    ///
+    /// For example, to only allow digits:
+    ///
+    /// ```
+    /// # use masonry::widgets::TextArea;
+    /// let area = TextArea::new_editable("")
+    ///     .with_filter(|text| text.chars().all(|c| c.is_ascii_digit()));
+    /// ```
+    ///
+    /// To modify this on an active text area, use [`set_filter`](Self::set_filter).
+    pub fn with_filter(mut self, filter: impl Fn(&str) -> bool + 'static) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the maximum number of graphemes the user can enter.
+    ///
+    /// Text which the user enters past this length is truncated.
+    /// This isn't applied to [programmatic changes](Self::reset_text).
+    ///
+    /// To modify this on an active text area, use [`set_max_length`](Self::set_max_length).
+    pub fn with_max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Sets whether the text is marked as invalid.
+    ///
+    /// This is reported to accessibility technology.
+    /// The visual invalid state is handled by wrappers such as [`TextInput`](super::TextInput).
+    ///
+    /// To modify this on an active text area, use [`set_invalid`](Self::set_invalid).
+    pub fn with_invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    /// Sets byte ranges of the text to highlight, such as search matches.
+    ///
+    /// Ranges whose ends are not char boundaries of the text are ignored.
+    /// The highlights are not painted whilst the text is masked or being composed.
+    ///
+    /// To modify this on an active text area, use [`set_highlights`](Self::set_highlights).
+    pub fn with_highlights(mut self, highlights: Vec<Range<usize>>) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
+    /// Sets which of the highlights is painted as active.
+    ///
+    /// Unlike [`set_active_highlight`](Self::set_active_highlight), this doesn't scroll
+    /// the highlight into view.
+    pub fn with_active_highlight(mut self, index: Option<usize>) -> Self {
+        self.active_highlight = index.filter(|index| *index < self.highlights.len());
+        self
+    }
+
+    /// Sets decorations to draw under ranges of the text, such as spelling mistakes.
+    ///
+    /// See [`set_decorations`](Self::set_decorations) for details.
+    pub fn with_decorations(mut self, decorations: Vec<TextDecoration>) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
     /// Shared logic between `with_style` and `insert_style`
     #[track_caller]
     fn insert_style_inner(&mut self, property: StyleProperty) -> Option<StyleProperty> {
@@ -225,6 +446,9 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
             );
             None
         } else {
+            if let StyleProperty::FontSize(font_size) = property {
+                self.font_size = font_size;
+            }
             self.editor.edit_styles().insert(property)
         }
     }
@@ -237,8 +461,30 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
     /// To update the text of an active text area, use [`reset_text`](Self::reset_text).
     ///
     /// The return value is not just `&str` to handle IME preedits.
+    ///
+    /// For a [secure](Self::with_secure) text area, this is the actual text, not its mask.
     pub fn text(&self) -> SplitString<'_> {
-        self.editor.text()
+        match &self.secret {
+            Some(secret) => secret.text(),
+            None => self.editor.text(),
+        }
+    }
+
+    /// Returns `true` if the text is a secret, such as a password.
+    ///
+    /// See [`with_secure`](Self::with_secure) for details.
+    pub fn is_secure(&self) -> bool {
+        self.secure
+    }
+
+    /// Returns `true` if the text of a secure text area is currently masked.
+    pub fn is_masked(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    /// Returns `true` if the text is marked as invalid.
+    pub fn is_invalid(&self) -> bool {
+        self.invalid
     }
 
     /// Check if this text area holds nothing, including IME preedit content.
@@ -246,6 +492,155 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         self.editor.raw_text().is_empty()
     }
 
+    /// Returns `true` if there is an edit which can be [undone](Self::undo).
+    pub fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an undone edit which can be [redone](Self::redo).
+    pub fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+
+    /// Returns the highlighted byte ranges of the text.
+    ///
+    /// See [`with_highlights`](Self::with_highlights) for details.
+    pub fn highlights(&self) -> &[Range<usize>] {
+        &self.highlights
+    }
+
+    /// Returns the index of the active highlight, if any.
+    pub fn active_highlight(&self) -> Option<usize> {
+        self.active_highlight
+    }
+
+    /// Returns the decorations drawn under the text, with their ranges adjusted for any edits.
+    pub fn decorations(&self) -> &[TextDecoration] {
+        &self.decorations
+    }
+
+    /// Returns the index of the decoration under the pointer, if any.
+    ///
+    /// When this changes, [`TextAction::DecorationHovered`] is emitted.
+    pub fn hovered_decoration(&self) -> Option<usize> {
+        self.hovered_decoration
+    }
+
+    /// Returns the byte ranges of the non-overlapping instances of `query` in the text.
+    ///
+    /// If `match_case` is false, the text is compared case-insensitively, char by char.
+    /// The result can be passed to [`set_highlights`](Self::set_highlights).
+    pub fn find(&self, query: &str, match_case: bool) -> Vec<Range<usize>> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let text = self.text().to_string();
+        if match_case {
+            return text
+                .match_indices(query)
+                .map(|(start, found)| start..start + found.len())
+                .collect();
+        }
+        let fold = |c: char| c.to_lowercase();
+        let mut matches = Vec::new();
+        let mut search_from = 0;
+        for (start, _) in text.char_indices() {
+            if start < search_from {
+                continue;
+            }
+            let mut rest = text[start..].char_indices();
+            let mut end = start;
+            let matched = query.chars().all(|q| match rest.next() {
+                Some((offset, c)) if fold(c).eq(fold(q)) => {
+                    end = start + offset + c.len_utf8();
+                    true
+                }
+                _ => false,
+            });
+            if matched {
+                matches.push(start..end);
+                search_from = end;
+            }
+        }
+        matches
+    }
+
+    /// Returns the rich text, if it still applies to the laid out text.
+    fn active_rich_text(&self) -> Option<&RichText> {
+        let rich_text = self.rich_text.as_ref()?;
+        let applies = self.secret.is_none()
+            && !self.editor.is_composing()
+            && **rich_text.text() == *self.editor.raw_text();
+        applies.then_some(rich_text)
+    }
+
+    /// Returns the highlights which still apply to the laid out text.
+    fn active_highlights(&self) -> impl Iterator<Item = (usize, &Range<usize>)> {
+        let text = self.editor.raw_text();
+        let applies = self.secret.is_none() && !self.editor.is_composing();
+        self.highlights
+            .iter()
+            .enumerate()
+            .filter(move |(_, range)| {
+                applies
+                    && range.start <= range.end
+                    && text.is_char_boundary(range.start)
+                    && text.is_char_boundary(range.end)
+            })
+    }
+
+    /// Returns the decorations which can be drawn on the laid out text.
+    fn active_decorations(&self) -> impl Iterator<Item = (usize, &TextDecoration)> {
+        let text = self.editor.raw_text();
+        let applies = self.secret.is_none() && !self.editor.is_composing();
+        self.decorations
+            .iter()
+            .enumerate()
+            .filter(move |(_, decoration)| {
+                let range = &decoration.range;
+                applies
+                    && range.start < range.end
+                    && text.is_char_boundary(range.start)
+                    && text.is_char_boundary(range.end)
+            })
+    }
+
+    /// Returns the index of the decoration at `pos`, in the text area's content-box coordinates.
+    fn decoration_at_point(&self, pos: Point) -> Option<usize> {
+        if self.decorations.is_empty() {
+            return None;
+        }
+        let layout = self.editor.try_layout()?;
+        let (cluster, _) = Cluster::from_point_exact(layout, pos.x as f32, pos.y as f32)?;
+        let index = cluster.text_range().start;
+        self.active_decorations()
+            .find(|(_, decoration)| decoration.range.contains(&index))
+            .map(|(i, _)| i)
+    }
+
+    /// Updates the hovered decoration, and reports it if it changed.
+    fn set_hovered_decoration(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        hovered_decoration: Option<usize>,
+    ) {
+        if self.hovered_decoration != hovered_decoration {
+            self.hovered_decoration = hovered_decoration;
+            ctx.submit_action::<TextAction>(TextAction::DecorationHovered(hovered_decoration));
+        }
+    }
+
+    /// Returns the target of the link at `pos`, in the text area's content-box coordinates.
+    fn link_at_point(&self, pos: Point) -> Option<&ArcStr> {
+        let rich_text = self.active_rich_text()?;
+        if !rich_text.has_links() {
+            return None;
+        }
+        let layout = self.editor.try_layout()?;
+        let (cluster, _) = Cluster::from_point_exact(layout, pos.x as f32, pos.y as f32)?;
+        rich_text.link_at(cluster.text_range().start)
+    }
+
     /// Returns the IME area from the editor, accounting for padding.
     ///
     /// This should only be called when the editor layout is available.
@@ -256,6 +651,279 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         );
         bounding_box_to_rect(self.editor.ime_cursor_area())
     }
+
+    /// Returns the current text and selection, without any IME preedit.
+    fn edit_state(&self) -> EditState {
+        let text = self.editor.raw_text();
+        if let Some(compose) = self.editor.raw_compose() {
+            let mut text = text.to_string();
+            text.replace_range(compose.clone(), "");
+            return EditState {
+                text,
+                anchor: compose.start,
+                focus: compose.start,
+            };
+        }
+        let selection = self.editor.raw_selection();
+        EditState {
+            text: text.to_string(),
+            anchor: selection.anchor().index(),
+            focus: selection.focus().index(),
+        }
+    }
+
+    /// Returns the anchor and focus of the selection, as byte offsets in [`text`](Self::text).
+    ///
+    /// Whilst composing, this is the start of the preedit.
+    fn selection(&self) -> (usize, usize) {
+        if let Some(compose) = self.editor.raw_compose() {
+            return (compose.start, compose.start);
+        }
+        let selection = self.editor.raw_selection();
+        let (anchor, focus) = (selection.anchor().index(), selection.focus().index());
+        match &self.secret {
+            Some(secret) => {
+                let text = secret.raw_text();
+                (unmask_offset(text, anchor), unmask_offset(text, focus))
+            }
+            None => (anchor, focus),
+        }
+    }
+
+    /// Submits a [`TextAction::SelectionChanged`] if the selection is no longer `before`.
+    fn report_selection(&self, ctx: &mut EventCtx<'_>, before: (usize, usize)) {
+        if !EDITABLE {
+            return;
+        }
+        let selection = self.selection();
+        if selection != before {
+            let (anchor, focus) = selection;
+            ctx.submit_action::<TextAction>(TextAction::SelectionChanged { anchor, focus });
+        }
+    }
+
+    /// Adds an undo step for the edit which changed the text from `before`.
+    ///
+    /// Does nothing if the text wasn't actually changed.
+    /// This should not be called whilst composing.
+    fn record_edit(&mut self, before: EditState, kind: EditKind) {
+        if self.secret.is_none() {
+            adjust_decorations(&mut self.decorations, &before.text, self.editor.raw_text());
+        }
+        // We don't keep secrets around longer than necessary.
+        if !EDITABLE || self.secure || before.text == self.editor.raw_text() {
+            return;
+        }
+        self.history.record(before, self.editor.raw_text(), kind);
+
+        // Typing whitespace ends the group, so that words are undone one at a time.
+        if kind == EditKind::Typing {
+            let caret = self.editor.raw_selection().focus().index();
+            if self
+                .editor
+                .raw_text()
+                .get(..caret)
+                .is_some_and(|text| text.ends_with(char::is_whitespace))
+            {
+                self.history.break_group();
+            }
+        }
+    }
+
+    /// Restores the state of the last undo step. Returns `false` if there was none.
+    fn undo_inner(&mut self, fctx: &mut FontContext, lctx: &mut LayoutContext<BrushIndex>) -> bool {
+        let Some(state) = self.history.undo(self.edit_state()) else {
+            return false;
+        };
+        self.restore(fctx, lctx, state);
+        true
+    }
+
+    /// Restores the state of the last undone step. Returns `false` if there was none.
+    fn redo_inner(&mut self, fctx: &mut FontContext, lctx: &mut LayoutContext<BrushIndex>) -> bool {
+        let Some(state) = self.history.redo(self.edit_state()) else {
+            return false;
+        };
+        self.restore(fctx, lctx, state);
+        true
+    }
+
+    /// Masks or unmasks the text, to match `secure` and `revealed`.
+    ///
+    /// The selection is only preserved if the text contexts are provided.
+    /// This should not be called whilst composing.
+    fn update_mask(
+        &mut self,
+        contexts: Option<(&mut FontContext, &mut LayoutContext<BrushIndex>)>,
+    ) {
+        let masked = self.secure && !self.revealed;
+        if masked == self.secret.is_some() {
+            return;
+        }
+        let selection = self.editor.raw_selection();
+        let (anchor, focus) = (selection.anchor().index(), selection.focus().index());
+        let (anchor, focus) = match self.secret.take() {
+            Some(secret) => {
+                let text = secret.raw_text();
+                self.editor.set_text(text);
+                (unmask_offset(text, anchor), unmask_offset(text, focus))
+            }
+            None => {
+                let text = self.editor.raw_text();
+                let mut secret = PlainEditor::new(self.font_size);
+                secret.set_text(text);
+                let selection = (mask_offset(text, anchor), mask_offset(text, focus));
+                self.editor.set_text(&mask(text));
+                self.secret = Some(secret);
+                selection
+            }
+        };
+        if let Some((fctx, lctx)) = contexts {
+            self.editor
+                .driver(fctx, lctx)
+                .select_byte_range(anchor, focus);
+        }
+    }
+
+    /// Replaces the whole text, without changing the selection.
+    fn set_text(&mut self, text: &str) {
+        if let Some(secret) = &mut self.secret {
+            secret.set_text(text);
+            self.editor.set_text(&mask(text));
+        } else {
+            self.editor.set_text(text);
+        }
+    }
+
+    /// Inserts `text` at the cursor, or replaces the selection with it.
+    ///
+    /// This applies the filter and the maximum length, as the text is entered by the user.
+    fn insert_or_replace_selection(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        text: &str,
+    ) {
+        let Some(text) = self.filter_insertion(text) else {
+            return;
+        };
+        if self.secret.is_some() {
+            let range = self.editor.raw_selection().text_range();
+            self.replace_masked(fctx, lctx, range, text);
+        } else {
+            self.editor
+                .driver(fctx, lctx)
+                .insert_or_replace_selection(text);
+        }
+    }
+
+    /// Applies the filter and the maximum length to `text` which the user is about to insert.
+    ///
+    /// Returns the text which should be inserted, or `None` if the edit should be ignored.
+    fn filter_insertion<'t>(&self, text: &'t str) -> Option<&'t str> {
+        if self.filter.is_none() && self.max_length.is_none() {
+            return Some(text);
+        }
+        let selection = self.editor.raw_selection().text_range();
+        let (current, range) = match &self.secret {
+            Some(secret) => {
+                let current = secret.raw_text();
+                let start = unmask_offset(current, selection.start);
+                let end = unmask_offset(current, selection.end);
+                (current, start..end)
+            }
+            None => (self.editor.raw_text(), selection),
+        };
+        let (before, after) = (&current[..range.start], &current[range.end..]);
+
+        let mut text = text;
+        if let Some(max_length) = self.max_length {
+            let kept = before.graphemes(true).count() + after.graphemes(true).count();
+            let available = max_length.saturating_sub(kept);
+            if let Some((end, _)) = text.grapheme_indices(true).nth(available) {
+                text = &text[..end];
+            }
+            if text.is_empty() {
+                return None;
+            }
+        }
+        if let Some(filter) = &self.filter {
+            let proposed = [before, text, after].concat();
+            if !filter(&proposed) {
+                return None;
+            }
+        }
+        Some(text)
+    }
+
+    /// Deletes the selection of a masked text area, or if it is collapsed,
+    /// the grapheme after (if `forward`) or before the cursor.
+    ///
+    /// Masked text has no visible words, so with `word` this deletes
+    /// up to the end or start of the text instead.
+    fn delete_masked(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        forward: bool,
+        word: bool,
+    ) {
+        let selection = self.editor.raw_selection().text_range();
+        let len = self.editor.raw_text().len();
+        let caret = selection.start;
+        let range = if !selection.is_empty() {
+            selection
+        } else if forward {
+            caret..if word {
+                len
+            } else {
+                (caret + MASK_CHAR.len_utf8()).min(len)
+            }
+        } else if word {
+            0..caret
+        } else {
+            caret.saturating_sub(MASK_CHAR.len_utf8())..caret
+        };
+        self.replace_masked(fctx, lctx, range, "");
+    }
+
+    /// Replaces the byte `range` of the mask of a masked text area with `text`,
+    /// and moves the cursor to the end of the inserted text.
+    fn replace_masked(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        range: Range<usize>,
+        text: &str,
+    ) {
+        let Some(secret) = &mut self.secret else {
+            debug_panic!("TextArea::replace_masked called on text which isn't masked");
+            return;
+        };
+        let mut new_text = secret.raw_text().to_string();
+        let start = unmask_offset(&new_text, range.start);
+        let end = unmask_offset(&new_text, range.end);
+        new_text.replace_range(start..end, text);
+        secret.set_text(&new_text);
+        // We recreate the whole mask, as the edit can also change how the
+        // surrounding text is split into graphemes, e.g. when typing a combining mark.
+        self.editor.set_text(&mask(&new_text));
+        let caret = mask_offset(&new_text, start + text.len());
+        self.editor.driver(fctx, lctx).move_to_byte(caret);
+    }
+
+    fn restore(
+        &mut self,
+        fctx: &mut FontContext,
+        lctx: &mut LayoutContext<BrushIndex>,
+        state: EditState,
+    ) {
+        adjust_decorations(&mut self.decorations, self.editor.raw_text(), &state.text);
+        self.editor.set_text(&state.text);
+        self.editor
+            .driver(fctx, lctx)
+            .select_byte_range(state.anchor, state.focus);
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -321,15 +989,20 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
     ///
     /// This is likely to be disruptive if the user is focused on this widget,
     /// as it does not retain selections, and may cause undesirable interactions with IME.
+    ///
+    /// If the text area is editable and the text changes, this is recorded as a
+    /// separate step in the undo history, so the user can undo it like any other edit.
+    /// Use [`clear_history`](Self::clear_history) if that isn't wanted.
     pub fn reset_text(this: &mut WidgetMut<'_, Self>, new_text: &str) {
-        // If the IME is currently composing, we need to clear the compose first. This is quite
-        // disruptive, but we've warned about that. The platform's state is not reset, and the
-        // preedit will show up again when the platform updates it.
-        if this.widget.editor.is_composing() {
-            let (fctx, lctx) = this.ctx.text_contexts();
-            this.widget.editor.driver(fctx, lctx).clear_compose();
-        }
-        this.widget.editor.set_text(new_text);
+        Self::clear_compose(this);
+        this.widget.rich_text = None;
+        this.widget.pressed_link = None;
+        this.widget.scoped_selection = None;
+        this.widget.highlights.clear();
+        this.widget.active_highlight = None;
+        let before = this.widget.edit_state();
+        this.widget.set_text(new_text);
+        this.widget.record_edit(before, EditKind::Other);
 
         let (fctx, lctx) = this.ctx.text_contexts();
         this.widget.editor.driver(fctx, lctx).move_to_text_end();
@@ -337,6 +1010,128 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         this.ctx.request_layout();
     }
 
+    /// Replaces the text with rich text.
+    ///
+    /// This has the same caveats as [`reset_text`](Self::reset_text).
+    ///
+    /// The runtime equivalent of [`with_rich_text`](Self::with_rich_text).
+    pub fn set_rich_text(this: &mut WidgetMut<'_, Self>, new_text: RichText) {
+        Self::reset_text(this, new_text.text());
+        this.widget.rich_text = (!new_text.is_plain()).then_some(new_text);
+    }
+
+    /// Reverts the last edit in the undo history.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    /// Unlike the user pressing <kbd>Ctrl</kbd>+<kbd>Z</kbd>, this doesn't emit [`TextAction::Changed`].
+    pub fn undo(this: &mut WidgetMut<'_, Self>) -> bool {
+        Self::clear_compose(this);
+        let (fctx, lctx) = this.ctx.text_contexts();
+        let undone = this.widget.undo_inner(fctx, lctx);
+        if undone {
+            this.ctx.request_layout();
+        }
+        undone
+    }
+
+    /// Reapplies the last edit reverted by [`undo`](Self::undo).
+    ///
+    /// Returns `false` if there was nothing to redo.
+    /// Unlike the user pressing <kbd>Ctrl</kbd>+<kbd>Y</kbd>, this doesn't emit [`TextAction::Changed`].
+    pub fn redo(this: &mut WidgetMut<'_, Self>) -> bool {
+        Self::clear_compose(this);
+        let (fctx, lctx) = this.ctx.text_contexts();
+        let redone = this.widget.redo_inner(fctx, lctx);
+        if redone {
+            this.ctx.request_layout();
+        }
+        redone
+    }
+
+    /// Discards the undo and redo history.
+    ///
+    /// This is useful when the text area starts editing an unrelated document.
+    pub fn clear_history(this: &mut WidgetMut<'_, Self>) {
+        this.widget.history = EditHistory::default();
+    }
+
+    /// Sets whether the text is a secret, such as a password.
+    ///
+    /// Making the text secure also clears the undo history.
+    ///
+    /// The runtime equivalent of [`with_secure`](Self::with_secure).
+    /// For full documentation, see that method.
+    pub fn set_secure(this: &mut WidgetMut<'_, Self>, secure: bool) {
+        if this.widget.secure == secure {
+            return;
+        }
+        Self::clear_compose(this);
+        this.widget.secure = secure;
+        if secure {
+            this.widget.history = EditHistory::default();
+        }
+        let (fctx, lctx) = this.ctx.text_contexts();
+        this.widget.update_mask(Some((fctx, lctx)));
+        this.ctx.request_layout();
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Sets whether the text of a secure text area is shown instead of masked.
+    ///
+    /// This can be used to implement a "show password" toggle.
+    ///
+    /// The runtime equivalent of [`with_revealed`](Self::with_revealed).
+    pub fn set_revealed(this: &mut WidgetMut<'_, Self>, revealed: bool) {
+        if this.widget.revealed == revealed {
+            return;
+        }
+        Self::clear_compose(this);
+        this.widget.revealed = revealed;
+        let (fctx, lctx) = this.ctx.text_contexts();
+        this.widget.update_mask(Some((fctx, lctx)));
+        this.ctx.request_layout();
+    }
+
+    /// Sets a filter for the text the user enters.
+    ///
+    /// The runtime equivalent of [`with_filter`](Self::with_filter).
+    /// For full documentation, see that method.
+    pub fn set_filter(this: &mut WidgetMut<'_, Self>, filter: impl Fn(&str) -> bool + 'static) {
+        this.widget.filter = Some(Box::new(filter));
+    }
+
+    /// Removes the filter set by [`set_filter`](Self::set_filter).
+    pub fn clear_filter(this: &mut WidgetMut<'_, Self>) {
+        this.widget.filter = None;
+    }
+
+    /// Sets the maximum number of graphemes the user can enter.
+    ///
+    /// The runtime equivalent of [`with_max_length`](Self::with_max_length).
+    /// For full documentation, see that method.
+    pub fn set_max_length(this: &mut WidgetMut<'_, Self>, max_length: Option<usize>) {
+        this.widget.max_length = max_length;
+    }
+
+    /// Sets whether the text is marked as invalid.
+    ///
+    /// The runtime equivalent of [`with_invalid`](Self::with_invalid).
+    /// For full documentation, see that method.
+    pub fn set_invalid(this: &mut WidgetMut<'_, Self>, invalid: bool) {
+        this.widget.invalid = invalid;
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Clears the IME compose region, if any.
+    fn clear_compose(this: &mut WidgetMut<'_, Self>) {
+        // This is quite disruptive, but the callers warn about that. The platform's state is
+        // not reset, and the preedit will show up again when the platform updates it.
+        if this.widget.editor.is_composing() {
+            let (fctx, lctx) = this.ctx.text_contexts();
+            this.widget.editor.driver(fctx, lctx).clear_compose();
+        }
+    }
+
     /// Sets [word wrapping](https://en.wikipedia.org/wiki/Line_wrap_and_word_wrap) for the text area.
     ///
     /// When enabled, the text will be laid out to fit within the available width.
@@ -378,8 +1173,20 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
 
     /// Sets the selection to the given byte range.
     ///
+    /// For a masked text area, the range is in the actual [`text`](Self::text).
+    ///
     /// No-op if either index is not a char boundary.
     pub fn select_byte_range(this: &mut WidgetMut<'_, Self>, start: usize, end: usize) {
+        let (start, end) = match &this.widget.secret {
+            Some(secret) => {
+                let text = secret.raw_text();
+                if !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+                    return;
+                }
+                (mask_offset(text, start), mask_offset(text, end))
+            }
+            None => (start, end),
+        };
         let (fctx, lctx) = this.ctx.text_contexts();
         this.widget
             .editor
@@ -400,9 +1207,129 @@ impl<const EDITABLE: bool> TextArea<EDITABLE> {
         let end = start + text.len();
         Self::select_byte_range(this, start, end);
     }
+
+    /// Sets byte ranges of the text to highlight, such as search matches.
+    ///
+    /// This clears the active highlight.
+    /// The highlights are cleared by [`reset_text`](Self::reset_text), but are not
+    /// adjusted when the user edits the text.
+    ///
+    /// The runtime equivalent of [`with_highlights`](Self::with_highlights).
+    pub fn set_highlights(this: &mut WidgetMut<'_, Self>, highlights: Vec<Range<usize>>) {
+        this.widget.highlights = highlights;
+        this.widget.active_highlight = None;
+        this.ctx.request_paint_only();
+    }
+
+    /// Sets which highlight is active, and scrolls it into view.
+    ///
+    /// The active highlight is painted with the [`ActiveHighlightColor`].
+    /// Scrolling sends [`Update::RequestPanToChild`] to the ancestors of this widget,
+    /// so that an enclosing [`Portal`](super::Portal) shows the highlight.
+    ///
+    /// No-op if `index` is out of bounds.
+    pub fn set_active_highlight(this: &mut WidgetMut<'_, Self>, index: Option<usize>) {
+        if index.is_some_and(|index| index >= this.widget.highlights.len()) {
+            return;
+        }
+        this.widget.active_highlight = index;
+        this.ctx.request_paint_only();
+
+        let Some(index) = index else {
+            return;
+        };
+        let (fctx, lctx) = this.ctx.text_contexts();
+        this.widget.editor.refresh_layout(fctx, lctx);
+        let Some((_, range)) = this.widget.active_highlights().find(|(i, _)| *i == index) else {
+            return;
+        };
+        let layout = this.widget.editor.try_layout().unwrap();
+        let rect = highlight_rects(layout, range.clone()).reduce(|a, b| a.union(b));
+        if let Some(rect) = rect {
+            this.ctx.request_scroll_to(rect);
+        }
+    }
+
+    /// Makes the highlight after the active one active, wrapping around to the first one.
+    ///
+    /// Returns the index of the new active highlight, or `None` if there are no highlights.
+    /// See [`set_active_highlight`](Self::set_active_highlight) for details.
+    pub fn next_highlight(this: &mut WidgetMut<'_, Self>) -> Option<usize> {
+        let len = this.widget.highlights.len();
+        if len == 0 {
+            return None;
+        }
+        let index = this.widget.active_highlight.map_or(0, |i| (i + 1) % len);
+        Self::set_active_highlight(this, Some(index));
+        Some(index)
+    }
+
+    /// Makes the highlight before the active one active, wrapping around to the last one.
+    ///
+    /// Returns the index of the new active highlight, or `None` if there are no highlights.
+    /// See [`set_active_highlight`](Self::set_active_highlight) for details.
+    pub fn previous_highlight(this: &mut WidgetMut<'_, Self>) -> Option<usize> {
+        let len = this.widget.highlights.len();
+        if len == 0 {
+            return None;
+        }
+        let index = this
+            .widget
+            .active_highlight
+            .map_or(len - 1, |i| (i + len - 1) % len);
+        Self::set_active_highlight(this, Some(index));
+        Some(index)
+    }
+
+    /// Sets decorations to draw under ranges of the text, such as spelling mistakes.
+    ///
+    /// As the text is edited, both by the user and with [`reset_text`](Self::reset_text),
+    /// the ranges of the decorations are adjusted so that they stay on the same text.
+    /// Text inserted inside a decorated range extends it.
+    /// A decoration whose text is deleted becomes empty, and is no longer drawn, but keeps its index.
+    /// Decorations are not drawn whilst the text is masked or being composed.
+    ///
+    /// When the pointer moves over or off a decoration, [`TextAction::DecorationHovered`]
+    /// is emitted, so that the app can show the [message](TextDecoration::message)
+    /// in a tooltip at the [`decoration_rect`](Self::decoration_rect).
+    ///
+    /// The runtime equivalent of [`with_decorations`](Self::with_decorations).
+    pub fn set_decorations(this: &mut WidgetMut<'_, Self>, decorations: Vec<TextDecoration>) {
+        this.widget.decorations = decorations;
+        this.widget.hovered_decoration = None;
+        this.ctx.request_paint_only();
+    }
+
+    /// Returns the bounding rectangle of the text under the decoration at `index`,
+    /// in the window's coordinate space.
+    ///
+    /// Returns `None` if there is no such decoration, if it isn't drawn,
+    /// or if the text area hasn't been laid out yet.
+    pub fn decoration_rect(this: &WidgetMut<'_, Self>, index: usize) -> Option<Rect> {
+        let layout = this.widget.editor.try_layout()?;
+        let (_, decoration) = this
+            .widget
+            .active_decorations()
+            .find(|(i, _)| *i == index)?;
+        let rect = highlight_rects(layout, decoration.range.clone()).reduce(|a, b| a.union(b))?;
+        Some(this.ctx.window_transform().transform_rect_bbox(rect))
+    }
+
+    /// Returns the rectangle of the caret, in the window's coordinate space.
+    ///
+    /// This is useful for positioning a layer, such as an autocomplete popup, next to the caret.
+    /// The caret is at the focus of the selection, and is returned even when the text area
+    /// isn't focused.
+    /// Returns `None` if the text area hasn't been laid out yet.
+    pub fn caret_rect(this: &WidgetMut<'_, Self>) -> Option<Rect> {
+        this.widget.editor.try_layout()?;
+        let caret = bounding_box_to_rect(this.widget.editor.cursor_geometry(1.5)?);
+        Some(this.ctx.window_transform().transform_rect_bbox(caret))
+    }
 }
 
-/// Text in a text area has been changed or submitted with enter.
+/// Text in a text area has been changed or submitted with enter,
+/// or the selection or composition has changed.
 #[derive(PartialEq, Debug)]
 // TODO: Should this be two different structs?
 pub enum TextAction {
@@ -413,7 +1340,46 @@ pub enum TextAction {
     /// Whether this action gets emitted depends on the [`InsertNewline`] setting
     /// and with [`InsertNewline::OnShiftEnter`] also on if the shift key is pressed.
     Entered(String),
-    // TODO: TextCursor changed, ImeChanged
+    /// A link in the [rich text](TextArea::with_rich_text) has been clicked.
+    ///
+    /// This is only emitted by text areas which aren't editable by the user.
+    /// The value is the target of the link, which is usually a URL.
+    LinkClicked(ArcStr),
+    /// The selection has been changed by the user.
+    ///
+    /// The anchor and focus are byte offsets in the [text](TextArea::text), and are equal
+    /// when there is only a caret.
+    /// The caret is at the focus; its position can be queried with [`TextArea::caret_rect`].
+    ///
+    /// This is also emitted when the selection moves because the user edited the text,
+    /// after the [`Changed`](Self::Changed) action for the edit.
+    /// It is not emitted whilst the user is composing text with an input method.
+    SelectionChanged {
+        /// The end of the selection which stays fixed when the selection is extended.
+        anchor: usize,
+        /// The end of the selection which moves when the selection is extended.
+        focus: usize,
+    },
+    /// The user is composing text with an input method.
+    ///
+    /// The text being composed (the "preedit") isn't part of the [text](TextArea::text)
+    /// until it is committed, which emits [`Changed`](Self::Changed).
+    /// The preedit of a [secure](TextArea::with_secure) text area isn't shown or reported.
+    Composing {
+        /// The text being composed.
+        preedit: String,
+        /// The byte range of the input method's cursor within the preedit, if any.
+        cursor: Option<(usize, usize)>,
+    },
+    /// The user has finished composing text with an input method.
+    ///
+    /// This is emitted both when the composition is committed and when it is cancelled.
+    CompositionEnded,
+    /// The pointer has moved over or off a [decoration](TextArea::set_decorations).
+    ///
+    /// The value is the index of the decoration now under the pointer, if any.
+    /// Its position can be queried with [`TextArea::decoration_rect`].
+    DecorationHovered(Option<usize>),
 }
 
 // --- MARK: IMPL WIDGET
@@ -478,6 +1444,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         if self.editor.is_composing() {
             return;
         }
+        let selection = self.selection();
 
         match event {
             PointerEvent::Down(PointerButtonEvent {
@@ -485,7 +1452,18 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 state,
                 ..
             }) => {
+                // Typing after moving the caret shouldn't be undone together with earlier typing.
+                self.history.break_group();
                 let cursor_pos = ctx.local_position(state.position);
+                self.pressed_link = if EDITABLE || state.count > 1 || state.modifiers.shift() {
+                    None
+                } else {
+                    self.link_at_point(cursor_pos).cloned()
+                };
+                if !EDITABLE && self.pressed_link.is_none() && ctx.selection_scope().is_some() {
+                    // Let the selection scope select text across widgets.
+                    return;
+                }
                 let (fctx, lctx) = ctx.text_contexts();
                 let mut drv = self.editor.driver(fctx, lctx);
                 match state.count {
@@ -511,6 +1489,8 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 ctx.capture_pointer();
             }
             PointerEvent::Move(PointerUpdate { current, .. }) => {
+                let hovered = self.decoration_at_point(ctx.local_position(current.position));
+                self.set_hovered_decoration(ctx, hovered);
                 if ctx.is_active() {
                     let cursor_pos = ctx.local_position(current.position);
                     let (fctx, lctx) = ctx.text_contexts();
@@ -525,8 +1505,22 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     }
                 }
             }
+            PointerEvent::Up(PointerButtonEvent { state, .. }) => {
+                // A link is only followed if the user didn't select text whilst pressing it.
+                if let Some(pressed) = self.pressed_link.take()
+                    && ctx.is_active()
+                    && self.editor.raw_selection().is_collapsed()
+                    && self.link_at_point(ctx.local_position(state.position)) == Some(&pressed)
+                {
+                    ctx.submit_action::<Self::Action>(TextAction::LinkClicked(pressed));
+                }
+            }
+            PointerEvent::Cancel(..) => {
+                self.pressed_link = None;
+            }
             _ => {}
         }
+        self.report_selection(ctx, selection);
     }
 
     fn on_text_event(
@@ -539,6 +1533,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         self.anim_prev_interval = 0;
         self.anim_elapsed = 0;
         ctx.request_anim_frame();
+        let selection = self.selection();
 
         match event {
             TextEvent::Keyboard(key_event) => {
@@ -556,7 +1551,30 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 let (fctx, lctx) = ctx.text_contexts();
                 // Whether the text was changed.
                 let mut edited = false;
+                // The state before the edit, to be recorded in the undo history.
+                let mut before = None;
                 match &key_event.key {
+                    // Undo and redo
+                    Key::Character(z)
+                        if EDITABLE && action_mod && z.as_str().eq_ignore_ascii_case("z") =>
+                    {
+                        edited = if shift {
+                            self.redo_inner(fctx, lctx)
+                        } else {
+                            self.undo_inner(fctx, lctx)
+                        };
+                    }
+                    Key::Character(y)
+                        if EDITABLE && action_mod && y.as_str().eq_ignore_ascii_case("y") =>
+                    {
+                        edited = self.redo_inner(fctx, lctx);
+                    }
+                    // Secure text can't be copied or cut.
+                    Key::Character(c)
+                        if self.secure
+                            && action_mod
+                            && (c.as_str().eq_ignore_ascii_case("c")
+                                || c.as_str().eq_ignore_ascii_case("x")) => {}
                     // Cut
                     #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                     Key::Character(x)
@@ -566,6 +1584,7 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                             && !text.is_empty()
                         {
                             let text = text.to_string();
+                            before = Some((self.edit_state(), EditKind::Other));
                             self.editor.driver(fctx, lctx).delete_selection();
                             edited = true;
                             ctx.set_clipboard(text);
@@ -662,42 +1681,54 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         }
                     }
                     Key::Named(NamedKey::Delete) if EDITABLE => {
-                        let mut drv = self.editor.driver(fctx, lctx);
-                        if action_mod {
-                            drv.delete_word();
+                        before = Some((self.edit_state(), EditKind::Deleting));
+                        if self.secret.is_some() {
+                            self.delete_masked(fctx, lctx, true, action_mod);
                         } else {
-                            drv.delete();
+                            let mut drv = self.editor.driver(fctx, lctx);
+                            if action_mod {
+                                drv.delete_word();
+                            } else {
+                                drv.delete();
+                            }
                         }
 
                         edited = true;
                     }
                     Key::Named(NamedKey::Backspace) if EDITABLE => {
-                        let mut drv = self.editor.driver(fctx, lctx);
-                        if action_mod {
-                            drv.backdelete_word();
+                        before = Some((self.edit_state(), EditKind::Deleting));
+                        if self.secret.is_some() {
+                            self.delete_masked(fctx, lctx, false, action_mod);
                         } else {
-                            drv.backdelete();
+                            let mut drv = self.editor.driver(fctx, lctx);
+                            if action_mod {
+                                drv.backdelete_word();
+                            } else {
+                                drv.backdelete();
+                            }
                         }
 
                         edited = true;
                     }
                     Key::Character(sp) if EDITABLE && sp.as_str() == " " => {
-                        self.editor
-                            .driver(fctx, lctx)
-                            .insert_or_replace_selection(" ");
+                        before = Some((self.edit_state(), EditKind::Typing));
+                        self.insert_or_replace_selection(fctx, lctx, " ");
                         edited = true;
                     }
-                    Key::Named(NamedKey::Enter) => {
+                    Key::Named(NamedKey::Enter) if self.search_field => {
+                        // Let the search go to the next match.
+                        return;
+                    }
+                    Key::Named(NamedKey::Enter) if EDITABLE => {
                         let insert_newline = match self.insert_newline {
                             InsertNewline::OnEnter => true,
                             InsertNewline::OnShiftEnter => shift,
                             InsertNewline::Never => false,
                         };
                         if insert_newline {
+                            before = Some((self.edit_state(), EditKind::Typing));
                             let (fctx, lctx) = ctx.text_contexts();
-                            self.editor
-                                .driver(fctx, lctx)
-                                .insert_or_replace_selection("\n");
+                            self.insert_or_replace_selection(fctx, lctx, "\n");
                             edited = true;
                         } else {
                             ctx.submit_action::<Self::Action>(TextAction::Entered(
@@ -712,9 +1743,8 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                         return;
                     }
                     Key::Character(text) if EDITABLE => {
-                        self.editor
-                            .driver(fctx, lctx)
-                            .insert_or_replace_selection(text);
+                        before = Some((self.edit_state(), EditKind::Typing));
+                        self.insert_or_replace_selection(fctx, lctx, text);
                         edited = true;
                     }
                     _ => {
@@ -723,6 +1753,11 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     }
                 }
                 ctx.set_handled();
+                match before {
+                    Some((before, kind)) => self.record_edit(before, kind),
+                    // Moving the caret ends the current group of edits.
+                    None => self.history.break_group(),
+                }
                 let new_generation = self.editor.generation();
                 if new_generation != self.rendered_generation {
                     if edited {
@@ -750,23 +1785,39 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 // Whether the returned text has changed.
                 // We don't send a TextChanged when the preedit changes
                 let mut edited = false;
+                let was_composing = self.editor.is_composing();
                 match e {
                     Ime::Disabled => {
                         self.editor.driver(fctx, lctx).clear_compose();
+                        self.history.composed = false;
                     }
                     Ime::Preedit(text, cursor) => {
                         if text.is_empty() {
                             self.editor.driver(fctx, lctx).clear_compose();
+                        } else if self.secret.is_some() {
+                            // Showing the preedit would reveal the text being typed.
                         } else {
                             self.editor.driver(fctx, lctx).set_compose(text, *cursor);
-                            edited = true;
+                            self.history.composed = true;
+                            ctx.submit_action::<Self::Action>(TextAction::Composing {
+                                preedit: text.clone(),
+                                cursor: *cursor,
+                            });
                         }
                     }
                     Ime::Commit(text) => {
-                        self.editor
-                            .driver(fctx, lctx)
-                            .insert_or_replace_selection(text);
-                        edited = true;
+                        let before = self.edit_state();
+                        self.insert_or_replace_selection(fctx, lctx, text);
+                        // Many platforms send typed text as commits without a preedit,
+                        // which we merge like other typing.
+                        let kind = if std::mem::take(&mut self.history.composed) {
+                            EditKind::Other
+                        } else {
+                            EditKind::Typing
+                        };
+                        // The filter or the length limit may have rejected the commit.
+                        edited = before.text != self.editor.raw_text();
+                        self.record_edit(before, kind);
                     }
                     Ime::Enabled => {}
                 }
@@ -776,6 +1827,9 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     let text = self.text().into_iter().collect();
                     ctx.submit_action::<Self::Action>(TextAction::Changed(text));
                 }
+                if was_composing && !self.editor.is_composing() {
+                    ctx.submit_action::<Self::Action>(TextAction::CompositionEnded);
+                }
 
                 let new_generation = self.editor.generation();
                 if new_generation != self.rendered_generation {
@@ -786,10 +1840,10 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
 
             TextEvent::ClipboardPaste(text) => {
                 if EDITABLE {
+                    let before = self.edit_state();
                     let (fctx, lctx) = ctx.text_contexts();
-                    self.editor
-                        .driver(fctx, lctx)
-                        .insert_or_replace_selection(text);
+                    self.insert_or_replace_selection(fctx, lctx, text);
+                    self.record_edit(before, EditKind::Other);
 
                     // TODO - Factor out with other branches
                     let new_generation = self.editor.generation();
@@ -802,7 +1856,10 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                     }
                 }
             }
+            // TextArea never requests the clipboard; pasting goes through `ClipboardPaste`.
+            TextEvent::ClipboardData(_) => {}
         }
+        self.report_selection(ctx, selection);
     }
 
     fn accepts_focus(&self) -> bool {
@@ -813,6 +1870,10 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         EDITABLE
     }
 
+    fn font_size(&self) -> Option<f64> {
+        Some(self.font_size.into())
+    }
+
     fn on_access_event(
         &mut self,
         ctx: &mut EventCtx<'_>,
@@ -824,17 +1885,20 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 return;
             }
 
-            if let Some(accesskit::ActionData::SetTextSelection(selection)) = &event.data {
+            if let Some(accesskit::ActionData::SetTextSelection(new_selection)) = &event.data {
+                let selection = self.selection();
+                self.history.break_group();
                 let (fctx, lctx) = ctx.text_contexts();
                 self.editor
                     .driver(fctx, lctx)
-                    .select_from_accesskit(selection);
+                    .select_from_accesskit(new_selection);
                 let new_generation = self.editor.generation();
                 if new_generation != self.rendered_generation {
                     ctx.request_render();
                     ctx.set_ime_area(self.ime_area());
                     self.rendered_generation = new_generation;
                 }
+                self.report_selection(ctx, selection);
             }
         }
     }
@@ -845,8 +1909,11 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         CaretColor::prop_changed(ctx, property_type);
         ContentColor::prop_changed(ctx, property_type);
         DisabledContentColor::prop_changed(ctx, property_type);
+        LinkColor::prop_changed(ctx, property_type);
         SelectionColor::prop_changed(ctx, property_type);
         UnfocusedSelectionColor::prop_changed(ctx, property_type);
+        HighlightColor::prop_changed(ctx, property_type);
+        ActiveHighlightColor::prop_changed(ctx, property_type);
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
@@ -858,6 +1925,14 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
                 // We might need to use the disabled brush, and stop displaying the selection.
                 ctx.request_render();
             }
+            Update::StashedChanged(false) if self.search_field => {
+                ctx.request_focus();
+            }
+            Update::HoveredChanged(false) => {
+                if self.hovered_decoration.take().is_some() {
+                    ctx.submit_action::<TextAction>(TextAction::DecorationHovered(None));
+                }
+            }
             _ => {}
         }
     }
@@ -993,6 +2068,30 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
             self.editor.refresh_layout(fctx, lctx);
             self.editor.try_layout().unwrap()
         };
+        let highlight_color = props.get::<HighlightColor>().color;
+        let active_highlight_color = props.get::<ActiveHighlightColor>().0.color;
+        for (index, range) in self.active_highlights() {
+            let color = if self.active_highlight == Some(index) {
+                active_highlight_color
+            } else {
+                highlight_color
+            };
+            for rect in highlight_rects(layout, range.clone()) {
+                scene.fill(Fill::NonZero, Affine::IDENTITY, color, None, &rect);
+            }
+        }
+        if let Some(selection) = &self.scoped_selection {
+            let selection_color = props.get::<SelectionColor>().color;
+            for rect in highlight_rects(layout, selection.clone()) {
+                scene.fill(
+                    Fill::NonZero,
+                    Affine::IDENTITY,
+                    selection_color,
+                    None,
+                    &rect,
+                );
+            }
+        }
         if ctx.is_focus_target() {
             let caret_color = props.get::<CaretColor>().color;
             let selection_color = if !ctx.is_window_focused()
@@ -1033,21 +2132,46 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
             props.get::<ContentColor>()
         };
 
-        render_text(
-            scene,
-            Affine::IDENTITY,
-            layout,
-            &[text_color.color.into()],
-            self.hint,
-        );
+        if let Some(rich_text) = self.active_rich_text() {
+            let link_color = props.get::<LinkColor>().color;
+            render_text_with_paint_spans(
+                scene,
+                Affine::IDENTITY,
+                layout,
+                rich_text,
+                &rich_text.brushes(text_color.color, link_color),
+                self.hint,
+            );
+        } else {
+            render_text(
+                scene,
+                Affine::IDENTITY,
+                layout,
+                &[text_color.color.into()],
+                self.hint,
+            );
+        }
+
+        for (_, decoration) in self.active_decorations() {
+            paint_decoration(scene, layout, decoration);
+        }
     }
 
-    fn get_cursor(&self, _ctx: &QueryCtx<'_>, _pos: Point) -> CursorIcon {
-        CursorIcon::Text
+    fn get_cursor(&self, ctx: &QueryCtx<'_>, pos: Point) -> CursorIcon {
+        let local_pos = ctx.window_transform().inverse() * pos;
+        if !EDITABLE && self.link_at_point(local_pos).is_some() {
+            CursorIcon::Pointer
+        } else {
+            CursorIcon::Text
+        }
     }
 
     fn accessibility_role(&self) -> Role {
-        if EDITABLE {
+        if self.secure {
+            Role::PasswordInput
+        } else if self.search_field {
+            Role::SearchInput
+        } else if EDITABLE {
             match self.insert_newline {
                 InsertNewline::OnShiftEnter | InsertNewline::OnEnter => Role::MultilineTextInput,
                 _ => Role::TextInput,
@@ -1066,6 +2190,9 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         if !EDITABLE {
             node.set_read_only();
         }
+        if self.invalid {
+            node.set_invalid(accesskit::Invalid::True);
+        }
         let updated =
             self.editor
                 .try_accessibility(ctx.tree_update(), node, AccessCtx::next_node_id, 0., 0.);
@@ -1081,11 +2208,34 @@ impl<const EDITABLE: bool> Widget for TextArea<EDITABLE> {
         ChildrenIds::new()
     }
 
+    fn selectable_text(&self) -> Option<&str> {
+        if EDITABLE || self.secret.is_some() {
+            return None;
+        }
+        Some(self.editor.raw_text())
+    }
+
+    fn selectable_text_index(&self, pos: Point) -> usize {
+        self.editor.try_layout().map_or(0, |layout| {
+            Cursor::from_point(layout, pos.x as f32, pos.y as f32).index()
+        })
+    }
+
+    fn set_scoped_selection(&mut self, selection: Option<Range<usize>>) {
+        self.scoped_selection = selection.filter(|range| {
+            let text = self.editor.raw_text();
+            text.is_char_boundary(range.start) && text.is_char_boundary(range.end)
+        });
+    }
+
     fn make_trace_span(&self, id: WidgetId) -> Span {
         trace_span!("TextArea", id = id.trace())
     }
 
     fn get_debug_text(&self) -> Option<String> {
+        if self.secure {
+            return Some(mask(&self.text().to_string()).chars().take(100).collect());
+        }
         Some(self.editor.text().chars().take(100).collect())
     }
 }
@@ -1104,6 +2254,319 @@ pub enum InsertNewline {
     Never,
 }
 
+/// A decoration drawn under a range of a text area's text, such as a spelling mistake.
+///
+/// See [`TextArea::set_decorations`] for details.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextDecoration {
+    /// The byte range of the decorated text.
+    pub range: Range<usize>,
+    /// How the decoration is drawn.
+    pub style: DecorationStyle,
+    /// The color of the decoration.
+    pub color: Color,
+    /// A description of the decoration, such as a diagnostic, to show when it is hovered.
+    ///
+    /// The text area doesn't show this itself.
+    pub message: Option<ArcStr>,
+}
+
+impl TextDecoration {
+    /// Creates a new decoration of the given byte range, without a message.
+    pub fn new(range: Range<usize>, style: DecorationStyle, color: Color) -> Self {
+        Self {
+            range,
+            style,
+            color,
+            message: None,
+        }
+    }
+
+    /// Sets the description of the decoration, such as a diagnostic.
+    pub fn with_message(mut self, message: impl Into<ArcStr>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// How a [`TextDecoration`] is drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DecorationStyle {
+    /// A wavy underline, commonly used for spelling mistakes and errors.
+    #[default]
+    Squiggle,
+    /// A dotted underline, commonly used for hints.
+    Dotted,
+    /// A solid underline.
+    Solid,
+}
+
+/// Adjusts the ranges of `decorations` for the edit which changed the text from `before` to `after`.
+fn adjust_decorations(decorations: &mut [TextDecoration], before: &str, after: &str) {
+    if decorations.is_empty() || before == after {
+        return;
+    }
+    // The edit replaced `start..old_end` of `before` with `start..new_end` of `after`.
+    let mut prefix = before
+        .bytes()
+        .zip(after.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !before.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+    let mut suffix = before
+        .bytes()
+        .rev()
+        .zip(after.bytes().rev())
+        .take(before.len().min(after.len()) - prefix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !before.is_char_boundary(before.len() - suffix) {
+        suffix -= 1;
+    }
+    let (start, old_end, new_end) = (prefix, before.len() - suffix, after.len() - suffix);
+
+    for decoration in decorations {
+        let range = &mut decoration.range;
+        let shift = |offset: usize| offset - old_end + new_end;
+        // Text inserted at the start of a decoration isn't decorated; at its end, it is.
+        range.start = if range.start < start {
+            range.start
+        } else if range.start >= old_end {
+            shift(range.start)
+        } else {
+            new_end
+        };
+        range.end = if range.end <= start {
+            range.end
+        } else if range.end >= old_end {
+            shift(range.end)
+        } else {
+            start
+        }
+        .max(range.start);
+    }
+}
+
+/// Paints a decoration under its range of the laid out text.
+fn paint_decoration(scene: &mut Scene, layout: &Layout<BrushIndex>, decoration: &TextDecoration) {
+    let range = &decoration.range;
+    let selection = Selection::new(
+        Cursor::from_byte_index(layout, range.start, Affinity::Downstream),
+        Cursor::from_byte_index(layout, range.end, Affinity::Upstream),
+    );
+    for (rect, line) in selection.geometry(layout) {
+        let Some(line) = layout.get(line) else {
+            continue;
+        };
+        let metrics = line.metrics();
+        let y = f64::from(metrics.baseline + metrics.descent * 0.5).min(rect.y1 - 1.5);
+        match decoration.style {
+            DecorationStyle::Squiggle => {
+                // A zigzag with a period of 4px.
+                let mut path = BezPath::new();
+                path.move_to((rect.x0, y));
+                let mut x = rect.x0;
+                let mut up = true;
+                while x < rect.x1 {
+                    x = (x + 2.).min(rect.x1);
+                    path.line_to((x, if up { y - 1. } else { y + 1. }));
+                    up = !up;
+                }
+                let stroke = Stroke::new(1.);
+                scene.stroke(&stroke, Affine::IDENTITY, decoration.color, None, &path);
+            }
+            DecorationStyle::Dotted => {
+                let line = Line::new((rect.x0, y), (rect.x1, y));
+                let stroke = Stroke::new(1.5).with_dashes(0., [1.5, 1.5]);
+                scene.stroke(&stroke, Affine::IDENTITY, decoration.color, None, &line);
+            }
+            DecorationStyle::Solid => {
+                let line = Line::new((rect.x0, y), (rect.x1, y));
+                let stroke = Stroke::new(1.);
+                scene.stroke(&stroke, Affine::IDENTITY, decoration.color, None, &line);
+            }
+        }
+    }
+}
+
+/// Returns the rectangles covering the given byte range of the laid out text.
+pub(crate) fn highlight_rects(
+    layout: &Layout<BrushIndex>,
+    range: Range<usize>,
+) -> impl Iterator<Item = Rect> {
+    let selection = Selection::new(
+        Cursor::from_byte_index(layout, range.start, Affinity::Downstream),
+        Cursor::from_byte_index(layout, range.end, Affinity::Upstream),
+    );
+    selection
+        .geometry(layout)
+        .into_iter()
+        .map(|(rect, _)| bounding_box_to_rect(rect))
+}
+
+// --- MARK: MASKING
+
+/// Returns the mask of `text`, with one [`MASK_CHAR`] per grapheme.
+fn mask(text: &str) -> String {
+    text.graphemes(true).map(|_| MASK_CHAR).collect()
+}
+
+/// Converts a byte offset in `text` to the matching byte offset in its mask.
+fn mask_offset(text: &str, offset: usize) -> usize {
+    let prefix = text.get(..offset).unwrap_or(text);
+    prefix.graphemes(true).count() * MASK_CHAR.len_utf8()
+}
+
+/// Converts a byte offset in the mask of `text` to the matching byte offset in `text`.
+fn unmask_offset(text: &str, offset: usize) -> usize {
+    let index = offset / MASK_CHAR.len_utf8();
+    text.grapheme_indices(true)
+        .nth(index)
+        .map_or(text.len(), |(offset, _)| offset)
+}
+
+// --- MARK: HISTORY
+
+/// The maximum number of steps kept in the undo history.
+const MAX_UNDO_STEPS: usize = 256;
+
+/// The undo and redo stacks of a text area.
+///
+/// Each step only stores the text which it replaces, rather than a snapshot of the whole text.
+#[derive(Debug, Default)]
+struct EditHistory {
+    /// The changes which undo each undoable edit, most recent last.
+    undo: VecDeque<EditDiff>,
+    /// The changes which redo each undone edit, most recent last.
+    redo: Vec<EditDiff>,
+    /// The kind of the last recorded edit, if following edits of the same kind
+    /// should be merged into it.
+    group: Option<EditKind>,
+    /// Whether the IME has shown a preedit since its last commit.
+    composed: bool,
+}
+
+/// A snapshot of the text and selection of a text area.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct EditState {
+    text: String,
+    anchor: usize,
+    focus: usize,
+}
+
+/// A change to the text and selection of a text area, stored in its [`EditHistory`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct EditDiff {
+    /// The byte range of the text to replace.
+    range: Range<usize>,
+    /// The text which replaces `range`.
+    text: String,
+    /// The anchor of the selection after the change.
+    anchor: usize,
+    /// The focus of the selection after the change.
+    focus: usize,
+}
+
+/// The kinds of edit, used to merge consecutive edits into a single undo step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EditKind {
+    /// Inserting text by typing. Consecutive typing is merged.
+    Typing,
+    /// Deleting text with Backspace or Delete. Consecutive deletions are merged.
+    Deleting,
+    /// Any other edit, such as pasting or committing an IME composition, which is always its own step.
+    Other,
+}
+
+impl EditDiff {
+    /// Returns the change which turns `current` into the `target` state.
+    ///
+    /// The replaced range is the smallest one, outside of which both texts are the same.
+    fn new(current: &str, target: &EditState) -> Self {
+        let target_text = target.text.as_str();
+        let mut prefix = current
+            .bytes()
+            .zip(target_text.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !current.is_char_boundary(prefix) {
+            prefix -= 1;
+        }
+        let max_suffix = current.len().min(target_text.len()) - prefix;
+        let mut suffix = current
+            .bytes()
+            .rev()
+            .zip(target_text.bytes().rev())
+            .take(max_suffix)
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !current.is_char_boundary(current.len() - suffix) {
+            suffix -= 1;
+        }
+        Self {
+            range: prefix..current.len() - suffix,
+            text: target_text[prefix..target_text.len() - suffix].to_string(),
+            anchor: target.anchor,
+            focus: target.focus,
+        }
+    }
+
+    /// Applies this change to `text`, returning the resulting state.
+    fn apply(&self, text: &str) -> EditState {
+        let mut text = text.to_string();
+        text.replace_range(self.range.clone(), &self.text);
+        EditState {
+            text,
+            anchor: self.anchor,
+            focus: self.focus,
+        }
+    }
+}
+
+impl EditHistory {
+    /// Records an edit which changed the text from the `before` state to `after`.
+    fn record(&mut self, before: EditState, after: &str, kind: EditKind) {
+        self.redo.clear();
+        if kind != EditKind::Other
+            && self.group == Some(kind)
+            && let Some(last) = self.undo.back_mut()
+        {
+            // Merge this edit into the last one, so that they are undone together.
+            let original = last.apply(&before.text);
+            *last = EditDiff::new(after, &original);
+            return;
+        }
+        if self.undo.len() == MAX_UNDO_STEPS {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(EditDiff::new(after, &before));
+        self.group = (kind != EditKind::Other).then_some(kind);
+    }
+
+    /// Stops the next edit from being merged into the previous one.
+    fn break_group(&mut self) {
+        self.group = None;
+    }
+
+    /// Returns the state to restore to undo the last edit, given the `current` state.
+    fn undo(&mut self, current: EditState) -> Option<EditState> {
+        let state = self.undo.pop_back()?.apply(&current.text);
+        self.redo.push(EditDiff::new(&state.text, &current));
+        self.group = None;
+        Some(state)
+    }
+
+    /// Returns the state to restore to redo the last undone edit, given the `current` state.
+    fn redo(&mut self, current: EditState) -> Option<EditState> {
+        let state = self.redo.pop()?.apply(&current.text);
+        self.undo.push_back(EditDiff::new(&state.text, &current));
+        self.group = None;
+        Some(state)
+    }
+}
+
 // TODO: What other tests can we have? Some options:
 // - Clicking in the right place changes the selection as expected?
 // - Keyboard actions have expected results?
@@ -1119,6 +2582,7 @@ mod tests {
     use crate::palette;
     use crate::testing::TestHarness;
     use crate::theme::test_property_set;
+    use crate::widgets::Portal;
     // Tests of alignment happen in Prose.
 
     #[test]
@@ -1285,7 +2749,11 @@ mod tests {
             assert_eq!(widget_id, text_id);
 
             // Check that only the one action was emitted so we don't miss an error case
-            // where Entered _and_ Changed actions are emitted
+            // where Entered _and_ Changed actions are emitted.
+            // Inserting a newline also moves the caret, which is reported separately.
+            while let Some((action, _)) = harness.pop_action::<TextAction>() {
+                assert!(matches!(action, TextAction::SelectionChanged { .. }));
+            }
             assert!(harness.pop_action_erased().is_none());
 
             if scenario.expect_text_entered_event {
@@ -1297,4 +2765,598 @@ mod tests {
             }
         }
     }
+
+    /// The modifier used for keyboard shortcuts such as undo.
+    fn action_mod() -> Modifiers {
+        if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        }
+    }
+
+    fn press(harness: &mut TestHarness<TextArea<true>>, key: Key, modifiers: Modifiers) {
+        harness.process_text_event(TextEvent::Keyboard(KeyboardEvent {
+            key,
+            modifiers,
+            ..Default::default()
+        }));
+    }
+
+    fn shortcut(harness: &mut TestHarness<TextArea<true>>, key: &str, modifiers: Modifiers) {
+        press(
+            harness,
+            Key::Character(key.into()),
+            action_mod() | modifiers,
+        );
+    }
+
+    fn text(harness: &TestHarness<TextArea<true>>) -> String {
+        harness.root_widget().text().to_string()
+    }
+
+    fn take_actions(harness: &mut TestHarness<TextArea<true>>) -> Vec<TextAction> {
+        std::iter::from_fn(|| harness.pop_action::<TextAction>().map(|(action, _)| action))
+            .collect()
+    }
+
+    #[test]
+    fn undo_typing_by_word() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+
+        harness.keyboard_type_chars("hello world");
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        assert_eq!(text(&harness), "hello wor");
+        while harness.pop_action::<TextAction>().is_some() {}
+
+        shortcut(&mut harness, "z", Modifiers::default());
+        assert_eq!(text(&harness), "hello world");
+        assert_eq!(
+            harness.pop_action::<TextAction>().unwrap().0,
+            TextAction::Changed("hello world".to_string())
+        );
+        shortcut(&mut harness, "z", Modifiers::default());
+        assert_eq!(text(&harness), "hello ");
+        shortcut(&mut harness, "z", Modifiers::default());
+        assert_eq!(text(&harness), "");
+        assert!(!harness.root_widget().can_undo());
+
+        shortcut(&mut harness, "Z", Modifiers::SHIFT);
+        assert_eq!(text(&harness), "hello ");
+        shortcut(&mut harness, "y", Modifiers::default());
+        assert_eq!(text(&harness), "hello world");
+
+        // A new edit discards the redo history.
+        harness.keyboard_type_chars("!");
+        assert!(!harness.root_widget().can_redo());
+        shortcut(&mut harness, "y", Modifiers::default());
+        assert_eq!(text(&harness), "hello world!");
+    }
+
+    #[test]
+    fn moving_caret_ends_group() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+
+        harness.keyboard_type_chars("ab");
+        press(
+            &mut harness,
+            Key::Named(NamedKey::ArrowLeft),
+            Modifiers::default(),
+        );
+        harness.keyboard_type_chars("c");
+        assert_eq!(text(&harness), "acb");
+
+        shortcut(&mut harness, "z", Modifiers::default());
+        assert_eq!(text(&harness), "ab");
+        shortcut(&mut harness, "z", Modifiers::default());
+        assert_eq!(text(&harness), "");
+    }
+
+    #[test]
+    fn paste_and_reset_text_are_undo_steps() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+
+        harness.keyboard_type_chars("one");
+        harness.process_text_event(TextEvent::ClipboardPaste("two".to_string()));
+        harness.keyboard_type_chars("three");
+        harness.edit_root_widget(|mut area| TextArea::reset_text(&mut area, "four"));
+        assert_eq!(text(&harness), "four");
+
+        harness.edit_root_widget(|mut area| assert!(TextArea::undo(&mut area)));
+        assert_eq!(text(&harness), "onetwothree");
+        shortcut(&mut harness, "z", Modifiers::default());
+        assert_eq!(text(&harness), "onetwo");
+        shortcut(&mut harness, "z", Modifiers::default());
+        assert_eq!(text(&harness), "one");
+
+        harness.edit_root_widget(|mut area| assert!(TextArea::redo(&mut area)));
+        assert_eq!(text(&harness), "onetwo");
+    }
+
+    #[test]
+    fn history_stores_changed_text() {
+        let state = |text: &str, caret| EditState {
+            text: text.to_string(),
+            anchor: caret,
+            focus: caret,
+        };
+        let mut history = EditHistory::default();
+
+        // Typing in the middle of a long text only stores the typed characters.
+        let long = "x".repeat(100);
+        let before = state(&format!("{long}éa{long}"), 103);
+        let after = format!("{long}éab{long}");
+        history.record(before.clone(), &after, EditKind::Typing);
+        assert_eq!(history.undo[0].range, 103..104);
+        assert_eq!(history.undo[0].text, "");
+
+        // A merged edit is stored as a single change.
+        let after_more = format!("{long}éabc{long}");
+        history.record(state(&after, 104), &after_more, EditKind::Typing);
+        assert_eq!(history.undo.len(), 1);
+        assert_eq!(history.undo[0].range, 103..105);
+
+        // Replacing a character doesn't split the characters around it.
+        let replaced = format!("{long}èabc{long}");
+        history.record(state(&after_more, 105), &replaced, EditKind::Other);
+        assert_eq!(history.undo[1].range, 100..102);
+        assert_eq!(history.undo[1].text, "é");
+
+        let undone = history.undo(state(&replaced, 102)).unwrap();
+        assert_eq!(undone, state(&after_more, 105));
+        let undone = history.undo(undone).unwrap();
+        assert_eq!(undone, before);
+        let redone = history.redo(undone).unwrap();
+        assert_eq!(redone, state(&after_more, 105));
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut history = EditHistory::default();
+        let mut text = String::new();
+        for i in 0..MAX_UNDO_STEPS + 10 {
+            let before = EditState {
+                text: text.clone(),
+                anchor: i,
+                focus: i,
+            };
+            text.push('a');
+            history.record(before, &text, EditKind::Other);
+        }
+        assert_eq!(history.undo.len(), MAX_UNDO_STEPS);
+        // The oldest steps were discarded.
+        assert_eq!(history.undo[0].anchor, 10);
+    }
+
+    #[test]
+    fn ime_composition_is_undo_step() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+
+        harness.keyboard_type_chars("a");
+        harness.process_text_event(TextEvent::Ime(Ime::Preedit("ni".to_string(), None)));
+        harness.process_text_event(TextEvent::Ime(Ime::Preedit(String::new(), None)));
+        harness.process_text_event(TextEvent::Ime(Ime::Commit("你".to_string())));
+        assert_eq!(text(&harness), "a你");
+
+        shortcut(&mut harness, "z", Modifiers::default());
+        assert_eq!(text(&harness), "a");
+    }
+
+    #[test]
+    fn clear_history() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+
+        harness.keyboard_type_chars("text");
+        assert!(harness.root_widget().can_undo());
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::clear_history(&mut area);
+            assert!(!TextArea::undo(&mut area));
+        });
+        assert!(!harness.root_widget().can_undo());
+        assert_eq!(text(&harness), "text");
+    }
+
+    #[test]
+    fn secure_text_is_masked() {
+        let area = NewWidget::new(TextArea::new_editable("").with_secure(true));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+
+        // The combining accent is typed separately, but forms a single grapheme with the "e".
+        harness.keyboard_type_chars("pwe\u{301}");
+        harness.process_text_event(TextEvent::ClipboardPaste("d!".to_string()));
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        assert_eq!(text(&harness), "pwe\u{301}d");
+        assert_eq!(harness.root_widget().editor.raw_text(), "••••");
+        assert_eq!(
+            harness.root_widget().get_debug_text().as_deref(),
+            Some("••••")
+        );
+        let mut last_action = None;
+        while let Some((action, _)) = harness.pop_action::<TextAction>() {
+            if !matches!(action, TextAction::SelectionChanged { .. }) {
+                last_action = Some(action);
+            }
+        }
+        assert_eq!(last_action, Some(TextAction::Changed("pwe\u{301}d".into())));
+
+        // Secure text can't be copied or cut, and isn't kept in the undo history.
+        shortcut(&mut harness, "a", Modifiers::default());
+        shortcut(&mut harness, "c", Modifiers::default());
+        shortcut(&mut harness, "x", Modifiers::default());
+        assert_eq!(harness.clipboard_contents(), "");
+        assert_eq!(text(&harness), "pwe\u{301}d");
+        assert!(!harness.root_widget().can_undo());
+
+        assert_eq!(
+            harness.root_widget().accessibility_role(),
+            Role::PasswordInput
+        );
+    }
+
+    #[test]
+    fn reveal_secure_text() {
+        let area = NewWidget::new(TextArea::new_editable("secret").with_secure(true));
+        let mut harness = TestHarness::create(test_property_set(), area);
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::select_byte_range(&mut area, 2, 4);
+            TextArea::set_revealed(&mut area, true);
+        });
+        let area = harness.root_widget();
+        assert!(!area.is_masked());
+        assert_eq!(area.editor.raw_text(), "secret");
+        assert_eq!(area.editor.selected_text(), Some("cr"));
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::set_revealed(&mut area, false);
+        });
+        let area = harness.root_widget();
+        assert!(area.is_masked());
+        assert_eq!(area.text().to_string(), "secret");
+        assert_eq!(area.editor.raw_text(), "••••••");
+        assert_eq!(area.editor.selected_text(), Some("••"));
+
+        harness.edit_root_widget(|mut area| {
+            TextArea::set_secure(&mut area, false);
+        });
+        assert_eq!(harness.root_widget().editor.raw_text(), "secret");
+    }
+
+    #[test]
+    fn filter_rejects_edits() {
+        let area = NewWidget::new(
+            TextArea::new_editable("").with_filter(|text| text.chars().all(|c| c.is_ascii_digit())),
+        );
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+
+        harness.keyboard_type_chars("1a2");
+        harness.process_text_event(TextEvent::ClipboardPaste("3b".to_string()));
+        harness.process_text_event(TextEvent::ClipboardPaste("45".to_string()));
+        assert_eq!(text(&harness), "1245");
+
+        harness.edit_root_widget(|mut area| TextArea::clear_filter(&mut area));
+        harness.keyboard_type_chars("x");
+        assert_eq!(text(&harness), "1245x");
+    }
+
+    #[test]
+    fn rejected_commit_is_not_reported() {
+        let area = NewWidget::new(
+            TextArea::new_editable("").with_filter(|text| text.chars().all(|c| c.is_ascii_digit())),
+        );
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+        while harness.pop_action_erased().is_some() {}
+
+        harness.process_text_event(TextEvent::Ime(Ime::Commit("x".to_string())));
+        assert_eq!(text(&harness), "");
+        assert!(harness.pop_action::<TextAction>().is_none());
+
+        harness.process_text_event(TextEvent::Ime(Ime::Commit("2".to_string())));
+        assert_eq!(
+            harness.pop_action::<TextAction>().map(|(action, _)| action),
+            Some(TextAction::Changed("2".into()))
+        );
+    }
+
+    #[test]
+    fn max_length_truncates_insertions() {
+        let area = NewWidget::new(TextArea::new_editable("").with_max_length(Some(4)));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+
+        // The combining accent forms a single grapheme with the "e".
+        harness.keyboard_type_chars("ae\u{301}");
+        harness.process_text_event(TextEvent::ClipboardPaste("bcd".to_string()));
+        assert_eq!(text(&harness), "ae\u{301}bc");
+        harness.keyboard_type_chars("z");
+        assert_eq!(text(&harness), "ae\u{301}bc");
+
+        // Replacing the selection frees up its graphemes.
+        shortcut(&mut harness, "a", Modifiers::default());
+        harness.process_text_event(TextEvent::ClipboardPaste("vwxyz".to_string()));
+        assert_eq!(text(&harness), "vwxy");
+    }
+
+    #[test]
+    fn invalid_state() {
+        let area = NewWidget::new(TextArea::new_editable("abc"));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        assert!(!harness.root_widget().is_invalid());
+
+        harness.edit_root_widget(|mut area| TextArea::set_invalid(&mut area, true));
+        assert!(harness.root_widget().is_invalid());
+        // The accessibility tree is only updated when rendering.
+        let _ = harness.render();
+        let node = harness.access_node(harness.root_id()).unwrap();
+        assert_eq!(node.data().invalid(), Some(accesskit::Invalid::True));
+    }
+
+    #[test]
+    fn link_click() {
+        let mut builder = RichText::builder();
+        builder
+            .push_link("docs", "https://docs.rs")
+            .push(" and some more text");
+        let area = NewWidget::new(TextArea::new_immutable("").with_rich_text(builder.build()));
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), area, Size::new(400.0, 40.0));
+
+        harness.mouse_move((5.0, 8.0));
+        assert_eq!(harness.cursor_icon(), CursorIcon::Pointer);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(
+            harness.pop_action::<TextAction>().map(|(action, _)| action),
+            Some(TextAction::LinkClicked("https://docs.rs".into()))
+        );
+
+        // Selecting part of a link doesn't follow it.
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_move((20.0, 8.0));
+        assert!(harness.root_widget().editor.selected_text().is_some());
+        harness.mouse_button_release(PointerButton::Primary);
+        assert_eq!(harness.pop_action::<TextAction>(), None);
+
+        // Changing the text drops the spans.
+        harness.edit_root_widget(|mut area| TextArea::reset_text(&mut area, "docs"));
+        harness.mouse_move((6.0, 8.0));
+        assert_eq!(harness.cursor_icon(), CursorIcon::Text);
+    }
+
+    #[test]
+    fn selection_changes_are_reported() {
+        let area = NewWidget::new(TextArea::new_editable(""));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+
+        harness.keyboard_type_chars("ab");
+        assert_eq!(
+            take_actions(&mut harness),
+            [
+                TextAction::Changed("a".into()),
+                TextAction::SelectionChanged {
+                    anchor: 1,
+                    focus: 1
+                },
+                TextAction::Changed("ab".into()),
+                TextAction::SelectionChanged {
+                    anchor: 2,
+                    focus: 2
+                },
+            ]
+        );
+
+        press(&mut harness, Key::Named(NamedKey::Home), Modifiers::SHIFT);
+        assert_eq!(
+            take_actions(&mut harness),
+            [TextAction::SelectionChanged {
+                anchor: 2,
+                focus: 0
+            }]
+        );
+
+        // Programmatic changes aren't reported, and don't cause later reports.
+        harness.edit_root_widget(|mut area| TextArea::reset_text(&mut area, "abc"));
+        press(
+            &mut harness,
+            Key::Named(NamedKey::End),
+            Modifiers::default(),
+        );
+        assert_eq!(take_actions(&mut harness), []);
+
+        // The caret is in window coordinates.
+        let caret = harness.edit_root_widget(|area| TextArea::caret_rect(&area));
+        let caret = caret.expect("text area has been laid out");
+        let origin = harness.root_widget().ctx().window_origin();
+        assert!(caret.x0 > origin.x);
+    }
+
+    #[test]
+    fn composition_is_reported() {
+        let area = NewWidget::new(TextArea::new_editable("a"));
+        let mut harness = TestHarness::create(test_property_set(), area);
+        harness.focus_on(Some(harness.root_id()));
+        harness.edit_root_widget(|mut area| TextArea::select_byte_range(&mut area, 1, 1));
+
+        harness.process_text_event(TextEvent::Ime(Ime::Preedit("ni".to_string(), Some((2, 2)))));
+        assert_eq!(
+            take_actions(&mut harness),
+            [TextAction::Composing {
+                preedit: "ni".into(),
+                cursor: Some((2, 2))
+            }]
+        );
+
+        // Platforms clear the preedit before committing it.
+        harness.process_text_event(TextEvent::Ime(Ime::Preedit(String::new(), None)));
+        assert_eq!(take_actions(&mut harness), [TextAction::CompositionEnded]);
+        harness.process_text_event(TextEvent::Ime(Ime::Commit("你".to_string())));
+        assert_eq!(
+            take_actions(&mut harness),
+            [
+                TextAction::Changed("a你".into()),
+                TextAction::SelectionChanged {
+                    anchor: 4,
+                    focus: 4
+                },
+            ]
+        );
+
+        harness.process_text_event(TextEvent::Ime(Ime::Preedit("x".to_string(), None)));
+        harness.process_text_event(TextEvent::Ime(Ime::Preedit(String::new(), None)));
+        assert_eq!(
+            take_actions(&mut harness),
+            [
+                TextAction::Composing {
+                    preedit: "x".into(),
+                    cursor: None
+                },
+                TextAction::CompositionEnded,
+            ]
+        );
+    }
+
+    #[test]
+    fn find_matches() {
+        let area = TextArea::new_immutable("Straße, STRASSE and straße");
+        assert_eq!(area.find("straße", true), vec![21..28]);
+        assert_eq!(area.find("STRAßE", false), vec![0..7, 21..28]);
+        assert_eq!(area.find("ss", false), vec![13..15]);
+        assert!(area.find("", false).is_empty());
+    }
+
+    #[test]
+    fn active_highlight_scrolls_portal() {
+        let text = (0..50).map(|i| format!("line {i}\n")).collect::<String>();
+        let area = TextArea::new_immutable(&text);
+        let matches = area.find("line 45", true);
+        let portal = Portal::new(area.with_highlights(matches).with_auto_id()).with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), portal, Size::new(200.0, 100.0));
+        assert_eq!(harness.root_widget().get_viewport_pos(), Point::ORIGIN);
+
+        harness.edit_root_widget(|mut portal| {
+            let mut area = Portal::child_mut(&mut portal);
+            assert_eq!(TextArea::next_highlight(&mut area), Some(0));
+            assert_eq!(TextArea::next_highlight(&mut area), Some(0));
+            assert_eq!(area.widget.active_highlight(), Some(0));
+        });
+        assert!(harness.root_widget().get_viewport_pos().y > 0.);
+
+        // Replacing the text clears the highlights.
+        harness.edit_root_widget(|mut portal| {
+            let mut area = Portal::child_mut(&mut portal);
+            TextArea::reset_text(&mut area, "line 45");
+            assert!(area.widget.highlights().is_empty());
+            assert_eq!(TextArea::next_highlight(&mut area), None);
+        });
+    }
+
+    #[test]
+    fn decorations_follow_edits() {
+        let squiggle =
+            |range| TextDecoration::new(range, DecorationStyle::Squiggle, palette::css::RED);
+        let area = TextArea::new_editable("helo wrld")
+            .with_decorations(vec![squiggle(0..4), squiggle(5..9)])
+            .with_auto_id();
+        let mut harness = TestHarness::create(test_property_set(), area);
+        let ranges = |harness: &TestHarness<TextArea<true>>| {
+            let area = harness.root_widget();
+            let ranges = area.decorations().iter().map(|d| d.range.clone());
+            ranges.collect::<Vec<_>>()
+        };
+        harness.focus_on(Some(harness.root_id()));
+
+        // Typing inside a decoration extends it.
+        harness.edit_root_widget(|mut area| TextArea::select_byte_range(&mut area, 3, 3));
+        harness.keyboard_type_chars("l");
+        assert_eq!(ranges(&harness), vec![0..5, 6..10]);
+
+        // Typing before a decoration moves it.
+        harness.edit_root_widget(|mut area| TextArea::select_byte_range(&mut area, 0, 0));
+        harness.keyboard_type_chars("¡");
+        assert_eq!(ranges(&harness), vec![2..7, 8..12]);
+
+        // Replacing text inside a decoration keeps it.
+        harness.edit_root_widget(|mut area| TextArea::select_text(&mut area, "wr"));
+        harness.keyboard_type_chars("wo");
+        assert_eq!(text(&harness), "¡hello wold");
+        assert_eq!(ranges(&harness), vec![2..7, 8..12]);
+
+        // Replacing the start of a decoration shrinks it.
+        harness.edit_root_widget(|mut area| TextArea::select_text(&mut area, "o wo"));
+        harness.keyboard_type_chars("_");
+        assert_eq!(text(&harness), "¡hell_ld");
+        assert_eq!(ranges(&harness), vec![2..6, 7..9]);
+
+        // Deleting the decorated text empties the decoration.
+        harness.edit_root_widget(|mut area| TextArea::select_text(&mut area, "_ld"));
+        press(
+            &mut harness,
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        );
+        assert_eq!(ranges(&harness), vec![2..6, 6..6]);
+
+        // Resetting the text keeps the decorations on unchanged text.
+        harness.edit_root_widget(|mut area| TextArea::reset_text(&mut area, "¡hell!"));
+        assert_eq!(ranges(&harness), vec![2..6, 7..7]);
+    }
+
+    #[test]
+    fn decoration_hover() {
+        let decoration = TextDecoration::new(5..14, DecorationStyle::Dotted, palette::css::BLUE)
+            .with_message("Unknown word");
+        let area = TextArea::new_immutable("some misspeled words")
+            .with_decorations(vec![decoration])
+            .with_auto_id();
+        let mut harness =
+            TestHarness::create_with_size(test_property_set(), area, Size::new(400.0, 40.0));
+        let mut rect = None;
+        harness.edit_root_widget(|area| rect = TextArea::decoration_rect(&area, 0));
+        let rect = rect.unwrap();
+
+        harness.mouse_move((2.0, rect.center().y));
+        assert_eq!(harness.pop_action::<TextAction>(), None);
+        harness.mouse_move(rect.center());
+        assert_eq!(harness.root_widget().hovered_decoration(), Some(0));
+        assert_eq!(
+            harness.pop_action::<TextAction>().map(|(action, _)| action),
+            Some(TextAction::DecorationHovered(Some(0)))
+        );
+        harness.mouse_move((rect.x0 + 1.0, rect.center().y));
+        assert_eq!(harness.pop_action::<TextAction>(), None);
+        harness.mouse_move((2.0, rect.center().y));
+        assert_eq!(
+            harness.pop_action::<TextAction>().map(|(action, _)| action),
+            Some(TextAction::DecorationHovered(None))
+        );
+    }
 }
@@ -17,8 +17,8 @@ use crate::core::{
 use crate::kurbo::{Axis, Point, Size};
 use crate::layout::{LayoutSize, LenReq};
 use crate::properties::{
-    CaretColor, ContentColor, FocusedBorderColor, LineBreaking, PlaceholderColor, SelectionColor,
-    UnfocusedSelectionColor,
+    CaretColor, ContentColor, FocusedBorderColor, InvalidBorderColor, LineBreaking,
+    PlaceholderColor, SelectionColor, UnfocusedSelectionColor,
 };
 use crate::widgets::{Label, TextArea};
 
@@ -34,6 +34,9 @@ use crate::widgets::{Label, TextArea};
 ///
 /// At runtime, most properties of the text will be set using [`text_mut`](Self::text_mut).
 /// This is because `TextInput` largely serves as a wrapper around a [`TextArea`].
+///
+/// The text input can be [marked as invalid](Self::set_invalid), e.g. after validating its text,
+/// in which case its border uses the [`InvalidBorderColor`] property.
 pub struct TextInput {
     text: WidgetPod<TextArea<true>>,
 
@@ -47,6 +50,9 @@ pub struct TextInput {
 
     /// Whether to clip the contained text.
     clip: bool,
+
+    /// Whether the text is marked as invalid.
+    invalid: bool,
 }
 
 // --- MARK: BUILDERS
@@ -66,6 +72,7 @@ impl TextInput {
             placeholder_text: "".into(),
             text_alignment: TextAlign::default(),
             clip: false,
+            invalid: false,
         }
     }
 
@@ -96,6 +103,14 @@ impl TextInput {
         self.clip = clip;
         self
     }
+
+    /// Sets whether the text is marked as invalid.
+    ///
+    /// To modify this on active text input, use [`set_invalid`](Self::set_invalid).
+    pub fn with_invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
 }
 
 // --- MARK: METHODS
@@ -106,6 +121,11 @@ impl TextInput {
     pub fn area_pod(&self) -> &WidgetPod<TextArea<true>> {
         &self.text
     }
+
+    /// Returns `true` if the text is marked as invalid.
+    pub fn is_invalid(&self) -> bool {
+        self.invalid
+    }
 }
 
 // --- MARK: WIDGETMUT
@@ -140,6 +160,18 @@ impl TextInput {
         this.ctx.request_layout();
     }
 
+    /// Sets whether the text is marked as invalid.
+    ///
+    /// An invalid text input paints its border with the [`InvalidBorderColor`] property,
+    /// and its text area is reported as [invalid](TextArea::set_invalid) to accessibility technology.
+    ///
+    /// The runtime equivalent of [`with_invalid`](Self::with_invalid).
+    pub fn set_invalid(this: &mut WidgetMut<'_, Self>, invalid: bool) {
+        this.widget.invalid = invalid;
+        TextArea::set_invalid(&mut Self::text_mut(this), invalid);
+        this.ctx.request_pre_paint();
+    }
+
     /// Sets the text alignment for both the input text and placeholder.
     pub fn set_text_alignment(this: &mut WidgetMut<'_, Self>, text_alignment: TextAlign) {
         this.widget.text_alignment = text_alignment;
@@ -149,6 +181,7 @@ impl TextInput {
 }
 
 impl HasProperty<CaretColor> for TextInput {}
+impl HasProperty<InvalidBorderColor> for TextInput {}
 impl HasProperty<PlaceholderColor> for TextInput {}
 impl HasProperty<SelectionColor> for TextInput {}
 impl HasProperty<UnfocusedSelectionColor> for TextInput {}
@@ -242,6 +275,12 @@ impl Widget for TextInput {
                     let mut label = Self::placeholder_mut(&mut input);
                     label.insert_prop(ContentColor::new(color));
                 });
+                if self.invalid {
+                    ctx.mutate_self_later(|mut input| {
+                        let mut input = input.downcast::<Self>();
+                        TextArea::set_invalid(&mut Self::text_mut(&mut input), true);
+                    });
+                }
             }
             // We check for `ChildFocusChanged` instead of `FocusChanged`
             // because the actual widget that receives focus is the child `TextArea`
@@ -304,18 +343,31 @@ impl Widget for TextInput {
 
     fn pre_paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
         let bbox = ctx.border_box();
-        let mut p = PrePaintProps::fetch(ctx, props);
+        let mut p = PrePaintProps::fetch_without_transitions(ctx, props);
 
         // We want to show a focus border if our child TextArea is focused
         if ctx.has_focus_target()
             && let Some(fb) = props.get_defined::<FocusedBorderColor>()
         {
-            p.border_color = &fb.0;
+            p.border_color = fb.0;
         }
-
-        paint_box_shadow(scene, bbox, p.box_shadow, p.corner_radius);
-        paint_background(scene, bbox, p.background, p.border_width, p.corner_radius);
-        paint_border(scene, bbox, p.border_color, p.border_width, p.corner_radius);
+        // The invalid state is more important than focus.
+        if self.invalid
+            && let Some(ib) = props.get_defined::<InvalidBorderColor>()
+        {
+            p.border_color = ib.0;
+        }
+        p.apply_transitions(ctx, props);
+
+        paint_box_shadow(scene, bbox, p.box_shadow, &p.corner_radius);
+        paint_background(scene, bbox, &p.background, p.border_width, &p.corner_radius);
+        paint_border(
+            scene,
+            bbox,
+            &p.border_color,
+            p.border_width,
+            &p.corner_radius,
+        );
     }
 
     fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
@@ -353,10 +405,15 @@ mod tests {
     use masonry_testing::TestHarnessParams;
 
     use super::*;
+    use crate::animation::{Easing, Interpolate};
     use crate::core::{StyleProperty, TextEvent};
     use crate::kurbo::Size;
+    use crate::palette::css::{BLUE, RED};
+    use crate::peniko::Color;
+    use crate::properties::{BorderColor, BorderWidth, CornerRadius, Transition, Transitions};
     use crate::testing::{TestHarness, assert_render_snapshot};
     use crate::theme::test_property_set;
+    use crate::util::Duration;
     use crate::widgets::TextArea;
 
     const HARNESS_PARAMS: TestHarnessParams = {
@@ -429,4 +486,46 @@ mod tests {
 
         assert_render_snapshot!(harness, "text_input_clip");
     }
+
+    #[test]
+    fn invalid_border_transitions() {
+        let text_input = NewWidget::new_with_props(
+            TextInput::new(""),
+            (
+                BorderWidth::all(4.),
+                BorderColor::new(RED),
+                InvalidBorderColor(BorderColor::new(BLUE)),
+                CornerRadius::all(0.),
+                Transitions::new().with::<BorderColor>(Transition::new(
+                    Duration::from_millis(100),
+                    Easing::Linear,
+                )),
+            ),
+        );
+        let mut harness = TestHarness::create_with(test_property_set(), text_input, HARNESS_PARAMS);
+
+        // A pixel in the middle of the left border, after the root padding.
+        let border_pixel = |harness: &mut TestHarness<_>| {
+            let image = harness.render();
+            image.get_pixel(16, 20).0
+        };
+        let assert_color = |pixel: [u8; 4], color: Color| {
+            let expected = color.to_rgba8().to_u8_array();
+            for (a, b) in pixel.into_iter().zip(expected) {
+                assert!(a.abs_diff(b) <= 2, "{pixel:?} != {expected:?}");
+            }
+        };
+        assert_color(border_pixel(&mut harness), RED);
+
+        harness.edit_root_widget(|mut text_input| {
+            TextInput::set_invalid(&mut text_input, true);
+        });
+        assert_color(border_pixel(&mut harness), RED);
+
+        harness.animate_ms(25);
+        assert_color(border_pixel(&mut harness), RED.interpolate(&BLUE, 0.25));
+
+        harness.animate_ms(75);
+        assert_color(border_pixel(&mut harness), BLUE);
+    }
 }
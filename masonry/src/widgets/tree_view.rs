@@ -0,0 +1,1194 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+#![warn(missing_docs)]
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Axis, BezPath, Point, Size};
+
+use crate::core::keyboard::{Key, NamedKey};
+use crate::core::{
+    AccessCtx, AccessEvent, ChildrenIds, EventCtx, HasProperty, LayoutCtx, MeasureCtx, NewWidget,
+    NoAction, PaintCtx, PointerButton, PointerButtonEvent, PointerEvent, PropertiesMut,
+    PropertiesRef, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetMut,
+    WidgetPod,
+};
+use crate::layout::{LayoutSize, LenReq, SizeDef};
+use crate::properties::{ContentColor, SelectionColor};
+use crate::util::{debug_panic, fill_color, stroke};
+use crate::widgets::{VirtualScroll, VirtualScrollAction};
+
+/// The horizontal offset of each level of the tree.
+const INDENT: f64 = 16.;
+/// The width of the area containing the disclosure triangle.
+const DISCLOSURE_WIDTH: f64 = 16.;
+
+/// An item of a [`TreeView`], as provided by the driver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TreeItem {
+    /// The key identifying the item.
+    ///
+    /// This must be unique within the tree.
+    pub key: u64,
+    /// Whether the item can be expanded.
+    ///
+    /// The children of the item don't need to be known yet;
+    /// they can be loaded when the user first expands it.
+    pub has_children: bool,
+}
+
+/// The action type sent by the [`TreeView`] widget.
+///
+/// The tree view doesn't change its expanded items or its selection by itself;
+/// one of the responses to these actions is to call [`TreeView::set_expanded`]
+/// or [`TreeView::set_selected`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeAction {
+    /// The user asked to expand the item with this key.
+    ///
+    /// If the children of the item haven't been provided yet, they should be
+    /// loaded with [`TreeView::set_children`] before expanding it.
+    Expand(u64),
+    /// The user asked to collapse the item with this key.
+    Collapse(u64),
+    /// The user asked to select the item with this key.
+    Select(u64),
+    /// The user activated the item with this key, by pressing `Enter`.
+    Activate(u64),
+}
+
+/// A list of hierarchical items, which can be expanded and collapsed.
+///
+/// Each item is identified by a `u64` key chosen by the [driver](crate::doc::creating_app#the-driver).
+/// The structure of the tree is provided with [`set_children`](Self::set_children), starting
+/// with the root items, and the children of an item only need to be provided once it's expanded.
+/// The driver is told when the user wants to expand an item through [`TreeAction::Expand`],
+/// which makes it easy to load children lazily.
+///
+/// The user can navigate the tree with the keyboard, following the
+/// [ARIA tree pattern](https://www.w3.org/WAI/ARIA/apg/patterns/treeview/):
+/// - `Up` and `Down` move the selection to the previous and next visible items.
+/// - `Right` expands a collapsed item, or moves to the first child of an expanded one.
+/// - `Left` collapses an expanded item, or moves to the parent of a collapsed one.
+/// - `Home` and `End` move to the first and last visible items.
+/// - `Enter` activates the selected item.
+///
+/// # Loading rows
+///
+/// The visible items are shown in an inner [`VirtualScroll`], with one row per item,
+/// so that only the rows near the visible area are loaded.
+/// That scroll area sends [`VirtualScrollAction`]s, with [`scroll_id`](Self::scroll_id)
+/// as their source, which should be handled as described in its documentation, except that
+/// [`TreeView::will_handle_action`], [`TreeView::add_row`] and [`TreeView::remove_row`]
+/// are used instead of the methods of `VirtualScroll`.
+/// The content of each row is provided by the driver, and is shown after the disclosure triangle.
+/// Use [`row_key`](Self::row_key) to know which item a row shows.
+///
+/// Expanding or collapsing items moves the rows below them, so after changing the structure
+/// of the tree, the content of some loaded rows may belong to another item.
+/// These rows are listed by [`stale_rows`](Self::stale_rows), and their content should be
+/// replaced using [`set_row_content`](Self::set_row_content).
+pub struct TreeView {
+    scroll: WidgetPod<VirtualScroll>,
+    nodes: HashMap<u64, TreeNode>,
+    roots: Vec<u64>,
+    /// The keys of the visible items, in display order.
+    rows: Vec<u64>,
+    /// The rows which the driver has loaded, by index.
+    loaded: HashMap<i64, LoadedRow>,
+    selected: Option<u64>,
+}
+
+struct TreeNode {
+    parent: Option<u64>,
+    depth: usize,
+    has_children: bool,
+    /// The keys of the children, or `None` if they haven't been provided.
+    children: Option<Vec<u64>>,
+    expanded: bool,
+}
+
+struct LoadedRow {
+    id: WidgetId,
+    /// The key of the item whose content is shown in the row.
+    content_key: u64,
+}
+
+/// The information a [`TreeRow`] needs about its item.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct RowInfo {
+    key: u64,
+    depth: usize,
+    has_children: bool,
+    expanded: bool,
+    selected: bool,
+}
+
+impl std::fmt::Debug for TreeView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TreeView")
+            .field("roots", &self.roots)
+            .field("rows", &self.rows)
+            .field("loaded", &self.loaded.keys().collect::<Vec<_>>())
+            .field("selected", &self.selected)
+            .finish_non_exhaustive()
+    }
+}
+
+// --- MARK: BUILDERS
+impl TreeItem {
+    /// Creates an item without children.
+    pub fn leaf(key: u64) -> Self {
+        Self {
+            key,
+            has_children: false,
+        }
+    }
+
+    /// Creates an item which can be expanded.
+    pub fn branch(key: u64) -> Self {
+        Self {
+            key,
+            has_children: true,
+        }
+    }
+}
+
+impl TreeView {
+    /// Creates a new tree view with the given root items.
+    pub fn new(roots: impl IntoIterator<Item = TreeItem>) -> Self {
+        let mut tree = Self {
+            scroll: WidgetPod::new(VirtualScroll::new(0)),
+            nodes: HashMap::new(),
+            roots: Vec::new(),
+            rows: Vec::new(),
+            loaded: HashMap::new(),
+            selected: None,
+        };
+        tree.replace_children(None, roots);
+        tree.with_visible_items()
+    }
+
+    /// Builder-style method to provide the children of the item with key `parent`.
+    ///
+    /// See [`set_children`](Self::set_children) for more details.
+    pub fn with_children(mut self, parent: u64, items: impl IntoIterator<Item = TreeItem>) -> Self {
+        if self.nodes.contains_key(&parent) {
+            self.replace_children(Some(parent), items);
+            self = self.with_visible_items();
+        } else {
+            debug_panic!("Tried to set the children of unknown tree item {parent}");
+        }
+        self
+    }
+
+    /// Builder-style method to expand the item with this key.
+    pub fn with_expanded(mut self, key: u64) -> Self {
+        if let Some(node) = self.nodes.get_mut(&key) {
+            node.expanded = node.has_children;
+            self = self.with_visible_items();
+        } else {
+            debug_panic!("Tried to expand unknown tree item {key}");
+        }
+        self
+    }
+
+    /// Updates the visible items of a widget which hasn't been added to the tree yet.
+    fn with_visible_items(mut self) -> Self {
+        self.rows = self.visible_items();
+        let valid_range = 0..row_idx(self.rows.len());
+        self.scroll = WidgetPod::new(VirtualScroll::new(0).with_valid_range(valid_range));
+        self
+    }
+
+    /// Builder-style method to set the selected item.
+    pub fn with_selected(mut self, key: Option<u64>) -> Self {
+        self.selected = key.filter(|key| self.nodes.contains_key(key));
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl TreeView {
+    /// Returns the id of the inner [`VirtualScroll`], which is the source of the
+    /// [`VirtualScrollAction`]s used to load rows.
+    pub fn scroll_id(&self) -> WidgetId {
+        self.scroll.id()
+    }
+
+    /// Returns the number of visible items.
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the key of the item shown in the row at `idx`.
+    pub fn row_key(&self, idx: i64) -> Option<u64> {
+        let idx = usize::try_from(idx).ok()?;
+        self.rows.get(idx).copied()
+    }
+
+    /// Returns the key of the selected item.
+    pub fn selected(&self) -> Option<u64> {
+        self.selected
+    }
+
+    /// Returns `true` if the item with this key is expanded.
+    pub fn is_expanded(&self, key: u64) -> bool {
+        self.nodes.get(&key).is_some_and(|node| node.expanded)
+    }
+
+    /// Returns `true` if the children of the item with this key have been provided.
+    pub fn children_loaded(&self, key: u64) -> bool {
+        self.nodes
+            .get(&key)
+            .is_some_and(|node| node.children.is_some())
+    }
+
+    /// Returns the loaded rows whose content doesn't belong to the item they now show,
+    /// along with the key of that item.
+    ///
+    /// See [loading rows](#loading-rows) for details.
+    pub fn stale_rows(&self) -> impl Iterator<Item = (i64, u64)> + '_ {
+        self.loaded.iter().filter_map(|(idx, row)| {
+            let key = self.row_key(*idx)?;
+            (key != row.content_key).then_some((*idx, key))
+        })
+    }
+
+    /// The number of currently loaded rows.
+    ///
+    /// This is intended for sanity-checking of higher-level processes.
+    #[expect(
+        clippy::len_without_is_empty,
+        reason = "Matches `VirtualScroll::len`, which this mirrors."
+    )]
+    pub fn len(&self) -> usize {
+        self.loaded.len()
+    }
+
+    /// Replaces the children of `parent`, or the roots if `parent` is `None`,
+    /// removing the items which aren't present anymore along with their descendants.
+    ///
+    /// Items which were already present keep their children and expanded state,
+    /// unless they can't have children anymore.
+    fn replace_children(&mut self, parent: Option<u64>, items: impl IntoIterator<Item = TreeItem>) {
+        let depth = match parent {
+            Some(key) => self.nodes[&key].depth + 1,
+            None => 0,
+        };
+        let items: Vec<TreeItem> = items.into_iter().collect();
+        let old = match parent {
+            Some(key) => self.nodes.get_mut(&key).unwrap().children.take(),
+            None => Some(std::mem::take(&mut self.roots)),
+        };
+        for key in old.into_iter().flatten() {
+            if !items.iter().any(|item| item.key == key) {
+                self.remove_node(key);
+            }
+        }
+
+        for item in &items {
+            let node = self.nodes.entry(item.key).or_insert(TreeNode {
+                parent,
+                depth,
+                has_children: item.has_children,
+                children: None,
+                expanded: false,
+            });
+            if node.parent != parent {
+                debug_panic!(
+                    "Tree item {} was given as a child of {parent:?}, but it's already a child of {:?}",
+                    item.key,
+                    node.parent,
+                );
+                continue;
+            }
+            node.has_children = item.has_children;
+            if !item.has_children {
+                let children = node.children.take();
+                node.expanded = false;
+                for child in children.into_iter().flatten() {
+                    self.remove_node(child);
+                }
+            }
+        }
+
+        let keys = items.iter().map(|item| item.key).collect();
+        match parent {
+            Some(key) => self.nodes.get_mut(&key).unwrap().children = Some(keys),
+            None => self.roots = keys,
+        }
+    }
+
+    /// Removes the item with this key and its descendants.
+    fn remove_node(&mut self, key: u64) {
+        let Some(node) = self.nodes.remove(&key) else {
+            return;
+        };
+        for child in node.children.into_iter().flatten() {
+            self.remove_node(child);
+        }
+    }
+
+    /// Returns the keys of the visible items, in display order.
+    fn visible_items(&self) -> Vec<u64> {
+        fn visit(tree: &TreeView, keys: &[u64], rows: &mut Vec<u64>) {
+            for key in keys {
+                rows.push(*key);
+                let node = &tree.nodes[key];
+                if node.expanded
+                    && let Some(children) = &node.children
+                {
+                    visit(tree, children, rows);
+                }
+            }
+        }
+        let mut rows = Vec::new();
+        visit(self, &self.roots, &mut rows);
+        rows
+    }
+
+    fn row_info(&self, idx: i64) -> Option<RowInfo> {
+        let key = self.row_key(idx)?;
+        let node = &self.nodes[&key];
+        Some(RowInfo {
+            key,
+            depth: node.depth,
+            has_children: node.has_children,
+            expanded: node.expanded,
+            selected: self.selected == Some(key),
+        })
+    }
+
+    fn row_index(&self, key: u64) -> Option<usize> {
+        self.rows.iter().position(|row| *row == key)
+    }
+
+    /// Returns the action to submit for a key press, following the ARIA tree pattern.
+    fn key_action(&self, key: &Key) -> Option<TreeAction> {
+        let last = self.rows.len().checked_sub(1)?;
+        let current = self.selected.and_then(|key| self.row_index(key));
+        let select = |idx: usize| TreeAction::Select(self.rows[idx]);
+        match key {
+            Key::Named(NamedKey::ArrowDown) => {
+                Some(select(current.map_or(0, |idx| (idx + 1).min(last))))
+            }
+            Key::Named(NamedKey::ArrowUp) => {
+                Some(select(current.map_or(0, |idx| idx.saturating_sub(1))))
+            }
+            Key::Named(NamedKey::Home) => Some(select(0)),
+            Key::Named(NamedKey::End) => Some(select(last)),
+            Key::Named(NamedKey::ArrowRight) => {
+                let Some(current) = current else {
+                    return Some(select(0));
+                };
+                let key = self.rows[current];
+                let node = &self.nodes[&key];
+                if !node.has_children {
+                    None
+                } else if !node.expanded {
+                    Some(TreeAction::Expand(key))
+                } else {
+                    let first_child = node.children.as_ref()?.first()?;
+                    Some(TreeAction::Select(*first_child))
+                }
+            }
+            Key::Named(NamedKey::ArrowLeft) => {
+                let Some(current) = current else {
+                    return Some(select(0));
+                };
+                let key = self.rows[current];
+                let node = &self.nodes[&key];
+                if node.expanded {
+                    Some(TreeAction::Collapse(key))
+                } else {
+                    node.parent.map(TreeAction::Select)
+                }
+            }
+            Key::Named(NamedKey::Enter) => self.selected.map(TreeAction::Activate),
+            _ => None,
+        }
+    }
+}
+
+/// Converts an index into the visible rows to a [`VirtualScroll`] item index.
+#[expect(
+    clippy::cast_possible_wrap,
+    reason = "a tree can't have more than `i64::MAX` visible rows"
+)]
+fn row_idx(idx: usize) -> i64 {
+    idx as i64
+}
+
+// --- MARK: WIDGETMUT
+impl TreeView {
+    /// Replaces the children of the item with key `parent`, or the root items if `parent` is `None`.
+    ///
+    /// Items which aren't present anymore are removed along with their descendants.
+    /// Items which were already present keep their children and expanded state.
+    pub fn set_children(
+        this: &mut WidgetMut<'_, Self>,
+        parent: Option<u64>,
+        items: impl IntoIterator<Item = TreeItem>,
+    ) {
+        if let Some(key) = parent
+            && !this.widget.nodes.contains_key(&key)
+        {
+            debug_panic!("Tried to set the children of unknown tree item {key}");
+            return;
+        }
+        this.widget.replace_children(parent, items);
+        Self::refresh_rows(this);
+    }
+
+    /// Expands or collapses the item with this key.
+    ///
+    /// Items can be expanded before their children are provided, in which case
+    /// nothing is shown below them until they are.
+    pub fn set_expanded(this: &mut WidgetMut<'_, Self>, key: u64, expanded: bool) {
+        let Some(node) = this.widget.nodes.get_mut(&key) else {
+            debug_panic!("Tried to expand unknown tree item {key}");
+            return;
+        };
+        let expanded = expanded && node.has_children;
+        if node.expanded != expanded {
+            node.expanded = expanded;
+            Self::refresh_rows(this);
+        }
+    }
+
+    /// Sets the selected item, and scrolls it into view.
+    pub fn set_selected(this: &mut WidgetMut<'_, Self>, key: Option<u64>) {
+        let key = key.filter(|key| this.widget.nodes.contains_key(key));
+        if this.widget.selected == key {
+            return;
+        }
+        this.widget.selected = key;
+        Self::refresh_rows(this);
+        if let Some(idx) = key.and_then(|key| this.widget.row_index(key)) {
+            let mut scroll = this.ctx.get_mut(&mut this.widget.scroll);
+            VirtualScroll::scroll_to_item(&mut scroll, row_idx(idx));
+        }
+        this.ctx.request_accessibility_update();
+    }
+
+    /// Indicates that `action`, sent by the inner [`VirtualScroll`], is about to be handled by the driver.
+    ///
+    /// See [`VirtualScroll::will_handle_action`] for details.
+    pub fn will_handle_action(this: &mut WidgetMut<'_, Self>, action: &VirtualScrollAction) {
+        let mut scroll = this.ctx.get_mut(&mut this.widget.scroll);
+        VirtualScroll::will_handle_action(&mut scroll, action);
+    }
+
+    /// Adds the row at `idx`, showing `content` after the disclosure triangle.
+    ///
+    /// This should be done only in the handling of a [`VirtualScrollAction`],
+    /// after calling [`TreeView::will_handle_action`].
+    #[track_caller]
+    pub fn add_row(this: &mut WidgetMut<'_, Self>, idx: i64, content: NewWidget<dyn Widget>) {
+        let info = this.widget.row_info(idx).unwrap_or_default();
+        let row = NewWidget::new(TreeRow::new(this.ctx.widget_id(), info, content));
+        this.widget.loaded.insert(
+            idx,
+            LoadedRow {
+                id: row.id(),
+                content_key: info.key,
+            },
+        );
+        this.ctx.request_accessibility_update();
+        let mut scroll = this.ctx.get_mut(&mut this.widget.scroll);
+        VirtualScroll::add_child(&mut scroll, idx, row.erased());
+    }
+
+    /// Removes the row at `idx`.
+    ///
+    /// This should be done only in the handling of a [`VirtualScrollAction`],
+    /// after calling [`TreeView::will_handle_action`].
+    #[track_caller]
+    pub fn remove_row(this: &mut WidgetMut<'_, Self>, idx: i64) {
+        this.widget.loaded.remove(&idx);
+        this.ctx.request_accessibility_update();
+        let mut scroll = this.ctx.get_mut(&mut this.widget.scroll);
+        VirtualScroll::remove_child(&mut scroll, idx);
+    }
+
+    /// Replaces the content of the loaded row at `idx` with the content for the item it now shows.
+    ///
+    /// See [loading rows](#loading-rows) for details.
+    ///
+    /// # Panics
+    ///
+    /// If the row at `idx` isn't loaded.
+    #[track_caller]
+    pub fn set_row_content(
+        this: &mut WidgetMut<'_, Self>,
+        idx: i64,
+        content: NewWidget<dyn Widget>,
+    ) {
+        let Some(key) = this.widget.row_key(idx) else {
+            debug_panic!("Tried to set the content of row {idx}, which shows no item");
+            return;
+        };
+        this.widget
+            .loaded
+            .get_mut(&idx)
+            .unwrap_or_else(|| panic!("`TreeView::set_row_content` called with unloaded row {idx}"))
+            .content_key = key;
+        let mut scroll = this.ctx.get_mut(&mut this.widget.scroll);
+        let mut row = VirtualScroll::child_mut(&mut scroll, idx);
+        let mut row = row.downcast::<TreeRow>();
+        TreeRow::set_content(&mut row, content);
+    }
+
+    /// Returns a mutable reference to the inner [`VirtualScroll`].
+    ///
+    /// Its children are [`TreeRow`]s, whose content can be accessed with [`TreeRow::content_mut`].
+    pub fn scroll_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, VirtualScroll> {
+        this.ctx.get_mut(&mut this.widget.scroll)
+    }
+
+    /// Updates the visible items and the loaded rows after a change to the tree.
+    fn refresh_rows(this: &mut WidgetMut<'_, Self>) {
+        let rows = this.widget.visible_items();
+        if this.widget.rows.len() != rows.len() {
+            let mut scroll = this.ctx.get_mut(&mut this.widget.scroll);
+            VirtualScroll::set_valid_range(&mut scroll, 0..row_idx(rows.len()));
+        }
+        this.widget.rows = rows;
+        if let Some(key) = this.widget.selected
+            && !this.widget.nodes.contains_key(&key)
+        {
+            this.widget.selected = None;
+        }
+
+        let infos: Vec<_> = this
+            .widget
+            .loaded
+            .keys()
+            .filter_map(|idx| Some((*idx, this.widget.row_info(*idx)?)))
+            .collect();
+        this.ctx.request_accessibility_update();
+        let mut scroll = this.ctx.get_mut(&mut this.widget.scroll);
+        for (idx, info) in infos {
+            let mut row = VirtualScroll::child_mut(&mut scroll, idx);
+            let mut row = row.downcast::<TreeRow>();
+            TreeRow::set_info(&mut row, info);
+        }
+    }
+}
+
+// --- MARK: IMPL WIDGET
+impl Widget for TreeView {
+    type Action = TreeAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        // The rows report clicks themselves; we only take focus for keyboard navigation.
+        if let PointerEvent::Down(PointerButtonEvent {
+            button: Some(PointerButton::Primary),
+            ..
+        }) = event
+            && !ctx.is_disabled()
+        {
+            ctx.request_focus();
+        }
+    }
+
+    fn on_text_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &TextEvent,
+    ) {
+        if ctx.is_disabled() || !ctx.is_focus_target() {
+            return;
+        }
+        let TextEvent::Keyboard(key_event) = event else {
+            return;
+        };
+        if !key_event.state.is_down() {
+            return;
+        }
+        let Some(action) = self.key_action(&key_event.key) else {
+            return;
+        };
+        // Moving past the first or last item doesn't change the selection.
+        if self.selected.map(TreeAction::Select) != Some(action) {
+            ctx.submit_action::<Self::Action>(action);
+        }
+        ctx.set_handled();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.scroll);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx<'_>, _props: &mut PropertiesMut<'_>, event: &Update) {
+        if let Update::FocusChanged(_) = event {
+            ctx.request_accessibility_update();
+        }
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let context_size = LayoutSize::maybe(axis.cross(), cross_length);
+        ctx.compute_length(
+            &mut self.scroll,
+            len_req.into(),
+            context_size,
+            axis,
+            cross_length,
+        )
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.scroll, size);
+        ctx.place_child(&mut self.scroll, Point::ORIGIN);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::Tree
+    }
+
+    fn accessibility(
+        &mut self,
+        ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        let selected_row = self
+            .selected
+            .and_then(|key| self.row_index(key))
+            .and_then(|idx| self.loaded.get(&row_idx(idx)));
+        if let Some(row) = selected_row
+            && ctx.is_focus_target()
+        {
+            node.set_active_descendant(row.id.into());
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.scroll.id()])
+    }
+
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TreeView", id = id.trace())
+    }
+
+    fn get_debug_text(&self) -> Option<String> {
+        Some(format!("{self:#?}"))
+    }
+}
+
+// --- MARK: TREE ROW
+/// A loaded row of a [`TreeView`], showing the disclosure triangle of its item
+/// and the content provided by the driver.
+pub struct TreeRow {
+    tree: WidgetId,
+    info: RowInfo,
+    content: WidgetPod<dyn Widget>,
+}
+
+impl TreeRow {
+    fn new(tree: WidgetId, info: RowInfo, content: NewWidget<dyn Widget>) -> Self {
+        Self {
+            tree,
+            info,
+            content: content.to_pod(),
+        }
+    }
+
+    /// Returns the key of the item shown in this row.
+    pub fn key(&self) -> u64 {
+        self.info.key
+    }
+
+    /// The horizontal offset of the content.
+    fn content_x(&self) -> f64 {
+        self.info.depth as f64 * INDENT + DISCLOSURE_WIDTH
+    }
+
+    fn submit(&self, ctx: &mut EventCtx<'_>, action: TreeAction) {
+        ctx.submit_untyped_action_for(self.tree, Box::new(action));
+    }
+}
+
+impl TreeRow {
+    /// Returns a mutable reference to the content of the row.
+    pub fn content_mut<'t>(this: &'t mut WidgetMut<'_, Self>) -> WidgetMut<'t, dyn Widget> {
+        this.ctx.get_mut(&mut this.widget.content)
+    }
+
+    fn set_content(this: &mut WidgetMut<'_, Self>, content: NewWidget<dyn Widget>) {
+        let old = std::mem::replace(&mut this.widget.content, content.to_pod());
+        this.ctx.remove_child(old);
+    }
+
+    fn set_info(this: &mut WidgetMut<'_, Self>, info: RowInfo) {
+        let old = std::mem::replace(&mut this.widget.info, info);
+        if old.depth != info.depth {
+            this.ctx.request_layout();
+        } else if old != info {
+            this.ctx.request_paint_only();
+        }
+        if old != info {
+            this.ctx.request_accessibility_update();
+        }
+    }
+}
+
+impl HasProperty<ContentColor> for TreeRow {}
+impl HasProperty<SelectionColor> for TreeRow {}
+
+impl Widget for TreeRow {
+    type Action = NoAction;
+
+    fn on_pointer_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &PointerEvent,
+    ) {
+        if ctx.is_disabled() {
+            return;
+        }
+        if let PointerEvent::Down(PointerButtonEvent {
+            button: Some(PointerButton::Primary),
+            state,
+            ..
+        }) = event
+        {
+            let x = ctx.local_position(state.position).x;
+            let disclosure = self.content_x() - DISCLOSURE_WIDTH..self.content_x();
+            let action = if self.info.has_children && disclosure.contains(&x) {
+                if self.info.expanded {
+                    TreeAction::Collapse(self.info.key)
+                } else {
+                    TreeAction::Expand(self.info.key)
+                }
+            } else if self.info.selected {
+                return;
+            } else {
+                TreeAction::Select(self.info.key)
+            };
+            self.submit(ctx, action);
+        }
+    }
+
+    fn on_access_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        event: &AccessEvent,
+    ) {
+        let action = match event.action {
+            accesskit::Action::Click => TreeAction::Select(self.info.key),
+            accesskit::Action::Expand if self.info.has_children => {
+                TreeAction::Expand(self.info.key)
+            }
+            accesskit::Action::Collapse if self.info.has_children => {
+                TreeAction::Collapse(self.info.key)
+            }
+            _ => return,
+        };
+        self.submit(ctx, action);
+        ctx.set_handled();
+    }
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.content);
+    }
+
+    fn property_changed(&mut self, ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
+        ContentColor::prop_changed(ctx, property_type);
+        SelectionColor::prop_changed(ctx, property_type);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        let offset = match axis {
+            Axis::Horizontal => self.content_x(),
+            Axis::Vertical => 0.,
+        };
+        let cross_space = cross_length.map(|cross_length| match axis {
+            Axis::Horizontal => cross_length,
+            Axis::Vertical => (cross_length - self.content_x()).max(0.),
+        });
+        let content_length = ctx.compute_length(
+            &mut self.content,
+            len_req.reduce(offset).into(),
+            LayoutSize::maybe(axis.cross(), cross_space),
+            axis,
+            cross_space,
+        );
+        match axis {
+            Axis::Horizontal => content_length + offset,
+            Axis::Vertical => content_length.max(DISCLOSURE_WIDTH),
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        let x = self.content_x();
+        let space = Size::new((size.width - x).max(0.), size.height);
+        let content_size = ctx.compute_size(&mut self.content, SizeDef::fit(space), space.into());
+        ctx.run_layout(&mut self.content, content_size);
+        let origin = Point::new(x, (size.height - content_size.height) * 0.5);
+        ctx.place_child(&mut self.content, origin);
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut Scene) {
+        let size = ctx.content_box_size();
+        if self.info.selected {
+            fill_color(scene, &size.to_rect(), props.get::<SelectionColor>().color);
+        }
+        if !self.info.has_children {
+            return;
+        }
+        let color = props.get::<ContentColor>().color;
+        let center = Point::new(self.content_x() - DISCLOSURE_WIDTH / 2., size.height / 2.);
+        // Expanded items have a triangle pointing down, and collapsed ones a triangle pointing right.
+        let mut triangle = BezPath::new();
+        if self.info.expanded {
+            triangle.move_to((center.x - 4., center.y - 2.));
+            triangle.line_to((center.x, center.y + 2.));
+            triangle.line_to((center.x + 4., center.y - 2.));
+        } else {
+            triangle.move_to((center.x - 2., center.y - 4.));
+            triangle.line_to((center.x + 2., center.y));
+            triangle.line_to((center.x - 2., center.y + 4.));
+        }
+        stroke(scene, &triangle, color, 1.5);
+    }
+
+    fn accessibility_role(&self) -> Role {
+        Role::TreeItem
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_level(self.info.depth + 1);
+        node.set_selected(self.info.selected);
+        node.add_action(accesskit::Action::Click);
+        if self.info.has_children {
+            node.set_expanded(self.info.expanded);
+            if self.info.expanded {
+                node.add_action(accesskit::Action::Collapse);
+            } else {
+                node.add_action(accesskit::Action::Expand);
+            }
+        }
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.content.id()])
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("TreeRow", id = id.trace(), key = self.info.key)
+    }
+}
+
+// --- MARK: TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ErasedAction;
+    use crate::testing::{TestHarness, assert_render_snapshot};
+    use crate::theme::test_property_set;
+    use crate::widgets::Label;
+
+    const NAMES: [&str; 8] = [
+        "Fruits",
+        "Apple",
+        "Banana",
+        "Vegetables",
+        "Carrot",
+        "Leafy greens",
+        "Lettuce",
+        "Grains",
+    ];
+
+    fn roots() -> Vec<TreeItem> {
+        vec![TreeItem::branch(0), TreeItem::branch(3), TreeItem::leaf(7)]
+    }
+
+    fn children(key: u64) -> Vec<TreeItem> {
+        match key {
+            0 => vec![TreeItem::leaf(1), TreeItem::leaf(2)],
+            3 => vec![TreeItem::leaf(4), TreeItem::branch(5)],
+            5 => vec![TreeItem::leaf(6)],
+            _ => vec![],
+        }
+    }
+
+    fn content(key: u64) -> NewWidget<dyn Widget> {
+        NewWidget::new(Label::new(NAMES[key as usize])).erased()
+    }
+
+    fn harness(tree: TreeView) -> TestHarness<TreeView> {
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            tree.with_auto_id(),
+            Size::new(200., 150.),
+        );
+        drive(&mut harness);
+        harness
+    }
+
+    /// Handles row loading, and handles the other actions like an app would, returning them.
+    fn drive(harness: &mut TestHarness<TreeView>) -> Vec<TreeAction> {
+        let mut actions = Vec::new();
+        while let Some((action, _)) = harness.pop_action_erased() {
+            let action: ErasedAction = match action.downcast::<VirtualScrollAction>() {
+                Ok(load) => {
+                    harness.edit_root_widget(|mut tree| load_rows(&mut tree, &load));
+                    continue;
+                }
+                Err(action) => action,
+            };
+            let action = *action.downcast::<TreeAction>().unwrap();
+            harness.edit_root_widget(|mut tree| {
+                match action {
+                    TreeAction::Expand(key) => {
+                        if !tree.widget.children_loaded(key) {
+                            TreeView::set_children(&mut tree, Some(key), children(key));
+                        }
+                        TreeView::set_expanded(&mut tree, key, true);
+                    }
+                    TreeAction::Collapse(key) => TreeView::set_expanded(&mut tree, key, false),
+                    TreeAction::Select(key) => TreeView::set_selected(&mut tree, Some(key)),
+                    TreeAction::Activate(_) => {}
+                }
+                let stale: Vec<_> = tree.widget.stale_rows().collect();
+                for (idx, key) in stale {
+                    TreeView::set_row_content(&mut tree, idx, content(key));
+                }
+            });
+            actions.push(action);
+        }
+        actions
+    }
+
+    fn load_rows(tree: &mut WidgetMut<'_, TreeView>, load: &VirtualScrollAction) {
+        TreeView::will_handle_action(tree, load);
+        for idx in load.old_active.clone() {
+            if !load.target.contains(&idx) {
+                TreeView::remove_row(tree, idx);
+            }
+        }
+        for idx in load.target.clone() {
+            if !load.old_active.contains(&idx) {
+                let key = tree.widget.row_key(idx).unwrap();
+                TreeView::add_row(tree, idx, content(key));
+            }
+        }
+    }
+
+    /// Returns the text shown by the loaded rows, in order.
+    fn row_texts(harness: &TestHarness<TreeView>) -> Vec<String> {
+        let tree = harness.root_widget();
+        let mut rows: Vec<_> = tree.inner().loaded.iter().collect();
+        rows.sort_unstable_by_key(|(idx, _)| **idx);
+        rows.into_iter()
+            .map(|(_, row)| {
+                let row = harness.get_widget_with_id(row.id);
+                let content = row.downcast::<TreeRow>().unwrap().inner().content.id();
+                let label = harness.get_widget_with_id(content);
+                label
+                    .downcast::<Label>()
+                    .unwrap()
+                    .inner()
+                    .text()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn row_id(harness: &TestHarness<TreeView>, idx: i64) -> WidgetId {
+        harness.root_widget().inner().loaded[&idx].id
+    }
+
+    fn click_row(harness: &mut TestHarness<TreeView>, idx: i64, x: f64) {
+        let row = harness.get_widget_with_id(row_id(harness, idx));
+        let y = row.ctx().content_box_size().height / 2.;
+        let pos = row.ctx().window_transform() * Point::new(x, y);
+        harness.mouse_move(pos);
+        harness.mouse_button_press(PointerButton::Primary);
+        harness.mouse_button_release(PointerButton::Primary);
+    }
+
+    fn press(harness: &mut TestHarness<TreeView>, key: NamedKey) {
+        harness.process_text_event(TextEvent::key_down(Key::Named(key)));
+    }
+
+    fn fruits_expanded() -> TreeView {
+        TreeView::new(roots())
+            .with_children(0, children(0))
+            .with_expanded(0)
+            .with_selected(Some(1))
+    }
+
+    #[test]
+    fn simple_tree_view() {
+        let mut harness = harness(fruits_expanded());
+
+        assert_eq!(
+            row_texts(&harness),
+            ["Fruits", "Apple", "Banana", "Vegetables", "Grains"]
+        );
+        assert_render_snapshot!(harness, "tree_view_simple");
+    }
+
+    #[test]
+    fn expand_loads_children() {
+        let mut harness = harness(fruits_expanded());
+
+        // Click on the disclosure triangle of "Vegetables".
+        click_row(&mut harness, 3, DISCLOSURE_WIDTH / 2.);
+        assert_eq!(drive(&mut harness), [TreeAction::Expand(3)]);
+        assert!(harness.root_widget().inner().children_loaded(3));
+        // The rows below the expanded item now show other items.
+        assert_eq!(
+            row_texts(&harness),
+            [
+                "Fruits",
+                "Apple",
+                "Banana",
+                "Vegetables",
+                "Carrot",
+                "Leafy greens",
+                "Grains"
+            ]
+        );
+
+        // Clicking elsewhere in a row selects it.
+        click_row(&mut harness, 6, 40.);
+        assert_eq!(drive(&mut harness), [TreeAction::Select(7)]);
+        assert_eq!(harness.root_widget().inner().selected(), Some(7));
+
+        click_row(&mut harness, 0, DISCLOSURE_WIDTH / 2.);
+        assert_eq!(drive(&mut harness), [TreeAction::Collapse(0)]);
+        assert_eq!(
+            row_texts(&harness),
+            ["Fruits", "Vegetables", "Carrot", "Leafy greens", "Grains"]
+        );
+    }
+
+    #[test]
+    fn keyboard_navigation() {
+        let mut harness = harness(fruits_expanded());
+        let tree_id = harness.root_id();
+        harness.focus_on(Some(tree_id));
+
+        use TreeAction::*;
+        let mut press = |key| {
+            press(&mut harness, key);
+            drive(&mut harness)
+        };
+        // Left moves from a child to its parent, then collapses the parent.
+        assert_eq!(press(NamedKey::ArrowLeft), [Select(0)]);
+        assert_eq!(press(NamedKey::ArrowLeft), [Collapse(0)]);
+        // Right expands the item, then moves to its first child.
+        assert_eq!(press(NamedKey::ArrowRight), [Expand(0)]);
+        assert_eq!(press(NamedKey::ArrowRight), [Select(1)]);
+        assert_eq!(press(NamedKey::ArrowDown), [Select(2)]);
+        assert_eq!(press(NamedKey::ArrowDown), [Select(3)]);
+        assert_eq!(press(NamedKey::End), [Select(7)]);
+        // Right doesn't do anything on leaves.
+        assert_eq!(press(NamedKey::ArrowRight), []);
+        assert_eq!(press(NamedKey::Enter), [Activate(7)]);
+        // Moving past the last item does nothing.
+        assert_eq!(press(NamedKey::ArrowDown), []);
+    }
+
+    #[test]
+    fn selection_scrolls_into_view() {
+        let tree = TreeView::new((0..100).map(TreeItem::leaf));
+        let mut harness = TestHarness::create_with_size(
+            test_property_set(),
+            tree.with_auto_id(),
+            Size::new(200., 150.),
+        );
+        let load = |tree: &mut WidgetMut<'_, TreeView>, load: &VirtualScrollAction| {
+            TreeView::will_handle_action(tree, load);
+            for idx in load.old_active.clone() {
+                if !load.target.contains(&idx) {
+                    TreeView::remove_row(tree, idx);
+                }
+            }
+            for idx in load.target.clone() {
+                if !load.old_active.contains(&idx) {
+                    let label = Label::new(format!("Item {idx}"));
+                    TreeView::add_row(tree, idx, NewWidget::new(label).erased());
+                }
+            }
+        };
+        let drive = |harness: &mut TestHarness<TreeView>| {
+            while let Some((action, _)) = harness.pop_action::<VirtualScrollAction>() {
+                harness.edit_root_widget(|mut tree| load(&mut tree, &action));
+            }
+        };
+        drive(&mut harness);
+        assert!(!harness.root_widget().inner().loaded.contains_key(&99));
+
+        harness.edit_root_widget(|mut tree| TreeView::set_selected(&mut tree, Some(99)));
+        drive(&mut harness);
+        let row = harness.get_widget_with_id(row_id(&harness, 99));
+        let tree = harness.root_widget();
+        let row_bottom = row.ctx().window_origin().y + row.ctx().border_box_size().height;
+        let tree_bottom = tree.ctx().window_origin().y + tree.ctx().border_box_size().height;
+        assert!(row.ctx().window_origin().y >= tree.ctx().window_origin().y);
+        assert!(row_bottom <= tree_bottom + 0.001);
+    }
+
+    #[test]
+    fn accessibility_roles() {
+        let mut harness = harness(fruits_expanded());
+        // The accessibility tree is only updated when rendering.
+        let _ = harness.render();
+
+        let node = harness.access_node(harness.root_id()).unwrap();
+        assert_eq!(node.role(), Role::Tree);
+
+        let fruits = harness.access_node(row_id(&harness, 0)).unwrap();
+        assert_eq!(fruits.role(), Role::TreeItem);
+        assert_eq!(fruits.data().level(), Some(1));
+        assert_eq!(fruits.data().is_expanded(), Some(true));
+
+        let apple = harness.access_node(row_id(&harness, 1)).unwrap();
+        assert_eq!(apple.data().level(), Some(2));
+        assert_eq!(apple.data().is_expanded(), None);
+        assert_eq!(apple.data().is_selected(), Some(true));
+    }
+}
@@ -8,6 +8,7 @@ use parley::style::FontWeight;
 use tracing::{Span, trace_span};
 use vello::Scene;
 
+use crate::animation::{Animation, Easing, Tween};
 use crate::core::{
     AccessCtx, ArcStr, ChildrenIds, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
     PropertiesMut, PropertiesRef, RegisterCtx, StyleProperty, Update, UpdateCtx, Widget, WidgetId,
@@ -15,9 +16,11 @@ use crate::core::{
 };
 use crate::kurbo::{Axis, Point, Size};
 use crate::layout::LenReq;
+use crate::util::Duration;
 use crate::widgets::Label;
 
 /// An `f32` value which can move towards a target value at a linear rate over time.
+#[deprecated(note = "use `masonry::animation::Tween<f32>` instead")]
 #[derive(Clone, Debug)]
 pub struct AnimatedF32 {
     /// The value which self will eventually reach.
@@ -29,6 +32,7 @@ pub struct AnimatedF32 {
     rate_per_millisecond: f32,
 }
 
+#[expect(deprecated, reason = "Implements the deprecated type")]
 impl AnimatedF32 {
     /// Creates a value which is not changing.
     pub fn stable(value: f32) -> Self {
@@ -99,22 +103,8 @@ impl AnimatedF32 {
 }
 
 /// The status an animation can be in.
-///
-/// Generally returned when an animation is advanced, to determine whether.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum AnimationStatus {
-    /// The animation has finished.
-    Completed,
-    /// The animation is still running
-    Ongoing,
-}
-
-impl AnimationStatus {
-    /// Return true if animation has finished.
-    pub fn is_completed(self) -> bool {
-        matches!(self, Self::Completed)
-    }
-}
+#[deprecated(note = "use `masonry::animation::AnimationStatus` instead")]
+pub type AnimationStatus = crate::animation::AnimationStatus;
 
 /// A widget displaying non-editable text, with a variable [weight](parley::style::FontWeight).
 ///
@@ -130,7 +120,7 @@ impl AnimationStatus {
 /// [`Dimensions::MAX`]: crate::properties::Dimensions::MAX
 pub struct VariableLabel {
     label: WidgetPod<Label>,
-    weight: AnimatedF32,
+    weight: Tween<f32>,
 }
 
 // --- MARK: BUILDERS
@@ -146,13 +136,13 @@ impl VariableLabel {
     pub fn from_label(label: NewWidget<Label>) -> Self {
         Self {
             label: label.to_pod(),
-            weight: AnimatedF32::stable(FontWeight::NORMAL.value()),
+            weight: Tween::stable(FontWeight::NORMAL.value()).with_easing(Easing::Linear),
         }
     }
 
     /// Sets the initial font weight for this text.
     pub fn with_initial_weight(mut self, weight: f32) -> Self {
-        self.weight = AnimatedF32::stable(weight);
+        self.weight.jump_to(weight);
         self
     }
 }
@@ -170,8 +160,20 @@ impl VariableLabel {
     }
 
     /// Sets the weight which this font will target.
+    ///
+    /// `over_millis` should be non-negative.
+    ///
+    /// # Panics
+    ///
+    /// If `target` or `over_millis` is not a finite value.
     pub fn set_target_weight(this: &mut WidgetMut<'_, Self>, target: f32, over_millis: f32) {
-        this.widget.weight.move_to(target, over_millis);
+        assert!(target.is_finite(), "invalid target value");
+        assert!(over_millis.is_finite(), "invalid delay value");
+        if over_millis < 0. {
+            tracing::warn!("set_target_weight: provided negative time step {over_millis}");
+        }
+        let duration = Duration::from_secs_f32(over_millis.max(0.) / 1000.);
+        this.widget.weight.move_to(target, duration);
         this.ctx.request_layout();
         this.ctx.request_anim_frame();
     }
@@ -203,9 +205,8 @@ impl Widget for VariableLabel {
         _props: &mut PropertiesMut<'_>,
         interval: u64,
     ) {
-        let millis = (interval as f64 / 1_000_000.) as f32;
-        let result = self.weight.advance(millis);
-        let new_weight = self.weight.value;
+        let result = ctx.animate(&mut self.weight, interval);
+        let new_weight = self.weight.value();
         // The ergonomics of child widgets are quite bad - ideally, this wouldn't need a mutate pass, since we
         // can set the required invalidation anyway.
         ctx.mutate_later(&mut self.label, move |mut label| {
@@ -220,9 +221,6 @@ impl Widget for VariableLabel {
                 StyleProperty::FontWeight(FontWeight::new(new_weight)),
             );
         });
-        if !result.is_completed() {
-            ctx.request_anim_frame();
-        }
     }
 
     fn measure(
@@ -234,6 +234,9 @@ pub struct VirtualScroll {
     warned_not_dense: bool,
     /// We don't want to spam warnings about missing an action, but we want the user to be aware of it.
     missed_actions_count: u32,
+
+    /// An item to scroll into view during the next layout.
+    scroll_target: Option<i64>,
 }
 
 impl std::fmt::Debug for VirtualScroll {
@@ -249,6 +252,7 @@ impl std::fmt::Debug for VirtualScroll {
             .field("mean_item_height", &self.mean_item_height)
             .field("anchor_height", &self.anchor_height)
             .field("warned_not_dense", &self.warned_not_dense)
+            .field("scroll_target", &self.scroll_target)
             .finish()
     }
 }
@@ -276,6 +280,7 @@ impl VirtualScroll {
             mean_item_height: DEFAULT_MEAN_ITEM_HEIGHT,
             anchor_height: DEFAULT_MEAN_ITEM_HEIGHT,
             warned_not_dense: false,
+            scroll_target: None,
         }
     }
 
@@ -455,6 +460,47 @@ impl VirtualScroll {
         this.ctx.request_layout();
     }
 
+    /// Scrolls the minimum amount needed for the item at `idx` to be fully visible.
+    ///
+    /// If the item isn't loaded, this aligns its top with the top of the virtual scroll
+    /// area instead, like [`overwrite_anchor`](Self::overwrite_anchor).
+    pub fn scroll_to_item(this: &mut WidgetMut<'_, Self>, idx: i64) {
+        this.widget.scroll_target = Some(idx);
+        this.ctx.request_layout();
+    }
+
+    /// Applies a [`scroll_to_item`](Self::scroll_to_item) request.
+    ///
+    /// This must be called after the loaded items have been laid out, and before the anchor is updated.
+    fn scroll_to_target(&mut self, ctx: &LayoutCtx<'_>, target: i64, viewport_height: f64) {
+        let item_height = |idx: i64| {
+            self.items
+                .get(&idx)
+                .filter(|_| self.active_range.contains(&idx))
+                .map(|item| ctx.child_size(item).height)
+        };
+        // The position of the top of the target relative to the top of the anchor,
+        // if all the items between them are loaded.
+        let top = if target < self.anchor_index {
+            (target..self.anchor_index)
+                .map(item_height)
+                .sum::<Option<f64>>()
+                .map(|height| -height)
+        } else {
+            (self.anchor_index..target).map(item_height).sum()
+        };
+        let (Some(top), Some(height)) = (top, item_height(target)) else {
+            self.anchor_index = target;
+            self.scroll_offset_from_anchor = 0.;
+            return;
+        };
+        if top < self.scroll_offset_from_anchor {
+            self.scroll_offset_from_anchor = top;
+        } else if top + height > self.scroll_offset_from_anchor + viewport_height {
+            self.scroll_offset_from_anchor = (top + height - viewport_height).min(top);
+        }
+    }
+
     /// Ensures that the correct follow-up passes are requested after the scroll position changes.
     ///
     /// `size` is the current viewport's size.
@@ -732,6 +778,10 @@ impl Widget for VirtualScroll {
         };
         self.mean_item_height = mean_item_height;
 
+        if let Some(target) = self.scroll_target.take() {
+            self.scroll_to_target(ctx, target, size.height);
+        }
+
         // Determine the new anchor
         loop {
             if self.scroll_offset_from_anchor < 0. {
@@ -190,6 +190,17 @@ impl CollectionWidget<ChildAlignment> for ZStack {
         this.ctx.children_changed();
     }
 
+    /// Moves the child at index `from` to index `to`, shifting the children in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        let child = this.widget.children.remove(from);
+        this.widget.children.insert(to, child);
+        this.ctx.children_changed();
+    }
+
     /// Removes the child at the given index.
     ///
     /// # Panics
@@ -0,0 +1,572 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types for animating values over time.
+//!
+//! Widgets usually store an [`Animation`] such as a [`Tween`], a [`Spring`] or a [`Timeline`]
+//! in their state, and advance it from [`Widget::on_anim_frame`] with [`UpdateCtx::animate`].
+//!
+//! [`Widget::on_anim_frame`]: crate::core::Widget::on_anim_frame
+//! [`UpdateCtx::animate`]: crate::core::UpdateCtx::animate
+
+use crate::kurbo::{Point, Size, Vec2};
+use crate::layout::Length;
+use crate::peniko::color::{AlphaColor, HueDirection, Srgb};
+use crate::util::Duration;
+
+/// A curve mapping the linear progress of an animation to its eased progress.
+///
+/// The named curves match the CSS [easing functions] of the same names.
+///
+/// [easing functions]: https://drafts.csswg.org/css-easing/
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slowly, then speeds up.
+    EaseIn,
+    /// Starts quickly, then slows down.
+    EaseOut,
+    /// Starts and ends slowly.
+    #[default]
+    EaseInOut,
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)` with the control points `(x1, y1)` and `(x2, y2)`.
+    ///
+    /// This is interpreted like [`cubic-bezier()`] in CSS.
+    /// The `x` values must be in the `0..=1` range.
+    ///
+    /// [`cubic-bezier()`]: https://drafts.csswg.org/css-easing/#cubic-bezier-easing-functions
+    CubicBezier(f64, f64, f64, f64),
+}
+
+/// A value which can be smoothly interpolated between two states.
+pub trait Interpolate {
+    /// Returns the value `t` of the way from `self` to `other`.
+    ///
+    /// `t` is usually in the `0..=1` range, where `0` returns `self` and `1` returns `other`.
+    /// Eased progress may overshoot that range slightly.
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+/// The status an animation can be in.
+///
+/// Generally returned when an animation is advanced, to determine whether it needs more frames.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnimationStatus {
+    /// The animation has finished.
+    Completed,
+    /// The animation is still running.
+    Ongoing,
+}
+
+/// A value which changes over time, driven by animation frames.
+pub trait Animation {
+    /// The type of the animated value.
+    type Value;
+
+    /// Advances the animation by `elapsed_ns` nanoseconds.
+    ///
+    /// Returns the status of the animation after this advancement.
+    fn advance(&mut self, elapsed_ns: u64) -> AnimationStatus;
+
+    /// Returns the current value of the animation.
+    fn value(&self) -> Self::Value;
+}
+
+/// A value which moves towards a target value over a fixed duration, following an [`Easing`] curve.
+#[derive(Clone, Debug)]
+pub struct Tween<T> {
+    from: T,
+    target: T,
+    value: T,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+}
+
+/// A value which moves towards a target value following the physics of a damped spring.
+///
+/// Unlike a [`Tween`], a spring doesn't have a fixed duration,
+/// and it keeps its velocity when its target changes mid-motion.
+/// This makes it well suited to values which follow user input, such as dragged panels.
+#[derive(Clone, Debug)]
+pub struct Spring {
+    value: f64,
+    velocity: f64,
+    target: f64,
+    stiffness: f64,
+    damping: f64,
+    mass: f64,
+}
+
+/// A sequence of keyframes, which are interpolated one after another.
+///
+/// # Example
+///
+/// ```
+/// # use masonry_core::animation::{Easing, Timeline};
+/// # use masonry_core::util::Duration;
+/// // Fades in, stays visible for a second, then fades out.
+/// let opacity = Timeline::new(0.0)
+///     .then(1.0, Duration::from_millis(200), Easing::EaseOut)
+///     .then(1.0, Duration::from_secs(1), Easing::Linear)
+///     .then(0.0, Duration::from_millis(200), Easing::EaseIn);
+/// assert_eq!(opacity.duration(), Duration::from_millis(1400));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Timeline<T> {
+    start: T,
+    keyframes: Vec<Keyframe<T>>,
+    elapsed: Duration,
+    looping: bool,
+}
+
+/// A keyframe of a [`Timeline`].
+#[derive(Clone, Debug)]
+pub struct Keyframe<T> {
+    /// The value at this keyframe.
+    pub value: T,
+    /// The time it takes to reach this keyframe from the previous one.
+    pub duration: Duration,
+    /// The easing curve used to reach this keyframe from the previous one.
+    pub easing: Easing,
+}
+
+// --- MARK: IMPL EASING
+
+impl Easing {
+    /// Returns the eased progress for the linear progress `t`.
+    ///
+    /// `t` is clamped to the `0..=1` range.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => cubic_bezier(0.42, 0., 1., 1., t),
+            Self::EaseOut => cubic_bezier(0., 0., 0.58, 1., t),
+            Self::EaseInOut => cubic_bezier(0.42, 0., 0.58, 1., t),
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier(x1.clamp(0., 1.), y1, x2.clamp(0., 1.), y2, t)
+            }
+        }
+    }
+}
+
+/// Evaluates the `y` coordinate of a unit cubic Bézier curve at the `x` coordinate `x`.
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, x: f64) -> f64 {
+    if x <= 0. || x >= 1. {
+        return x;
+    }
+    let coord = |p1: f64, p2: f64, s: f64| {
+        let r = 1. - s;
+        3. * r * r * s * p1 + 3. * r * s * s * p2 + s * s * s
+    };
+    // The curve is monotonic in x, so we can bisect for the parameter.
+    let mut low = 0.;
+    let mut high = 1.;
+    let mut s = x;
+    for _ in 0..32 {
+        let estimate = coord(x1, x2, s);
+        if (estimate - x).abs() < 1e-7 {
+            break;
+        }
+        if estimate < x {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.;
+    }
+    coord(y1, y2, s)
+}
+
+/// Interpolates values which can't change smoothly, by switching halfway through.
+///
+/// This matches the CSS behavior for [discrete animations].
+///
+/// [discrete animations]: https://drafts.csswg.org/web-animations-1/#discrete
+pub fn interpolate_discrete<T: Clone>(from: &T, to: &T, t: f64) -> T {
+    if t < 0.5 { from.clone() } else { to.clone() }
+}
+
+// --- MARK: IMPL ANIMATIONS
+
+impl AnimationStatus {
+    /// Returns `true` if the animation has finished.
+    pub fn is_completed(self) -> bool {
+        matches!(self, Self::Completed)
+    }
+}
+
+impl<T: Interpolate + Clone> Tween<T> {
+    /// Creates a value which is not changing.
+    pub fn stable(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            target: value.clone(),
+            value,
+            duration: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Builder-style method to set the easing curve of future moves.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the easing curve of future moves.
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
+
+    /// Moves this value to `target` over `duration`, starting from the current value.
+    ///
+    /// If `duration` is zero, the value changes immediately.
+    pub fn move_to(&mut self, target: T, duration: Duration) {
+        self.from = self.value.clone();
+        self.target = target;
+        self.duration = duration;
+        self.elapsed = Duration::ZERO;
+        if duration.is_zero() {
+            self.value = self.target.clone();
+        }
+    }
+
+    /// Sets the value immediately, stopping any ongoing move.
+    pub fn jump_to(&mut self, value: T) {
+        *self = Self::stable(value).with_easing(self.easing);
+    }
+
+    /// Returns the value this tween will eventually reach.
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Returns `true` if the value hasn't reached its target yet.
+    pub fn is_running(&self) -> bool {
+        self.elapsed < self.duration
+    }
+}
+
+impl<T: Interpolate + Clone> Animation for Tween<T> {
+    type Value = T;
+
+    fn advance(&mut self, elapsed_ns: u64) -> AnimationStatus {
+        if !self.is_running() {
+            return AnimationStatus::Completed;
+        }
+        self.elapsed += Duration::from_nanos(elapsed_ns);
+        if self.elapsed >= self.duration {
+            self.value = self.target.clone();
+            return AnimationStatus::Completed;
+        }
+        let progress = self.elapsed.as_secs_f64() / self.duration.as_secs_f64();
+        self.value = self
+            .from
+            .interpolate(&self.target, self.easing.apply(progress));
+        AnimationStatus::Ongoing
+    }
+
+    fn value(&self) -> T {
+        self.value.clone()
+    }
+}
+
+impl Spring {
+    /// The default stiffness of the spring.
+    pub const DEFAULT_STIFFNESS: f64 = 170.;
+    /// The default damping coefficient of the spring.
+    pub const DEFAULT_DAMPING: f64 = 26.;
+
+    /// The spring is at rest once both its distance to its target
+    /// and its velocity are below this threshold.
+    const REST_THRESHOLD: f64 = 1e-3;
+    /// How close the damping ratio must be to `1` for the spring to be treated as critically damped.
+    const CRITICAL_EPSILON: f64 = 1e-6;
+
+    /// Creates a value which is not moving.
+    pub fn stable(value: f64) -> Self {
+        assert!(value.is_finite(), "invalid spring value");
+        Self {
+            value,
+            velocity: 0.,
+            target: value,
+            stiffness: Self::DEFAULT_STIFFNESS,
+            damping: Self::DEFAULT_DAMPING,
+            mass: 1.,
+        }
+    }
+
+    /// Builder-style method to set the stiffness of the spring.
+    ///
+    /// Stiffer springs move faster.
+    pub fn with_stiffness(mut self, stiffness: f64) -> Self {
+        assert!(stiffness > 0., "spring stiffness must be positive");
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Builder-style method to set the damping coefficient of the spring.
+    ///
+    /// Springs with less damping oscillate more around their target before settling.
+    pub fn with_damping(mut self, damping: f64) -> Self {
+        assert!(damping >= 0., "spring damping must not be negative");
+        self.damping = damping;
+        self
+    }
+
+    /// Builder-style method to set the mass attached to the spring.
+    ///
+    /// Heavier springs move slower and have more momentum.
+    pub fn with_mass(mut self, mass: f64) -> Self {
+        assert!(mass > 0., "spring mass must be positive");
+        self.mass = mass;
+        self
+    }
+
+    /// Moves this value towards `target`, keeping its current velocity.
+    pub fn move_to(&mut self, target: f64) {
+        assert!(target.is_finite(), "invalid spring target");
+        self.target = target;
+    }
+
+    /// Sets the value immediately, stopping any ongoing motion.
+    pub fn jump_to(&mut self, value: f64) {
+        assert!(value.is_finite(), "invalid spring value");
+        self.value = value;
+        self.target = value;
+        self.velocity = 0.;
+    }
+
+    /// Returns the value this spring will eventually settle at.
+    pub fn target(&self) -> f64 {
+        self.target
+    }
+
+    /// Returns the current velocity, in units per second.
+    pub fn velocity(&self) -> f64 {
+        self.velocity
+    }
+
+    /// Returns `true` if the spring hasn't settled at its target yet.
+    pub fn is_running(&self) -> bool {
+        (self.value - self.target).abs() >= Self::REST_THRESHOLD
+            || self.velocity.abs() >= Self::REST_THRESHOLD
+    }
+}
+
+impl Animation for Spring {
+    type Value = f64;
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "animation frames don't need nanosecond precision"
+    )]
+    fn advance(&mut self, elapsed_ns: u64) -> AnimationStatus {
+        let t = elapsed_ns as f64 * 1e-9;
+        if t > 0. && self.is_running() {
+            // We use the closed-form solution of the damped harmonic oscillator,
+            // which is exact for any time step, however stiff the spring is.
+            let x0 = self.value - self.target;
+            let v0 = self.velocity;
+            let omega = (self.stiffness / self.mass).sqrt();
+            let zeta = self.damping / (2. * (self.stiffness * self.mass).sqrt());
+            let (x, v) = if (zeta - 1.).abs() < Self::CRITICAL_EPSILON {
+                let b = v0 + omega * x0;
+                let decay = (-omega * t).exp();
+                ((x0 + b * t) * decay, (v0 - omega * b * t) * decay)
+            } else if zeta < 1. {
+                let a = zeta * omega;
+                let omega_d = omega * (1. - zeta * zeta).sqrt();
+                let (sin, cos) = (omega_d * t).sin_cos();
+                let decay = (-a * t).exp();
+                let x = (x0 * cos + (v0 + a * x0) / omega_d * sin) * decay;
+                let v = (v0 * cos - (a * v0 + omega * omega * x0) / omega_d * sin) * decay;
+                (x, v)
+            } else {
+                let root = (zeta * zeta - 1.).sqrt();
+                // Written this way to avoid cancellation for heavily damped springs.
+                let r1 = -omega / (zeta + root);
+                let r2 = -omega * (zeta + root);
+                let c1 = (v0 - r2 * x0) / (r1 - r2);
+                let c2 = x0 - c1;
+                let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+                (c1 * e1 + c2 * e2, r1 * c1 * e1 + r2 * c2 * e2)
+            };
+            self.value = self.target + x;
+            self.velocity = v;
+        }
+        if self.is_running() {
+            AnimationStatus::Ongoing
+        } else {
+            self.value = self.target;
+            self.velocity = 0.;
+            AnimationStatus::Completed
+        }
+    }
+
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<T: Interpolate + Clone> Timeline<T> {
+    /// Creates a timeline starting at `start`, with no keyframes yet.
+    pub fn new(start: T) -> Self {
+        Self {
+            start,
+            keyframes: Vec::new(),
+            elapsed: Duration::ZERO,
+            looping: false,
+        }
+    }
+
+    /// Builder-style method to add a keyframe, reached `duration` after the previous one.
+    pub fn then(mut self, value: T, duration: Duration, easing: Easing) -> Self {
+        self.keyframes.push(Keyframe {
+            value,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    /// Builder-style method to set whether the timeline restarts once it reaches its end.
+    ///
+    /// Looping timelines never complete.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Returns the keyframes of this timeline.
+    pub fn keyframes(&self) -> &[Keyframe<T>] {
+        &self.keyframes
+    }
+
+    /// Returns the total duration of one run of this timeline.
+    pub fn duration(&self) -> Duration {
+        self.keyframes
+            .iter()
+            .map(|keyframe| keyframe.duration)
+            .sum()
+    }
+
+    /// Returns the time since the start of the current run of this timeline.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Goes back to the start of the timeline.
+    pub fn restart(&mut self) {
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Returns `true` if the timeline hasn't reached its end yet.
+    pub fn is_running(&self) -> bool {
+        let duration = self.duration();
+        (self.looping && !duration.is_zero()) || self.elapsed < duration
+    }
+
+    /// Returns the value of the timeline `time` after its start.
+    pub fn value_at(&self, mut time: Duration) -> T {
+        let mut previous = &self.start;
+        for keyframe in &self.keyframes {
+            if time < keyframe.duration {
+                let progress = time.as_secs_f64() / keyframe.duration.as_secs_f64();
+                return previous.interpolate(&keyframe.value, keyframe.easing.apply(progress));
+            }
+            time -= keyframe.duration;
+            previous = &keyframe.value;
+        }
+        previous.clone()
+    }
+}
+
+impl<T: Interpolate + Clone> Animation for Timeline<T> {
+    type Value = T;
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the remainder is smaller than the duration, which fits in a u64"
+    )]
+    fn advance(&mut self, elapsed_ns: u64) -> AnimationStatus {
+        let duration = self.duration();
+        self.elapsed += Duration::from_nanos(elapsed_ns);
+        if self.looping && !duration.is_zero() {
+            let elapsed_ns = self.elapsed.as_nanos() % duration.as_nanos();
+            self.elapsed = Duration::from_nanos(elapsed_ns as u64);
+            return AnimationStatus::Ongoing;
+        }
+        if self.elapsed >= duration {
+            self.elapsed = duration;
+            AnimationStatus::Completed
+        } else {
+            AnimationStatus::Ongoing
+        }
+    }
+
+    fn value(&self) -> T {
+        self.value_at(self.elapsed)
+    }
+}
+
+// --- MARK: IMPL INTERPOLATE
+
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for f32 {
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "interpolation factors don't need f64 precision"
+    )]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t as Self
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self.lerp(*other, t)
+    }
+}
+
+impl Interpolate for Vec2 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self.lerp(*other, t)
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Self::new(
+            self.width.interpolate(&other.width, t),
+            self.height.interpolate(&other.height, t),
+        )
+    }
+}
+
+impl Interpolate for AlphaColor<Srgb> {
+    #[expect(clippy::cast_possible_truncation, reason = "color components are f32")]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self.lerp(*other, t as f32, HueDirection::default())
+    }
+}
+
+impl Interpolate for Length {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        if self.unit() != other.unit() {
+            return interpolate_discrete(self, other, t);
+        }
+        // Eased progress can overshoot, but lengths can't be negative.
+        let value = self.value().interpolate(&other.value(), t).max(0.);
+        Self::new(value, self.unit())
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use accesskit::{Node, Role};
+use tracing::{Span, trace_span};
+use vello::Scene;
+use vello::kurbo::{Axis, Point, Size};
+
+use crate::core::{
+    AccessCtx, ChildrenIds, EventCtx, Layer, LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx,
+    PointerEvent, PropertiesMut, PropertiesRef, QueryCtx, RegisterCtx, Widget, WidgetId, WidgetPod,
+    WidgetRef,
+};
+use crate::layout::LenReq;
+
+/// The root of the layer showing the preview of a [drag-and-drop] operation.
+///
+/// The preview is invisible to pointer hit-testing, so that the widgets under the
+/// dragged item still get hovered and can be picked as drop targets.
+///
+/// [drag-and-drop]: crate::doc::masonry_concepts#drag-and-drop
+pub(crate) struct DragPreview {
+    child: WidgetPod<dyn Widget>,
+}
+
+impl DragPreview {
+    pub(crate) fn new(child: NewWidget<impl Widget + ?Sized>) -> Self {
+        Self {
+            child: child.erased().to_pod(),
+        }
+    }
+}
+
+impl Widget for DragPreview {
+    type Action = NoAction;
+
+    fn register_children(&mut self, ctx: &mut RegisterCtx<'_>) {
+        ctx.register_child(&mut self.child);
+    }
+
+    fn measure(
+        &mut self,
+        ctx: &mut MeasureCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        axis: Axis,
+        _len_req: LenReq,
+        cross_length: Option<f64>,
+    ) -> f64 {
+        ctx.redirect_measurement(&mut self.child, axis, cross_length)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx<'_>, _props: &PropertiesRef<'_>, size: Size) {
+        ctx.run_layout(&mut self.child, size);
+        ctx.place_child(&mut self.child, Point::ORIGIN);
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx<'_>, _props: &PropertiesRef<'_>, _scene: &mut Scene) {}
+
+    fn accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    fn accessibility(
+        &mut self,
+        _ctx: &mut AccessCtx<'_>,
+        _props: &PropertiesRef<'_>,
+        node: &mut Node,
+    ) {
+        node.set_hidden();
+    }
+
+    fn children_ids(&self) -> ChildrenIds {
+        ChildrenIds::from_slice(&[self.child.id()])
+    }
+
+    fn as_layer(&mut self) -> Option<&mut dyn Layer> {
+        Some(self)
+    }
+
+    fn accepts_pointer_interaction(&self) -> bool {
+        false
+    }
+
+    fn find_widget_under_pointer<'c>(
+        &'c self,
+        _ctx: QueryCtx<'c>,
+        _pos: Point,
+    ) -> Option<WidgetRef<'c, dyn Widget>> {
+        None
+    }
+
+    fn make_trace_span(&self, id: WidgetId) -> Span {
+        trace_span!("DragPreview", id = id.trace())
+    }
+}
+
+impl Layer for DragPreview {
+    fn capture_pointer_event(
+        &mut self,
+        _ctx: &mut EventCtx<'_>,
+        _props: &mut PropertiesMut<'_>,
+        _event: &PointerEvent,
+    ) {
+    }
+}
@@ -3,14 +3,18 @@
 
 //! Types needed for running a Masonry app.
 
+mod drag_preview;
 mod layer_stack;
 mod render_root;
 mod tracing_backend;
 
-pub use render_root::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
+pub use render_root::{
+    RenderRoot, RenderRootOptions, RenderRootSignal, ThemeProperties, WindowSizePolicy,
+};
 pub use tracing_backend::{
     TracingSubscriberHasBeenSetError, default_tracing_subscriber, try_init_test_tracing,
     try_init_tracing,
 };
 
-pub(crate) use render_root::{MutateCallback, RenderRootState};
+pub(crate) use drag_preview::DragPreview;
+pub(crate) use render_root::{DragState, MutateCallback, RenderRootState};
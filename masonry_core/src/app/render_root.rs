@@ -6,26 +6,30 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use accesskit::{ActionRequest, NodeId, TreeUpdate};
+use anymore::AnyDebug;
 use dpi::{LogicalPosition, LogicalSize, PhysicalSize};
 use parley::fontique::{Blob, Collection, CollectionOptions, FamilyId, FontInfo, SourceCache};
 use parley::{FontContext, LayoutContext};
 use tracing::{debug, info_span, warn};
 use tree_arena::{ArenaMut, TreeArena};
 use vello::Scene;
-use vello::kurbo::{Point, Rect, Size};
+use vello::kurbo::{Point, Rect, Size, Vec2};
 
+use crate::app::drag_preview::DragPreview;
 use crate::app::layer_stack::LayerStack;
 use crate::core::{
-    AccessCtx, AccessEvent, BrushIndex, CursorIcon, DefaultProperties, ErasedAction, FromDynWidget,
-    Handled, Ime, LayerType, NewWidget, PointerEvent, PropertiesRef, QueryCtx, ResizeDirection,
-    TextEvent, Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetMut, WidgetPod, WidgetRef,
-    WidgetState, WidgetTag, WidgetTagInner, WindowEvent,
+    AccessCtx, AccessEvent, BrushIndex, ClipboardData, CursorIcon, DefaultProperties, ErasedAction,
+    FromDynWidget, Handled, Ime, LayerType, NewWidget, PointerEvent, PropertiesRef, QueryCtx,
+    ResizeDirection, TextEvent, Widget, WidgetArena, WidgetArenaNode, WidgetId, WidgetMut,
+    WidgetPod, WidgetRef, WidgetState, WidgetTag, WidgetTagInner, WindowEvent, WindowTheme,
 };
+use crate::layout::LengthContext;
 use crate::passes::accessibility::run_accessibility_pass;
 use crate::passes::anim::run_update_anim_pass;
 use crate::passes::compose::run_compose_pass;
 use crate::passes::event::{
-    run_on_access_event_pass, run_on_pointer_event_pass, run_on_text_event_pass,
+    run_on_access_event_pass, run_on_clipboard_data_pass, run_on_pointer_event_pass,
+    run_on_text_event_pass,
 };
 use crate::passes::layout::run_layout_pass;
 use crate::passes::mutate::{mutate_widget, run_mutate_pass};
@@ -37,6 +41,7 @@ use crate::passes::update::{
 };
 use crate::passes::{PassTracing, recurse_on_children};
 use crate::properties::Dimensions;
+use crate::util::Sanitize;
 
 /// We ensure that any valid initial IME area is sent to the platform by storing an invalid initial
 /// IME area as the `last_sent_ime_area`.
@@ -71,6 +76,12 @@ pub struct RenderRoot {
     /// Default values that properties will have if not defined per-widget.
     pub(crate) default_properties: Arc<DefaultProperties>,
 
+    /// The default properties to switch to when the window theme changes, if any.
+    pub(crate) theme_properties: Option<ThemeProperties>,
+
+    /// The last reported light/dark mode of the window.
+    pub(crate) window_theme: Option<WindowTheme>,
+
     /// State passed to context types.
     pub(crate) global_state: RenderRootState,
 
@@ -120,6 +131,9 @@ pub(crate) struct RenderRootState {
     /// Widget that currently has pointer capture.
     pub(crate) pointer_capture_target: Option<WidgetId>,
 
+    /// The ongoing drag-and-drop operation, if any.
+    pub(crate) drag: Option<DragState>,
+
     /// Current cursor icon.
     pub(crate) cursor_icon: CursorIcon,
 
@@ -168,10 +182,41 @@ pub(crate) struct RenderRootState {
     /// Kurbo coordinates are assumed to be in logical pixels
     pub(crate) scale_factor: f64,
 
+    /// The font size used to resolve [`LengthUnit::Rem`] lengths, in logical pixels.
+    ///
+    /// This is also the font size of widgets which don't have a font size of their own.
+    ///
+    /// [`LengthUnit::Rem`]: crate::layout::LengthUnit::Rem
+    pub(crate) root_font_size: f64,
+
+    /// The window size, in logical pixels, as of the last layout pass.
+    ///
+    /// This is used to resolve viewport-relative lengths.
+    pub(crate) viewport_size: Size,
+
     /// Whether to paint widget's bounding boxes and other visual helpers.
     pub(crate) debug_paint: bool,
 }
 
+/// The state of an ongoing [drag-and-drop] operation.
+///
+/// [drag-and-drop]: crate::doc::masonry_concepts#drag-and-drop
+pub(crate) struct DragState {
+    /// The widget which started the drag.
+    pub(crate) source: WidgetId,
+    pub(crate) payload: Box<dyn AnyDebug>,
+    /// The root of the preview's layer.
+    pub(crate) preview_id: WidgetId,
+    /// The preview's root widget, until its layer is created.
+    pub(crate) new_preview: Option<NewWidget<DragPreview>>,
+    /// The origin of the preview's layer when the drag started, in the window's coordinate space.
+    pub(crate) preview_origin: Point,
+    /// The offset from the pointer to the origin of the preview's layer.
+    pub(crate) preview_offset: Vec2,
+    /// The widget under the dragged item which accepts drops.
+    pub(crate) target: Option<WidgetId>,
+}
+
 pub(crate) struct MutateCallback {
     pub(crate) id: WidgetId,
     pub(crate) callback: Box<dyn FnOnce(WidgetMut<'_, dyn Widget>)>,
@@ -221,6 +266,27 @@ pub struct RenderRootOptions {
     pub test_font: Option<Blob<u8>>,
 }
 
+/// Default properties for each light/dark mode, used to follow the window's theme.
+///
+/// See [`RenderRoot::set_theme_properties`].
+#[derive(Clone, Debug)]
+pub struct ThemeProperties {
+    /// Default properties used in light mode.
+    pub light: Arc<DefaultProperties>,
+    /// Default properties used in dark mode.
+    pub dark: Arc<DefaultProperties>,
+}
+
+impl ThemeProperties {
+    /// Returns the default properties for the given `theme`.
+    pub fn get(&self, theme: WindowTheme) -> &Arc<DefaultProperties> {
+        match theme {
+            WindowTheme::Light => &self.light,
+            WindowTheme::Dark => &self.dark,
+        }
+    }
+}
+
 /// Objects emitted by the [`RenderRoot`] to signal that something has changed or require external actions.
 #[derive(Debug)]
 pub enum RenderRootSignal {
@@ -234,6 +300,15 @@ pub enum RenderRootSignal {
     ImeMoved(LogicalPosition<f64>, LogicalSize<f64>),
     /// A user interaction has sent something to the clipboard.
     ClipboardStore(String),
+    /// A user interaction has sent content in one or more formats to the clipboard.
+    ///
+    /// Platforms which don't support some of the formats should store the ones they do support.
+    ClipboardStoreData(ClipboardData),
+    /// A widget has asked for the contents of the clipboard.
+    ///
+    /// The platform should answer by calling [`RenderRoot::handle_clipboard_data`]
+    /// with the id of that widget.
+    ClipboardRead(WidgetId),
     /// The window needs to be redrawn.
     RequestRedraw,
     /// The window should be redrawn for an animation frame. Currently this isn't really different from `RequestRedraw`.
@@ -336,6 +411,8 @@ impl RenderRoot {
             size,
             last_mouse_pos: None,
             default_properties,
+            theme_properties: None,
+            window_theme: None,
             global_state: RenderRootState {
                 signal_sink: Box::new(signal_sink),
                 focused_widget: None,
@@ -348,6 +425,7 @@ impl RenderRoot {
                 hovered_path: Vec::new(),
                 active_path: Vec::new(),
                 pointer_capture_target: None,
+                drag: None,
                 cursor_icon: CursorIcon::Default,
                 font_context: FontContext {
                     collection: Collection::new(CollectionOptions {
@@ -371,6 +449,8 @@ impl RenderRoot {
                 },
                 access_tree_active: false,
                 scale_factor,
+                root_font_size: LengthContext::DEFAULT_FONT_SIZE,
+                viewport_size: Size::ZERO,
                 debug_paint,
             },
             widget_arena: WidgetArena {
@@ -454,6 +534,7 @@ impl RenderRoot {
                 self.size = size;
                 self.root_state_mut().request_layout = true;
                 self.root_state_mut().set_needs_layout(true);
+                self.request_layout_viewport_relative();
                 self.run_rewrite_passes();
                 Handled::Yes
             }
@@ -476,6 +557,14 @@ impl RenderRoot {
                 self.global_state.access_tree_active = false;
                 Handled::Yes
             }
+            WindowEvent::ThemeChanged(theme) => {
+                self.window_theme = Some(theme);
+                if let Some(theme_properties) = &self.theme_properties {
+                    let default_properties = theme_properties.get(theme).clone();
+                    self.set_default_properties(default_properties);
+                }
+                Handled::Yes
+            }
         }
     }
 
@@ -506,6 +595,19 @@ impl RenderRoot {
         handled
     }
 
+    /// Delivers the contents of the clipboard to the widget which asked for them.
+    ///
+    /// This should be called in response to [`RenderRootSignal::ClipboardRead`].
+    /// The widget receives a [`TextEvent::ClipboardData`] event.
+    /// If the widget has been removed since the request, the data is dropped.
+    pub fn handle_clipboard_data(&mut self, target: WidgetId, data: ClipboardData) -> Handled {
+        let _span = info_span!("clipboard_data");
+        let handled = run_on_clipboard_data_pass(self, target, &TextEvent::ClipboardData(data));
+        self.run_rewrite_passes();
+
+        handled
+    }
+
     /// Handles an accesskit event.
     pub fn handle_access_event(&mut self, event: ActionRequest) {
         let _span = info_span!("access_event");
@@ -548,6 +650,11 @@ impl RenderRoot {
 
         // TODO - Handle invalidation regions
         let scene = run_paint_pass(self);
+        // Property transitions can start during the paint pass.
+        if self.root_state().needs_anim {
+            self.global_state
+                .emit_signal(RenderRootSignal::RequestAnimFrame);
+        }
         let tree_update = access_tree_active
             .then(|| run_accessibility_pass(self, self.global_state.scale_factor));
         (scene, tree_update)
@@ -579,7 +686,10 @@ impl RenderRoot {
             widget_state: state,
             properties: PropertiesRef {
                 map: properties,
-                default_map: self.default_properties.for_widget(widget.type_id()),
+                default_map: self.default_properties.for_widget(
+                    widget.type_id(),
+                    state.style_state(self.global_state.focused_widget),
+                ),
             },
             children,
             default_properties: &self.default_properties,
@@ -733,6 +843,80 @@ impl RenderRoot {
         self.size
     }
 
+    /// Returns the default values that properties have if not defined per-widget.
+    pub fn default_properties(&self) -> &Arc<DefaultProperties> {
+        &self.default_properties
+    }
+
+    /// Replaces the default values that properties have if not defined per-widget.
+    ///
+    /// This is how apps switch themes at runtime.
+    /// Every widget will be laid out and painted again.
+    ///
+    /// If the render root is [following the window theme](Self::set_theme_properties),
+    /// the default properties will be replaced again the next time the window theme changes.
+    pub fn set_default_properties(&mut self, default_properties: Arc<DefaultProperties>) {
+        if Arc::ptr_eq(&self.default_properties, &default_properties) {
+            return;
+        }
+        self.default_properties = default_properties;
+        self.request_render_all();
+        self.request_layout_all();
+    }
+
+    /// Returns the last reported light/dark mode of the window, if any.
+    ///
+    /// This is updated by [`WindowEvent::ThemeChanged`].
+    pub fn window_theme(&self) -> Option<WindowTheme> {
+        self.window_theme
+    }
+
+    /// Makes the default properties follow the window's light/dark mode.
+    ///
+    /// If the window theme is already known, the matching default properties are applied immediately.
+    /// Afterwards, they are swapped every time a [`WindowEvent::ThemeChanged`] event is received.
+    ///
+    /// Passing `None` stops following the window theme, and keeps the current default properties.
+    pub fn set_theme_properties(&mut self, theme_properties: Option<ThemeProperties>) {
+        self.theme_properties = theme_properties;
+        if let Some(theme_properties) = &self.theme_properties
+            && let Some(theme) = self.window_theme
+        {
+            let default_properties = theme_properties.get(theme).clone();
+            self.set_default_properties(default_properties);
+        }
+    }
+
+    /// Returns the font size used to resolve [`LengthUnit::Rem`] lengths, in logical pixels.
+    ///
+    /// [`LengthUnit::Rem`]: crate::layout::LengthUnit::Rem
+    pub fn root_font_size(&self) -> f64 {
+        self.global_state.root_font_size
+    }
+
+    /// Sets the font size used to resolve [`LengthUnit::Rem`] lengths, in logical pixels.
+    ///
+    /// This is also the font size of widgets which don't have a font size of their own,
+    /// which makes it the basis for [`LengthUnit::Em`] lengths in those widgets.
+    /// Apps can use this to make their layout follow the user's font settings.
+    ///
+    /// The default is [`LengthContext::DEFAULT_FONT_SIZE`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `font_size` is non-finite or negative and debug assertions are enabled.
+    ///
+    /// [`LengthUnit::Rem`]: crate::layout::LengthUnit::Rem
+    /// [`LengthUnit::Em`]: crate::layout::LengthUnit::Em
+    pub fn set_root_font_size(&mut self, font_size: f64) {
+        let font_size = font_size.sanitize("root font size");
+        if self.global_state.root_font_size == font_size {
+            return;
+        }
+        self.global_state.root_font_size = font_size;
+        self.request_layout_all();
+    }
+
     pub(crate) fn get_kurbo_size(&self) -> Size {
         let size = self.size.to_logical(self.global_state.scale_factor);
         Size::new(size.width, size.height)
@@ -887,6 +1071,33 @@ impl RenderRoot {
         self.run_rewrite_passes();
     }
 
+    /// Requests layout for every widget whose layout depends on the viewport size.
+    pub(crate) fn request_layout_viewport_relative(&mut self) {
+        fn request_layout_in(node: ArenaMut<'_, WidgetArenaNode>) -> bool {
+            let children = node.children;
+            let widget = &mut *node.item.widget;
+            let state = &mut node.item.state;
+
+            let mut needs_layout = false;
+            let id = state.id;
+            recurse_on_children(id, widget, children, |node| {
+                needs_layout |= request_layout_in(node);
+            });
+
+            if state.uses_viewport_units {
+                state.request_layout = true;
+                needs_layout = true;
+            }
+            if needs_layout {
+                state.set_needs_layout(true);
+            }
+            needs_layout
+        }
+
+        let root_node = self.widget_arena.get_node_mut(self.root_id());
+        request_layout_in(root_node);
+    }
+
     /// Checks whether the given id points to a widget that is "interactive".
     /// i.e. not disabled or stashed.
     /// Only interactive widgets can have text focus or pointer capture.
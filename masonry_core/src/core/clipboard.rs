@@ -0,0 +1,293 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Clipboard payloads.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The contents of the clipboard, in one or more formats.
+///
+/// Each format is identified by a MIME type, such as `text/plain` or `image/png`,
+/// and holds raw bytes.
+/// The same content is usually offered in several formats, from the richest to the
+/// simplest, so that the receiving application can pick the one it understands best.
+///
+/// Widgets write to the clipboard with [`set_clipboard_data`] and read from it with
+/// [`request_clipboard`].
+/// The contents are then delivered to the requesting widget in a
+/// [`TextEvent::ClipboardData`] event.
+///
+/// Which formats reach the system clipboard depends on the platform.
+/// Masonry Winit exchanges plain text, HTML, PNG images and file lists with other applications,
+/// but the system clipboard only holds one of them at a time (besides the plain text
+/// alternative of HTML), so it stores the richest one: files, then the image, then HTML.
+/// The other formats are handed back to widgets of the same application,
+/// as long as the system clipboard still holds what was stored.
+/// On Android and iOS, the clipboard is only shared within the application.
+///
+/// [`set_clipboard_data`]: crate::core::EventCtx::set_clipboard_data
+/// [`request_clipboard`]: crate::core::EventCtx::request_clipboard
+/// [`TextEvent::ClipboardData`]: crate::core::TextEvent::ClipboardData
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClipboardData {
+    formats: Vec<(String, Arc<[u8]>)>,
+}
+
+impl ClipboardData {
+    /// The MIME type of UTF-8 plain text.
+    pub const TEXT: &str = "text/plain";
+    /// The MIME type of an HTML fragment.
+    pub const HTML: &str = "text/html";
+    /// The MIME type of a PNG image.
+    pub const PNG: &str = "image/png";
+    /// The MIME type of a list of files, as `file://` URIs separated by CRLF.
+    pub const FILE_LIST: &str = "text/uri-list";
+
+    /// Creates empty clipboard contents.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates clipboard contents holding only plain text.
+    pub fn from_text(text: impl Into<String>) -> Self {
+        Self::new().with_text(text)
+    }
+
+    /// Builder-style method to add a format with the given MIME type.
+    ///
+    /// If the format is already present, its data is replaced.
+    pub fn with_format(mut self, mime_type: impl Into<String>, data: impl Into<Arc<[u8]>>) -> Self {
+        self.insert(mime_type, data);
+        self
+    }
+
+    /// Builder-style method to add plain text.
+    pub fn with_text(self, text: impl Into<String>) -> Self {
+        self.with_format(Self::TEXT, text.into().into_bytes())
+    }
+
+    /// Builder-style method to add an HTML fragment.
+    pub fn with_html(self, html: impl Into<String>) -> Self {
+        self.with_format(Self::HTML, html.into().into_bytes())
+    }
+
+    /// Builder-style method to add a PNG-encoded image.
+    pub fn with_png(self, png: impl Into<Arc<[u8]>>) -> Self {
+        self.with_format(Self::PNG, png)
+    }
+
+    /// Builder-style method to add a list of files.
+    ///
+    /// The paths should be absolute.
+    /// They are stored as percent-encoded `file://` URIs, as described in [RFC 8089].
+    ///
+    /// [RFC 8089]: https://www.rfc-editor.org/rfc/rfc8089
+    pub fn with_files(self, files: impl IntoIterator<Item = PathBuf>) -> Self {
+        let list = files
+            .into_iter()
+            .map(|path| file_uri(&path.to_string_lossy(), cfg!(windows)))
+            .collect::<Vec<_>>()
+            .join("\r\n");
+        self.with_format(Self::FILE_LIST, list.into_bytes())
+    }
+
+    /// Adds a format with the given MIME type.
+    ///
+    /// If the format is already present, its data is replaced.
+    pub fn insert(&mut self, mime_type: impl Into<String>, data: impl Into<Arc<[u8]>>) {
+        let mime_type = mime_type.into();
+        let data = data.into();
+        if let Some(entry) = self.formats.iter_mut().find(|(ty, _)| *ty == mime_type) {
+            entry.1 = data;
+        } else {
+            self.formats.push((mime_type, data));
+        }
+    }
+
+    /// Returns `true` if there are no formats.
+    pub fn is_empty(&self) -> bool {
+        self.formats.is_empty()
+    }
+
+    /// Returns the MIME types of the available formats, in insertion order.
+    pub fn mime_types(&self) -> impl Iterator<Item = &str> {
+        self.formats.iter().map(|(ty, _)| ty.as_str())
+    }
+
+    /// Returns the data of the format with the given MIME type, if any.
+    pub fn get(&self, mime_type: &str) -> Option<&[u8]> {
+        self.formats
+            .iter()
+            .find(|(ty, _)| ty == mime_type)
+            .map(|(_, data)| &**data)
+    }
+
+    /// Returns the plain text, if any.
+    ///
+    /// Returns `None` if the text isn't valid UTF-8.
+    pub fn text(&self) -> Option<&str> {
+        self.get(Self::TEXT)
+            .and_then(|data| std::str::from_utf8(data).ok())
+    }
+
+    /// Returns the HTML fragment, if any.
+    ///
+    /// Returns `None` if the fragment isn't valid UTF-8.
+    pub fn html(&self) -> Option<&str> {
+        self.get(Self::HTML)
+            .and_then(|data| std::str::from_utf8(data).ok())
+    }
+
+    /// Returns the PNG-encoded image, if any.
+    pub fn png(&self) -> Option<&[u8]> {
+        self.get(Self::PNG)
+    }
+
+    /// Returns the list of files, if any.
+    ///
+    /// Entries which aren't `file:` URIs of local files are skipped, as are comment lines.
+    pub fn files(&self) -> Option<Vec<PathBuf>> {
+        let list = std::str::from_utf8(self.get(Self::FILE_LIST)?).ok()?;
+        Some(
+            list.lines()
+                .filter(|line| !line.starts_with('#'))
+                .filter_map(|line| file_path(line.trim(), cfg!(windows)))
+                .map(PathBuf::from)
+                .collect(),
+        )
+    }
+}
+
+/// Returns the `file://` URI of the absolute `path`.
+///
+/// If `windows` is true, `path` is a Windows path, such as `C:\dir\file` or `\\server\share\file`.
+fn file_uri(path: &str, windows: bool) -> String {
+    let mut uri = String::from("file://");
+    let path = if windows {
+        let path = path.replace('\\', "/");
+        match path.strip_prefix("//") {
+            // A UNC path, whose first component is the host.
+            Some(unc) => unc.to_string(),
+            None => format!("/{path}"),
+        }
+    } else {
+        path.to_string()
+    };
+    for byte in path.bytes() {
+        // The unreserved characters and the separators which are allowed in a path (RFC 3986).
+        if byte.is_ascii_alphanumeric() || b"-._~/:@!$&'()*+,;=".contains(&byte) {
+            uri.push(char::from(byte));
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
+/// Returns the path of the local file with the `file:` URI `uri`, if it is one.
+///
+/// If `windows` is true, the path is returned as a Windows path,
+/// and URIs with a host other than `localhost` are returned as UNC paths.
+fn file_path(uri: &str, windows: bool) -> Option<String> {
+    let rest = uri.strip_prefix("file:")?;
+    let (host, path) = match rest.strip_prefix("//") {
+        Some(rest) => rest.split_at(rest.find('/')?),
+        None => ("", rest),
+    };
+    if !path.starts_with('/') {
+        return None;
+    }
+    let path = percent_decode(path)?;
+    if !windows {
+        return (host.is_empty() || host == "localhost").then_some(path);
+    }
+    let path = if host.is_empty() || host == "localhost" {
+        // Drive letters are written as `/C:/dir` or, in older URIs, `/C|/dir`.
+        let bytes = path.as_bytes();
+        if bytes.len() >= 3 && bytes[1].is_ascii_alphabetic() && matches!(bytes[2], b':' | b'|') {
+            format!("{}:{}", &path[1..2], &path[3..])
+        } else {
+            path
+        }
+    } else {
+        format!("//{}{path}", percent_decode(host)?)
+    };
+    Some(path.replace('/', "\\"))
+}
+
+/// Decodes the `%XX` escapes in `text`, returning `None` if they aren't valid UTF-8.
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = tail
+            .get(..2)
+            .filter(|hex| byte == b'%' && hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(decoded) => {
+                bytes.push(decoded);
+                rest = &tail[2..];
+            }
+            None => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+impl From<String> for ClipboardData {
+    fn from(text: String) -> Self {
+        Self::from_text(text)
+    }
+}
+
+impl From<&str> for ClipboardData {
+    fn from(text: &str) -> Self {
+        Self::from_text(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unix_file_uris() {
+        let path = "/home/me/My files/#1 100%.txt";
+        let uri = file_uri(path, false);
+        assert_eq!(uri, "file:///home/me/My%20files/%231%20100%25.txt");
+        assert_eq!(file_path(&uri, false).as_deref(), Some(path));
+
+        assert_eq!(
+            file_path("file://localhost/tmp/caf%C3%A9", false).as_deref(),
+            Some("/tmp/café")
+        );
+        assert_eq!(file_path("file:/tmp/a", false).as_deref(), Some("/tmp/a"));
+        assert_eq!(file_path("file://server/tmp/a", false), None);
+        assert_eq!(file_path("https://example.com/a", false), None);
+        // Invalid escapes are kept as they are.
+        assert_eq!(file_path("file:///a%2", false).as_deref(), Some("/a%2"));
+        assert_eq!(file_path("file:///a%+1", false).as_deref(), Some("/a%+1"));
+    }
+
+    #[test]
+    fn windows_file_uris() {
+        let path = r"C:\Users\Me\My files\#1.txt";
+        let uri = file_uri(path, true);
+        assert_eq!(uri, "file:///C:/Users/Me/My%20files/%231.txt");
+        assert_eq!(file_path(&uri, true).as_deref(), Some(path));
+        assert_eq!(
+            file_path("file:///c|/dir/a.txt", true).as_deref(),
+            Some(r"c:\dir\a.txt")
+        );
+
+        let unc = r"\\server\share\a b.txt";
+        let uri = file_uri(unc, true);
+        assert_eq!(uri, "file://server/share/a%20b.txt");
+        assert_eq!(file_path(&uri, true).as_deref(), Some(unc));
+    }
+}
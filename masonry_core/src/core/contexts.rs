@@ -13,14 +13,15 @@ use parley::{FontContext, LayoutContext};
 use tracing::{trace, warn};
 use tree_arena::{ArenaMut, ArenaMutList, ArenaRefList};
 
-use crate::app::{MutateCallback, RenderRootSignal, RenderRootState};
+use crate::animation::{Animation, AnimationStatus};
+use crate::app::{DragPreview, DragState, MutateCallback, RenderRootSignal, RenderRootState};
 use crate::core::{
-    AllowRawMut, BrushIndex, DefaultProperties, ErasedAction, FromDynWidget, LayerType, NewWidget,
-    PropertiesMut, PropertiesRef, ResizeDirection, Widget, WidgetArenaNode, WidgetId, WidgetMut,
-    WidgetPod, WidgetRef, WidgetState,
+    AllowRawMut, BrushIndex, ClipboardData, DefaultProperties, ErasedAction, FromDynWidget,
+    LayerType, NewWidget, PropertiesMut, PropertiesRef, ResizeDirection, Widget, WidgetArenaNode,
+    WidgetId, WidgetMut, WidgetPod, WidgetRef, WidgetState,
 };
 use crate::kurbo::{Affine, Axis, Insets, Point, Rect, Size, Vec2};
-use crate::layout::{LayoutSize, LenDef, SizeDef};
+use crate::layout::{LayoutSize, LenDef, Length, LengthContext, SizeDef};
 use crate::passes::layout::{place_widget, resolve_length, resolve_size, run_layout_on};
 use crate::peniko::Color;
 use crate::util::{TypeSet, get_debug_color};
@@ -87,6 +88,7 @@ pub struct EventCtx<'a> {
     pub(crate) children: ArenaMutList<'a, WidgetArenaNode>,
     pub(crate) default_properties: &'a DefaultProperties,
     pub(crate) target: WidgetId,
+    pub(crate) selection_scope: Option<WidgetId>,
     pub(crate) allow_pointer_capture: bool,
     pub(crate) is_handled: bool,
 }
@@ -137,7 +139,7 @@ pub struct ComposeCtx<'a> {
 /// A context passed to [`Widget::paint`] method.
 pub struct PaintCtx<'a> {
     pub(crate) global_state: &'a mut RenderRootState,
-    pub(crate) widget_state: &'a WidgetState,
+    pub(crate) widget_state: &'a mut WidgetState,
     pub(crate) children: ArenaMutList<'a, WidgetArenaNode>,
 }
 
@@ -256,13 +258,19 @@ impl MutateCtx<'_> {
             .children
             .item_mut(child.id())
             .expect("get_mut: child not found");
+        let style_state = node_mut
+            .item
+            .state
+            .style_state(self.global_state.focused_widget);
         let child_ctx = MutateCtx {
             global_state: self.global_state,
             parent_widget_state: Some(&mut self.widget_state),
             widget_state: &mut node_mut.item.state,
             properties: PropertiesMut {
                 map: &mut node_mut.item.properties,
-                default_map: self.properties.default_map,
+                default_map: self
+                    .default_properties
+                    .for_widget((*node_mut.item.widget).type_id(), style_state),
             },
             changed_properties: &mut node_mut.item.changed_properties,
             children: node_mut.children,
@@ -324,7 +332,13 @@ impl<'w> QueryCtx<'w> {
             widget_state: &child_node.item.state,
             properties: PropertiesRef {
                 map: &child_node.item.properties,
-                default_map: self.properties.default_map,
+                default_map: self.default_properties.for_widget(
+                    (*child_node.item.widget).type_id(),
+                    child_node
+                        .item
+                        .state
+                        .style_state(self.global_state.focused_widget),
+                ),
             },
             children: child_node.children,
             default_properties: self.default_properties,
@@ -435,23 +449,53 @@ impl EventCtx<'_> {
         self.global_state.needs_pointer_pass = true;
     }
 
-    /// Sends a signal to parent widgets to scroll this widget's border-box into view.
-    pub fn request_scroll_to_this(&mut self) {
-        let rect = self.widget_state.border_box_size().to_rect();
-        self.global_state
-            .scroll_request_targets
-            .push((self.widget_state.id, rect));
+    /// Starts a [drag-and-drop] operation carrying `payload`.
+    ///
+    /// The `preview` widget is shown on a new layer, initially over this widget,
+    /// and follows the pointer until the drag ends.
+    /// Widgets under the pointer which [accept drops] then get [`DragEvent`]s,
+    /// and this widget gets [`DragEvent::Ended`] when the pointer is released.
+    ///
+    /// This should be called while handling a pointer event, usually a [`Move`] event
+    /// after the pointer was pressed on this widget.
+    ///
+    /// [drag-and-drop]: crate::doc::masonry_concepts#drag-and-drop
+    /// [accept drops]: Widget::accepts_drop
+    /// [`DragEvent`]: crate::core::DragEvent
+    /// [`DragEvent::Ended`]: crate::core::DragEvent::Ended
+    /// [`Move`]: ui_events::pointer::PointerEvent::Move
+    #[track_caller]
+    pub fn start_drag(&mut self, payload: impl AnyDebug, preview: NewWidget<impl Widget + ?Sized>) {
+        let id = self.widget_id();
+        if self.global_state.drag.is_some() {
+            debug_panic!("start_drag - '{id}': a drag is already in progress");
+            return;
+        }
+        let preview = NewWidget::new(DragPreview::new(preview));
+        let preview_origin = self.to_window(self.border_box().origin());
+        self.global_state.drag = Some(DragState {
+            source: id,
+            payload: Box::new(payload),
+            preview_id: preview.id(),
+            new_preview: Some(preview),
+            preview_origin,
+            preview_offset: Vec2::ZERO,
+            target: None,
+        });
     }
 
-    /// Sends a signal to parent widgets to scroll the provided `rect` into view.
+    /// Returns `true` if a [drag-and-drop] operation is in progress.
     ///
-    /// The `rect` must be in this widget's content-box coordinate space.
-    pub fn request_scroll_to(&mut self, rect: Rect) {
-        // Convert from this widget's content-box space to border-box space.
-        let rect = rect + self.widget_state.border_box_translation();
-        self.global_state
-            .scroll_request_targets
-            .push((self.widget_state.id, rect));
+    /// [drag-and-drop]: crate::doc::masonry_concepts#drag-and-drop
+    pub fn is_dragging(&self) -> bool {
+        self.global_state.drag.is_some()
+    }
+
+    /// Returns the payload of the ongoing [drag-and-drop] operation, if it has type `T`.
+    ///
+    /// [drag-and-drop]: crate::doc::masonry_concepts#drag-and-drop
+    pub fn drag_payload<T: AnyDebug>(&self) -> Option<&T> {
+        self.global_state.drag.as_ref()?.payload.downcast_ref()
     }
 
     /// Sets the event as "handled", which stops its propagation to parent
@@ -473,6 +517,52 @@ impl EventCtx<'_> {
         self.target
     }
 
+    /// The closest ancestor of the [target](Self::target) which is a [selection scope].
+    ///
+    /// Widgets with [selectable text] should let pointer presses bubble up to the scope
+    /// when this is `Some`, so that the scope can select text across them.
+    ///
+    /// This is only computed for [`PointerEvent::Down`](crate::core::PointerEvent::Down),
+    /// which is the event which starts a selection, and is `None` for other events.
+    ///
+    /// [selection scope]: crate::doc::masonry_concepts#selection-scopes
+    /// [selectable text]: Widget::selectable_text
+    pub fn selection_scope(&self) -> Option<WidgetId> {
+        self.selection_scope
+    }
+
+    /// Returns a [`WidgetRef`] to a child widget.
+    ///
+    /// This lets a widget inspect its descendants while handling an event,
+    /// e.g. to find which of them is under a pointer it has captured.
+    pub fn get(&self, child: WidgetId) -> WidgetRef<'_, dyn Widget> {
+        let child_node = self
+            .children
+            .reborrow()
+            .into_item(child)
+            .expect("get: child not found");
+        let child_ctx = QueryCtx {
+            global_state: self.global_state,
+            widget_state: &child_node.item.state,
+            properties: PropertiesRef {
+                map: &child_node.item.properties,
+                default_map: self.default_properties.for_widget(
+                    (*child_node.item.widget).type_id(),
+                    child_node
+                        .item
+                        .state
+                        .style_state(self.global_state.focused_widget),
+                ),
+            },
+            children: child_node.children,
+            default_properties: self.default_properties,
+        };
+        WidgetRef {
+            ctx: child_ctx,
+            widget: &*child_node.item.widget,
+        }
+    }
+
     /// Requests [text focus].
     ///
     /// Because only one widget can be focused at a time, multiple focus requests
@@ -526,6 +616,60 @@ impl EventCtx<'_> {
     }
 }
 
+impl UpdateCtx<'_> {
+    /// Requests [text focus].
+    ///
+    /// This behaves like [`EventCtx::request_focus`], and is mostly useful for widgets
+    /// which should be focused as soon as they're added, such as menus in a [layer].
+    ///
+    /// [text focus]: crate::doc::masonry_concepts#text-focus
+    /// [layer]: crate::doc::masonry_concepts#layers
+    pub fn request_focus(&mut self) {
+        trace!("request_focus");
+        let id = self.widget_id();
+        self.global_state.next_focused_widget = Some(id);
+    }
+
+    /// Advances `animation` by `elapsed_ns`, and requests another animation frame if it's still running.
+    ///
+    /// This is meant to be called from [`Widget::on_anim_frame`] with the interval it receives.
+    /// Widgets still need to request the passes which display the new value,
+    /// such as [`request_layout`](Self::request_layout) or [`request_paint_only`](Self::request_paint_only).
+    ///
+    /// Returns the status of the animation after this advancement.
+    ///
+    /// [`Widget::on_anim_frame`]: crate::core::Widget::on_anim_frame
+    pub fn animate(&mut self, animation: &mut impl Animation, elapsed_ns: u64) -> AnimationStatus {
+        let status = animation.advance(elapsed_ns);
+        if !status.is_completed() {
+            self.request_anim_frame();
+        }
+        status
+    }
+}
+
+// --- MARK: SCROLL REQUESTS
+impl_context_method!(MutateCtx<'_>, EventCtx<'_>, {
+    /// Sends a signal to parent widgets to scroll this widget's border-box into view.
+    pub fn request_scroll_to_this(&mut self) {
+        let rect = self.widget_state.border_box_size().to_rect();
+        self.global_state
+            .scroll_request_targets
+            .push((self.widget_state.id, rect));
+    }
+
+    /// Sends a signal to parent widgets to scroll the provided `rect` into view.
+    ///
+    /// The `rect` must be in this widget's content-box coordinate space.
+    pub fn request_scroll_to(&mut self, rect: Rect) {
+        // Convert from this widget's content-box space to border-box space.
+        let rect = rect + self.widget_state.border_box_translation();
+        self.global_state
+            .scroll_request_targets
+            .push((self.widget_state.id, rect));
+    }
+});
+
 // --- MARK: ACCESSIBILITY
 impl AccessCtx<'_> {
     // TODO - We need access to the TreeUpdate to create sub-nodes for text runs,
@@ -545,6 +689,25 @@ impl AccessCtx<'_> {
 
 // --- MARK: COMPUTE LENGTH
 impl_context_method!(MeasureCtx<'_>, LayoutCtx<'_>, {
+    /// Resolves a possibly relative `length` into logical pixels.
+    ///
+    /// Font-relative lengths are resolved against this widget's font size,
+    /// and viewport-relative lengths against the window size.
+    /// If `length` is viewport-relative, this widget will be laid out again
+    /// when the window is resized.
+    ///
+    /// `context_length` is used for percentages, and must be in logical pixels.
+    /// Returns `None` if `length` is a percentage and there is no context length.
+    pub fn resolve_length(&mut self, length: Length, context_length: Option<f64>) -> Option<f64> {
+        self.widget_state.uses_viewport_units |= length.is_viewport_relative();
+        let lengths = LengthContext {
+            font_size: self.widget_state.font_size,
+            root_font_size: self.global_state.root_font_size,
+            viewport_size: self.global_state.viewport_size,
+        };
+        length.resolve(&lengths, context_length)
+    }
+
     /// Computes the `child`'s preferred border-box length on the given `axis`.
     ///
     /// The returned length will be finite, non-negative, and in device pixels.
@@ -606,6 +769,7 @@ impl_context_method!(MeasureCtx<'_>, LayoutCtx<'_>, {
             context_size,
             axis,
             cross_length,
+            self.widget_state.font_size,
         )
     }
 });
@@ -780,6 +944,7 @@ impl LayoutCtx<'_> {
             node,
             auto_size,
             context_size,
+            self.widget_state.font_size,
         )
     }
 
@@ -812,6 +977,7 @@ impl LayoutCtx<'_> {
             self.default_properties,
             node,
             chosen_size,
+            self.widget_state.font_size,
         );
 
         let state_mut = &mut self.children.item_mut(id).unwrap().item.state;
@@ -1604,6 +1770,23 @@ impl_context_method!(
             self.global_state.mutate_callbacks.push(callback);
         }
 
+        /// Queues a callback that will be called with a [`WidgetMut`] for the given descendant widget.
+        ///
+        /// This is like [`mutate_later`](Self::mutate_later), but the widget is identified by its id,
+        /// so it doesn't need to be a direct child.
+        /// The widget must still be in the tree when the callbacks are run.
+        pub fn mutate_descendant_later(
+            &mut self,
+            descendant: WidgetId,
+            f: impl FnOnce(WidgetMut<'_, dyn Widget>) + Send + 'static,
+        ) {
+            let callback = MutateCallback {
+                id: descendant,
+                callback: Box::new(f),
+            };
+            self.global_state.mutate_callbacks.push(callback);
+        }
+
         /// Returns direct reference to the stored child, and a context handle for that child.
         pub fn get_raw<Child: Widget + FromDynWidget + ?Sized>(
             &mut self,
@@ -1703,6 +1886,22 @@ impl_context_method!(
                 .emit_signal(RenderRootSignal::Action(action, self.widget_state.id));
         }
 
+        /// Submits a type-erased action on behalf of the widget with the given `source` id.
+        ///
+        /// This is meant for [layers] which report user interaction back to the widget
+        /// which created them, for instance a menu reporting the chosen item to the widget
+        /// which opened it.
+        /// The app driver will receive the action as if `source` had submitted it.
+        ///
+        /// For further details see [`ErasedAction`].
+        ///
+        /// [layers]: crate::doc::masonry_concepts#layers
+        pub fn submit_untyped_action_for(&mut self, source: WidgetId, action: ErasedAction) {
+            trace!("submit_untyped_action_for");
+            self.global_state
+                .emit_signal(RenderRootSignal::Action(action, source));
+        }
+
         /// Sets the IME cursor area in the widget's content-box coordinate space.
         ///
         /// When this widget is [focused] and [accepts text input], the reported IME area is sent
@@ -1735,6 +1934,29 @@ impl_context_method!(
                 .emit_signal(RenderRootSignal::ClipboardStore(contents));
         }
 
+        /// Sets the contents of the platform clipboard, in one or more formats.
+        ///
+        /// Platforms store the formats they support and drop the others.
+        /// See [`ClipboardData`] for details.
+        pub fn set_clipboard_data(&mut self, data: ClipboardData) {
+            trace!("set_clipboard_data");
+            self.global_state
+                .emit_signal(RenderRootSignal::ClipboardStoreData(data));
+        }
+
+        /// Asks the platform for the contents of the clipboard.
+        ///
+        /// The contents are delivered to this widget later, in a
+        /// [`TextEvent::ClipboardData`] event.
+        /// Platforms may answer with an empty [`ClipboardData`] if the clipboard can't be read.
+        ///
+        /// [`TextEvent::ClipboardData`]: crate::core::TextEvent::ClipboardData
+        pub fn request_clipboard(&mut self) {
+            trace!("request_clipboard");
+            self.global_state
+                .emit_signal(RenderRootSignal::ClipboardRead(self.widget_state.id));
+        }
+
         /// Starts a window drag.
         ///
         /// Moves the window with the left mouse button until the button is released.
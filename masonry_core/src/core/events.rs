@@ -6,7 +6,8 @@
 use ui_events::keyboard::{Code, Key, KeyState, KeyboardEvent};
 use vello::kurbo::Rect;
 
-use crate::dpi::PhysicalSize;
+use crate::core::{ClipboardData, WidgetId};
+use crate::dpi::{PhysicalPosition, PhysicalSize};
 use crate::util::Duration;
 
 // --- MARK: TYPES
@@ -24,6 +25,8 @@ pub enum WindowEvent {
     EnableAccessTree,
     /// The accessibility tree is no longer updated when rendering the app.
     DisableAccessTree,
+    /// The window's light/dark mode changed, e.g. because the user changed their OS settings.
+    ThemeChanged(WindowTheme),
 }
 
 /// A text-related event.
@@ -35,9 +38,21 @@ pub enum TextEvent {
     Ime(Ime),
     /// The window took or lost focus.
     WindowFocusChange(bool),
-    // TODO - Handle rich text copy-pasting
-    /// The user pasted content in.
+    /// The user pasted text in.
+    ///
+    /// This is sent to the focused widget by the platform when the user triggers
+    /// its paste shortcut, and only carries plain text.
+    /// Widgets which want richer formats can call [`request_clipboard`] instead.
+    ///
+    /// [`request_clipboard`]: crate::core::EventCtx::request_clipboard
     ClipboardPaste(String),
+    /// The contents of the clipboard, as asked for with [`request_clipboard`].
+    ///
+    /// Unlike other text events, this targets the widget which made the request,
+    /// rather than the focused widget, then bubbles to each parent.
+    ///
+    /// [`request_clipboard`]: crate::core::EventCtx::request_clipboard
+    ClipboardData(ClipboardData),
 }
 
 /// An accessibility event.
@@ -55,8 +70,48 @@ pub struct AccessEvent {
     pub data: Option<accesskit::ActionData>,
 }
 
-/// The light/dark mode of the window.
+/// An event related to a [drag-and-drop] operation.
+///
+/// Apart from [`DragEvent::Ended`], these events target the widget currently
+/// under the dragged item which [accepts drops](crate::core::Widget::accepts_drop),
+/// then bubble to each parent.
+///
+/// [drag-and-drop]: crate::doc::masonry_concepts#drag-and-drop
 #[derive(Debug, Clone, PartialEq)]
+pub enum DragEvent {
+    /// The dragged item entered the widget.
+    Enter(DragUpdate),
+    /// The dragged item moved over the widget.
+    ///
+    /// This is also sent right after [`DragEvent::Enter`].
+    Over(DragUpdate),
+    /// The dragged item left the widget, or the drag was cancelled.
+    Leave,
+    /// The dragged item was dropped on the widget.
+    ///
+    /// The widget should call [`EventCtx::set_handled`] to accept the drop.
+    /// Otherwise, the drop is considered rejected.
+    ///
+    /// [`EventCtx::set_handled`]: crate::core::EventCtx::set_handled
+    Drop(DragUpdate),
+    /// The drag started by this widget ended.
+    ///
+    /// This targets the widget which started the drag.
+    /// The value is `true` if the item was dropped on a widget which accepted it.
+    Ended(bool),
+}
+
+/// The state of an ongoing drag, as seen by a drop target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragUpdate {
+    /// The widget which started the drag.
+    pub source: WidgetId,
+    /// The position of the pointer, in the window's coordinate space.
+    pub position: PhysicalPosition<f64>,
+}
+
+/// The light/dark mode of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WindowTheme {
     /// Light mode.
     Light,
@@ -217,6 +272,7 @@ impl TextEvent {
             Self::Ime(Ime::Preedit(_, _)) => "Ime::Preedit(\"...\")",
             Self::WindowFocusChange(_) => "WindowFocusChange",
             Self::ClipboardPaste(_) => "ClipboardPaste",
+            Self::ClipboardData(_) => "ClipboardData",
         }
     }
 }
@@ -13,6 +13,12 @@ use crate::core::{EventCtx, PointerEvent, PropertiesMut};
 pub enum LayerType {
     /// A simple tooltip showing some text until the mouse moves.
     Tooltip(String),
+    /// A context menu, usually opened with a secondary click.
+    ContextMenu,
+    /// The option list of a dropdown.
+    Dropdown,
+    /// The preview of an item being dragged, following the pointer.
+    DragPreview,
     /// Unknown layer type. Always use the widget fallback.
     #[default]
     Other,
@@ -3,10 +3,13 @@
 
 //! Basic types and traits Masonry is built on.
 
+mod clipboard;
 mod contexts;
 mod events;
 mod layer;
 mod properties;
+mod rich_text;
+mod style;
 mod text;
 mod widget;
 mod widget_arena;
@@ -17,10 +20,13 @@ mod widget_ref;
 mod widget_state;
 mod widget_tag;
 
+pub use clipboard::*;
 pub use contexts::*;
 pub use events::*;
 pub use layer::*;
 pub use properties::*;
+pub use rich_text::*;
+pub use style::*;
 pub use text::*;
 pub use widget::*;
 pub use widget_mut::*;
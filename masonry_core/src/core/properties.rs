@@ -5,7 +5,7 @@ use std::any::TypeId;
 use std::collections::HashMap;
 use std::default::Default;
 
-use crate::core::Widget;
+use crate::core::{Selector, StyleClasses, StyleRule, StyleState, Widget};
 use crate::util::AnyMap;
 
 /// A marker trait that indicates that a type is intended to be used as a widget's property.
@@ -52,7 +52,7 @@ pub struct Properties {
 #[derive(Clone, Copy)]
 pub struct PropertiesRef<'a> {
     pub(crate) map: &'a AnyMap,
-    pub(crate) default_map: &'a AnyMap,
+    pub(crate) default_map: DefaultMap<'a>,
 }
 
 /// Mutable reference to a collection of [properties](Property) that a widget has access to.
@@ -60,7 +60,7 @@ pub struct PropertiesRef<'a> {
 /// Used by the [`Widget`] trait during most passes.
 pub struct PropertiesMut<'a> {
     pub(crate) map: &'a mut AnyMap,
-    pub(crate) default_map: &'a AnyMap,
+    pub(crate) default_map: DefaultMap<'a>,
 }
 
 // TODO - Better document local vs default properties.
@@ -69,13 +69,33 @@ pub struct PropertiesMut<'a> {
 ///
 /// Default property values can be added to this collection for
 /// every `(widget type, property type)` pair.
+///
+/// This also acts as a stylesheet: values can be added for
+/// `(widget type, selector, property type)` triples, where the [`Selector`]
+/// matches widgets by [style class](StyleClasses) and interaction state.
 #[derive(Default, Debug)]
 pub struct DefaultProperties {
-    /// Maps widget types to the default property map for that widget.
-    pub(crate) map: HashMap<TypeId, AnyMap>,
+    /// Maps widget types to the default properties for that widget.
+    pub(crate) map: HashMap<TypeId, TypeDefaults>,
     pub(crate) dummy_map: AnyMap,
 }
 
+/// The default properties for a single widget type.
+#[derive(Default, Debug)]
+pub(crate) struct TypeDefaults {
+    pub(crate) map: AnyMap,
+    /// Sorted by increasing specificity.
+    pub(crate) rules: Vec<StyleRule>,
+}
+
+/// The default properties which apply to a single widget, given its type and state.
+#[derive(Clone, Copy)]
+pub(crate) struct DefaultMap<'a> {
+    pub(crate) map: &'a AnyMap,
+    pub(crate) rules: &'a [StyleRule],
+    pub(crate) state: StyleState,
+}
+
 /// A marker trait indicating that the widget this is implemented for supports the property `P`.
 ///
 /// You should implement this for your widget types, with each property the widget reads.
@@ -170,12 +190,12 @@ impl PropertiesRef<'_> {
     /// Returns value of property `P`.
     ///
     /// If the widget has an entry for `P`, returns its value.
-    /// If the default property map has an entry for `P`, returns its value.
+    /// If a matching style rule or the default property map has an entry for `P`, returns its value.
     /// Otherwise returns [`Property::static_default()`].
     pub fn get<P: Property>(&self) -> &P {
         if let Some(p) = self.map.get::<P>() {
             p
-        } else if let Some(p) = self.default_map.get::<P>() {
+        } else if let Some(p) = self.default_map.get::<P>(self.map) {
             p
         } else {
             P::static_default()
@@ -184,10 +204,12 @@ impl PropertiesRef<'_> {
 
     /// Returns the defined value of property `P`.
     ///
-    /// If the widget has an explicit entry, or the default property map has an explicit entry,
-    /// then this will return a value. Otherwise it will return `None`.
+    /// If the widget has an explicit entry, or a matching style rule or the default property map
+    /// has an explicit entry, then this will return a value. Otherwise it will return `None`.
     pub fn get_defined<P: Property>(&self) -> Option<&P> {
-        self.map.get::<P>().or_else(|| self.default_map.get::<P>())
+        self.map
+            .get::<P>()
+            .or_else(|| self.default_map.get::<P>(self.map))
     }
 }
 
@@ -202,12 +224,12 @@ impl PropertiesMut<'_> {
     /// Returns value of property `P`.
     ///
     /// If the widget has an entry for `P`, returns its value.
-    /// If the default property map has an entry for `P`, returns its value.
+    /// If a matching style rule or the default property map has an entry for `P`, returns its value.
     /// Otherwise returns [`Property::static_default()`].
     pub fn get<P: Property>(&self) -> &P {
         if let Some(p) = self.map.get::<P>() {
             p
-        } else if let Some(p) = self.default_map.get::<P>() {
+        } else if let Some(p) = self.default_map.get::<P>(self.map) {
             p
         } else {
             P::static_default()
@@ -216,10 +238,12 @@ impl PropertiesMut<'_> {
 
     /// Returns the defined value of property `P`.
     ///
-    /// If the widget has an explicit entry, or the default property map has an explicit entry,
-    /// then this will return a value. Otherwise it will return `None`.
+    /// If the widget has an explicit entry, or a matching style rule or the default property map
+    /// has an explicit entry, then this will return a value. Otherwise it will return `None`.
     pub fn get_defined<P: Property>(&self) -> Option<&P> {
-        self.map.get::<P>().or_else(|| self.default_map.get::<P>())
+        self.map
+            .get::<P>()
+            .or_else(|| self.default_map.get::<P>(self.map))
     }
 
     /// Sets local property `P` to given value. Returns the previous value if `P` was already set.
@@ -253,6 +277,30 @@ impl PropertiesMut<'_> {
     }
 }
 
+impl<'a> DefaultMap<'a> {
+    /// Returns the value of `P` from the most specific matching style rule,
+    /// or from the plain default properties.
+    ///
+    /// `local_map` is used to find the style classes of the widget.
+    pub(crate) fn get<P: Property>(self, local_map: &AnyMap) -> Option<&'a P> {
+        if !self.rules.is_empty() {
+            let classes = local_map
+                .get::<StyleClasses>()
+                .unwrap_or(StyleClasses::static_default());
+            let rule_value = self
+                .rules
+                .iter()
+                .rev()
+                .filter(|rule| rule.selector.matches(classes, self.state))
+                .find_map(|rule| rule.properties.get::<P>());
+            if rule_value.is_some() {
+                return rule_value;
+            }
+        }
+        self.map.get::<P>()
+    }
+}
+
 impl DefaultProperties {
     /// Creates an empty property map with no default values.
     ///
@@ -271,10 +319,80 @@ impl DefaultProperties {
     ///
     /// Widgets for which the property `P` isn't set will get `value` instead.
     pub fn insert<W: Widget, P: Property>(&mut self, value: P) -> Option<P> {
-        self.map.entry(TypeId::of::<W>()).or_default().insert(value)
+        self.map
+            .entry(TypeId::of::<W>())
+            .or_default()
+            .map
+            .insert(value)
+    }
+
+    /// Sets the value of property `P` for widgets of type `W` matched by `selector`.
+    ///
+    /// Returns the previous value if this rule already set `P`.
+    ///
+    /// See [`Selector`] for how rules are resolved.
+    pub fn insert_rule<W: Widget, P: Property>(
+        &mut self,
+        selector: Selector,
+        value: P,
+    ) -> Option<P> {
+        if selector == Selector::new() {
+            return self.insert::<W, P>(value);
+        }
+        let rules = &mut self.map.entry(TypeId::of::<W>()).or_default().rules;
+        if let Some(rule) = rules.iter_mut().find(|rule| rule.selector == selector) {
+            return rule.properties.insert(value);
+        }
+        let mut properties = AnyMap::new();
+        properties.insert(value);
+        // Keep rules sorted by specificity, with later rules after earlier ones.
+        let idx =
+            rules.partition_point(|rule| rule.selector.specificity() <= selector.specificity());
+        rules.insert(
+            idx,
+            StyleRule {
+                selector,
+                properties,
+            },
+        );
+        None
+    }
+
+    pub(crate) fn for_widget(&self, id: TypeId, state: StyleState) -> DefaultMap<'_> {
+        match self.map.get(&id) {
+            Some(defaults) => DefaultMap {
+                map: &defaults.map,
+                rules: &defaults.rules,
+                state,
+            },
+            None => DefaultMap {
+                map: &self.dummy_map,
+                rules: &[],
+                state,
+            },
+        }
     }
 
-    pub(crate) fn for_widget(&self, id: TypeId) -> &AnyMap {
-        self.map.get(&id).unwrap_or(&self.dummy_map)
+    /// Returns the type ids of the properties set by the rules for widget type `id`
+    /// whose selector satisfies `filter`.
+    ///
+    /// Used to notify widgets when rules start or stop applying to them.
+    pub(crate) fn rule_properties(
+        &self,
+        id: TypeId,
+        filter: impl Fn(&Selector) -> bool,
+    ) -> Vec<TypeId> {
+        let Some(defaults) = self.map.get(&id) else {
+            return Vec::new();
+        };
+        let mut property_types = Vec::new();
+        for rule in defaults.rules.iter().filter(|rule| filter(&rule.selector)) {
+            for property_type in rule.properties.as_raw().keys() {
+                if !property_types.contains(property_type) {
+                    property_types.push(*property_type);
+                }
+            }
+        }
+        property_types
     }
 }
@@ -0,0 +1,378 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use parley::RangedBuilder;
+use parley::layout::Decoration;
+use vello::peniko::{Brush, Color};
+
+use crate::core::{ArcStr, BrushIndex, StyleProperty};
+
+/// The brush index used for the text of links.
+const LINK_BRUSH: BrushIndex = BrushIndex(1);
+/// The brush index used for the first [`SpanStyle::Color`] of a [`RichText`].
+const FIRST_COLOR_BRUSH: usize = 2;
+
+/// Text with styles applied to ranges of it.
+///
+/// Rich text is cheap to clone, and comparing two clones is cheap,
+/// so views can store and diff it on every rebuild.
+///
+/// It can be created from a plain string, and spans added with [`with_span`](Self::with_span),
+/// or built piece by piece with a [`RichTextBuilder`]:
+///
+/// ```
+/// # use masonry_core as masonry;
+/// use masonry::core::{RichText, SpanStyle, StyleProperty};
+/// use masonry::parley::FontWeight;
+///
+/// let mut builder = RichText::builder();
+/// builder
+///     .push("Read the ")
+///     .push_styled("full", [StyleProperty::FontWeight(FontWeight::BOLD).into()])
+///     .push(" ")
+///     .push_link("documentation", "https://docs.rs/masonry");
+/// let text = builder.build();
+///
+/// assert_eq!(&**text.text(), "Read the full documentation");
+/// assert_eq!(text.link_at(20).map(|link| &**link), Some("https://docs.rs/masonry"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RichText {
+    text: ArcStr,
+    spans: Arc<[TextSpan]>,
+}
+
+/// A style applied to a range of a [`RichText`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSpan {
+    /// The byte range of the text which has the style.
+    pub range: Range<usize>,
+    /// The style of the range.
+    pub style: SpanStyle,
+}
+
+/// A style which can be applied to a range of a [`RichText`].
+///
+/// When spans overlap, the span which was added last takes precedence.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpanStyle {
+    /// A text style, such as the font weight or size.
+    ///
+    /// Brush properties are ignored; use [`SpanStyle::Color`] instead.
+    Property(StyleProperty),
+    /// The color of the text.
+    Color(Color),
+    /// A link to `target`, which is usually a URL.
+    ///
+    /// Links are underlined and drawn with the widget's link color.
+    /// Widgets which show rich text emit an action when a link is clicked.
+    Link(ArcStr),
+}
+
+impl From<StyleProperty> for SpanStyle {
+    fn from(property: StyleProperty) -> Self {
+        Self::Property(property)
+    }
+}
+
+impl From<Color> for SpanStyle {
+    fn from(color: Color) -> Self {
+        Self::Color(color)
+    }
+}
+
+/// The paint-only part of a text style.
+///
+/// See [`RichText::paint_style_at`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PaintStyle {
+    pub(crate) brush: BrushIndex,
+    pub(crate) underline: Option<Decoration<BrushIndex>>,
+    pub(crate) strikethrough: Option<Decoration<BrushIndex>>,
+}
+
+// --- MARK: BUILDERS
+impl RichText {
+    /// Creates rich text without any styles.
+    pub fn new(text: impl Into<ArcStr>) -> Self {
+        Self {
+            text: text.into(),
+            spans: Arc::new([]),
+        }
+    }
+
+    /// Creates a builder which creates rich text piece by piece.
+    pub fn builder() -> RichTextBuilder {
+        RichTextBuilder::default()
+    }
+
+    /// Applies `style` to the byte `range` of the text.
+    ///
+    /// The range must lie on character boundaries.
+    #[track_caller]
+    pub fn with_span(mut self, range: Range<usize>, style: impl Into<SpanStyle>) -> Self {
+        let mut spans = self.spans.to_vec();
+        push_span(&self.text, &mut spans, range, style.into());
+        self.spans = spans.into();
+        self
+    }
+}
+
+// --- MARK: METHODS
+impl RichText {
+    /// Returns the text, without its styles.
+    pub fn text(&self) -> &ArcStr {
+        &self.text
+    }
+
+    /// Returns the styled ranges of the text, in the order they were added.
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+
+    /// Returns true if the text has no styled ranges.
+    pub fn is_plain(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns true if the text contains any links.
+    pub fn has_links(&self) -> bool {
+        self.spans
+            .iter()
+            .any(|span| matches!(span.style, SpanStyle::Link(_)))
+    }
+
+    /// Returns the target of the link at the byte `index`, if any.
+    pub fn link_at(&self, index: usize) -> Option<&ArcStr> {
+        self.spans.iter().rev().find_map(|span| match &span.style {
+            SpanStyle::Link(target) if span.range.contains(&index) => Some(target),
+            _ => None,
+        })
+    }
+
+    /// Pushes the styles of the spans into `builder`.
+    ///
+    /// The builder must have been created for [`text`](Self::text).
+    /// The text then uses the brush indices of [`brushes`](Self::brushes).
+    pub fn push_styles(&self, builder: &mut RangedBuilder<'_, BrushIndex>) {
+        let mut color_brush = FIRST_COLOR_BRUSH;
+        for span in self.spans.iter() {
+            let range = span.range.clone();
+            match &span.style {
+                SpanStyle::Property(
+                    StyleProperty::Brush(_)
+                    | StyleProperty::UnderlineBrush(_)
+                    | StyleProperty::StrikethroughBrush(_),
+                ) => {}
+                SpanStyle::Property(property) => builder.push(property.clone(), range),
+                SpanStyle::Color(_) => {
+                    builder.push(StyleProperty::Brush(BrushIndex(color_brush)), range);
+                    color_brush += 1;
+                }
+                SpanStyle::Link(_) => {
+                    builder.push(StyleProperty::Brush(LINK_BRUSH), range.clone());
+                    builder.push(StyleProperty::Underline(true), range);
+                }
+            }
+        }
+    }
+
+    /// Returns the brushes for rendering the text with [`render_text`](super::render_text).
+    ///
+    /// Text outside of any colored span or link uses `text_color`, and links use `link_color`.
+    pub fn brushes(&self, text_color: Color, link_color: Color) -> Vec<Brush> {
+        let mut brushes = vec![text_color.into(), link_color.into()];
+        brushes.extend(self.spans.iter().filter_map(|span| match span.style {
+            SpanStyle::Color(color) => Some(color.into()),
+            _ => None,
+        }));
+        brushes
+    }
+
+    /// Returns whether any span which only affects painting overlaps `range`.
+    pub(crate) fn has_paint_spans_in(&self, range: &Range<usize>) -> bool {
+        self.spans.iter().any(|span| {
+            is_paint_only(&span.style)
+                && span.range.start < range.end
+                && range.start < span.range.end
+        })
+    }
+
+    /// Applies the spans which only affect painting at the byte `index` to `base`.
+    ///
+    /// This is the equivalent of [`push_styles`](Self::push_styles)
+    /// for layouts which were built without the spans.
+    pub(crate) fn paint_style_at(&self, index: usize, base: &PaintStyle) -> PaintStyle {
+        let mut brush = base.brush.clone();
+        let mut underline = base.underline.is_some();
+        let mut strikethrough = base.strikethrough.is_some();
+        let mut color_brush = FIRST_COLOR_BRUSH;
+        for span in self.spans.iter() {
+            let applies = span.range.contains(&index);
+            match &span.style {
+                SpanStyle::Color(_) => {
+                    if applies {
+                        brush = BrushIndex(color_brush);
+                    }
+                    color_brush += 1;
+                }
+                SpanStyle::Link(_) if applies => {
+                    brush = LINK_BRUSH;
+                    underline = true;
+                }
+                SpanStyle::Property(StyleProperty::Underline(value)) if applies => {
+                    underline = *value;
+                }
+                SpanStyle::Property(StyleProperty::Strikethrough(value)) if applies => {
+                    strikethrough = *value;
+                }
+                _ => {}
+            }
+        }
+
+        // Decorations which follow the color of the text keep doing so.
+        let decoration = |enabled: bool, base: &Option<Decoration<BrushIndex>>, text_brush| {
+            if !enabled {
+                return None;
+            }
+            Some(match base {
+                Some(decoration) if decoration.brush != text_brush => decoration.clone(),
+                Some(decoration) => Decoration {
+                    brush: brush.clone(),
+                    ..decoration.clone()
+                },
+                None => Decoration {
+                    brush: brush.clone(),
+                    offset: None,
+                    size: None,
+                },
+            })
+        };
+        PaintStyle {
+            underline: decoration(underline, &base.underline, base.brush.clone()),
+            strikethrough: decoration(strikethrough, &base.strikethrough, base.brush.clone()),
+            brush,
+        }
+    }
+}
+
+/// Returns whether `style` can be applied without changing the layout of the text.
+fn is_paint_only(style: &SpanStyle) -> bool {
+    matches!(
+        style,
+        SpanStyle::Color(_)
+            | SpanStyle::Link(_)
+            | SpanStyle::Property(StyleProperty::Underline(_) | StyleProperty::Strikethrough(_))
+    )
+}
+
+#[track_caller]
+fn push_span(text: &str, spans: &mut Vec<TextSpan>, range: Range<usize>, style: SpanStyle) {
+    if range.start > range.end
+        || !text.is_char_boundary(range.start)
+        || !text.is_char_boundary(range.end)
+    {
+        debug_panic!(
+            "Span range {range:?} isn't a valid range of the text, which has {} bytes.",
+            text.len()
+        );
+        return;
+    }
+    if !range.is_empty() {
+        spans.push(TextSpan { range, style });
+    }
+}
+
+impl PartialEq for RichText {
+    fn eq(&self, other: &Self) -> bool {
+        (Arc::ptr_eq(&self.text, &other.text) || self.text == other.text)
+            && (Arc::ptr_eq(&self.spans, &other.spans) || self.spans == other.spans)
+    }
+}
+
+impl From<ArcStr> for RichText {
+    fn from(text: ArcStr) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for RichText {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+// --- MARK: RICHTEXTBUILDER
+
+/// A builder which creates a [`RichText`] piece by piece.
+///
+/// Created with [`RichText::builder`].
+#[derive(Clone, Debug, Default)]
+pub struct RichTextBuilder {
+    text: String,
+    spans: Vec<TextSpan>,
+}
+
+impl RichTextBuilder {
+    /// Appends text without any styles.
+    pub fn push(&mut self, text: &str) -> &mut Self {
+        self.text.push_str(text);
+        self
+    }
+
+    /// Appends text with the given styles.
+    pub fn push_styled(
+        &mut self,
+        text: &str,
+        styles: impl IntoIterator<Item = SpanStyle>,
+    ) -> &mut Self {
+        let range = self.text.len()..self.text.len() + text.len();
+        self.text.push_str(text);
+        for style in styles {
+            push_span(&self.text, &mut self.spans, range.clone(), style);
+        }
+        self
+    }
+
+    /// Appends text which links to `target`.
+    pub fn push_link(&mut self, text: &str, target: impl Into<ArcStr>) -> &mut Self {
+        self.push_styled(text, [SpanStyle::Link(target.into())])
+    }
+
+    /// Applies `style` to the byte `range` of the text pushed so far.
+    ///
+    /// This is useful for styles which span several pieces of text.
+    /// The range must lie on character boundaries.
+    #[track_caller]
+    pub fn add_span(&mut self, range: Range<usize>, style: impl Into<SpanStyle>) -> &mut Self {
+        push_span(&self.text, &mut self.spans, range, style.into());
+        self
+    }
+
+    /// Returns the length in bytes of the text pushed so far.
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns true if no text has been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Creates the rich text.
+    pub fn build(&self) -> RichText {
+        RichText {
+            text: self.text.as_str().into(),
+            spans: self.spans.as_slice().into(),
+        }
+    }
+}
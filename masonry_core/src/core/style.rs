@@ -0,0 +1,230 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+
+use crate::core::{HasProperty, Property, Widget};
+use crate::util::AnyMap;
+
+/// The named style classes of a widget, e.g. `"primary"` or `"danger"`.
+///
+/// Classes are stored as a regular [property](Property) of the widget.
+/// They select which [style rules](Selector) of the [`DefaultProperties`] apply to the widget.
+///
+/// [`DefaultProperties`]: crate::core::DefaultProperties
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StyleClasses {
+    classes: Vec<Cow<'static, str>>,
+}
+
+/// The interaction states a [`Selector`] can require.
+///
+/// A selector with a state flag set only matches widgets which are currently in that state.
+/// Flags which aren't set match widgets in any state.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StyleState {
+    /// The widget is [hovered](crate::core::EventCtx::is_hovered).
+    pub hovered: bool,
+    /// The widget is [active](crate::core::EventCtx::is_active).
+    pub active: bool,
+    /// The widget is the [focus target](crate::core::EventCtx::is_focus_target).
+    pub focused: bool,
+    /// The widget is [disabled](crate::core::EventCtx::is_disabled).
+    pub disabled: bool,
+}
+
+/// Which widgets of a given type a style rule applies to.
+///
+/// Style rules are inserted into [`DefaultProperties`] with [`DefaultProperties::insert_rule`].
+/// A rule applies to widgets which have the selector's class (if any),
+/// and which are in every state the selector requires.
+///
+/// When several rules set the same property, the most specific one wins:
+/// rules with a class beat rules without one, then rules requiring more states
+/// beat rules requiring fewer.
+/// Among equally specific rules, the last inserted one wins.
+///
+/// Local properties of a widget always take precedence over style rules,
+/// and style rules always take precedence over the plain default properties of the widget type.
+///
+/// [`DefaultProperties`]: crate::core::DefaultProperties
+/// [`DefaultProperties::insert_rule`]: crate::core::DefaultProperties::insert_rule
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Selector {
+    class: Option<Cow<'static, str>>,
+    state: StyleState,
+}
+
+/// A set of property values which apply to the widgets matched by `selector`.
+#[derive(Debug)]
+pub(crate) struct StyleRule {
+    pub(crate) selector: Selector,
+    pub(crate) properties: AnyMap,
+}
+
+impl Property for StyleClasses {
+    fn static_default() -> &'static Self {
+        static DEFAULT: StyleClasses = StyleClasses {
+            classes: Vec::new(),
+        };
+        &DEFAULT
+    }
+}
+
+// Every widget can have style classes.
+impl<W: Widget + ?Sized> HasProperty<StyleClasses> for W {}
+
+impl StyleClasses {
+    /// Creates an empty set of classes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder-style method to add a class.
+    pub fn with(mut self, class: impl Into<Cow<'static, str>>) -> Self {
+        self.insert(class);
+        self
+    }
+
+    /// Adds a class. Does nothing if the class is already present.
+    pub fn insert(&mut self, class: impl Into<Cow<'static, str>>) {
+        let class = class.into();
+        if !self.contains(&class) {
+            self.classes.push(class);
+        }
+    }
+
+    /// Removes a class. Returns `true` if the class was present.
+    pub fn remove(&mut self, class: &str) -> bool {
+        let len = self.classes.len();
+        self.classes.retain(|c| c != class);
+        self.classes.len() != len
+    }
+
+    /// Returns `true` if the class is present.
+    pub fn contains(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c == class)
+    }
+
+    /// Returns an iterator over the classes, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(|c| &**c)
+    }
+
+    /// Returns `true` if there are no classes.
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+}
+
+impl From<&'static str> for StyleClasses {
+    fn from(class: &'static str) -> Self {
+        Self::new().with(class)
+    }
+}
+
+impl From<String> for StyleClasses {
+    fn from(class: String) -> Self {
+        Self::new().with(class)
+    }
+}
+
+impl<const N: usize> From<[&'static str; N]> for StyleClasses {
+    fn from(classes: [&'static str; N]) -> Self {
+        classes.into_iter().collect()
+    }
+}
+
+impl<C: Into<Cow<'static, str>>> FromIterator<C> for StyleClasses {
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        let mut classes = Self::new();
+        for class in iter {
+            classes.insert(class);
+        }
+        classes
+    }
+}
+
+impl StyleState {
+    /// Returns `true` if no state flag is set.
+    pub fn is_empty(self) -> bool {
+        self == Self::default()
+    }
+
+    /// Returns `true` if every flag set in `self` is also set in `other`.
+    fn is_subset_of(self, other: Self) -> bool {
+        (!self.hovered || other.hovered)
+            && (!self.active || other.active)
+            && (!self.focused || other.focused)
+            && (!self.disabled || other.disabled)
+    }
+
+    fn count(self) -> u8 {
+        u8::from(self.hovered)
+            + u8::from(self.active)
+            + u8::from(self.focused)
+            + u8::from(self.disabled)
+    }
+}
+
+impl Selector {
+    /// Creates a selector which matches every widget of the type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a selector which matches widgets with the given `class`.
+    pub fn class(class: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            class: Some(class.into()),
+            state: StyleState::default(),
+        }
+    }
+
+    /// Builder-style method to only match hovered widgets.
+    pub fn hovered(mut self) -> Self {
+        self.state.hovered = true;
+        self
+    }
+
+    /// Builder-style method to only match active widgets.
+    pub fn active(mut self) -> Self {
+        self.state.active = true;
+        self
+    }
+
+    /// Builder-style method to only match focused widgets.
+    pub fn focused(mut self) -> Self {
+        self.state.focused = true;
+        self
+    }
+
+    /// Builder-style method to only match disabled widgets.
+    pub fn disabled(mut self) -> Self {
+        self.state.disabled = true;
+        self
+    }
+
+    /// Returns the class this selector requires, if any.
+    pub fn class_name(&self) -> Option<&str> {
+        self.class.as_deref()
+    }
+
+    /// Returns the states this selector requires.
+    pub fn state(&self) -> StyleState {
+        self.state
+    }
+
+    /// Returns `true` if a widget with the given classes and state matches this selector.
+    pub fn matches(&self, classes: &StyleClasses, state: StyleState) -> bool {
+        let class_matches = match &self.class {
+            Some(class) => classes.contains(class),
+            None => true,
+        };
+        class_matches && self.state.is_subset_of(state)
+    }
+
+    pub(crate) fn specificity(&self) -> (bool, u8) {
+        (self.class.is_some(), self.state.count())
+    }
+}
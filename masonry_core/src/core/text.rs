@@ -31,11 +31,14 @@ pub type StyleProperty = parley::StyleProperty<'static, BrushIndex>;
 /// A set of styles specialised for use within Masonry.
 pub type StyleSet = parley::StyleSet<BrushIndex>;
 
+use parley::layout::{Decoration, GlyphRun};
 use parley::{Layout, PositionedLayoutItem};
 use vello::Scene;
 use vello::kurbo::{Affine, Line, Stroke};
 use vello::peniko::{Brush, Fill};
 
+use crate::core::{PaintStyle, RichText};
+
 /// A function that renders laid out glyphs to a [`Scene`].
 ///
 /// The `BrushIndex` values of the runs are indices into `brushes`.
@@ -47,106 +50,227 @@ pub fn render_text(
     // TODO: Should this be part of `BrushIndex` (i.e. `brushes`)?
     hint: bool,
 ) {
+    render_text_inner(scene, transform, layout, None, brushes, hint);
+}
+
+/// Renders laid out glyphs like [`render_text`], painting the spans of `rich_text` over them.
+///
+/// This is for layouts which weren't built with [`RichText::push_styles`],
+/// such as the layout of a [`PlainEditor`](parley::PlainEditor), which only supports styling
+/// the whole text.
+/// Only the spans which don't affect the layout are painted: colors, links, underlines
+/// and strikethroughs. Other spans are ignored.
+///
+/// `rich_text` must have the same text as the layout, and `brushes` should come
+/// from [`RichText::brushes`].
+pub fn render_text_with_paint_spans(
+    scene: &mut Scene,
+    transform: Affine,
+    layout: &Layout<BrushIndex>,
+    rich_text: &RichText,
+    brushes: &[Brush],
+    hint: bool,
+) {
+    render_text_inner(scene, transform, layout, Some(rich_text), brushes, hint);
+}
+
+fn render_text_inner(
+    scene: &mut Scene,
+    transform: Affine,
+    layout: &Layout<BrushIndex>,
+    rich_text: Option<&RichText>,
+    brushes: &[Brush],
+    hint: bool,
+) {
+    // The text index of each glyph of the run which is being split into segments, in visual order.
+    let mut run_glyph_indices = Vec::new();
+    let mut glyphs_consumed = 0;
+    let mut segment_glyphs = Vec::new();
     for line in layout.lines() {
+        let mut split_run = None;
         for item in line.items() {
             let PositionedLayoutItem::GlyphRun(glyph_run) = item else {
                 continue;
             };
             let style = glyph_run.style();
-            // We draw underlines under the text, then the strikethrough on top, following:
-            // https://drafts.csswg.org/css-text-decor/#painting-order
-            if let Some(underline) = &style.underline {
-                let underline_brush = &brushes[underline.brush.0];
-                let run_metrics = glyph_run.run().metrics();
-                let offset = match underline.offset {
-                    Some(offset) => offset,
-                    None => run_metrics.underline_offset,
-                };
-                let width = match underline.size {
-                    Some(size) => size,
-                    None => run_metrics.underline_size,
-                };
-                // The `offset` is the distance from the baseline to the top of the underline
-                // so we move the line down by half the width
-                // Remember that we are using a y-down coordinate system
-                // If there's a custom width, because this is an underline, we want the custom
-                // width to go down from the default expectation
-                let y = glyph_run.baseline() - offset + width / 2.;
-
-                let line = Line::new(
-                    (glyph_run.offset() as f64, y as f64),
-                    ((glyph_run.offset() + glyph_run.advance()) as f64, y as f64),
-                );
-                scene.stroke(
-                    &Stroke::new(width.into()),
-                    transform,
-                    underline_brush,
-                    None,
-                    &line,
-                );
-            }
-            let mut x = glyph_run.offset();
-            let y = glyph_run.baseline();
+            let base = PaintStyle {
+                brush: style.brush.clone(),
+                underline: style.underline.clone(),
+                strikethrough: style.strikethrough.clone(),
+            };
             let run = glyph_run.run();
-            let font = run.font();
-            let font_size = run.font_size();
-            let synthesis = run.synthesis();
-            let glyph_xform = synthesis
-                .skew()
-                .map(|angle| Affine::skew(angle.to_radians().tan() as f64, 0.0));
-            let coords = run.normalized_coords();
-            let brush = &brushes[style.brush.0];
-            scene
-                .draw_glyphs(font)
-                .brush(brush)
-                .hint(hint)
-                .transform(transform)
-                .glyph_transform(glyph_xform)
-                .font_size(font_size)
-                .normalized_coords(coords)
-                .draw(
-                    Fill::NonZero,
-                    glyph_run.glyphs().map(|glyph| {
-                        let gx = x + glyph.x;
-                        let gy = y - glyph.y;
-                        x += glyph.advance;
-                        vello::Glyph {
-                            id: glyph.id,
-                            x: gx,
-                            y: gy,
-                        }
-                    }),
-                );
+            let run_range = run.text_range();
+            let y = glyph_run.baseline();
 
-            if let Some(strikethrough) = &style.strikethrough {
-                let strikethrough_brush = &brushes[strikethrough.brush.0];
-                let run_metrics = glyph_run.run().metrics();
-                let offset = match strikethrough.offset {
-                    Some(offset) => offset,
-                    None => run_metrics.strikethrough_offset,
-                };
-                let width = match strikethrough.size {
-                    Some(size) => size,
-                    None => run_metrics.strikethrough_size,
-                };
-                // The `offset` is the distance from the baseline to the *top* of the strikethrough
-                // so we calculate the middle y-position of the strikethrough based on the font's
-                // standard strikethrough width.
-                // Remember that we are using a y-down coordinate system
-                let y = glyph_run.baseline() - offset + run_metrics.strikethrough_size / 2.;
-
-                let line = Line::new(
-                    (glyph_run.offset() as f64, y as f64),
-                    ((glyph_run.offset() + glyph_run.advance()) as f64, y as f64),
+            let Some(rich_text) = rich_text.filter(|text| text.has_paint_spans_in(&run_range))
+            else {
+                let mut x = glyph_run.offset();
+                let glyphs = glyph_run.glyphs().map(|glyph| {
+                    let gx = x + glyph.x;
+                    let gy = y - glyph.y;
+                    x += glyph.advance;
+                    vello::Glyph {
+                        id: glyph.id,
+                        x: gx,
+                        y: gy,
+                    }
+                });
+                let (start, advance) = (glyph_run.offset(), glyph_run.advance());
+                render_segment(
+                    scene, transform, &glyph_run, start, advance, glyphs, &base, brushes, hint,
                 );
-                scene.stroke(
-                    &Stroke::new(width.into()),
+                continue;
+            };
+
+            // A run is split into several glyph runs when its style changes,
+            // so we keep track of how many of its glyphs we've already seen.
+            if split_run.as_ref() != Some(&run_range) {
+                run_glyph_indices.clear();
+                for cluster in run.visual_clusters() {
+                    let index = cluster.text_range().start;
+                    run_glyph_indices.extend(cluster.glyphs().map(|_| index));
+                }
+                split_run = Some(run_range);
+                glyphs_consumed = 0;
+            }
+
+            let mut x = glyph_run.offset();
+            let mut segment: Option<(PaintStyle, f32)> = None;
+            for glyph in glyph_run.glyphs() {
+                let index = run_glyph_indices
+                    .get(glyphs_consumed)
+                    .copied()
+                    .unwrap_or_default();
+                glyphs_consumed += 1;
+                let paint = rich_text.paint_style_at(index, &base);
+                if segment.as_ref().is_none_or(|(style, _)| *style != paint) {
+                    if let Some((style, start)) = segment.take() {
+                        render_segment(
+                            scene,
+                            transform,
+                            &glyph_run,
+                            start,
+                            x - start,
+                            segment_glyphs.drain(..),
+                            &style,
+                            brushes,
+                            hint,
+                        );
+                    }
+                    segment = Some((paint, x));
+                }
+                segment_glyphs.push(vello::Glyph {
+                    id: glyph.id,
+                    x: x + glyph.x,
+                    y: y - glyph.y,
+                });
+                x += glyph.advance;
+            }
+            if let Some((style, start)) = segment {
+                render_segment(
+                    scene,
                     transform,
-                    strikethrough_brush,
-                    None,
-                    &line,
+                    &glyph_run,
+                    start,
+                    x - start,
+                    segment_glyphs.drain(..),
+                    &style,
+                    brushes,
+                    hint,
                 );
             }
         }
     }
 }
+
+/// Renders a horizontal segment of a glyph run, which starts at `start` and is `advance` wide.
+#[expect(clippy::too_many_arguments, reason = "This is a private helper")]
+fn render_segment(
+    scene: &mut Scene,
+    transform: Affine,
+    glyph_run: &GlyphRun<'_, BrushIndex>,
+    start: f32,
+    advance: f32,
+    glyphs: impl Iterator<Item = vello::Glyph>,
+    style: &PaintStyle,
+    brushes: &[Brush],
+    hint: bool,
+) {
+    let run = glyph_run.run();
+    let run_metrics = run.metrics();
+    let decoration_line = |y: f32| {
+        Line::new(
+            (start as f64, y as f64),
+            ((start + advance) as f64, y as f64),
+        )
+    };
+
+    // We draw underlines under the text, then the strikethrough on top, following:
+    // https://drafts.csswg.org/css-text-decor/#painting-order
+    if let Some(underline) = &style.underline {
+        let (offset, width) = decoration_metrics(
+            underline,
+            run_metrics.underline_offset,
+            run_metrics.underline_size,
+        );
+        // The `offset` is the distance from the baseline to the top of the underline
+        // so we move the line down by half the width
+        // Remember that we are using a y-down coordinate system
+        // If there's a custom width, because this is an underline, we want the custom
+        // width to go down from the default expectation
+        let y = glyph_run.baseline() - offset + width / 2.;
+        scene.stroke(
+            &Stroke::new(width.into()),
+            transform,
+            &brushes[underline.brush.0],
+            None,
+            &decoration_line(y),
+        );
+    }
+
+    let synthesis = run.synthesis();
+    let glyph_xform = synthesis
+        .skew()
+        .map(|angle| Affine::skew(angle.to_radians().tan() as f64, 0.0));
+    scene
+        .draw_glyphs(run.font())
+        .brush(&brushes[style.brush.0])
+        .hint(hint)
+        .transform(transform)
+        .glyph_transform(glyph_xform)
+        .font_size(run.font_size())
+        .normalized_coords(run.normalized_coords())
+        .draw(Fill::NonZero, glyphs);
+
+    if let Some(strikethrough) = &style.strikethrough {
+        let (offset, width) = decoration_metrics(
+            strikethrough,
+            run_metrics.strikethrough_offset,
+            run_metrics.strikethrough_size,
+        );
+        // The `offset` is the distance from the baseline to the *top* of the strikethrough
+        // so we calculate the middle y-position of the strikethrough based on the font's
+        // standard strikethrough width.
+        // Remember that we are using a y-down coordinate system
+        let y = glyph_run.baseline() - offset + run_metrics.strikethrough_size / 2.;
+        scene.stroke(
+            &Stroke::new(width.into()),
+            transform,
+            &brushes[strikethrough.brush.0],
+            None,
+            &decoration_line(y),
+        );
+    }
+}
+
+/// Returns the offset and width of `decoration`, falling back to the given metrics of the run.
+fn decoration_metrics(
+    decoration: &Decoration<BrushIndex>,
+    run_offset: f32,
+    run_size: f32,
+) -> (f32, f32) {
+    (
+        decoration.offset.unwrap_or(run_offset),
+        decoration.size.unwrap_or(run_size),
+    )
+}
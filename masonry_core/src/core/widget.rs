@@ -4,6 +4,7 @@
 use std::any::{Any, TypeId};
 use std::fmt::{Debug, Display};
 use std::num::NonZeroU64;
+use std::ops::Range;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use accesskit::{Node, Role};
@@ -14,9 +15,9 @@ use vello::Scene;
 use vello::kurbo::{Axis, Point, Size};
 
 use crate::core::{
-    AccessCtx, AccessEvent, ComposeCtx, CursorIcon, EventCtx, Layer, LayoutCtx, MeasureCtx,
-    NewWidget, PaintCtx, PointerEvent, Properties, PropertiesMut, PropertiesRef, QueryCtx,
-    RegisterCtx, TextEvent, Update, UpdateCtx, WidgetMut, WidgetRef, pre_paint,
+    AccessCtx, AccessEvent, ComposeCtx, CursorIcon, DragEvent, EventCtx, Layer, LayoutCtx,
+    MeasureCtx, NewWidget, PaintCtx, PointerEvent, Properties, PropertiesMut, PropertiesRef,
+    QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, WidgetMut, WidgetRef, pre_paint,
 };
 use crate::layout::LenReq;
 
@@ -184,6 +185,22 @@ pub trait Widget: AsDynWidget + Any {
     ) {
     }
 
+    /// Handles a [drag-and-drop] event.
+    ///
+    /// Drag events target the widget under the dragged item which [accepts drops],
+    /// then bubble to each parent.
+    /// [`DragEvent::Ended`] targets the widget which started the drag.
+    ///
+    /// [drag-and-drop]: crate::doc::masonry_concepts#drag-and-drop
+    /// [accepts drops]: Self::accepts_drop
+    fn on_drag_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        props: &mut PropertiesMut<'_>,
+        event: &DragEvent,
+    ) {
+    }
+
     /// Called at the beginning of a new animation frame.
     ///
     /// An animation frame does not implicitly request a repaint of this widget.
@@ -458,6 +475,61 @@ pub trait Widget: AsDynWidget + Any {
         false
     }
 
+    /// Whether this widget can be the target of a [drag-and-drop]. False by default.
+    ///
+    /// If true, the widget gets [`DragEvent`]s when an item is dragged over it.
+    ///
+    /// [drag-and-drop]: crate::doc::masonry_concepts#drag-and-drop
+    fn accepts_drop(&self) -> bool {
+        false
+    }
+
+    /// Whether this widget coordinates text selection between its descendants. False by default.
+    ///
+    /// See the [selection scope documentation](crate::doc::masonry_concepts#selection-scopes).
+    fn is_selection_scope(&self) -> bool {
+        false
+    }
+
+    /// Returns the text of this widget which can be selected in a [selection scope].
+    /// `None` by default.
+    ///
+    /// [selection scope]: crate::doc::masonry_concepts#selection-scopes
+    fn selectable_text(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the byte index into [`selectable_text`] closest to `pos`.
+    ///
+    /// `pos` is in this widget's content-box coordinate space.
+    /// Only called if [`selectable_text`] returns `Some`.
+    ///
+    /// [`selectable_text`]: Self::selectable_text
+    fn selectable_text_index(&self, pos: Point) -> usize {
+        0
+    }
+
+    /// Sets the byte range of [`selectable_text`] which is selected in the enclosing
+    /// [selection scope], or `None` if none of it is.
+    ///
+    /// The selection scope requests a repaint of this widget after calling this.
+    ///
+    /// [`selectable_text`]: Self::selectable_text
+    /// [selection scope]: crate::doc::masonry_concepts#selection-scopes
+    fn set_scoped_selection(&mut self, selection: Option<Range<usize>>) {}
+
+    /// Returns the font size of this widget's text, in logical pixels. `None` by default.
+    ///
+    /// This is used to resolve [`LengthUnit::Em`] lengths for this widget.
+    /// Widgets which return `None` inherit the font size of their parent.
+    ///
+    /// If the value returned by this method changes, the widget must request layout.
+    ///
+    /// [`LengthUnit::Em`]: crate::layout::LengthUnit::Em
+    fn font_size(&self) -> Option<f64> {
+        None
+    }
+
     // TODO - Write a generic default implementation once
     // `const std::any::type_name` is stable.
     // See https://github.com/rust-lang/rust/issues/63084
@@ -718,6 +790,27 @@ pub trait CollectionWidget<Params>: Widget {
     /// Panics if `a` or `b` are out of bounds.
     fn swap(this: &mut WidgetMut<'_, Self>, a: usize, b: usize);
 
+    /// Moves the child at index `from` to index `to`, shifting the children in between.
+    ///
+    /// The default implementation [swaps](Self::swap) the child with each of its neighbours
+    /// in turn, so params follow the same rules as for `swap`.
+    /// Collections should override it to move the child in one step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    fn move_child(this: &mut WidgetMut<'_, Self>, from: usize, to: usize) {
+        if from < to {
+            for idx in from..to {
+                Self::swap(this, idx, idx + 1);
+            }
+        } else {
+            for idx in (to..from).rev() {
+                Self::swap(this, idx, idx + 1);
+            }
+        }
+    }
+
     /// Removes the child at the given index.
     ///
     /// # Panics
@@ -3,9 +3,9 @@
 
 use std::any::TypeId;
 
-use crate::core::{FromDynWidget, MutateCtx, Property, Widget, WidgetId};
+use crate::core::{FromDynWidget, MutateCtx, Property, StyleClasses, Widget, WidgetId};
 use crate::kurbo::Affine;
-use crate::properties::core_property_changed;
+use crate::properties::{core_property_changed, style_rules_changed};
 
 /// A rich mutable reference to a [`Widget`].
 ///
@@ -96,6 +96,11 @@ impl<W: Widget + ?Sized> WidgetMut<'_, W> {
         let property_type = TypeId::of::<P>();
         core_property_changed(&mut ctx, property_type);
         self.widget.property_changed(&mut ctx, property_type);
+        if StyleClasses::matches(property_type) {
+            style_rules_changed(self.widget.as_mut_dyn(), &mut ctx, |selector| {
+                selector.class_name().is_some()
+            });
+        }
         value
     }
 
@@ -111,6 +116,11 @@ impl<W: Widget + ?Sized> WidgetMut<'_, W> {
         let property_type = TypeId::of::<P>();
         core_property_changed(&mut ctx, property_type);
         self.widget.property_changed(&mut ctx, property_type);
+        if StyleClasses::matches(property_type) {
+            style_rules_changed(self.widget.as_mut_dyn(), &mut ctx, |selector| {
+                selector.class_name().is_some()
+            });
+        }
         value
     }
 
@@ -1,6 +1,8 @@
 // Copyright 2026 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
+
 use vello::Scene;
 
 use crate::core::{PaintCtx, PropertiesRef};
@@ -8,30 +10,47 @@ use crate::kurbo::{Affine, Join, Rect, Stroke};
 use crate::peniko::Fill;
 use crate::properties::{
     ActiveBackground, Background, BorderColor, BorderWidth, BoxShadow, CornerRadius,
-    DisabledBackground, FocusedBorderColor, HoveredBorderColor,
+    DisabledBackground, FocusedBorderColor, HoveredBorderColor, Transitions,
 };
 
-/// References to common pre-paint properties.
+/// Common pre-paint properties.
 pub struct PrePaintProps<'a> {
     /// Box shadow.
     pub box_shadow: &'a BoxShadow,
     /// Background.
     ///
-    /// Considers disabled and active state.
-    pub background: &'a Background,
+    /// Considers disabled and active state, and [`Transitions`].
+    pub background: Cow<'a, Background>,
     /// Border width.
     pub border_width: &'a BorderWidth,
     /// Border color.
     ///
-    /// Considers focus and hovered state.
-    pub border_color: &'a BorderColor,
+    /// Considers focus and hovered state, and [`Transitions`].
+    pub border_color: BorderColor,
     /// Corner radius,
-    pub corner_radius: &'a CornerRadius,
+    ///
+    /// Considers [`Transitions`].
+    pub corner_radius: CornerRadius,
 }
 
 impl<'a> PrePaintProps<'a> {
     /// Returns common pre-paint properties based on widget state.
+    ///
+    /// If the widget has [`Transitions`] for some of these properties,
+    /// this starts a transition whenever their value changes,
+    /// and returns their interpolated value while the transition runs.
     pub fn fetch(ctx: &mut PaintCtx<'_>, props: &'a PropertiesRef<'_>) -> Self {
+        let mut p = Self::fetch_without_transitions(ctx, props);
+        p.apply_transitions(ctx, props);
+        p
+    }
+
+    /// Returns common pre-paint properties based on widget state, ignoring [`Transitions`].
+    ///
+    /// Widgets which override some of these properties based on their own state
+    /// should use this, override them, and then call [`apply_transitions`](Self::apply_transitions),
+    /// so that the overridden values are transitioned to as well.
+    pub fn fetch_without_transitions(ctx: &mut PaintCtx<'_>, props: &'a PropertiesRef<'_>) -> Self {
         let box_shadow = props.get::<BoxShadow>();
         let background = if ctx.is_disabled()
             && let Some(db) = props.get_defined::<DisabledBackground>()
@@ -60,10 +79,52 @@ impl<'a> PrePaintProps<'a> {
 
         Self {
             box_shadow,
-            background,
+            background: Cow::Borrowed(background),
             border_width,
-            border_color,
-            corner_radius,
+            border_color: *border_color,
+            corner_radius: *corner_radius,
+        }
+    }
+
+    /// Replaces the properties which have [`Transitions`] with their interpolated value.
+    ///
+    /// This starts a transition whenever their value differs from the one in the previous call.
+    pub fn apply_transitions(&mut self, ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>) {
+        let transitions = props.get::<Transitions>();
+        let background_transition = transitions.get::<Background>();
+        let border_color_transition = transitions.get::<BorderColor>();
+        let corner_radius_transition = transitions.get::<CornerRadius>();
+        if background_transition.is_none()
+            && border_color_transition.is_none()
+            && corner_radius_transition.is_none()
+        {
+            ctx.widget_state.transitions = None;
+            return;
+        }
+
+        let state = ctx.widget_state.transitions.get_or_insert_default();
+        if let Some(background) = state
+            .background
+            .update(&self.background, background_transition)
+        {
+            self.background = Cow::Owned(background);
+        }
+        if let Some(border_color) = state
+            .border_color
+            .update(&self.border_color, border_color_transition)
+        {
+            self.border_color = border_color;
+        }
+        if let Some(corner_radius) = state
+            .corner_radius
+            .update(&self.corner_radius, corner_radius_transition)
+        {
+            self.corner_radius = corner_radius;
+        }
+
+        // The animation pass will advance the transitions and request a new pre-paint.
+        if state.is_running() {
+            ctx.widget_state.needs_anim = true;
         }
     }
 }
@@ -73,9 +134,15 @@ pub fn pre_paint(ctx: &mut PaintCtx<'_>, props: &PropertiesRef<'_>, scene: &mut
     let bbox = ctx.border_box();
     let p = PrePaintProps::fetch(ctx, props);
 
-    paint_box_shadow(scene, bbox, p.box_shadow, p.corner_radius);
-    paint_background(scene, bbox, p.background, p.border_width, p.corner_radius);
-    paint_border(scene, bbox, p.border_color, p.border_width, p.corner_radius);
+    paint_box_shadow(scene, bbox, p.box_shadow, &p.corner_radius);
+    paint_background(scene, bbox, &p.background, p.border_width, &p.corner_radius);
+    paint_border(
+        scene,
+        bbox,
+        &p.border_color,
+        p.border_width,
+        &p.corner_radius,
+    );
 }
 
 /// Paints the widget's box shadow.
@@ -132,7 +132,10 @@ impl<'w, W: Widget + ?Sized> WidgetRef<'w, W> {
                     widget_state: state,
                     properties: PropertiesRef {
                         map: properties,
-                        default_map: self.ctx.properties.default_map,
+                        default_map: self.ctx.default_properties.for_widget(
+                            widget.type_id(),
+                            state.style_state(self.ctx.global_state.focused_widget),
+                        ),
                     },
                     children,
                     default_properties: self.ctx.default_properties,
@@ -6,8 +6,9 @@ use std::any::TypeId;
 use tracing::Span;
 use vello::kurbo::{Affine, Insets, Point, Rect, Size, Vec2};
 
-use crate::core::{WidgetId, WidgetOptions};
-use crate::layout::MeasurementCache;
+use crate::core::{StyleState, WidgetId, WidgetOptions};
+use crate::layout::{LengthContext, MeasurementCache};
+use crate::properties::PrePaintTransitions;
 
 // TODO - Reduce WidgetState size.
 // See https://github.com/linebender/xilem/issues/706
@@ -118,6 +119,16 @@ pub(crate) struct WidgetState {
     pub(crate) layout_baseline_offset: f64,
     /// The pixel-snapped position of the baseline in the parent's border-box coordinate space.
     pub(crate) baseline_y: f64,
+    /// The font size used to resolve [`LengthUnit::Em`] lengths, in logical pixels.
+    ///
+    /// This is the widget's own font size, or the one inherited from its parent.
+    ///
+    /// [`LengthUnit::Em`]: crate::layout::LengthUnit::Em
+    pub(crate) font_size: f64,
+    /// Whether the widget's layout depends on the viewport size.
+    ///
+    /// Once set, this is never cleared.
+    pub(crate) uses_viewport_units: bool,
 
     // TODO - Use general Shape
     // Currently Kurbo doesn't really provide a type that lets us
@@ -243,6 +254,14 @@ pub(crate) struct WidgetState {
     /// Descendants of the focused widget are not in the focused path.
     pub(crate) has_focus_target: bool,
 
+    // --- TRANSITIONS ---
+    /// The transitions of the widget's pre-paint properties.
+    ///
+    /// This is only allocated once the widget has painted with a [`Transitions`] property.
+    ///
+    /// [`Transitions`]: crate::properties::Transitions
+    pub(crate) transitions: Option<Box<PrePaintTransitions>>,
+
     // --- DEBUG INFO ---
     /// The typename of the associated widget.
     ///
@@ -257,6 +276,16 @@ pub(crate) struct WidgetState {
 }
 
 impl WidgetState {
+    /// Returns the interaction state used to match [style rules](crate::core::Selector).
+    pub(crate) fn style_state(&self, focused_widget: Option<WidgetId>) -> StyleState {
+        StyleState {
+            hovered: self.is_hovered,
+            active: self.is_active,
+            focused: focused_widget == Some(self.id),
+            disabled: self.is_disabled,
+        }
+    }
+
     pub(crate) fn new(
         id: WidgetId,
         widget_name: &'static str,
@@ -275,6 +304,8 @@ impl WidgetState {
             bounding_box: Rect::ZERO,
             layout_baseline_offset: 0.0,
             baseline_y: 0.0,
+            font_size: LengthContext::DEFAULT_FONT_SIZE,
+            uses_viewport_units: false,
             clip_path: Option::default(),
             transform: options.transform,
             window_transform: Affine::IDENTITY,
@@ -318,6 +349,8 @@ impl WidgetState {
             is_active: false,
             has_focus_target: false,
 
+            transitions: None,
+
             trace_span: Span::none(),
             #[cfg(debug_assertions)]
             widget_name,
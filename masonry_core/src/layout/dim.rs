@@ -1,7 +1,7 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::layout::{LenDef, Length};
+use crate::layout::{LenDef, Length, LengthContext};
 
 /// Specifies how a widget dimension's length is derived.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
@@ -15,7 +15,14 @@ pub enum Dim {
     Auto,
     /// Specific fixed length.
     ///
-    /// The value is in logical pixels, represented by a [`Length`].
+    /// The value is represented by a [`Length`], which can also be relative
+    /// to the font size, the viewport size, or the context length.
+    ///
+    /// A [percentage](crate::layout::LengthUnit::Percent) falls back to [`Auto`]
+    /// if there is no context length, just like [`Ratio`].
+    ///
+    /// [`Auto`]: Self::Auto
+    /// [`Ratio`]: Self::Ratio
     Fixed(Length),
     /// Multiple of context length.
     ///
@@ -70,12 +77,26 @@ impl From<Length> for Dim {
 }
 
 impl Dim {
+    /// Returns `true` if this is a [`Fixed`](Self::Fixed) length relative to the viewport size.
+    pub const fn is_viewport_relative(&self) -> bool {
+        matches!(self, Self::Fixed(length) if length.is_viewport_relative())
+    }
+
     /// Resolves, if possible, into a [`LenDef`].
     ///
     /// If `context_length` is provided, it must be in device pixels.
-    pub fn resolve(&self, scale: f64, context_length: Option<f64>) -> Option<LenDef> {
+    ///
+    /// Relative [`Length`]s are resolved with `lengths`.
+    pub fn resolve(
+        &self,
+        scale: f64,
+        context_length: Option<f64>,
+        lengths: &LengthContext,
+    ) -> Option<LenDef> {
         match self {
-            Self::Fixed(length) => Some(LenDef::Fixed(length.dp(scale))),
+            Self::Fixed(length) => length
+                .resolve(lengths, context_length.map(|cl| cl / scale))
+                .map(|length| LenDef::Fixed(length * scale)),
             Self::Ratio(mul) => context_length.map(|cl| LenDef::Fixed(cl * *mul)),
             Self::Stretch => context_length.map(LenDef::Fixed),
             Self::MinContent => Some(LenDef::MinContent),
@@ -1,12 +1,62 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::kurbo::Size;
+
 /// A value representing a width, height, or similar distance value.
 ///
 /// It is always finite and non-negative.
+///
+/// Lengths are usually in logical pixels, but they can also be relative
+/// to the font size, the viewport size, or the context length.
+/// See [`LengthUnit`] for details.
 #[derive(Clone, Copy, PartialEq)]
 pub struct Length {
     value: f64,
+    unit: LengthUnit,
+}
+
+/// The unit of a [`Length`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    /// Logical pixels.
+    #[default]
+    Px,
+    /// Multiple of the widget's font size.
+    ///
+    /// Widgets which don't display text inherit the font size of their parent.
+    Em,
+    /// Multiple of the root font size.
+    ///
+    /// The root font size is set with [`RenderRoot::set_root_font_size`].
+    ///
+    /// [`RenderRoot::set_root_font_size`]: crate::app::RenderRoot::set_root_font_size
+    Rem,
+    /// Percentage of the viewport width.
+    Vw,
+    /// Percentage of the viewport height.
+    Vh,
+    /// Percentage of the context length.
+    ///
+    /// This is resolved the same way as [`Dim::Ratio`], so it falls back to [`Dim::Auto`]
+    /// if there is no context length.
+    ///
+    /// [`Dim::Ratio`]: crate::layout::Dim::Ratio
+    /// [`Dim::Auto`]: crate::layout::Dim::Auto
+    Percent,
+}
+
+/// The values relative [`Length`]s are resolved against.
+///
+/// All values are in logical pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LengthContext {
+    /// The font size used for [`LengthUnit::Em`].
+    pub font_size: f64,
+    /// The font size used for [`LengthUnit::Rem`].
+    pub root_font_size: f64,
+    /// The viewport size used for [`LengthUnit::Vw`] and [`LengthUnit::Vh`].
+    pub viewport_size: Size,
 }
 
 impl std::fmt::Debug for Length {
@@ -17,13 +67,24 @@ impl std::fmt::Debug for Length {
 
 impl std::fmt::Display for Length {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}px", self.value)
+        let suffix = match self.unit {
+            LengthUnit::Px => "px",
+            LengthUnit::Em => "em",
+            LengthUnit::Rem => "rem",
+            LengthUnit::Vw => "vw",
+            LengthUnit::Vh => "vh",
+            LengthUnit::Percent => "%",
+        };
+        write!(f, "{}{suffix}", self.value)
     }
 }
 
 impl Length {
     /// A length of zero.
-    pub const ZERO: Self = Self { value: 0. };
+    pub const ZERO: Self = Self {
+        value: 0.,
+        unit: LengthUnit::Px,
+    };
 
     /// Creates a length, in logical pixels.
     ///
@@ -38,13 +99,7 @@ impl Length {
     /// If debug assertions are off, this will return zero instead of panicking.
     #[track_caller]
     pub fn px(value: f64) -> Self {
-        if value < 0. || !value.is_finite() {
-            // TODO - Make const once const formatting is allowed.
-            // (aka see you in 2030)
-            debug_panic!("Invalid length value '{value}'");
-            return Self::ZERO;
-        }
-        Self { value }
+        Self::new(value, LengthUnit::Px)
     }
 
     /// Creates a length, in logical pixels.
@@ -59,22 +114,144 @@ impl Length {
         if value < 0. || !value.is_finite() {
             panic!("Invalid length value");
         }
-        Self { value }
+        Self {
+            value,
+            unit: LengthUnit::Px,
+        }
+    }
+
+    /// Creates a length as a multiple of the widget's font size.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`px`](Self::px).
+    #[track_caller]
+    pub fn em(value: f64) -> Self {
+        Self::new(value, LengthUnit::Em)
+    }
+
+    /// Creates a length as a multiple of the root font size.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`px`](Self::px).
+    #[track_caller]
+    pub fn rem(value: f64) -> Self {
+        Self::new(value, LengthUnit::Rem)
+    }
+
+    /// Creates a length as a percentage of the viewport width.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`px`](Self::px).
+    #[track_caller]
+    pub fn vw(value: f64) -> Self {
+        Self::new(value, LengthUnit::Vw)
+    }
+
+    /// Creates a length as a percentage of the viewport height.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`px`](Self::px).
+    #[track_caller]
+    pub fn vh(value: f64) -> Self {
+        Self::new(value, LengthUnit::Vh)
+    }
+
+    /// Creates a length as a percentage of the context length.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`px`](Self::px).
+    #[track_caller]
+    pub fn percent(value: f64) -> Self {
+        Self::new(value, LengthUnit::Percent)
+    }
+
+    /// Creates a length with the given `unit`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`px`](Self::px).
+    #[track_caller]
+    pub fn new(value: f64, unit: LengthUnit) -> Self {
+        if value < 0. || !value.is_finite() {
+            // TODO - Make const once const formatting is allowed.
+            // (aka see you in 2030)
+            debug_panic!("Invalid length value '{value}'");
+            return Self::ZERO;
+        }
+        Self { value, unit }
+    }
+
+    /// Returns the unit of this length.
+    pub const fn unit(self) -> LengthUnit {
+        self.unit
+    }
+
+    /// Returns the value, in this length's [`unit`](Self::unit).
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+
+    /// Returns `true` if this length is in logical pixels.
+    pub const fn is_absolute(self) -> bool {
+        matches!(self.unit, LengthUnit::Px)
+    }
+
+    /// Returns `true` if this length depends on the viewport size.
+    pub const fn is_viewport_relative(self) -> bool {
+        matches!(self.unit, LengthUnit::Vw | LengthUnit::Vh)
     }
 
     /// Returns the value, in logical pixels.
     ///
     /// The value is guaranteed to be finite and non-negative.
-    pub const fn get(self) -> f64 {
+    ///
+    /// # Panics
+    ///
+    /// Relative lengths must be [resolved](Self::resolve) instead.
+    /// If debug assertions are on, calling this on a relative length will panic.
+    /// If debug assertions are off, this will return the unresolved value instead.
+    #[track_caller]
+    pub fn get(self) -> f64 {
+        if !self.is_absolute() {
+            debug_panic!("Tried to get the pixel value of relative length '{self}'");
+        }
         self.value
     }
 
     /// Scales the value to device pixels.
-    pub const fn dp(self, scale: f64) -> f64 {
-        self.value * scale
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`get`](Self::get).
+    #[track_caller]
+    pub fn dp(self, scale: f64) -> f64 {
+        self.get() * scale
+    }
+
+    /// Resolves the length into logical pixels.
+    ///
+    /// `context_length` is used for [`LengthUnit::Percent`], and must be in logical pixels.
+    /// Returns `None` if this is a percentage and there is no context length.
+    pub fn resolve(self, lengths: &LengthContext, context_length: Option<f64>) -> Option<f64> {
+        let resolved = match self.unit {
+            LengthUnit::Px => self.value,
+            LengthUnit::Em => self.value * lengths.font_size,
+            LengthUnit::Rem => self.value * lengths.root_font_size,
+            LengthUnit::Vw => self.value * lengths.viewport_size.width / 100.,
+            LengthUnit::Vh => self.value * lengths.viewport_size.height / 100.,
+            LengthUnit::Percent => self.value * context_length? / 100.,
+        };
+        Some(resolved)
     }
 
     /// Returns the minimum of the two lengths.
+    ///
+    /// Both lengths must have the same unit for the result to be meaningful.
     pub const fn min(self, other: Self) -> Self {
         if self.value < other.value {
             self
@@ -84,6 +261,8 @@ impl Length {
     }
 
     /// Returns the maximum of the two lengths.
+    ///
+    /// Both lengths must have the same unit for the result to be meaningful.
     pub const fn max(self, other: Self) -> Self {
         if self.value > other.value {
             self
@@ -92,3 +271,8 @@ impl Length {
         }
     }
 }
+
+impl LengthContext {
+    /// The font size used when nothing else is specified, in logical pixels.
+    pub const DEFAULT_FONT_SIZE: f64 = 16.;
+}
@@ -89,6 +89,7 @@ pub mod util;
 
 mod passes;
 
+pub mod animation;
 pub mod app;
 pub mod core;
 pub mod layout;
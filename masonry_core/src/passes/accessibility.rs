@@ -47,7 +47,11 @@ fn build_accessibility_tree(
         let mut node = build_access_node(widget, &mut ctx, scale_factor);
         let props = PropertiesRef {
             map: properties,
-            default_map: default_properties.for_widget(widget.type_id()),
+            default_map: default_properties.for_widget(
+                widget.type_id(),
+                ctx.widget_state
+                    .style_state(ctx.global_state.focused_widget),
+            ),
         };
         widget.accessibility(&mut ctx, &props, &mut node);
 
@@ -27,6 +27,16 @@ fn update_anim_for_widget(
     }
     state.needs_anim = false;
 
+    // Property transitions are advanced here, and interpolated in the next pre-paint,
+    // which requests another frame if they're still running.
+    if let Some(transitions) = &mut state.transitions
+        && transitions.is_running()
+    {
+        transitions.advance(elapsed_ns);
+        state.request_pre_paint = true;
+        state.needs_paint = true;
+    }
+
     // Most passes reset their `needs` and `request` flags after the call to
     // the widget method, but it's valid and expected for `request_anim` to be
     // set in response to `AnimFrame`.
@@ -40,7 +50,11 @@ fn update_anim_for_widget(
         };
         let mut props = PropertiesMut {
             map: properties,
-            default_map: default_properties.for_widget(widget.type_id()),
+            default_map: default_properties.for_widget(
+                widget.type_id(),
+                ctx.widget_state
+                    .style_state(ctx.global_state.focused_widget),
+            ),
         };
         widget.on_anim_frame(&mut ctx, &mut props, elapsed_ns);
     }
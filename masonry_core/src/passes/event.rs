@@ -6,11 +6,12 @@ use tracing::{info_span, trace};
 use crate::app::{RenderRoot, RenderRootSignal};
 use crate::core::keyboard::{Key, KeyState, NamedKey};
 use crate::core::{
-    AccessEvent, EventCtx, Handled, Ime, PointerButtonEvent, PointerEvent, PointerGestureEvent,
-    PointerInfo, PointerScrollEvent, PointerType, PointerUpdate, PropertiesMut, TextEvent, Widget,
-    WidgetId,
+    AccessEvent, DragEvent, DragUpdate, EventCtx, Handled, Ime, LayerType, PointerButtonEvent,
+    PointerEvent, PointerGestureEvent, PointerInfo, PointerScrollEvent, PointerType, PointerUpdate,
+    PropertiesMut, TextEvent, Widget, WidgetId,
 };
 use crate::dpi::{LogicalPosition, PhysicalPosition};
+use crate::kurbo::Point;
 use crate::passes::update::find_next_focusable;
 use crate::passes::{enter_span, merge_state_up};
 
@@ -39,6 +40,23 @@ fn get_pointer_target(
     None
 }
 
+/// Returns the closest ancestor of `widget_id` which is a selection scope.
+fn find_selection_scope(root: &RenderRoot, widget_id: WidgetId) -> Option<WidgetId> {
+    let mut widget_id = root.widget_arena.parent_of(widget_id)?;
+    loop {
+        if root
+            .widget_arena
+            .get_node(widget_id)
+            .item
+            .widget
+            .is_selection_scope()
+        {
+            return Some(widget_id);
+        }
+        widget_id = root.widget_arena.parent_of(widget_id)?;
+    }
+}
+
 /// `true` if this [`PointerEvent`] type is likely to occur every frame.
 fn is_very_frequent(e: &PointerEvent) -> bool {
     matches!(e, PointerEvent::Move(..) | PointerEvent::Scroll { .. })
@@ -76,6 +94,7 @@ fn run_event_pass<E>(
     event: &E,
     skip_if_disabled: bool,
     allow_pointer_capture: bool,
+    with_selection_scope: bool,
     pass_fn: impl FnMut(&mut dyn Widget, &mut EventCtx<'_>, &mut PropertiesMut<'_>, &E),
     trace: bool,
 ) -> Handled {
@@ -89,6 +108,10 @@ fn run_event_pass<E>(
     }
 
     let original_target = target;
+    // Finding the scope walks up the tree, so we only do it for the events which use it.
+    let selection_scope = target
+        .filter(|_| with_selection_scope)
+        .and_then(|id| find_selection_scope(root, id));
     let mut target_widget_id = target;
     let mut is_handled = false;
     while let Some(widget_id) = target_widget_id {
@@ -109,6 +132,7 @@ fn run_event_pass<E>(
                 children: node.children.reborrow_mut(),
                 default_properties: &root.default_properties,
                 target: original_target.unwrap(),
+                selection_scope,
                 allow_pointer_capture,
                 is_handled: false,
             };
@@ -123,7 +147,11 @@ fn run_event_pass<E>(
 
             let mut props = PropertiesMut {
                 map: &mut node.item.properties,
-                default_map: root.default_properties.for_widget(widget.type_id()),
+                default_map: root.default_properties.for_widget(
+                    widget.type_id(),
+                    ctx.widget_state
+                        .style_state(ctx.global_state.focused_widget),
+                ),
             };
             pass_fn(widget, &mut ctx, &mut props, event);
             is_handled = ctx.is_handled;
@@ -188,12 +216,17 @@ pub(crate) fn run_on_pointer_event_pass(root: &mut RenderRoot, event: &PointerEv
                 children: layer_root.children.reborrow_mut(),
                 default_properties: &root.default_properties,
                 target: layer_id,
+                selection_scope: None,
                 allow_pointer_capture: false,
                 is_handled: false,
             };
             let mut props = PropertiesMut {
                 map: &mut layer_root.item.properties,
-                default_map: root.default_properties.for_widget(layer.type_id()),
+                default_map: root.default_properties.for_widget(
+                    layer.type_id(),
+                    ctx.widget_state
+                        .style_state(ctx.global_state.focused_widget),
+                ),
             };
 
             layer.capture_pointer_event(&mut ctx, &mut props, event);
@@ -229,12 +262,17 @@ pub(crate) fn run_on_pointer_event_pass(root: &mut RenderRoot, event: &PointerEv
         event,
         skip_if_disabled,
         matches!(event, PointerEvent::Down { .. }),
+        matches!(event, PointerEvent::Down { .. }),
         |widget, ctx, props, event| {
             widget.on_pointer_event(ctx, props, event);
         },
         !is_very_frequent(event),
     );
 
+    if root.global_state.drag.is_some() {
+        run_drag_pass(root, event);
+    }
+
     if matches!(event, PointerEvent::Up { .. } | PointerEvent::Cancel(..)) {
         // Automatically release the pointer on pointer up or leave. If a widget holds the capture,
         // it is notified of the pointer event before the capture is released, so it knows it is
@@ -253,6 +291,119 @@ pub(crate) fn run_on_pointer_event_pass(root: &mut RenderRoot, event: &PointerEv
     handled
 }
 
+// --- MARK: DRAG AND DROP
+/// Returns the closest widget under the pointer, or ancestor of it, which accepts drops.
+fn get_drop_target(root: &RenderRoot, pointer_pos: LogicalPosition<f64>) -> Option<WidgetId> {
+    // TODO - Apply scale
+    let pointer_pos = (pointer_pos.x, pointer_pos.y).into();
+    let mut widget_id = root
+        .get_widget(root.root_id())
+        .expect("root widget not in widget tree")
+        .find_widget_under_pointer(pointer_pos)?
+        .id();
+    loop {
+        let node = root.widget_arena.get_node(widget_id);
+        if node.item.widget.accepts_drop() && !node.item.state.is_disabled {
+            return Some(widget_id);
+        }
+        widget_id = root.widget_arena.parent_of(widget_id)?;
+    }
+}
+
+fn run_drag_event(root: &mut RenderRoot, target: WidgetId, event: &DragEvent) -> Handled {
+    if !root.has_widget(target) {
+        return Handled::No;
+    }
+    run_event_pass(
+        root,
+        Some(target),
+        event,
+        false,
+        false,
+        false,
+        |widget, ctx, props, event| {
+            widget.on_drag_event(ctx, props, event);
+        },
+        !matches!(event, DragEvent::Over(..)),
+    )
+}
+
+/// Ends the ongoing drag, notifying its source.
+fn end_drag(root: &mut RenderRoot, dropped: bool) {
+    let Some(drag) = root.global_state.drag.take() else {
+        return;
+    };
+    if drag.new_preview.is_none() {
+        root.global_state
+            .emit_signal(RenderRootSignal::RemoveLayer(drag.preview_id));
+    }
+    if !dropped && let Some(target) = drag.target {
+        run_drag_event(root, target, &DragEvent::Leave);
+    }
+    run_drag_event(root, drag.source, &DragEvent::Ended(dropped));
+}
+
+/// Moves the drag preview and sends [`DragEvent`]s to drop targets.
+///
+/// See the [drag-and-drop documentation](crate::doc::masonry_concepts#drag-and-drop).
+fn run_drag_pass(root: &mut RenderRoot, event: &PointerEvent) {
+    let Some(position) = try_event_position(event) else {
+        if matches!(event, PointerEvent::Cancel(..)) {
+            end_drag(root, false);
+        }
+        return;
+    };
+    let pos = position.to_logical(root.global_state.scale_factor);
+    let pointer_pos = Point::new(pos.x, pos.y);
+
+    let Some(drag) = root.global_state.drag.as_mut() else {
+        return;
+    };
+    let source = drag.source;
+    if let Some(preview) = drag.new_preview.take() {
+        // The preview keeps its offset from the pointer for the rest of the drag.
+        drag.preview_offset = drag.preview_origin - pointer_pos;
+        let origin = drag.preview_origin;
+        root.global_state.emit_signal(RenderRootSignal::NewLayer(
+            LayerType::DragPreview,
+            preview.erased(),
+            origin,
+        ));
+    } else if matches!(event, PointerEvent::Move(..)) {
+        let preview_id = drag.preview_id;
+        let origin = pointer_pos + drag.preview_offset;
+        root.global_state
+            .emit_signal(RenderRootSignal::RepositionLayer(preview_id, origin));
+    }
+
+    let update = DragUpdate { source, position };
+    if matches!(event, PointerEvent::Up(..)) {
+        let target = root.global_state.drag.as_ref().and_then(|drag| drag.target);
+        let dropped = target.is_some_and(|target| {
+            run_drag_event(root, target, &DragEvent::Drop(update)).is_handled()
+        });
+        end_drag(root, dropped);
+        return;
+    }
+
+    let new_target = get_drop_target(root, pos);
+    let Some(drag) = root.global_state.drag.as_mut() else {
+        return;
+    };
+    let old_target = std::mem::replace(&mut drag.target, new_target);
+    if old_target != new_target {
+        if let Some(old_target) = old_target {
+            run_drag_event(root, old_target, &DragEvent::Leave);
+        }
+        if let Some(new_target) = new_target {
+            run_drag_event(root, new_target, &DragEvent::Enter(update.clone()));
+        }
+    }
+    if let Some(new_target) = new_target {
+        run_drag_event(root, new_target, &DragEvent::Over(update));
+    }
+}
+
 // --- MARK: TEXT EVENT
 /// See the [passes documentation](crate::doc::pass_system#event-passes).
 pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -> Handled {
@@ -275,6 +426,16 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
         root.global_state.window_focused = *focused;
     }
 
+    // Escape cancels an ongoing drag.
+    if let TextEvent::Keyboard(key) = event
+        && key.key == Key::Named(NamedKey::Escape)
+        && key.state == KeyState::Down
+        && root.global_state.drag.is_some()
+    {
+        end_drag(root, false);
+        return Handled::Yes;
+    }
+
     let target = root.global_state.focused_widget.or_else(|| {
         if let Some(focus_fallback) = root.global_state.focus_fallback
             && root.is_still_interactive(focus_fallback)
@@ -292,6 +453,7 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
         event,
         skip_if_disabled,
         false,
+        false,
         |widget, ctx, props, event| {
             widget.on_text_event(ctx, props, event);
         },
@@ -340,6 +502,37 @@ pub(crate) fn run_on_text_event_pass(root: &mut RenderRoot, event: &TextEvent) -
     handled
 }
 
+/// Sends the contents of the clipboard to the widget which asked for them.
+///
+/// See [`TextEvent::ClipboardData`].
+pub(crate) fn run_on_clipboard_data_pass(
+    root: &mut RenderRoot,
+    target: WidgetId,
+    event: &TextEvent,
+) -> Handled {
+    let _span = info_span!("dispatch_clipboard_data").entered();
+    trace!("Running ON_TEXT_EVENT pass with {}", event.short_name());
+
+    if !root.has_widget(target) {
+        // The widget was removed while the platform was reading the clipboard.
+        return Handled::No;
+    }
+
+    let skip_if_disabled = true;
+    run_event_pass(
+        root,
+        Some(target),
+        event,
+        skip_if_disabled,
+        false,
+        false,
+        |widget, ctx, props, event| {
+            widget.on_text_event(ctx, props, event);
+        },
+        true,
+    )
+}
+
 // --- MARK: ACCESS EVENT
 /// See the [passes documentation](crate::doc::pass_system#event-passes).
 pub(crate) fn run_on_access_event_pass(
@@ -357,6 +550,7 @@ pub(crate) fn run_on_access_event_pass(
         event,
         skip_if_disabled,
         false,
+        false,
         |widget, ctx, props, event| {
             widget.on_access_event(ctx, props, event);
         },
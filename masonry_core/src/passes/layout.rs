@@ -17,7 +17,7 @@ use crate::core::{
     WidgetState,
 };
 use crate::kurbo::{Axis, Insets, Point, Size};
-use crate::layout::{LayoutSize, LenDef, LenReq, MeasurementInputs, SizeDef};
+use crate::layout::{LayoutSize, LenDef, LenReq, LengthContext, MeasurementInputs, SizeDef};
 use crate::passes::{enter_span_if, recurse_on_children};
 use crate::properties::{BorderWidth, BoxShadow, Dimensions, Padding};
 use crate::util::Sanitize;
@@ -35,10 +35,6 @@ use crate::util::Sanitize;
 /// `cross_length`, if present, must be [sanitized] and in device pixels.
 ///
 /// [sanitized]: Sanitize
-#[expect(
-    clippy::trivially_copy_pass_by_ref,
-    reason = "Widget::measure takes props by ref"
-)]
 fn measure_border_box(
     widget: &mut dyn Widget,
     ctx: &mut MeasureCtx<'_>,
@@ -87,10 +83,6 @@ fn measure_border_box(
 ///
 /// [sanitized]: Sanitize
 /// [`measure`]: Widget::measure
-#[expect(
-    clippy::trivially_copy_pass_by_ref,
-    reason = "Widget::measure takes props by ref"
-)]
 fn resolve_len_def(
     widget: &mut dyn Widget,
     ctx: &mut MeasureCtx<'_>,
@@ -144,6 +136,26 @@ fn resolve_len_def(
     result
 }
 
+/// Updates the font size of the widget and returns the values its relative lengths resolve against.
+///
+/// `parent_font_size` is used if the widget doesn't have a font size of its own.
+fn update_length_context(
+    global_state: &RenderRootState,
+    widget: &dyn Widget,
+    state: &mut WidgetState,
+    parent_font_size: f64,
+) -> LengthContext {
+    state.font_size = widget
+        .font_size()
+        .unwrap_or(parent_font_size)
+        .sanitize("font size");
+    LengthContext {
+        font_size: state.font_size,
+        root_font_size: global_state.root_font_size,
+        viewport_size: global_state.viewport_size,
+    }
+}
+
 /// Resolves the widget's preferred border-box length on the given `axis`.
 ///
 /// The returned length will be finite, non-negative, and in device pixels.
@@ -155,6 +167,8 @@ fn resolve_len_def(
 /// `cross_length`, if present, must be finite, non-negative, and in device pixels.
 /// Invalid `cross_length` value is fall back to `None`.
 ///
+/// `parent_font_size` is the font size of the parent widget, in logical pixels.
+///
 /// # Panics
 ///
 /// Panics if `auto_length` has a non-finite or negative value and debug assertions are enabled.
@@ -177,6 +191,7 @@ pub(crate) fn resolve_length(
     context_size: LayoutSize,
     axis: Axis,
     cross_length: Option<f64>,
+    parent_font_size: f64,
 ) -> f64 {
     // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
     //       https://github.com/linebender/xilem/issues/1264
@@ -192,14 +207,20 @@ pub(crate) fn resolve_length(
     let widget = &mut *node.item.widget;
     let props = PropertiesRef {
         map: &mut node.item.properties,
-        default_map: default_properties.for_widget(widget.type_id()),
+        default_map: default_properties.for_widget(
+            widget.type_id(),
+            node.item.state.style_state(global_state.focused_widget),
+        ),
     };
     let dims = props.get::<Dimensions>();
+    let lengths =
+        update_length_context(global_state, widget, &mut node.item.state, parent_font_size);
+    node.item.state.uses_viewport_units |= dims.is_viewport_relative();
 
     // Resolve the dimension on the given axis
     let len_def = dims
         .dim(axis)
-        .resolve(scale, context_size.length(axis))
+        .resolve(scale, context_size.length(axis), &lengths)
         .unwrap_or(auto_length)
         .sanitize("len_def");
 
@@ -224,7 +245,7 @@ pub(crate) fn resolve_length(
     let cross_length = cross_length.or_else(|| {
         let cross = axis.cross();
         dims.dim(cross)
-            .resolve(scale, context_size.length(cross))
+            .resolve(scale, context_size.length(cross), &lengths)
             .and_then(|cross_len_def| cross_len_def.sanitize("cross_len_def").fixed())
     });
 
@@ -241,6 +262,8 @@ pub(crate) fn resolve_length(
 ///
 /// `context_size` must be in device pixels.
 ///
+/// `parent_font_size` is the font size of the parent widget, in logical pixels.
+///
 /// # Panics
 ///
 /// Panics if a dimension resolves to a non-finite or negative value
@@ -257,6 +280,7 @@ pub(crate) fn resolve_size(
     node: ArenaMut<'_, WidgetArenaNode>,
     auto_size: SizeDef,
     context_size: LayoutSize,
+    parent_font_size: f64,
 ) -> Size {
     // TODO: Remove HACK: Until scale factor rework happens, just pretend it's always 1.0.
     //       https://github.com/linebender/xilem/issues/1264
@@ -272,21 +296,27 @@ pub(crate) fn resolve_size(
     let widget = &mut *node.item.widget;
     let props = PropertiesRef {
         map: &mut node.item.properties,
-        default_map: default_properties.for_widget(widget.type_id()),
+        default_map: default_properties.for_widget(
+            widget.type_id(),
+            node.item.state.style_state(global_state.focused_widget),
+        ),
     };
     let dims = props.get::<Dimensions>();
+    let lengths =
+        update_length_context(global_state, widget, &mut node.item.state, parent_font_size);
+    node.item.state.uses_viewport_units |= dims.is_viewport_relative();
 
     // Resolve the dimensions
     let inline_auto = auto_size.dim(inline);
     let inline_def = dims
         .dim(inline)
-        .resolve(scale, context_size.length(inline))
+        .resolve(scale, context_size.length(inline), &lengths)
         .unwrap_or(inline_auto)
         .sanitize("inline_def");
     let block_auto = auto_size.dim(block);
     let block_def = dims
         .dim(block)
-        .resolve(scale, context_size.length(block))
+        .resolve(scale, context_size.length(block), &lengths)
         .unwrap_or(block_auto)
         .sanitize("block_def");
 
@@ -359,6 +389,7 @@ pub(crate) fn run_layout_on(
     default_properties: &DefaultProperties,
     node: ArenaMut<'_, WidgetArenaNode>,
     chosen_size: Size,
+    parent_font_size: f64,
 ) {
     // Ensure the chosen size is sanitized.
     let chosen_size = Size::new(
@@ -396,9 +427,15 @@ pub(crate) fn run_layout_on(
     //       https://github.com/linebender/xilem/issues/1264
     let scale = 1.0;
 
+    // Widgets can query the length context during layout, so the font size must be up to date.
+    update_length_context(global_state, widget, state, parent_font_size);
+
     let props = PropertiesRef {
         map: properties,
-        default_map: default_properties.for_widget(widget.type_id()),
+        default_map: default_properties.for_widget(
+            widget.type_id(),
+            state.style_state(global_state.focused_widget),
+        ),
     };
 
     let border_width = props.get::<BorderWidth>();
@@ -595,6 +632,8 @@ pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
     root.global_state.needs_pointer_pass = true;
 
     let window_size = root.get_kurbo_size();
+    root.global_state.viewport_size = window_size;
+    let root_font_size = root.global_state.root_font_size;
     let mut root_node = root.widget_arena.get_node_mut(root.root_id());
     let root_node_size = match root.size_policy {
         WindowSizePolicy::User => resolve_size(
@@ -603,6 +642,7 @@ pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
             root_node.reborrow_mut(),
             SizeDef::fixed(window_size),
             window_size.into(),
+            root_font_size,
         ),
         WindowSizePolicy::Content => resolve_size(
             &mut root.global_state,
@@ -610,6 +650,7 @@ pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
             root_node.reborrow_mut(),
             SizeDef::MAX,
             LayoutSize::NONE,
+            root_font_size,
         ),
     };
 
@@ -618,6 +659,7 @@ pub(crate) fn run_layout_pass(root: &mut RenderRoot) {
         &root.default_properties,
         root_node.reborrow_mut(),
         root_node_size,
+        root_font_size,
     );
     place_widget(&mut root_node.item.state, Point::ORIGIN);
 
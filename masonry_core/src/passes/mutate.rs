@@ -29,6 +29,7 @@ pub(crate) fn mutate_widget<R>(
     // NOTE - we can set parent_widget_state to None here, because the loop below will merge the
     // states up to the root.
 
+    let style_state = state.style_state(root.global_state.focused_widget);
     let root_widget = WidgetMut {
         ctx: MutateCtx {
             global_state: &mut root.global_state,
@@ -36,7 +37,9 @@ pub(crate) fn mutate_widget<R>(
             widget_state: state,
             properties: PropertiesMut {
                 map: properties,
-                default_map: root.default_properties.for_widget(widget.type_id()),
+                default_map: root
+                    .default_properties
+                    .for_widget(widget.type_id(), style_state),
             },
             changed_properties,
             children,
@@ -51,22 +51,26 @@ fn paint_widget(
         };
         let props = PropertiesRef {
             map: properties,
-            default_map: default_properties.for_widget(widget.type_id()),
+            default_map: default_properties.for_widget(
+                widget.type_id(),
+                ctx.widget_state
+                    .style_state(ctx.global_state.focused_widget),
+            ),
         };
 
         // TODO - Reserve scene
         // https://github.com/linebender/xilem/issues/524
         let (pre_scene, scene, post_scene) = scene_cache.entry(id).or_default();
 
-        if state.request_pre_paint {
+        if ctx.widget_state.request_pre_paint {
             pre_scene.reset();
             widget.pre_paint(&mut ctx, &props, pre_scene);
         }
-        if state.request_paint {
+        if ctx.widget_state.request_paint {
             scene.reset();
             widget.paint(&mut ctx, &props, scene);
         }
-        if state.request_post_paint {
+        if ctx.widget_state.request_post_paint {
             post_scene.reset();
             widget.post_paint(&mut ctx, &props, post_scene);
         }
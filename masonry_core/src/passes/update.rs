@@ -15,6 +15,7 @@ use crate::core::{
 };
 use crate::passes::event::{run_on_pointer_event_pass, run_on_text_event_pass};
 use crate::passes::{enter_span, enter_span_if, merge_state_up, recurse_on_children};
+use crate::properties::style_rules_changed;
 
 // --- MARK: HELPERS
 /// Returns the id path starting from the given widget id and ending at the root.
@@ -76,7 +77,11 @@ fn run_targeted_update_pass(
         };
         let mut props = PropertiesMut {
             map: properties,
-            default_map: root.default_properties.for_widget(widget.type_id()),
+            default_map: root.default_properties.for_widget(
+                widget.type_id(),
+                ctx.widget_state
+                    .style_state(ctx.global_state.focused_widget),
+            ),
         };
         pass_fn(widget, &mut ctx, &mut props);
 
@@ -112,7 +117,11 @@ fn run_single_update_pass(
     };
     let mut props = PropertiesMut {
         map: properties,
-        default_map: root.default_properties.for_widget(widget.type_id()),
+        default_map: root.default_properties.for_widget(
+            widget.type_id(),
+            ctx.widget_state
+                .style_state(ctx.global_state.focused_widget),
+        ),
     };
     pass_fn(widget, &mut ctx, &mut props);
 
@@ -153,7 +162,11 @@ fn update_widget_tree(
         };
         let mut props = PropertiesMut {
             map: properties,
-            default_map: default_properties.for_widget(widget.type_id()),
+            default_map: default_properties.for_widget(
+                widget.type_id(),
+                ctx.widget_state
+                    .style_state(ctx.global_state.focused_widget),
+            ),
         };
         widget.update(&mut ctx, &mut props, &Update::WidgetAdded);
         if trace {
@@ -280,9 +293,14 @@ fn update_disabled_for_widget(
         };
         let mut props = PropertiesMut {
             map: properties,
-            default_map: default_properties.for_widget(widget.type_id()),
+            default_map: default_properties.for_widget(
+                widget.type_id(),
+                ctx.widget_state
+                    .style_state(ctx.global_state.focused_widget),
+            ),
         };
         widget.update(&mut ctx, &mut props, &Update::DisabledChanged(disabled));
+        style_rules_changed(widget, &mut ctx, |selector| selector.state().disabled);
         state.is_disabled = disabled;
         state.needs_update_focusable = true;
         state.request_accessibility = true;
@@ -359,7 +377,11 @@ fn update_stashed_for_widget(
         };
         let mut props = PropertiesMut {
             map: properties,
-            default_map: default_properties.for_widget(widget.type_id()),
+            default_map: default_properties.for_widget(
+                widget.type_id(),
+                ctx.widget_state
+                    .style_state(ctx.global_state.focused_widget),
+            ),
         };
         widget.update(&mut ctx, &mut props, &Update::StashedChanged(stashed));
         state.is_stashed = stashed;
@@ -685,6 +707,7 @@ pub(crate) fn run_update_focus_pass(root: &mut RenderRoot) {
         // We also request accessibility, because build_access_node() depends on the focus state.
         run_single_update_pass(root, prev_focused, |widget, ctx, props| {
             widget.update(ctx, props, &Update::FocusChanged(false));
+            style_rules_changed(widget, ctx, |selector| selector.state().focused);
             ctx.widget_state.request_accessibility = true;
             ctx.widget_state.needs_accessibility = true;
             // FocusedBorderColor needs pre-paint
@@ -693,6 +716,7 @@ pub(crate) fn run_update_focus_pass(root: &mut RenderRoot) {
         });
         run_single_update_pass(root, next_focused, |widget, ctx, props| {
             widget.update(ctx, props, &Update::FocusChanged(true));
+            style_rules_changed(widget, ctx, |selector| selector.state().focused);
             ctx.widget_state.request_accessibility = true;
             ctx.widget_state.needs_accessibility = true;
             // FocusedBorderColor needs pre-paint
@@ -863,6 +887,7 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
             ctx.widget_state.request_pre_paint = true;
             ctx.widget_state.needs_paint = true;
             widget.update(ctx, props, &Update::ActiveChanged(false));
+            style_rules_changed(widget, ctx, |selector| selector.state().active);
         });
         run_single_update_pass(root, next_active_widget, |widget, ctx, props| {
             ctx.widget_state.is_active = true;
@@ -870,6 +895,7 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
             ctx.widget_state.request_pre_paint = true;
             ctx.widget_state.needs_paint = true;
             widget.update(ctx, props, &Update::ActiveChanged(true));
+            style_rules_changed(widget, ctx, |selector| selector.state().active);
         });
     }
 
@@ -964,6 +990,7 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
             ctx.widget_state.request_pre_paint = true;
             ctx.widget_state.needs_paint = true;
             widget.update(ctx, props, &Update::HoveredChanged(false));
+            style_rules_changed(widget, ctx, |selector| selector.state().hovered);
         });
         run_single_update_pass(root, next_hovered_widget, |widget, ctx, props| {
             ctx.widget_state.is_hovered = true;
@@ -971,6 +998,7 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
             ctx.widget_state.request_pre_paint = true;
             ctx.widget_state.needs_paint = true;
             widget.update(ctx, props, &Update::HoveredChanged(true));
+            style_rules_changed(widget, ctx, |selector| selector.state().hovered);
         });
     }
 
@@ -995,7 +1023,10 @@ pub(crate) fn run_update_pointer_pass(root: &mut RenderRoot) {
             widget_state: state,
             properties: PropertiesRef {
                 map: properties,
-                default_map: root.default_properties.for_widget(widget.type_id()),
+                default_map: root.default_properties.for_widget(
+                    widget.type_id(),
+                    state.style_state(root.global_state.focused_widget),
+                ),
             },
             children,
             default_properties: &root.default_properties,
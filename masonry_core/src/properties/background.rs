@@ -1,6 +1,7 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::animation::Interpolate;
 use crate::core::{HasProperty, Property, Widget};
 use crate::kurbo::Rect;
 use crate::peniko::color::{AlphaColor, Srgb};
@@ -75,6 +76,26 @@ impl Background {
     }
 }
 
+impl Interpolate for Background {
+    /// Interpolates between two backgrounds.
+    ///
+    /// Colors are interpolated with each other, and a color is interpolated with a gradient
+    /// as if it were a gradient of that uniform color.
+    /// See the [`Interpolate`] implementation of [`Gradient`] for how gradients are interpolated.
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        match (self, other) {
+            (Self::Color(from), Self::Color(to)) => Self::Color(from.interpolate(to, t)),
+            (Self::Gradient(from), Self::Gradient(to)) => Self::Gradient(from.interpolate(to, t)),
+            (Self::Color(from), Self::Gradient(to)) => {
+                Self::Gradient(to.with_uniform_color(*from).interpolate(to, t))
+            }
+            (Self::Gradient(from), Self::Color(to)) => {
+                Self::Gradient(from.interpolate(&from.with_uniform_color(*to), t))
+            }
+        }
+    }
+}
+
 // ---
 
 impl Property for ActiveBackground {
@@ -1,6 +1,7 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::animation::Interpolate;
 use crate::core::{HasProperty, Property, Widget};
 use crate::peniko::BrushRef;
 use crate::peniko::color::{AlphaColor, Srgb};
@@ -67,6 +68,12 @@ impl BorderColor {
     }
 }
 
+impl Interpolate for BorderColor {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Self::new(self.color.interpolate(&other.color, t))
+    }
+}
+
 // ---
 
 impl Default for HoveredBorderColor {
@@ -1,6 +1,7 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::animation::Interpolate;
 use crate::core::{HasProperty, Property, Widget};
 
 // Every widget has a corner radius.
@@ -26,3 +27,10 @@ impl CornerRadius {
         Self { radius }
     }
 }
+
+impl Interpolate for CornerRadius {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        // Eased progress can overshoot, but radii can't be negative.
+        Self::all(self.radius.interpolate(&other.radius, t).max(0.))
+    }
+}
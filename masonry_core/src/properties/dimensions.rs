@@ -164,4 +164,9 @@ impl Dimensions {
             Axis::Vertical => self.height,
         }
     }
+
+    /// Returns `true` if either dimension is relative to the viewport size.
+    pub const fn is_viewport_relative(&self) -> bool {
+        self.width.is_viewport_relative() || self.height.is_viewport_relative()
+    }
 }
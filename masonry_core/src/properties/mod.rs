@@ -10,6 +10,7 @@ mod box_shadow;
 mod corner_radius;
 mod dimensions;
 mod padding;
+mod transition;
 
 pub mod types;
 
@@ -22,8 +23,9 @@ pub use box_shadow::*;
 pub use corner_radius::*;
 pub use dimensions::*;
 pub use padding::*;
+pub use transition::*;
 
-use crate::core::{Property, UpdateCtx};
+use crate::core::{Property, Selector, UpdateCtx, Widget};
 
 /// Handles core property changes.
 pub(crate) fn core_property_changed(ctx: &mut UpdateCtx<'_>, property_type: TypeId) {
@@ -48,3 +50,21 @@ pub(crate) fn core_property_changed(ctx: &mut UpdateCtx<'_>, property_type: Type
         ctx.request_pre_paint();
     }
 }
+
+/// Notifies `widget` that the properties set by its style rules matching `filter` may have changed.
+///
+/// This is called when the widget's state or style classes change,
+/// which can make style rules start or stop applying to it.
+pub(crate) fn style_rules_changed(
+    widget: &mut dyn Widget,
+    ctx: &mut UpdateCtx<'_>,
+    filter: impl Fn(&Selector) -> bool,
+) {
+    let property_types = ctx
+        .default_properties
+        .rule_properties(widget.type_id(), filter);
+    for property_type in property_types {
+        core_property_changed(ctx, property_type);
+        widget.property_changed(ctx, property_type);
+    }
+}
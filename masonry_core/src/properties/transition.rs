@@ -0,0 +1,222 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::TypeId;
+
+use crate::animation::{Easing, Interpolate};
+use crate::core::{HasProperty, Property, Widget};
+use crate::properties::{Background, BorderColor, CornerRadius};
+use crate::util::Duration;
+
+// Every widget can have transitions.
+impl<W: Widget> HasProperty<Transitions> for W {}
+
+/// How a property animates from its old value to its new value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition {
+    /// How long the transition lasts.
+    pub duration: Duration,
+    /// The easing curve of the transition.
+    pub easing: Easing,
+}
+
+/// The transitions a widget uses when some of its properties change.
+///
+/// Transitions are declared per property type, either on individual widgets
+/// or for a whole widget type through [`DefaultProperties`].
+///
+/// Masonry currently animates the properties painted by [`pre_paint`]:
+/// [`Background`], [`BorderColor`] and [`CornerRadius`].
+/// A transition for [`Background`] applies to the background the widget actually paints,
+/// which includes [`ActiveBackground`] and [`DisabledBackground`],
+/// and a transition for [`BorderColor`] similarly covers [`HoveredBorderColor`]
+/// and [`FocusedBorderColor`].
+///
+/// [`DefaultProperties`]: crate::core::DefaultProperties
+/// [`pre_paint`]: crate::core::pre_paint
+/// [`Background`]: crate::properties::Background
+/// [`BorderColor`]: crate::properties::BorderColor
+/// [`CornerRadius`]: crate::properties::CornerRadius
+/// [`ActiveBackground`]: crate::properties::ActiveBackground
+/// [`DisabledBackground`]: crate::properties::DisabledBackground
+/// [`HoveredBorderColor`]: crate::properties::HoveredBorderColor
+/// [`FocusedBorderColor`]: crate::properties::FocusedBorderColor
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Transitions {
+    transitions: Vec<(TypeId, Transition)>,
+}
+
+impl Property for Transitions {
+    fn static_default() -> &'static Self {
+        static DEFAULT: Transitions = Transitions {
+            transitions: Vec::new(),
+        };
+        &DEFAULT
+    }
+}
+
+impl Transition {
+    /// Creates a transition with the given duration and easing.
+    pub const fn new(duration: Duration, easing: Easing) -> Self {
+        Self { duration, easing }
+    }
+
+    /// Creates a transition lasting `ms` milliseconds, with the default easing.
+    pub const fn ms(ms: u64) -> Self {
+        Self {
+            duration: Duration::from_millis(ms),
+            easing: Easing::EaseInOut,
+        }
+    }
+}
+
+impl Transitions {
+    /// Creates an empty set of transitions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder-style method to set the transition of property `P`.
+    pub fn with<P: Property + Interpolate>(mut self, transition: Transition) -> Self {
+        self.insert::<P>(transition);
+        self
+    }
+
+    /// Sets the transition of property `P`. Returns the previous transition, if any.
+    pub fn insert<P: Property + Interpolate>(
+        &mut self,
+        transition: Transition,
+    ) -> Option<Transition> {
+        let property_type = TypeId::of::<P>();
+        if let Some((_, prev)) = self
+            .transitions
+            .iter_mut()
+            .find(|(ty, _)| *ty == property_type)
+        {
+            return Some(std::mem::replace(prev, transition));
+        }
+        self.transitions.push((property_type, transition));
+        None
+    }
+
+    /// Returns the transition of property `P`, if any.
+    pub fn get<P: Property>(&self) -> Option<Transition> {
+        let property_type = TypeId::of::<P>();
+        self.transitions
+            .iter()
+            .find(|(ty, _)| *ty == property_type)
+            .map(|(_, transition)| *transition)
+    }
+}
+
+// --- MARK: TRANSITION STATE
+
+/// The running transitions of the properties painted by [`pre_paint`](crate::core::pre_paint).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PrePaintTransitions {
+    pub(crate) background: TransitionSlot<Background>,
+    pub(crate) border_color: TransitionSlot<BorderColor>,
+    pub(crate) corner_radius: TransitionSlot<CornerRadius>,
+}
+
+/// Tracks the value of a single property with a transition.
+#[derive(Clone, Debug)]
+pub(crate) struct TransitionSlot<T> {
+    /// The value the property had the last time it was painted, without transitions applied.
+    target: Option<T>,
+    running: Option<RunningTransition<T>>,
+}
+
+#[derive(Clone, Debug)]
+struct RunningTransition<T> {
+    from: T,
+    transition: Transition,
+    elapsed_ns: u64,
+}
+
+impl PrePaintTransitions {
+    /// Returns `true` if any transition is in progress.
+    pub(crate) fn is_running(&self) -> bool {
+        self.background.is_running()
+            || self.border_color.is_running()
+            || self.corner_radius.is_running()
+    }
+
+    /// Advances every transition in progress by `elapsed_ns`.
+    pub(crate) fn advance(&mut self, elapsed_ns: u64) {
+        self.background.advance(elapsed_ns);
+        self.border_color.advance(elapsed_ns);
+        self.corner_radius.advance(elapsed_ns);
+    }
+}
+
+impl<T> Default for TransitionSlot<T> {
+    fn default() -> Self {
+        Self {
+            target: None,
+            running: None,
+        }
+    }
+}
+
+impl<T: Interpolate + Clone + PartialEq> TransitionSlot<T> {
+    /// Returns the value to paint for the property, given its new `target` value.
+    ///
+    /// If `target` differs from the previous target, this starts a transition
+    /// from the currently displayed value.
+    /// Returns `None` if no transition is in progress, in which case `target` should be painted.
+    pub(crate) fn update(&mut self, target: &T, transition: Option<Transition>) -> Option<T> {
+        let Some(transition) = transition else {
+            *self = Self::default();
+            return None;
+        };
+
+        match &self.target {
+            // We don't animate the first value a widget is painted with.
+            None => self.target = Some(target.clone()),
+            Some(previous) if previous != target => {
+                let from = self.current().unwrap_or_else(|| previous.clone());
+                self.running = Some(RunningTransition {
+                    from,
+                    transition,
+                    elapsed_ns: 0,
+                });
+                self.target = Some(target.clone());
+            }
+            Some(_) => {}
+        }
+
+        let value = self.current();
+        if value.is_none() {
+            self.running = None;
+        }
+        value
+    }
+
+    /// Returns the interpolated value of the transition in progress, if any.
+    fn current(&self) -> Option<T> {
+        let running = self.running.as_ref()?;
+        let target = self.target.as_ref()?;
+        let duration_ns = running.transition.duration.as_nanos();
+        if u128::from(running.elapsed_ns) >= duration_ns {
+            return None;
+        }
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "transitions don't need nanosecond precision"
+        )]
+        let progress = running.elapsed_ns as f64 / duration_ns as f64;
+        let t = running.transition.easing.apply(progress);
+        Some(running.from.interpolate(target, t))
+    }
+
+    fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    fn advance(&mut self, elapsed_ns: u64) {
+        if let Some(running) = &mut self.running {
+            running.elapsed_ns = running.elapsed_ns.saturating_add(elapsed_ns);
+        }
+    }
+}
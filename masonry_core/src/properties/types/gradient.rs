@@ -3,9 +3,10 @@
 
 use std::f64::consts::TAU;
 
+use crate::animation::{Interpolate, interpolate_discrete};
 use crate::kurbo::{Point, Rect};
 use crate::layout::UnitPoint;
-use crate::peniko::color::{ColorSpaceTag, HueDirection};
+use crate::peniko::color::{AlphaColor, ColorSpaceTag, DynamicColor, HueDirection, Srgb};
 use crate::peniko::{ColorStops, ColorStopsSource, Extend};
 use crate::peniko::{
     InterpolationAlphaSpace, LinearGradientPosition, RadialGradientPosition, SweepGradientPosition,
@@ -228,6 +229,75 @@ impl Gradient {
             interpolation_alpha_space: InterpolationAlphaSpace::default(),
         }
     }
+
+    /// Returns a copy of this gradient with every stop set to `color`.
+    pub(crate) fn with_uniform_color(&self, color: AlphaColor<Srgb>) -> Self {
+        let mut gradient = self.clone();
+        for stop in gradient.stops.iter_mut() {
+            stop.color = DynamicColor::from_alpha_color(color);
+        }
+        gradient
+    }
+}
+
+impl Interpolate for Gradient {
+    /// Interpolates between two gradients.
+    ///
+    /// The stops are interpolated pairwise, in the gradient's interpolation color space.
+    /// Linear angles and sweep angles are interpolated too.
+    ///
+    /// Gradients which don't have the same number of stops, the same kind of shape,
+    /// or the same extend and color interpolation settings switch halfway through instead.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "stop offsets and colors are f32"
+    )]
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        let shape = match (&self.shape, &other.shape) {
+            (GradientShape::Linear { angle: from }, GradientShape::Linear { angle: to }) => {
+                Some(GradientShape::Linear {
+                    angle: from.interpolate(to, t),
+                })
+            }
+            (
+                GradientShape::Sweep {
+                    center,
+                    start_angle: from_start,
+                    end_angle: from_end,
+                },
+                GradientShape::Sweep {
+                    center: to_center,
+                    start_angle: to_start,
+                    end_angle: to_end,
+                },
+            ) if center == to_center => Some(GradientShape::Sweep {
+                center: *center,
+                start_angle: from_start.interpolate(to_start, t),
+                end_angle: from_end.interpolate(to_end, t),
+            }),
+            (from, to) if from == to => Some(from.clone()),
+            _ => None,
+        };
+        let Some(shape) = shape.filter(|_| {
+            self.stops.len() == other.stops.len()
+                && self.extend == other.extend
+                && self.interpolation_cs == other.interpolation_cs
+                && self.hue_direction == other.hue_direction
+        }) else {
+            return interpolate_discrete(self, other, t);
+        };
+
+        let mut gradient = self.clone();
+        gradient.shape = shape;
+        for (stop, to) in gradient.stops.iter_mut().zip(other.stops.iter()) {
+            stop.offset = stop.offset.interpolate(&to.offset, t).clamp(0., 1.);
+            stop.color = stop
+                .color
+                .interpolate(to.color, self.interpolation_cs, self.hue_direction)
+                .eval(t as f32);
+        }
+        gradient
+    }
 }
 
 #[expect(
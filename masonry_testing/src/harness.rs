@@ -19,14 +19,15 @@ use tracing::debug;
 use masonry_core::accesskit::{Action, ActionRequest, Node, Role, Tree, TreeUpdate};
 use masonry_core::anymore::AnyDebug;
 use masonry_core::app::{
-    RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy, try_init_test_tracing,
+    RenderRoot, RenderRootOptions, RenderRootSignal, ThemeProperties, WindowSizePolicy,
+    try_init_test_tracing,
 };
 use masonry_core::core::keyboard::{Code, Key, KeyState, NamedKey};
 use masonry_core::core::{
-    CursorIcon, DefaultProperties, ErasedAction, FromDynWidget, Handled, Ime, KeyboardEvent,
-    Modifiers, NewWidget, PointerButton, PointerButtonEvent, PointerEvent, PointerId, PointerInfo,
-    PointerScrollEvent, PointerState, PointerType, PointerUpdate, ScrollDelta, TextEvent, Widget,
-    WidgetId, WidgetMut, WidgetRef, WidgetTag, WindowEvent,
+    ClipboardData, CursorIcon, DefaultProperties, ErasedAction, FromDynWidget, Handled, Ime,
+    KeyboardEvent, Modifiers, NewWidget, PointerButton, PointerButtonEvent, PointerEvent,
+    PointerId, PointerInfo, PointerScrollEvent, PointerState, PointerType, PointerUpdate,
+    ScrollDelta, TextEvent, Widget, WidgetId, WidgetMut, WidgetRef, WidgetTag, WindowEvent,
 };
 use masonry_core::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
 use masonry_core::kurbo::{Affine, Point, Rect, Size, Vec2};
@@ -148,7 +149,8 @@ pub struct TestHarness<W: Widget> {
     action_queue: VecDeque<(ErasedAction, WidgetId)>,
     has_ime_session: bool,
     ime_rect: (LogicalPosition<f64>, LogicalSize<f64>),
-    clipboard: String,
+    clipboard: ClipboardData,
+    pending_clipboard_reads: Vec<WidgetId>,
     title: String,
     _marker: PhantomData<W>,
 }
@@ -368,7 +370,8 @@ impl<W: Widget> TestHarness<W> {
             action_queue: VecDeque::new(),
             has_ime_session: false,
             ime_rect: Default::default(),
-            clipboard: String::new(),
+            clipboard: ClipboardData::new(),
+            pending_clipboard_reads: Vec::new(),
             title: String::new(),
             _marker: PhantomData,
         };
@@ -444,7 +447,13 @@ impl<W: Widget> TestHarness<W> {
                     self.ime_rect = (position, size);
                 }
                 RenderRootSignal::ClipboardStore(text) => {
-                    self.clipboard = text;
+                    self.clipboard = ClipboardData::from_text(text);
+                }
+                RenderRootSignal::ClipboardStoreData(data) => {
+                    self.clipboard = data;
+                }
+                RenderRootSignal::ClipboardRead(widget_id) => {
+                    self.pending_clipboard_reads.push(widget_id);
                 }
                 RenderRootSignal::RequestRedraw => (),
                 RenderRootSignal::RequestAnimFrame => (),
@@ -473,6 +482,17 @@ impl<W: Widget> TestHarness<W> {
                 }
             }
         }
+
+        // Clipboard reads are answered once the event which requested them is fully processed,
+        // like a platform would.
+        let reads = std::mem::take(&mut self.pending_clipboard_reads);
+        if !reads.is_empty() {
+            for widget_id in reads {
+                self.render_root
+                    .handle_clipboard_data(widget_id, self.clipboard.clone());
+            }
+            self.process_signals();
+        }
     }
 
     // --- MARK: RENDER
@@ -678,6 +698,11 @@ impl<W: Widget> TestHarness<W> {
         }));
     }
 
+    /// Sets the keyboard modifiers held during the following mouse events.
+    pub fn set_mouse_modifiers(&mut self, modifiers: Modifiers) {
+        self.mouse_state.modifiers = modifiers;
+    }
+
     /// Sends a [`Scroll`](PointerEvent::Scroll) event to the window.
     pub fn mouse_wheel(&mut self, Vec2 { x, y }: Vec2) {
         self.process_pointer_event(PointerEvent::Scroll(PointerScrollEvent {
@@ -762,6 +787,51 @@ impl<W: Widget> TestHarness<W> {
         self.mouse_move(widget_center);
     }
 
+    /// Sends events that drag the pointer from `from` to `to` with the primary button pressed.
+    ///
+    /// The pointer moves in several steps, so that widgets can start a [drag-and-drop]
+    /// operation and drop targets along the way get [`DragEvent`]s.
+    ///
+    /// [drag-and-drop]: masonry_core::doc::masonry_concepts#drag-and-drop
+    /// [`DragEvent`]: masonry_core::core::DragEvent
+    pub fn mouse_drag(&mut self, from: impl Into<Point>, to: impl Into<Point>) {
+        const STEPS: u32 = 5;
+        let (from, to) = (from.into(), to.into());
+
+        self.mouse_move(from);
+        self.mouse_button_press(PointerButton::Primary);
+        for step in 1..=STEPS {
+            self.mouse_move(from.lerp(to, f64::from(step) / f64::from(STEPS)));
+        }
+        self.mouse_button_release(PointerButton::Primary);
+    }
+
+    /// Sends events that drag the `source` widget and drop it on the `target` widget.
+    ///
+    /// Combines [`mouse_move_to`](Self::mouse_move_to) and [`mouse_drag`](Self::mouse_drag),
+    /// going from the center of `source` to the center of `target`.
+    ///
+    /// # Panics
+    ///
+    /// - If either widget is not found in the tree.
+    /// - If either widget is stashed.
+    /// - If `source` doesn't accept pointer events.
+    /// - If `source` is scrolled out of view.
+    #[track_caller]
+    pub fn mouse_drag_and_drop(&mut self, source: WidgetId, target: WidgetId) {
+        self.mouse_move_to(source);
+        let PhysicalPosition { x, y } = self.mouse_state.position;
+
+        let widget = self.get_widget_with_id(target);
+        let local_widget_center = (widget.ctx().border_box_size() / 2.0).to_vec2().to_point();
+        let widget_center = widget.ctx().window_transform() * local_widget_center;
+        if widget.ctx().is_stashed() {
+            panic!("Widget {target} is stashed");
+        }
+
+        self.mouse_drag((x, y), widget_center);
+    }
+
     /// Tries to get the target widget into the viewport.
     ///
     /// This will send an accesskit [`ScrollIntoView`] action to the widget,
@@ -868,6 +938,34 @@ impl<W: Widget> TestHarness<W> {
         let _ = self.render_root.set_focus_fallback(id);
     }
 
+    /// Sets the root font size, which relative lengths are resolved against.
+    ///
+    /// See [`RenderRoot::set_root_font_size`] for details.
+    pub fn set_root_font_size(&mut self, font_size: f64) {
+        self.render_root.set_root_font_size(font_size);
+        self.process_signals();
+    }
+
+    /// Replaces the default properties of the widget tree.
+    ///
+    /// See [`RenderRoot::set_default_properties`] for details.
+    pub fn set_default_properties(&mut self, default_properties: DefaultProperties) {
+        self.render_root
+            .set_default_properties(Arc::new(default_properties));
+        self.process_signals();
+    }
+
+    /// Makes the default properties follow the simulated window's light/dark mode.
+    ///
+    /// Send [`WindowEvent::ThemeChanged`] with [`process_window_event`](Self::process_window_event)
+    /// to simulate a change of OS theme.
+    ///
+    /// See [`RenderRoot::set_theme_properties`] for details.
+    pub fn set_theme_properties(&mut self, theme_properties: Option<ThemeProperties>) {
+        self.render_root.set_theme_properties(theme_properties);
+        self.process_signals();
+    }
+
     /// Runs an animation pass on the widget tree.
     pub fn animate_ms(&mut self, ms: u64) {
         self.render_root
@@ -1069,11 +1167,26 @@ impl<W: Widget> TestHarness<W> {
         self.ime_rect
     }
 
-    /// Returns the contents of the emulated clipboard.
+    /// Returns the plain text contents of the emulated clipboard.
     ///
-    /// This is an empty string by default.
+    /// This is an empty string by default, or if the clipboard holds no plain text.
     pub fn clipboard_contents(&self) -> String {
-        self.clipboard.clone()
+        self.clipboard.text().unwrap_or_default().to_string()
+    }
+
+    /// Returns the contents of the emulated clipboard, in all formats.
+    pub fn clipboard_data(&self) -> &ClipboardData {
+        &self.clipboard
+    }
+
+    /// Sets the contents of the emulated clipboard.
+    ///
+    /// These are what widgets receive when they [request the clipboard].
+    /// Any MIME type can be used.
+    ///
+    /// [request the clipboard]: masonry_core::core::EventCtx::request_clipboard
+    pub fn set_clipboard_data(&mut self, data: impl Into<ClipboardData>) {
+        self.clipboard = data.into();
     }
 
     /// Returns the size of the simulated window.
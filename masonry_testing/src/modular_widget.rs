@@ -2,11 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::any::TypeId;
+use std::ops::Range;
 
 use masonry_core::accesskit::{Node, Role};
 use masonry_core::core::{
-    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, CursorIcon, EventCtx, Layer, LayoutCtx,
-    MeasureCtx, NewWidget, NoAction, PaintCtx, PointerEvent, Properties, PropertiesMut,
+    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, CursorIcon, DragEvent, EventCtx, Layer,
+    LayoutCtx, MeasureCtx, NewWidget, NoAction, PaintCtx, PointerEvent, Properties, PropertiesMut,
     PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId,
     WidgetPod, WidgetRef, find_widget_under_pointer, pre_paint,
 };
@@ -21,6 +22,8 @@ pub(crate) type TextEventFn<S> =
     dyn FnMut(&mut S, &mut EventCtx<'_>, &mut PropertiesMut<'_>, &TextEvent);
 pub(crate) type AccessEventFn<S> =
     dyn FnMut(&mut S, &mut EventCtx<'_>, &mut PropertiesMut<'_>, &AccessEvent);
+pub(crate) type DragEventFn<S> =
+    dyn FnMut(&mut S, &mut EventCtx<'_>, &mut PropertiesMut<'_>, &DragEvent);
 pub(crate) type AnimFrameFn<S> = dyn FnMut(&mut S, &mut UpdateCtx<'_>, &mut PropertiesMut<'_>, u64);
 pub(crate) type RegisterChildrenFn<S> = dyn FnMut(&mut S, &mut RegisterCtx<'_>);
 pub(crate) type UpdateFn<S> =
@@ -45,9 +48,13 @@ pub struct ModularWidget<S> {
     accepts_pointer_interaction: bool,
     accepts_focus: bool,
     accepts_text_input: bool,
+    accepts_drop: bool,
+    is_selection_scope: bool,
+    font_size: Option<f64>,
     on_pointer_event: Option<Box<PointerEventFn<S>>>,
     on_text_event: Option<Box<TextEventFn<S>>>,
     on_access_event: Option<Box<AccessEventFn<S>>>,
+    on_drag_event: Option<Box<DragEventFn<S>>>,
     on_anim_frame: Option<Box<AnimFrameFn<S>>>,
     register_children: Option<Box<RegisterChildrenFn<S>>>,
     update: Option<Box<UpdateFn<S>>>,
@@ -75,9 +82,13 @@ impl<S> ModularWidget<S> {
             accepts_pointer_interaction: true,
             accepts_focus: false,
             accepts_text_input: false,
+            accepts_drop: false,
+            is_selection_scope: false,
+            font_size: None,
             on_pointer_event: None,
             on_text_event: None,
             on_access_event: None,
+            on_drag_event: None,
             on_anim_frame: None,
             register_children: None,
             update: None,
@@ -184,6 +195,24 @@ impl<S> ModularWidget<S> {
         self.accepts_text_input = flag;
         self
     }
+
+    /// See [`Widget::accepts_drop`]
+    pub fn accepts_drop(mut self, flag: bool) -> Self {
+        self.accepts_drop = flag;
+        self
+    }
+
+    /// See [`Widget::is_selection_scope`]
+    pub fn is_selection_scope(mut self, flag: bool) -> Self {
+        self.is_selection_scope = flag;
+        self
+    }
+
+    /// See [`Widget::font_size`]
+    pub fn font_size(mut self, font_size: Option<f64>) -> Self {
+        self.font_size = font_size;
+        self
+    }
 }
 
 /// Builder methods.
@@ -217,6 +246,15 @@ impl<S> ModularWidget<S> {
         self
     }
 
+    /// See [`Widget::on_drag_event`]
+    pub fn drag_event_fn(
+        mut self,
+        f: impl FnMut(&mut S, &mut EventCtx<'_>, &mut PropertiesMut<'_>, &DragEvent) + 'static,
+    ) -> Self {
+        self.on_drag_event = Some(Box::new(f));
+        self
+    }
+
     /// See [`Widget::on_anim_frame`]
     pub fn anim_frame_fn(
         mut self,
@@ -364,6 +402,17 @@ impl<S: 'static> Widget for ModularWidget<S> {
         }
     }
 
+    fn on_drag_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        props: &mut PropertiesMut<'_>,
+        event: &DragEvent,
+    ) {
+        if let Some(f) = self.on_drag_event.as_mut() {
+            f(&mut self.state, ctx, props, event);
+        }
+    }
+
     fn on_anim_frame(
         &mut self,
         ctx: &mut UpdateCtx<'_>,
@@ -483,6 +532,28 @@ impl<S: 'static> Widget for ModularWidget<S> {
         self.accepts_text_input
     }
 
+    fn accepts_drop(&self) -> bool {
+        self.accepts_drop
+    }
+
+    fn is_selection_scope(&self) -> bool {
+        self.is_selection_scope
+    }
+
+    fn selectable_text(&self) -> Option<&str> {
+        None
+    }
+
+    fn selectable_text_index(&self, _pos: Point) -> usize {
+        0
+    }
+
+    fn set_scoped_selection(&mut self, _selection: Option<Range<usize>>) {}
+
+    fn font_size(&self) -> Option<f64> {
+        self.font_size
+    }
+
     fn make_trace_span(&self, id: WidgetId) -> tracing::Span {
         trace_span!("ModularWidget", id = id.trace())
     }
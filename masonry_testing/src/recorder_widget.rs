@@ -11,13 +11,15 @@
 use std::any::TypeId;
 use std::cell::RefCell;
 use std::collections::VecDeque;
+use std::ops::Range;
 use std::rc::Rc;
 
 use masonry_core::accesskit::{Node, Role};
 use masonry_core::core::{
-    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, CursorIcon, EventCtx, Layer, LayoutCtx,
-    MeasureCtx, NewWidget, PaintCtx, PointerEvent, Properties, PropertiesMut, PropertiesRef,
-    QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId, WidgetRef,
+    AccessCtx, AccessEvent, ChildrenIds, ComposeCtx, CursorIcon, DragEvent, EventCtx, Layer,
+    LayoutCtx, MeasureCtx, NewWidget, PaintCtx, PointerEvent, Properties, PropertiesMut,
+    PropertiesRef, QueryCtx, RegisterCtx, TextEvent, Update, UpdateCtx, Widget, WidgetId,
+    WidgetRef,
 };
 use masonry_core::kurbo::{Axis, Point, Size};
 use masonry_core::layout::LenReq;
@@ -69,6 +71,8 @@ pub enum Record {
     TextEvent(TextEvent),
     /// Access event.
     AccessEvent(AccessEvent),
+    /// Drag event.
+    DragEvent(DragEvent),
     /// Animation frame.
     AnimFrame(u64),
     /// Register children
@@ -185,6 +189,16 @@ impl<W: Widget> Widget for Recorder<W> {
         self.child.on_access_event(ctx, props, event);
     }
 
+    fn on_drag_event(
+        &mut self,
+        ctx: &mut EventCtx<'_>,
+        props: &mut PropertiesMut<'_>,
+        event: &DragEvent,
+    ) {
+        self.recording.push(Record::DragEvent(event.clone()));
+        self.child.on_drag_event(ctx, props, event);
+    }
+
     fn on_anim_frame(
         &mut self,
         ctx: &mut UpdateCtx<'_>,
@@ -282,6 +296,30 @@ impl<W: Widget> Widget for Recorder<W> {
         self.child.accepts_text_input()
     }
 
+    fn accepts_drop(&self) -> bool {
+        self.child.accepts_drop()
+    }
+
+    fn is_selection_scope(&self) -> bool {
+        self.child.is_selection_scope()
+    }
+
+    fn selectable_text(&self) -> Option<&str> {
+        self.child.selectable_text()
+    }
+
+    fn selectable_text_index(&self, pos: Point) -> usize {
+        self.child.selectable_text_index(pos)
+    }
+
+    fn set_scoped_selection(&mut self, selection: Option<Range<usize>>) {
+        self.child.set_scoped_selection(selection);
+    }
+
+    fn font_size(&self) -> Option<f64> {
+        self.child.font_size()
+    }
+
     fn make_trace_span(&self, id: WidgetId) -> tracing::Span {
         self.child.make_trace_span(id)
     }
@@ -0,0 +1,337 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Exchanging [`ClipboardData`] with the platform clipboard.
+
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use image::{ImageFormat, RgbaImage};
+use masonry_core::core::ClipboardData;
+
+/// The clipboard shared with other apps.
+///
+/// The platform clipboard holds one kind of content at a time, so when the stored data
+/// has several formats, only the richest one is placed on it: files, then an image,
+/// then HTML with its plain text alternative, then plain text.
+/// The full data is kept here, and handed back to this app as long as the platform
+/// clipboard still holds what was placed on it.
+pub(crate) struct Clipboard {
+    /// `None` if the platform clipboard isn't available,
+    /// in which case the data is only shared within this app.
+    platform: Option<platform::Platform>,
+    /// The last data which was stored, and the content which was placed on the platform clipboard.
+    stored: Option<(ClipboardData, Option<Content>)>,
+}
+
+impl Clipboard {
+    pub(crate) fn new() -> Self {
+        Self {
+            platform: platform::Platform::new(),
+            stored: None,
+        }
+    }
+
+    /// Replaces the contents of the clipboard with `data`.
+    pub(crate) fn store(&mut self, data: ClipboardData) {
+        let content = Content::richest(&data);
+        if let Some(platform) = &mut self.platform {
+            let result = match &content {
+                Some(content) => platform.set(content),
+                None => platform.clear(),
+            };
+            if let Err(err) = result {
+                tracing::warn!("Failed to write to the clipboard: {err}");
+            }
+        }
+        self.stored = Some((data, content));
+    }
+
+    /// Returns the contents of the clipboard, in all the formats which are available.
+    pub(crate) fn read(&mut self) -> ClipboardData {
+        let Some(platform) = &mut self.platform else {
+            return self
+                .stored
+                .as_ref()
+                .map(|(data, _)| data.clone())
+                .unwrap_or_default();
+        };
+        let contents = Contents {
+            text: platform.text(),
+            html: platform.html(),
+            image: platform.image(),
+            files: platform.files(),
+        };
+        match &self.stored {
+            Some((data, Some(content))) if contents.holds(content) => data.clone(),
+            _ => contents.into_data(),
+        }
+    }
+}
+
+/// A kind of content which the platform clipboard can hold.
+#[derive(Clone, Debug, PartialEq)]
+enum Content {
+    Text(String),
+    /// An HTML fragment, and its plain text alternative.
+    Html(String, Option<String>),
+    Image(RgbaImage),
+    Files(Vec<PathBuf>),
+}
+
+impl Content {
+    /// Returns the richest content of `data`, or `None` if it is empty.
+    fn richest(data: &ClipboardData) -> Option<Self> {
+        if let Some(files) = data.files()
+            && !files.is_empty()
+        {
+            return Some(Self::Files(files));
+        }
+        if let Some(image) = data.png().and_then(decode_png) {
+            return Some(Self::Image(image));
+        }
+        if let Some(html) = data.html() {
+            return Some(Self::Html(html.into(), data.text().map(Into::into)));
+        }
+        data.text().map(|text| Self::Text(text.into()))
+    }
+}
+
+/// What the platform clipboard holds, in each of the formats it exchanges.
+#[derive(Debug)]
+struct Contents {
+    text: Option<String>,
+    html: Option<String>,
+    image: Option<RgbaImage>,
+    files: Option<Vec<PathBuf>>,
+}
+
+impl Contents {
+    /// Whether these contents are still `content`, i.e. no other app has replaced it.
+    fn holds(&self, content: &Content) -> bool {
+        match content {
+            Content::Text(text) => self.text.as_ref() == Some(text),
+            // Platforms may wrap the fragment, so the text is more reliable.
+            Content::Html(_, Some(text)) => self.text.as_ref() == Some(text),
+            Content::Html(html, None) => self.html.as_ref() == Some(html),
+            Content::Image(image) => self.image.as_ref() == Some(image),
+            Content::Files(files) => self.files.as_ref() == Some(files),
+        }
+    }
+
+    fn into_data(self) -> ClipboardData {
+        let mut data = ClipboardData::new();
+        if let Some(text) = self.text {
+            data = data.with_text(text);
+        }
+        if let Some(html) = self.html {
+            data = data.with_html(html);
+        }
+        if let Some(png) = self.image.as_ref().and_then(encode_png) {
+            data = data.with_png(png);
+        }
+        if let Some(files) = self.files {
+            data = data.with_files(files);
+        }
+        data
+    }
+}
+
+fn decode_png(png: &[u8]) -> Option<RgbaImage> {
+    match image::load_from_memory_with_format(png, ImageFormat::Png) {
+        Ok(image) => Some(image.to_rgba8()),
+        Err(err) => {
+            tracing::warn!("Failed to decode the PNG for the clipboard: {err}");
+            None
+        }
+    }
+}
+
+fn encode_png(image: &RgbaImage) -> Option<Vec<u8>> {
+    let mut png = Vec::new();
+    match image.write_to(&mut Cursor::new(&mut png), ImageFormat::Png) {
+        Ok(()) => Some(png),
+        Err(err) => {
+            tracing::warn!("Failed to encode the image from the clipboard: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(any(
+    windows,
+    target_os = "macos",
+    all(
+        unix,
+        not(any(target_os = "android", target_os = "ios", target_os = "emscripten"))
+    )
+))]
+mod platform {
+    use std::borrow::Cow;
+    use std::path::PathBuf;
+
+    use arboard::{Clipboard, Error, ImageData};
+    use image::RgbaImage;
+
+    use super::Content;
+
+    pub(super) struct Platform(Clipboard);
+
+    impl Platform {
+        pub(super) fn new() -> Option<Self> {
+            match Clipboard::new() {
+                Ok(clipboard) => Some(Self(clipboard)),
+                Err(err) => {
+                    tracing::warn!("The clipboard is only shared within this app: {err}");
+                    None
+                }
+            }
+        }
+
+        pub(super) fn set(&mut self, content: &Content) -> Result<(), Error> {
+            match content {
+                Content::Text(text) => self.0.set_text(text),
+                Content::Html(html, text) => self.0.set_html(html, text.as_ref()),
+                Content::Image(image) => self.0.set_image(ImageData {
+                    width: image.width() as usize,
+                    height: image.height() as usize,
+                    bytes: Cow::Borrowed(image.as_raw()),
+                }),
+                Content::Files(files) => self.0.set().file_list(files),
+            }
+        }
+
+        pub(super) fn clear(&mut self) -> Result<(), Error> {
+            self.0.clear()
+        }
+
+        pub(super) fn text(&mut self) -> Option<String> {
+            available(self.0.get_text())
+        }
+
+        pub(super) fn html(&mut self) -> Option<String> {
+            available(self.0.get().html())
+        }
+
+        pub(super) fn image(&mut self) -> Option<RgbaImage> {
+            let image = available(self.0.get_image())?;
+            let width = image.width.try_into().ok()?;
+            let height = image.height.try_into().ok()?;
+            RgbaImage::from_raw(width, height, image.bytes.into_owned())
+        }
+
+        pub(super) fn files(&mut self) -> Option<Vec<PathBuf>> {
+            available(self.0.get().file_list())
+        }
+    }
+
+    /// Returns the value, logging errors other than the format being unavailable.
+    fn available<T>(result: Result<T, Error>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(Error::ContentNotAvailable) => None,
+            Err(err) => {
+                tracing::warn!("Failed to read the clipboard: {err}");
+                None
+            }
+        }
+    }
+}
+
+/// The platforms which arboard doesn't support have no platform clipboard.
+#[cfg(not(any(
+    windows,
+    target_os = "macos",
+    all(
+        unix,
+        not(any(target_os = "android", target_os = "ios", target_os = "emscripten"))
+    )
+)))]
+mod platform {
+    use std::convert::Infallible;
+    use std::path::PathBuf;
+
+    use image::RgbaImage;
+
+    use super::Content;
+
+    pub(super) enum Platform {}
+
+    impl Platform {
+        pub(super) fn new() -> Option<Self> {
+            None
+        }
+
+        pub(super) fn set(&mut self, _: &Content) -> Result<(), Infallible> {
+            match *self {}
+        }
+
+        pub(super) fn clear(&mut self) -> Result<(), Infallible> {
+            match *self {}
+        }
+
+        pub(super) fn text(&mut self) -> Option<String> {
+            match *self {}
+        }
+
+        pub(super) fn html(&mut self) -> Option<String> {
+            match *self {}
+        }
+
+        pub(super) fn image(&mut self) -> Option<RgbaImage> {
+            match *self {}
+        }
+
+        pub(super) fn files(&mut self) -> Option<Vec<PathBuf>> {
+            match *self {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn richest_content_is_placed() {
+        let data = ClipboardData::from_text("text").with_html("<b>text</b>");
+        assert_eq!(
+            Content::richest(&data),
+            Some(Content::Html("<b>text</b>".into(), Some("text".into())))
+        );
+
+        let image = RgbaImage::from_raw(1, 2, vec![255, 0, 0, 255, 0, 0, 255, 128]).unwrap();
+        let data = data.with_png(encode_png(&image).unwrap());
+        assert_eq!(Content::richest(&data), Some(Content::Image(image)));
+
+        let data = data.with_files([PathBuf::from("/tmp/file")]);
+        assert_eq!(
+            Content::richest(&data),
+            Some(Content::Files(vec![PathBuf::from("/tmp/file")]))
+        );
+
+        assert_eq!(Content::richest(&ClipboardData::new()), None);
+    }
+
+    #[test]
+    fn contents_hold_placed_content() {
+        let image = RgbaImage::from_raw(1, 1, vec![1, 2, 3, 4]).unwrap();
+        let contents = Contents {
+            text: Some("text".into()),
+            html: Some("<html><b>text</b></html>".into()),
+            image: Some(image.clone()),
+            files: None,
+        };
+        assert!(contents.holds(&Content::Text("text".into())));
+        assert!(!contents.holds(&Content::Text("other".into())));
+        assert!(contents.holds(&Content::Html("<b>text</b>".into(), Some("text".into()))));
+        assert!(contents.holds(&Content::Image(image.clone())));
+        assert!(!contents.holds(&Content::Files(vec![])));
+
+        let data = contents.into_data();
+        assert_eq!(data.text(), Some("text"));
+        assert_eq!(data.html(), Some("<html><b>text</b></html>"));
+        assert_eq!(data.png().and_then(decode_png), Some(image));
+        assert_eq!(data.files(), None);
+    }
+}
@@ -1,9 +1,9 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use masonry_core::core::{Ime, ResizeDirection};
+use masonry_core::core::{Ime, ResizeDirection, WindowTheme};
 use winit::event::Ime as WinitIme;
-use winit::window::ResizeDirection as WinitResizeDirection;
+use winit::window::{ResizeDirection as WinitResizeDirection, Theme as WinitTheme};
 
 pub(crate) fn masonry_resize_direction_to_winit(dir: ResizeDirection) -> WinitResizeDirection {
     match dir {
@@ -26,3 +26,10 @@ pub(crate) fn winit_ime_to_masonry(event: WinitIme) -> Ime {
         WinitIme::Commit(text) => Ime::Commit(text),
     }
 }
+
+pub(crate) fn winit_theme_to_masonry(theme: WinitTheme) -> WindowTheme {
+    match theme {
+        WinitTheme::Light => WindowTheme::Light,
+        WinitTheme::Dark => WindowTheme::Dark,
+    }
+}
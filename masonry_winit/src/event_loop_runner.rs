@@ -7,12 +7,13 @@ use std::sync::mpsc::Sender;
 use std::sync::{Arc, mpsc};
 
 use accesskit_winit::Adapter;
-use copypasta::nop_clipboard::NopClipboardContext;
-use copypasta::{ClipboardContext, ClipboardProvider};
-use masonry_core::app::{RenderRoot, RenderRootOptions, RenderRootSignal, WindowSizePolicy};
+use masonry_core::app::{
+    RenderRoot, RenderRootOptions, RenderRootSignal, ThemeProperties, WindowSizePolicy,
+};
 use masonry_core::core::keyboard::{Key, KeyState};
 use masonry_core::core::{
-    DefaultProperties, ErasedAction, NewWidget, TextEvent, Widget, WindowEvent,
+    ClipboardData, DefaultProperties, ErasedAction, NewWidget, TextEvent, Widget, WindowEvent,
+    WindowTheme,
 };
 use masonry_core::kurbo::Affine;
 use masonry_core::peniko::Color;
@@ -31,9 +32,10 @@ use winit::window::{Window as WindowHandle, WindowAttributes, WindowId as Handle
 
 use crate::app::{
     AppDriver, DriverCtx, WgpuContext, WgpuLimits, masonry_resize_direction_to_winit,
-    winit_ime_to_masonry,
+    winit_ime_to_masonry, winit_theme_to_masonry,
 };
 use crate::app_driver::WindowId;
+use crate::clipboard::Clipboard;
 use crate::vello_util::{RenderContext, RenderSurface};
 
 /// The custom event type that we inject into winit's [`EventLoop`](winit::event_loop::EventLoop).
@@ -78,6 +80,33 @@ pub struct NewWindow {
     pub root_widget: NewWidget<dyn Widget>,
     /// The base color of the window.
     pub base_color: Color,
+    /// If set, the window's default properties and base color follow the OS light/dark mode.
+    ///
+    /// This takes precedence over [`base_color`](Self::base_color) once the OS theme is known.
+    pub follow_theme: Option<FollowTheme>,
+}
+
+/// The default properties and base colors a window uses in light and dark mode.
+///
+/// See [`NewWindow::with_follow_theme`].
+#[derive(Clone, Debug)]
+pub struct FollowTheme {
+    /// The default properties for each mode.
+    pub properties: ThemeProperties,
+    /// The base color of the window in light mode.
+    pub light_base_color: Color,
+    /// The base color of the window in dark mode.
+    pub dark_base_color: Color,
+}
+
+impl FollowTheme {
+    /// Returns the base color for the given `theme`.
+    pub fn base_color(&self, theme: WindowTheme) -> Color {
+        match theme {
+            WindowTheme::Light => self.light_base_color,
+            WindowTheme::Dark => self.dark_base_color,
+        }
+    }
 }
 
 impl NewWindow {
@@ -105,6 +134,7 @@ impl NewWindow {
             attributes,
             root_widget,
             base_color: Color::BLACK,
+            follow_theme: None,
         }
     }
 
@@ -120,6 +150,15 @@ impl NewWindow {
         self.base_color = base_color;
         self
     }
+
+    /// Makes the new window follow the OS light/dark mode.
+    ///
+    /// Whenever the OS theme changes, the window's default properties and base color
+    /// are replaced with the ones from `follow_theme`.
+    pub fn with_follow_theme(mut self, follow_theme: FollowTheme) -> Self {
+        self.follow_theme = Some(follow_theme);
+        self
+    }
 }
 
 /// Per-Window state
@@ -130,6 +169,7 @@ pub struct Window {
     event_reducer: WindowEventReducer,
     pub(crate) render_root: RenderRoot,
     pub(crate) base_color: Color,
+    follow_theme: Option<FollowTheme>,
 }
 
 impl Window {
@@ -164,6 +204,31 @@ impl Window {
                 },
             ),
             base_color,
+            follow_theme: None,
+        }
+    }
+
+    /// Makes this window follow the OS light/dark mode, or stops following it if `None`.
+    ///
+    /// If the OS theme is already known, the matching default properties and base color
+    /// are applied immediately.
+    pub fn set_follow_theme(&mut self, follow_theme: Option<FollowTheme>) {
+        self.render_root
+            .set_theme_properties(follow_theme.as_ref().map(|f| f.properties.clone()));
+        if let Some(follow_theme) = &follow_theme
+            && let Some(theme) = self.render_root.window_theme()
+        {
+            self.base_color = follow_theme.base_color(theme);
+        }
+        self.follow_theme = follow_theme;
+    }
+
+    fn handle_theme_changed(&mut self, theme: WindowTheme) {
+        self.render_root
+            .handle_window_event(WindowEvent::ThemeChanged(theme));
+        if let Some(follow_theme) = &self.follow_theme {
+            self.base_color = follow_theme.base_color(theme);
+            self.handle.request_redraw();
         }
     }
 
@@ -207,7 +272,7 @@ pub struct MasonryState<'a> {
     surfaces: HashMap<HandleId, RenderSurface<'a>>,
     windows: HashMap<HandleId, Window>,
 
-    clipboard_cx: Box<dyn ClipboardProvider>,
+    clipboard: Clipboard,
 
     // Is `Some` if the most recently displayed frame was an animation frame.
     last_anim: Option<Instant>,
@@ -367,16 +432,6 @@ impl MasonryState<'_> {
 
         let (signal_sender, signal_receiver) = mpsc::channel::<(WindowId, RenderRootSignal)>();
 
-        let clipboard_cx =
-            ClipboardContext::new().map(|cx| -> Box<dyn ClipboardProvider> { Box::new(cx) });
-        let clipboard_cx = if cfg!(target_os = "linux") {
-            // If we're running on Linux, we might fail to get the clipboard context because
-            // we're using Wayland, so we fall back to NopClipboardContext to be safe.
-            clipboard_cx.unwrap_or_else(|_| Box::new(NopClipboardContext))
-        } else {
-            clipboard_cx.unwrap()
-        };
-
         MasonryState {
             is_suspended: true,
             render_cx,
@@ -392,7 +447,7 @@ impl MasonryState<'_> {
             windows: HashMap::new(),
             surfaces: HashMap::new(),
 
-            clipboard_cx,
+            clipboard: Clipboard::new(),
 
             signal_sender,
             default_properties: Arc::new(default_properties),
@@ -533,7 +588,8 @@ impl MasonryState<'_> {
         #[cfg(not(target_os = "ios"))]
         let size = handle.inner_size();
 
-        let window = Window::new(
+        let initial_theme = handle.theme();
+        let mut window = Window::new(
             new_window.id,
             handle,
             adapter,
@@ -544,6 +600,12 @@ impl MasonryState<'_> {
             size,
             scale_factor,
         );
+        if let Some(theme) = initial_theme {
+            window
+                .render_root
+                .handle_window_event(WindowEvent::ThemeChanged(winit_theme_to_masonry(theme)));
+        }
+        window.set_follow_theme(new_window.follow_theme);
 
         tracing::debug!(window_id = window.id.trace(), handle=?handle_id, "creating window");
         self.window_id_to_handle_id.insert(window.id, handle_id);
@@ -891,11 +953,10 @@ impl MasonryState<'_> {
                         && action_mod
                         && k.state == KeyState::Down
                     {
+                        let text = self.clipboard.read().text().unwrap_or_default().to_string();
                         window
                             .render_root
-                            .handle_text_event(TextEvent::ClipboardPaste(
-                                self.clipboard_cx.get_contents().unwrap(),
-                            ));
+                            .handle_text_event(TextEvent::ClipboardPaste(text));
                     } else {
                         window.render_root.handle_text_event(TextEvent::Keyboard(k));
                     }
@@ -927,6 +988,9 @@ impl MasonryState<'_> {
                 let ime = winit_ime_to_masonry(ime);
                 window.render_root.handle_text_event(TextEvent::Ime(ime));
             }
+            WinitWindowEvent::ThemeChanged(theme) => {
+                window.handle_theme_changed(winit_theme_to_masonry(theme));
+            }
             WinitWindowEvent::Focused(new_focus) => {
                 window
                     .render_root
@@ -1068,7 +1132,14 @@ impl MasonryState<'_> {
                     handle.set_ime_cursor_area(position, size);
                 }
                 RenderRootSignal::ClipboardStore(text) => {
-                    self.clipboard_cx.set_contents(text).unwrap();
+                    self.clipboard.store(ClipboardData::from_text(text));
+                }
+                RenderRootSignal::ClipboardStoreData(data) => {
+                    self.clipboard.store(data);
+                }
+                RenderRootSignal::ClipboardRead(widget_id) => {
+                    let data = self.clipboard.read();
+                    window.render_root.handle_clipboard_data(widget_id, data);
                 }
                 RenderRootSignal::RequestRedraw => {
                     need_redraw.insert(*handle_id);
@@ -87,6 +87,7 @@
 #![expect(missing_debug_implementations, reason = "Deferred: Noisy")]
 
 mod app_driver;
+mod clipboard;
 mod convert_winit_event;
 mod event_loop_runner;
 mod vello_util;
@@ -97,11 +98,11 @@ pub use winit;
 pub mod app {
     pub use super::app_driver::{AppDriver, DriverCtx, WgpuContext, WgpuLimits, WindowId};
     pub use super::event_loop_runner::{
-        EventLoop, EventLoopBuilder, EventLoopProxy, MasonryState, MasonryUserEvent, NewWindow,
-        Window, run, run_with,
+        EventLoop, EventLoopBuilder, EventLoopProxy, FollowTheme, MasonryState, MasonryUserEvent,
+        NewWindow, Window, run, run_with,
     };
 
     pub(crate) use super::convert_winit_event::{
-        masonry_resize_direction_to_winit, winit_ime_to_masonry,
+        masonry_resize_direction_to_winit, winit_ime_to_masonry, winit_theme_to_masonry,
     };
 }
@@ -12,7 +12,7 @@ use xilem::view::{
 use xilem::{FontWeight, TextAlign};
 
 use crate::actions::Navigation;
-use crate::{Avatars, status_html_to_plaintext};
+use crate::{Avatars, status_html_to_rich_text};
 
 mod timeline;
 pub(crate) use timeline::Timeline;
@@ -34,6 +34,7 @@ fn base_status<State: ViewArgument>(
     // TODO: This really should be Arced or something.
     let status_clone: Status = status.clone();
     let acct_clone = status.account.acct.clone();
+    let mentions = status.mentions.clone();
     // TODO: In theory, it's possible to reblog a reblog; it's not clear what happens in this case.
     debug_assert!(status.reblog.is_none(), "`base_status` can't show reblogs.");
     // We return a child list.
@@ -63,7 +64,18 @@ fn base_status<State: ViewArgument>(
             inline_prose(status.created_at.format("%Y-%m-%d %H:%M:%S").to_string())
                 .text_alignment(TextAlign::End),
         )),
-        prose(status_html_to_plaintext(status.content.as_str())).flex(CrossAxisAlignment::Start),
+        prose(status_html_to_rich_text(status.content.as_str()))
+            .on_link(move |_: Arg<'_, State>, target| {
+                // Mentions open the timeline of the mentioned user.
+                match mentions.iter().find(|mention| *mention.url == *target) {
+                    Some(mention) => Navigation::LoadUser(mention.acct.clone()),
+                    None => {
+                        tracing::info!(%target, "Opening external links isn't supported yet.");
+                        Navigation::None
+                    }
+                }
+            })
+            .flex(CrossAxisAlignment::Start),
         status
             .media_attachments
             .iter()
@@ -8,7 +8,9 @@ use xilem::masonry::layout::AsUnit;
 use xilem::masonry::util::debug_panic;
 use xilem::palette::css;
 use xilem::style::{Padding, Style};
-use xilem::view::{CrossAxisAlignment, FlexExt, flex_col, flex_row, label, portal, sized_box};
+use xilem::view::{
+    CrossAxisAlignment, FlexExt, flex_col, flex_row, label, portal, selection_scope, sized_box,
+};
 
 use crate::Placehero;
 use crate::actions::Navigation;
@@ -53,7 +55,8 @@ pub(crate) fn thread(
         descendant_views.push(thread_ancestor(descendant));
     }
 
-    portal(
+    // The selection scope lets the text of the whole thread be selected and copied at once.
+    portal(selection_scope(
         flex_col((
             ancestor_views,
             base_status(root_status),
@@ -65,7 +68,7 @@ pub(crate) fn thread(
             right: 20.,
             ..Padding::all(5.0)
         }),
-    )
+    ))
 }
 
 /// The component for a single post in a thread.
@@ -1,6 +1,8 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use xilem::masonry::core::{RichText, SpanStyle};
+
 #[derive(Debug)]
 enum TagCloseBehaviour {
     /// Nothing needs to happen when the span is closed (i.e. we didn't do anything for it?)
@@ -11,27 +13,30 @@ enum TagCloseBehaviour {
     Ellipsis,
     /// A paragraph.
     Paragraph,
+    /// A link to `href`, whose text starts at the byte index `start`.
+    Link { start: usize, href: String },
 }
 
-/// Convert sanitised HTML into a displayable string.
+/// Convert sanitised HTML into displayable rich text.
 ///
 /// See <https://docs.joinmastodon.org/spec/activitypub/#sanitization> for the tags we have to support.
 ///
 /// Note:
 /// 1) We convert HTML entities to their regular value (hopefully?)
-/// 2) We only handle the `p`, `br`, `span.invisible`, `span.ellipsis` cases
-/// 3) We don't handle `microformat` at all.
+/// 2) We only handle the `p`, `br`, `a`, `span.invisible`, `span.ellipsis` cases
+/// 3) We don't handle `microformat` at all; links become plain links.
 ///
 /// For certain error cases, this [`warn`](tracing::warn)s (or `error`s).
 /// For additional context, the app can be run with the environment variable
 /// `RUST_LOG` set to `"info,placehero::html_content=trace"`.
 // TODO: We know this code is not great (and probably way too imperative!)
 // We're deferring refactoring this until we want to handle more attributes.
-pub(crate) fn status_html_to_plaintext(content: &str) -> String {
+pub(crate) fn status_html_to_rich_text(content: &str) -> RichText {
     let _span = tracing::info_span!("handle_content_html").entered();
     let tokeniser = html5gum::Tokenizer::new(content);
     // The resulting string will *always*(?) be shorter than the initial string
     let mut result = String::with_capacity(content.len());
+    let mut links = Vec::<(std::ops::Range<usize>, String)>::new();
 
     let mut stack = Vec::<TagCloseBehaviour>::new();
     let mut emit = true;
@@ -118,9 +123,21 @@ pub(crate) fn status_html_to_plaintext(content: &str) -> String {
                     }
                 }
                 b"a" => {
-                    tracing::trace!("Encountered intentionally unhandled <a> tag.");
+                    let href = start_tag
+                        .attributes
+                        .remove(b"href".as_slice())
+                        .map(|href| String::from_utf8_lossy(&href.value.0).into_owned());
                     if !start_tag.self_closing {
-                        stack.push(TagCloseBehaviour::None);
+                        match href {
+                            Some(href) => stack.push(TagCloseBehaviour::Link {
+                                start: result.len(),
+                                href,
+                            }),
+                            None => {
+                                tracing::warn!("Got <a> tag without a href.");
+                                stack.push(TagCloseBehaviour::None);
+                            }
+                        }
                     }
                 }
                 _ => {
@@ -157,6 +174,9 @@ pub(crate) fn status_html_to_plaintext(content: &str) -> String {
                         result.push_str("...");
                     }
                     TagCloseBehaviour::Paragraph => result.push_str("\n\n"),
+                    TagCloseBehaviour::Link { start, href } => {
+                        links.push((start..result.len(), href));
+                    }
                 },
                 None => {
                     tracing::error!(
@@ -207,5 +227,10 @@ pub(crate) fn status_html_to_plaintext(content: &str) -> String {
     // Clear trailing whitespace.
     let trimmed_len = result.trim_end().len();
     result.truncate(trimmed_len);
-    result
+    links
+        .into_iter()
+        .fold(RichText::new(result), |text, (range, href)| {
+            let range = range.start.min(trimmed_len)..range.end.min(trimmed_len);
+            text.with_span(range, SpanStyle::Link(href.into()))
+        })
 }
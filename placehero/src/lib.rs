@@ -43,7 +43,7 @@ mod html_content;
 mod login_flow;
 
 pub(crate) use avatars::Avatars;
-pub(crate) use html_content::status_html_to_plaintext;
+pub(crate) use html_content::status_html_to_rich_text;
 
 use crate::actions::Navigation;
 use crate::components::{Timeline, thread};
@@ -4,12 +4,12 @@
 //! Modularizing state can be done with `lens` which allows using modular components.
 
 use winit::error::EventLoopError;
-use xilem::core::lens;
+use xilem::core::{Lenses, lens};
 use xilem::view::{MainAxisAlignment, flex_col, flex_row, label, text_button};
 use xilem::{EventLoop, WidgetView, WindowOptions, Xilem};
 use xilem_core::Edit;
 
-#[derive(Default)]
+#[derive(Default, Lenses)]
 struct AppState {
     modularized_count: i32,
     global_count: i32,
@@ -26,9 +26,7 @@ fn modular_counter(count: &mut i32) -> impl WidgetView<Edit<i32>> + use<> {
 
 fn app_logic(state: &mut AppState) -> impl WidgetView<Edit<AppState>> + use<> {
     flex_row((
-        lens(modular_counter, |state: &mut AppState, ()| {
-            &mut state.modularized_count
-        }),
+        lens(modular_counter, AppState::modularized_count),
         text_button(
             format!("clicked {} times", state.global_count),
             |state: &mut AppState| state.global_count += 1,
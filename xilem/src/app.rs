@@ -11,7 +11,8 @@ use masonry_winit::app::{EventLoopBuilder, MasonryUserEvent, NewWindow, WindowId
 use tokio::runtime::Runtime as TokioRuntime;
 use winit::error::EventLoopError;
 
-use crate::core::{Edit, map_state};
+use crate::core::{Edit, RebuildSummary, map_state};
+use crate::driver::RebuildReport;
 use crate::window_options::WindowCallbacks;
 use crate::{MasonryDriver, WidgetView, WindowOptions, WindowView};
 
@@ -26,6 +27,7 @@ pub struct Xilem<State, Logic> {
     default_properties: Option<DefaultProperties>,
     // Font data to include in loading.
     fonts: Vec<Blob<u8>>,
+    rebuild_report: Option<RebuildReport>,
 }
 
 /// State type used by [`Xilem::new_simple`].
@@ -147,6 +149,7 @@ where
             runtime,
             default_properties: None,
             fonts: Vec::new(),
+            rebuild_report: None,
         }
     }
 
@@ -165,6 +168,31 @@ where
         self
     }
 
+    /// Records how often each type of view is rebuilt, and how long that takes.
+    ///
+    /// After each rebuild of a window's view tree, `report` is called with the
+    /// rebuilds which were recorded in that window.
+    /// This is meant to help find which views are costly to rebuild, and would benefit from
+    /// [`memoize`](crate::core::memoize).
+    /// See [`RebuildProfiler`](crate::core::RebuildProfiler) for which views are recorded.
+    ///
+    /// As this has some overhead, it should usually only be enabled while investigating performance.
+    ///
+    /// ```no_run
+    /// # use xilem::{Xilem, WindowOptions, view::label};
+    /// # let app = Xilem::new_simple((), |_: &mut ()| label("Hello"), WindowOptions::new("Profiling"));
+    /// let app = app.with_rebuild_profiling(|_window_id, summary| {
+    ///     tracing::info!("View rebuilds:\n{summary}");
+    /// });
+    /// ```
+    pub fn with_rebuild_profiling(
+        mut self,
+        report: impl FnMut(WindowId, &RebuildSummary) + 'static,
+    ) -> Self {
+        self.rebuild_report = Some(Box::new(report));
+        self
+    }
+
     /// Run app with custom window attributes.
     pub fn run_in(mut self, mut event_loop: EventLoopBuilder) -> Result<(), EventLoopError> {
         let event_loop = event_loop.build()?;
@@ -186,6 +214,13 @@ where
         self,
         proxy: impl Fn(MasonryUserEvent) -> Result<(), MasonryUserEvent> + Send + Sync + 'static,
     ) -> (MasonryDriver<State, Logic>, Vec<NewWindow>) {
-        MasonryDriver::new(self.state, self.logic, proxy, self.runtime, self.fonts)
+        MasonryDriver::new(
+            self.state,
+            self.logic,
+            proxy,
+            self.runtime,
+            self.fonts,
+            self.rebuild_report,
+        )
     }
 }
@@ -4,7 +4,9 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::time::Instant;
 
+use masonry::app::RenderRoot;
 use masonry::core::{ErasedAction, WidgetId};
 use masonry::peniko::Blob;
 use masonry_winit::app::{
@@ -12,11 +14,12 @@ use masonry_winit::app::{
 };
 
 use crate::core::{
-    DynMessage, MessageCtx, MessageResult, ProxyError, RawProxy, SendMessage, View, ViewId,
-    ViewPathTracker,
+    DeliveryFailure, DeliveryFailureHandler, DynMessage, MessageCtx, MessageResult, ProxyError,
+    ProxyMessageId, RawProxy, Rebuild, RebuildProfiler, RebuildSummary, SendMessage, View, ViewId,
+    ViewPathTracker, profile_rebuild,
 };
 use crate::window_view::{WindowView, WindowViewState};
-use crate::{AppState, ViewCtx};
+use crate::{AppState, MasonryRoot, ViewCtx};
 
 /// The composition root of Xilem's Masonry backend.
 ///
@@ -30,8 +33,12 @@ pub struct MasonryDriver<State: 'static, Logic> {
     runtime: Arc<tokio::runtime::Runtime>,
     // Fonts which will be registered on startup.
     fonts: Vec<Blob<u8>>,
+    rebuild_report: Option<RebuildReport>,
 }
 
+/// The callback given to [`Xilem::with_rebuild_profiling`](crate::Xilem::with_rebuild_profiling).
+pub(crate) type RebuildReport = Box<dyn FnMut(WindowId, &RebuildSummary)>;
+
 struct Window<State: 'static> {
     view: WindowView<State>,
     view_ctx: ViewCtx,
@@ -52,6 +59,7 @@ where
         event_sink: impl Fn(MasonryUserEvent) -> Result<(), MasonryUserEvent> + Send + Sync + 'static,
         runtime: Arc<tokio::runtime::Runtime>,
         fonts: Vec<Blob<u8>>,
+        rebuild_report: Option<RebuildReport>,
     ) -> (Self, Vec<NewWindow>) {
         let mut driver = Self {
             state,
@@ -60,6 +68,7 @@ where
             proxy: Arc::new(MasonryProxy(Box::new(event_sink))),
             runtime,
             fonts,
+            rebuild_report,
         };
         let windows: Vec<_> = (driver.logic)(&mut driver.state)
             .map(|view| driver.build_window(view))
@@ -70,20 +79,57 @@ where
 
 /// The action which should be used for async events.
 pub fn async_action(path: Arc<[ViewId]>, message: SendMessage) -> ErasedAction {
-    Box::<MessagePackage>::new((path, message))
+    Box::new(MessagePackage {
+        path,
+        message,
+        on_failure: None,
+    })
 }
 
 /// The type used to send a message for async events.
-type MessagePackage = (Arc<[ViewId]>, SendMessage);
+struct MessagePackage {
+    path: Arc<[ViewId]>,
+    message: SendMessage,
+    /// Where to report the message not being delivered, if anywhere.
+    on_failure: Option<(ProxyMessageId, DeliveryFailureHandler)>,
+}
+
+impl Debug for MessagePackage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessagePackage")
+            .field("path", &self.path)
+            .field("message", &self.message)
+            .field("id", &self.on_failure.as_ref().map(|(id, _)| id))
+            .finish()
+    }
+}
+
+/// Passes the rebuilds recorded in a window since the last call to `rebuild_report`, if profiling is enabled.
+fn report_rebuilds(
+    rebuild_report: &mut Option<RebuildReport>,
+    window_id: WindowId,
+    view_ctx: &mut ViewCtx,
+) {
+    if let Some(report) = rebuild_report
+        && let Some(profiler) = view_ctx.environment().rebuild_profiler()
+    {
+        report(window_id, &profiler.take_summary());
+    }
+}
+
+/// Tells the sender of the message sent to `path` that it couldn't be delivered, if they asked to know.
+fn report_failure(
+    path: Arc<[ViewId]>,
+    on_failure: Option<(ProxyMessageId, DeliveryFailureHandler)>,
+) {
+    if let Some((id, on_failure)) = on_failure {
+        on_failure(DeliveryFailure { id, path });
+    }
+}
 
 impl MasonryProxy {
-    fn send_message(
-        &self,
-        window_id: WindowId,
-        path: Arc<[ViewId]>,
-        message: SendMessage,
-    ) -> Result<(), ProxyError> {
-        let user_event = MasonryUserEvent::AsyncAction(window_id, async_action(path, message));
+    fn send_message(&self, window_id: WindowId, package: MessagePackage) -> Result<(), ProxyError> {
+        let user_event = MasonryUserEvent::AsyncAction(window_id, Box::new(package));
         match (self.0)(user_event) {
             Ok(()) => Ok(()),
             Err(err) => {
@@ -93,7 +139,7 @@ impl MasonryProxy {
                     )
                 };
                 Err(ProxyError::DriverFinished(
-                    res.downcast::<MessagePackage>().unwrap().1,
+                    res.downcast::<MessagePackage>().unwrap().message,
                 ))
             }
         }
@@ -115,7 +161,27 @@ struct WindowProxy(WindowId, Arc<MasonryProxy>);
 
 impl RawProxy for WindowProxy {
     fn send_message(&self, path: Arc<[ViewId]>, message: SendMessage) -> Result<(), ProxyError> {
-        self.1.send_message(self.0, path, message)
+        let package = MessagePackage {
+            path,
+            message,
+            on_failure: None,
+        };
+        self.1.send_message(self.0, package)
+    }
+
+    fn send_tracked_message(
+        &self,
+        path: Arc<[ViewId]>,
+        message: SendMessage,
+        id: ProxyMessageId,
+        on_failure: DeliveryFailureHandler,
+    ) -> Result<(), ProxyError> {
+        let package = MessagePackage {
+            path,
+            message,
+            on_failure: Some((id, on_failure)),
+        };
+        self.1.send_message(self.0, package)
     }
 
     fn dyn_debug(&self) -> &dyn Debug {
@@ -134,6 +200,12 @@ where
             Arc::new(WindowProxy(window_view.id, self.proxy.clone())),
             self.runtime.clone(),
         );
+        if self.rebuild_report.is_some() {
+            let start = Instant::now();
+            view_ctx
+                .environment()
+                .set_rebuild_profiler(Some(RebuildProfiler::new(move || start.elapsed())));
+        }
         let (new_window, view_state) = window_view.build(&mut view_ctx, &mut self.state);
         self.windows.insert(
             window_view.id,
@@ -182,13 +254,16 @@ where
                     view_ctx,
                     view_state,
                 }) => {
-                    next_view.rebuild(
-                        view,
-                        view_state,
-                        view_ctx,
-                        driver_ctx.window(next_view.id),
-                        &mut self.state,
-                    );
+                    profile_rebuild::<WindowView<State>, _, _>(view_ctx, |view_ctx| {
+                        next_view.rebuild(
+                            view,
+                            view_state,
+                            view_ctx,
+                            driver_ctx.window(next_view.id),
+                            &mut self.state,
+                        );
+                    });
+                    report_rebuilds(&mut self.rebuild_report, next_view.id, view_ctx);
                     *view = next_view;
                 }
                 None => self.create_window(driver_ctx, next_view),
@@ -220,6 +295,25 @@ where
         id_path: Vec<ViewId>,
         message: DynMessage,
     ) -> MessageResult<()> {
+        let (res, _message) = self.route_message(
+            window_id,
+            masonry_ctx.render_root(window_id),
+            id_path,
+            message,
+        );
+        res
+    }
+
+    /// Routes `message` to the view at `id_path` in the given window.
+    ///
+    /// Also returns the message if no view took it, which means that there is no view at `id_path` anymore.
+    fn route_message(
+        &mut self,
+        window_id: WindowId,
+        render_root: &mut RenderRoot,
+        id_path: Vec<ViewId>,
+        message: DynMessage,
+    ) -> (MessageResult<()>, Option<DynMessage>) {
         let Some(window) = self.windows.get_mut(&window_id) else {
             unreachable!("Already checked");
         };
@@ -229,28 +323,93 @@ where
             id_path,
             message,
         );
-        let res = window.view.message(
+        let res = window.view.masonry_root.message(
             &mut window.view_state,
             &mut message_context,
-            masonry_ctx.window(window_id),
+            render_root,
             &mut self.state,
         );
-        // TODO: Handle `_message` somehow?
-        let (env, _id_path, _message) = message_context.finish();
+        let (env, _id_path, message) = message_context.finish();
         *window.view_ctx.environment() = env;
 
-        res
+        (res, message)
+    }
+
+    /// Routes a message sent through a [`MessageProxy`](crate::core::MessageProxy) in the given window.
+    ///
+    /// If the window is gone (`render_root` is `None`), or the message doesn't reach a view,
+    /// this is reported to the sender.
+    fn route_async_message(
+        &mut self,
+        window_id: WindowId,
+        render_root: Option<&mut RenderRoot>,
+        package: MessagePackage,
+    ) -> MessageResult<()> {
+        let MessagePackage {
+            path,
+            message,
+            on_failure,
+        } = package;
+        let Some(render_root) = render_root else {
+            // The window, and so the view, has gone away.
+            report_failure(path, on_failure);
+            return MessageResult::Nop;
+        };
+
+        let (message_result, undelivered) =
+            self.route_message(window_id, render_root, Vec::from(&*path), message.into());
+        if undelivered.is_some() {
+            tracing::info!("Message to {path:?} wasn't delivered");
+            report_failure(path, on_failure);
+        }
+        message_result
+    }
+
+    /// Rebuilds the root widget of a window against the same view, e.g. after a view requested it.
+    fn rebuild_root(&mut self, window_id: WindowId, render_root: &mut RenderRoot) {
+        let window = self.windows.get_mut(&window_id).unwrap();
+        let masonry_root = &window.view.masonry_root;
+        profile_rebuild::<MasonryRoot<State>, _, _>(&mut window.view_ctx, |view_ctx| {
+            masonry_root.rebuild(
+                masonry_root,
+                &mut window.view_state,
+                view_ctx,
+                render_root,
+                &mut self.state,
+            );
+        });
+        report_rebuilds(&mut self.rebuild_report, window_id, &mut window.view_ctx);
     }
 
     fn handle_message_result(
         &mut self,
         window_id: WindowId,
         masonry_ctx: &mut DriverCtx<'_, '_>,
-        message_result: MessageResult<()>,
+        mut message_result: MessageResult<()>,
     ) {
+        // Let the views reading any resources which changed know about it, so
+        // that the memoizing views above them are rebuilt.
         let Some(window) = self.windows.get_mut(&window_id) else {
             unreachable!("Already checked");
         };
+        let queued_rebuilds = window.view_ctx.environment().take_queued_rebuilds();
+        for path in queued_rebuilds {
+            let result = self.dispatch_message(
+                window_id,
+                masonry_ctx,
+                path.to_vec(),
+                DynMessage::new(Rebuild),
+            );
+            if matches!(result, MessageResult::RequestRebuild)
+                && !matches!(message_result, MessageResult::Action(()))
+            {
+                message_result = MessageResult::RequestRebuild;
+            }
+        }
+
+        if !self.windows.contains_key(&window_id) {
+            unreachable!("Already checked");
+        }
 
         match message_result {
             // The semantics here haven't exactly been worked out.
@@ -262,13 +421,7 @@ where
                 self.run_logic(masonry_ctx);
             }
             MessageResult::RequestRebuild => {
-                window.view.masonry_root.rebuild(
-                    &window.view.masonry_root,
-                    &mut window.view_state,
-                    &mut window.view_ctx,
-                    masonry_ctx.render_root(window_id),
-                    &mut self.state,
-                );
+                self.rebuild_root(window_id, masonry_ctx.render_root(window_id));
             }
             MessageResult::Nop => {}
             MessageResult::Stale => {
@@ -319,20 +472,19 @@ where
         masonry_ctx: &mut DriverCtx<'_, '_>,
         action: ErasedAction,
     ) {
-        if self.windows.get_mut(&window_id).is_none() {
+        let package = *action.downcast::<MessagePackage>().unwrap();
+        if !self.windows.contains_key(&window_id) {
             tracing::warn!(
                 window_id = window_id.trace(),
                 "called on_async_action for unknown window"
             );
+            self.route_async_message(window_id, None, package);
             return;
         };
 
         // Dispatch this message using the path it contains.
-        let (id_path, message) = *action.downcast::<MessagePackage>().unwrap();
-        let id_path = Vec::from(&*id_path);
-        let message = message.into();
-
-        let message_result = self.dispatch_message(window_id, masonry_ctx, id_path, message);
+        let render_root = masonry_ctx.render_root(window_id);
+        let message_result = self.route_async_message(window_id, Some(render_root), package);
         self.handle_message_result(window_id, masonry_ctx, message_result);
     }
 
@@ -369,3 +521,232 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::iter::Once;
+    use std::sync::Mutex;
+
+    use masonry::app::{RenderRootOptions, WindowSizePolicy};
+    use masonry::dpi::PhysicalSize;
+    use masonry::palette;
+    use masonry::theme::{default_property_set, theme_properties};
+
+    use super::*;
+    use crate::WidgetView;
+    use crate::core::{Edit, MessageProxy, fork};
+    use crate::view::{label, task_raw};
+    use crate::{FollowTheme, window};
+
+    type Proxy = Arc<Mutex<Option<MessageProxy<u32>>>>;
+    type TestDriver = MasonryDriver<TestState, fn(&mut TestState) -> Once<WindowView<TestState>>>;
+
+    struct TestState {
+        window_id: WindowId,
+        show_task: bool,
+        received: Vec<u32>,
+        /// The proxy given to the task, if it has been built.
+        proxy: Proxy,
+    }
+
+    impl AppState for TestState {
+        fn keep_running(&self) -> bool {
+            true
+        }
+    }
+
+    fn app_view(state: &mut TestState) -> impl WidgetView<Edit<TestState>> + use<> {
+        let proxy = state.proxy.clone();
+        fork(
+            label("Test"),
+            state.show_task.then(|| {
+                task_raw(
+                    move |task_proxy: MessageProxy<u32>, _| {
+                        *proxy.lock().unwrap() = Some(task_proxy);
+                        async {}
+                    },
+                    |state: &mut TestState, message| state.received.push(message),
+                )
+            }),
+        )
+    }
+
+    fn logic(state: &mut TestState) -> Once<WindowView<TestState>> {
+        std::iter::once(window(state.window_id, "Test", app_view(state)))
+    }
+
+    /// A driver with a single window, and the packages it sent through its proxy.
+    fn test_driver(
+        window_id: WindowId,
+        rebuild_report: Option<RebuildReport>,
+    ) -> (TestDriver, RenderRoot, Arc<Mutex<Vec<MessagePackage>>>) {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let event_sink = {
+            let sent = sent.clone();
+            move |event| {
+                let MasonryUserEvent::AsyncAction(_, action) = event else {
+                    unreachable!()
+                };
+                sent.lock()
+                    .unwrap()
+                    .push(*action.downcast::<MessagePackage>().unwrap());
+                Ok(())
+            }
+        };
+        let state = TestState {
+            window_id,
+            show_task: true,
+            received: Vec::new(),
+            proxy: Proxy::default(),
+        };
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let (driver, mut windows) = MasonryDriver::new(
+            state,
+            logic as _,
+            event_sink,
+            Arc::new(runtime),
+            vec![],
+            rebuild_report,
+        );
+        let render_root = RenderRoot::new(
+            windows.remove(0).root_widget,
+            |_| {},
+            RenderRootOptions {
+                default_properties: Arc::new(default_property_set()),
+                use_system_fonts: false,
+                size_policy: WindowSizePolicy::User,
+                size: PhysicalSize::new(100, 100),
+                scale_factor: 1.0,
+                test_font: None,
+            },
+        );
+        (driver, render_root, sent)
+    }
+
+    #[test]
+    fn undelivered_messages_are_reported() {
+        let window_id = WindowId::next();
+        let (mut driver, mut render_root, sent) = test_driver(window_id, None);
+        let failures = Arc::new(Mutex::new(Vec::new()));
+        let proxy = driver.state.proxy.lock().unwrap().take().unwrap();
+        let proxy = proxy.on_delivery_failure({
+            let failures = failures.clone();
+            move |failure: DeliveryFailure| failures.lock().unwrap().push(failure.id)
+        });
+
+        // A message to a view which is still there is delivered.
+        proxy.send(1).unwrap();
+        let package = sent.lock().unwrap().remove(0);
+        let result = driver.route_async_message(window_id, Some(&mut render_root), package);
+        assert!(matches!(result, MessageResult::Action(())));
+        assert_eq!(driver.state.received, [1]);
+        assert!(failures.lock().unwrap().is_empty());
+
+        // Once the task is torn down, its messages can't be routed.
+        driver.state.show_task = false;
+        let next_view = (driver.logic)(&mut driver.state).next().unwrap();
+        let window = driver.windows.get_mut(&window_id).unwrap();
+        next_view.masonry_root.rebuild(
+            &window.view.masonry_root,
+            &mut window.view_state,
+            &mut window.view_ctx,
+            &mut render_root,
+            &mut driver.state,
+        );
+        window.view = next_view;
+
+        let torn_down = proxy.send(2).unwrap();
+        let package = sent.lock().unwrap().remove(0);
+        driver.route_async_message(window_id, Some(&mut render_root), package);
+        assert_eq!(driver.state.received, [1]);
+        assert_eq!(*failures.lock().unwrap(), [torn_down]);
+
+        // The same goes for messages to windows which have been closed.
+        let closed = proxy.send(3).unwrap();
+        let package = sent.lock().unwrap().remove(0);
+        driver.route_async_message(window_id, None, package);
+        assert_eq!(*failures.lock().unwrap(), [torn_down, closed]);
+    }
+
+    #[test]
+    fn windows_can_follow_the_os_theme() {
+        let follow_theme = FollowTheme {
+            properties: theme_properties(),
+            light_base_color: palette::css::WHITE,
+            dark_base_color: palette::css::BLACK,
+        };
+        let state = TestState {
+            window_id: WindowId::next(),
+            show_task: false,
+            received: Vec::new(),
+            proxy: Proxy::default(),
+        };
+        let logic = {
+            let follow_theme = follow_theme.clone();
+            move |state: &mut TestState| {
+                let follow_theme = follow_theme.clone();
+                std::iter::once(
+                    window(state.window_id, "Test", app_view(state))
+                        .with_options(|options| options.with_initial_follow_theme(follow_theme)),
+                )
+            }
+        };
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let (_driver, windows) =
+            MasonryDriver::new(state, logic, |_| Ok(()), Arc::new(runtime), vec![], None);
+
+        let built = windows[0].follow_theme.as_ref().unwrap();
+        assert!(Arc::ptr_eq(
+            &built.properties.light,
+            &follow_theme.properties.light
+        ));
+        assert!(Arc::ptr_eq(
+            &built.properties.dark,
+            &follow_theme.properties.dark
+        ));
+        assert_eq!(built.light_base_color, palette::css::WHITE);
+        assert_eq!(built.dark_base_color, palette::css::BLACK);
+    }
+
+    #[test]
+    fn untracked_messages_are_not_reported() {
+        let window_id = WindowId::next();
+        let (mut driver, mut render_root, sent) = test_driver(window_id, None);
+        let proxy = driver.state.proxy.lock().unwrap().take().unwrap();
+
+        proxy.message(1).unwrap();
+        let package = sent.lock().unwrap().remove(0);
+        assert!(package.on_failure.is_none());
+        let result = driver.route_async_message(window_id, Some(&mut render_root), package);
+        assert!(matches!(result, MessageResult::Action(())));
+        assert_eq!(driver.state.received, [1]);
+    }
+
+    #[test]
+    fn root_rebuilds_are_profiled() {
+        let window_id = WindowId::next();
+        let summaries = Arc::new(Mutex::new(Vec::new()));
+        let report: RebuildReport = Box::new({
+            let summaries = summaries.clone();
+            move |_, summary: &RebuildSummary| summaries.lock().unwrap().push(summary.clone())
+        });
+        let (mut driver, mut render_root, _) = test_driver(window_id, Some(report));
+
+        driver.rebuild_root(window_id, &mut render_root);
+
+        let summaries = summaries.lock().unwrap();
+        let [summary] = &summaries[..] else {
+            panic!("expected one summary, got {summaries:?}");
+        };
+        let root = summary.get("MasonryRoot").unwrap();
+        assert_eq!(root.rebuilds, 1);
+        // The root's time covers the whole cycle.
+        for stats in &summary.views {
+            assert!(root.total_time >= stats.total_time);
+        }
+    }
+}
@@ -166,7 +166,7 @@ pub use masonry::parley::Alignment as TextAlign;
 pub use masonry::parley::style::FontWeight;
 pub use masonry::peniko::{Blob, Color, ImageBrush, ImageFormat};
 pub use masonry::widgets::InsertNewline;
-pub use masonry_winit::app::{EventLoop, EventLoopBuilder, WindowId};
+pub use masonry_winit::app::{EventLoop, EventLoopBuilder, FollowTheme, WindowId};
 
 pub use xilem_masonry::{
     AnyWidgetView, InitialRootWidget, MasonryRoot, Pod, ViewCtx, WidgetView, WidgetViewSequence,
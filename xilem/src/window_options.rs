@@ -1,6 +1,7 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use masonry_winit::app::FollowTheme;
 use winit::dpi::{Position, Size};
 use winit::window::{Cursor, Icon, Window, WindowAttributes, WindowButtons, WindowLevel};
 
@@ -40,6 +41,7 @@ pub(crate) struct InitialAttrs {
     transparent: bool,
     // TODO: move window_icon to ReactiveWindowAttrs once the winit type implements PartialEq
     window_icon: Option<Icon>,
+    follow_theme: Option<FollowTheme>,
     platform_specific: PlatformSpecificInitialWindowAttrs,
 }
 
@@ -72,6 +74,7 @@ impl<State> WindowOptions<State> {
                 position: None,
                 transparent: false,
                 window_icon: None,
+                follow_theme: None,
                 platform_specific: PlatformSpecificInitialWindowAttrs::default(),
             },
             callbacks: WindowCallbacks::default(),
@@ -169,6 +172,22 @@ impl<State> WindowOptions<State> {
         self
     }
 
+    /// Makes the window follow the OS light/dark mode.
+    ///
+    /// Whenever the OS theme changes, the window's default properties and base color
+    /// are replaced with the ones from `follow_theme`.
+    /// [`masonry::theme::theme_properties`] gives the properties of Masonry's light and dark themes.
+    ///
+    /// The default is `None`, which keeps the window's default properties and base color.
+    pub fn with_initial_follow_theme(mut self, follow_theme: FollowTheme) -> Self {
+        self.initial.follow_theme = Some(follow_theme);
+        self
+    }
+
+    pub(crate) fn initial_follow_theme(&self) -> Option<&FollowTheme> {
+        self.initial.follow_theme.as_ref()
+    }
+
     pub(crate) fn build_initial_attrs(&self) -> WindowAttributes {
         let mut attrs = WindowAttributes::default()
             .with_title(self.reactive.title.clone())
@@ -262,6 +281,13 @@ impl<State> WindowOptions<State> {
             );
         }
 
+        // FollowTheme doesn't implement PartialEq, as the properties of each theme can't be compared
+        if current.follow_theme.is_some() != prev.follow_theme.is_some() {
+            tracing::warn!(
+                "attempted to change follow_theme attribute after window creation, this is not supported"
+            );
+        }
+
         current.platform_specific.warn(&prev.platform_specific);
     }
 }
@@ -81,17 +81,13 @@ impl<State> View<Edit<State>, (), ViewCtx> for WindowView<State> {
     ) -> (Self::Element, Self::ViewState) {
         let (InitialRootWidget(root_widget), view_state) = self.masonry_root.build(ctx, app_state);
         let initial_attributes = self.options.build_initial_attrs();
-        (
-            PodWindow(
-                NewWindow::new_with_id(
-                    self.id,
-                    initial_attributes,
-                    root_widget.new_widget.erased(),
-                )
-                .with_base_color(self.base_color),
-            ),
-            view_state,
-        )
+        let mut new_window =
+            NewWindow::new_with_id(self.id, initial_attributes, root_widget.new_widget.erased())
+                .with_base_color(self.base_color);
+        if let Some(follow_theme) = self.options.initial_follow_theme() {
+            new_window = new_window.with_follow_theme(follow_theme.clone());
+        }
+        (PodWindow(new_window), view_state)
     }
 
     fn rebuild(
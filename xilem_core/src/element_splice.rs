@@ -28,6 +28,19 @@ pub trait ElementSplice<Element: ViewElement> {
     fn index(&self) -> usize;
     /// Delete the next existing element, after running a function on it.
     fn delete<R>(&mut self, f: impl FnOnce(Element::Mut<'_>) -> R) -> R;
+    /// Move the existing element `offset` elements after the next one, so that it becomes the next one.
+    ///
+    /// The elements it skips over are shifted back by one.
+    /// This doesn't change the [`index`](ElementSplice::index), and an `offset` of 0 does nothing.
+    /// Unlike deleting and re-inserting the element, this preserves its identity.
+    ///
+    /// Returns `false` if this splice can't move elements, in which case nothing is changed.
+    /// That is the default, so that sequences which need to move elements (such as
+    /// [`keyed`](crate::keyed)) tear them down and build them again instead.
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        let _ = offset;
+        false
+    }
 }
 
 /// An append only `Vec`.
@@ -14,13 +14,15 @@ use hashbrown::HashMap;
 use hashbrown::hash_map::Entry;
 
 use crate::{
-    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+    Arg, MessageCtx, MessageResult, Mut, RebuildProfiler, View, ViewArgument, ViewId, ViewMarker,
+    ViewPathTracker,
 };
 
 #[derive(Debug)]
 /// A message sent to Views to instruct them to rebuild themselves.
 ///
 /// This will be sent when a value in the environment value is modified.
+/// Drivers send this message to each path returned by [`Environment::take_queued_rebuilds`].
 pub struct Rebuild;
 
 // --- MARK: Environment
@@ -31,11 +33,33 @@ pub struct EnvironmentItem {
     #[expect(missing_docs, reason = "Public on an interim basis")]
     pub value: Box<dyn AnyDebug>,
     // TODO: Can we/do we want to make these share an allocation?
-    // TODO: How do we GC this?
-    // TODO: The change listeners currently aren't ever notified.
+    /// The paths of the [`with_context`] views which read this value.
+    ///
+    /// Entries are set to `None` when their view is torn down, so that the
+    /// indices held by the other views stay valid.
     change_listeners: Vec<Option<Arc<[ViewId]>>>,
 }
 
+impl EnvironmentItem {
+    /// Registers the view at `path` as reading this value, and returns the index to unregister it with.
+    fn add_listener(&mut self, path: Arc<[ViewId]>) -> usize {
+        if let Some(idx) = self.change_listeners.iter().position(Option::is_none) {
+            self.change_listeners[idx] = Some(path);
+            idx
+        } else {
+            self.change_listeners.push(Some(path));
+            self.change_listeners.len() - 1
+        }
+    }
+
+    fn remove_listener(&mut self, idx: usize) {
+        self.change_listeners[idx] = None;
+        while let Some(None) = self.change_listeners.last() {
+            self.change_listeners.pop();
+        }
+    }
+}
+
 #[derive(Debug)]
 #[expect(missing_docs, reason = "Public on an interim basis")]
 pub struct Slot {
@@ -63,8 +87,9 @@ pub struct Environment {
     // We use u32 here so that we could move to a generation
     free_slots: Vec<u32>,
     types: HashMap<TypeId, u32>,
-    // TODO: Think about how to handle this.
-    // queued_rebuilds: Vec<Arc<[ViewId]>>,
+    /// The paths of the views which read a resource which has changed.
+    queued_rebuilds: Vec<Arc<[ViewId]>>,
+    rebuild_profiler: Option<Box<RebuildProfiler>>,
 }
 
 impl Environment {
@@ -78,10 +103,23 @@ impl Environment {
             slots: Vec::new(),
             free_slots: Vec::new(),
             types: HashMap::new(),
-            // queued_rebuilds: Vec::new(),
+            queued_rebuilds: Vec::new(),
+            rebuild_profiler: None,
         }
     }
 
+    /// Sets the profiler which records the rebuilds of the views using this environment.
+    ///
+    /// Profiling is disabled by default, and can be disabled again by passing `None`.
+    pub fn set_rebuild_profiler(&mut self, profiler: Option<RebuildProfiler>) {
+        self.rebuild_profiler = profiler.map(Box::new);
+    }
+
+    /// The profiler which records the rebuilds of the views using this environment, if enabled.
+    pub fn rebuild_profiler(&mut self) -> Option<&mut RebuildProfiler> {
+        self.rebuild_profiler.as_deref_mut()
+    }
+
     // TODO: Possibly reconsider the name here.
     fn create_slot_for_type<Context>(&mut self) -> u32
     where
@@ -122,6 +160,44 @@ impl Environment {
     {
         self.types.get(&TypeId::of::<Context>()).copied()
     }
+
+    /// Marks the value of type `Context` which is currently provided as changed.
+    ///
+    /// This queues a rebuild of every [`with_context`] view reading that value.
+    /// This is meant to be called while handling a message, after mutating the value
+    /// through [`MessageCtx::environment`]; [`on_action_with_context`] already does this for you.
+    ///
+    /// Does nothing if no value of that type is currently provided.
+    pub fn mark_changed<Context>(&mut self)
+    where
+        Context: Resource,
+    {
+        if let Some(slot) = self.get_slot_for_type::<Context>() {
+            self.queue_listeners(slot);
+        }
+    }
+
+    fn queue_listeners(&mut self, slot: u32) {
+        let slot = &self.slots[usize::try_from(slot).unwrap()];
+        let Some(item) = slot.item.as_ref() else {
+            return;
+        };
+        for path in item.change_listeners.iter().flatten() {
+            if !self.queued_rebuilds.contains(path) {
+                self.queued_rebuilds.push(path.clone());
+            }
+        }
+    }
+
+    /// Returns the paths of the views which read a resource which changed, and clears them.
+    ///
+    /// After handling a message, drivers should send a [`Rebuild`] message to each of these paths.
+    /// The reading views answer with [`MessageResult::RequestRebuild`], which marks any memoizing
+    /// ancestors as needing to rebuild, so that a rebuild of the view tree only visits the parts
+    /// which depend on the changed resource.
+    pub fn take_queued_rebuilds(&mut self) -> Vec<Arc<[ViewId]>> {
+        core::mem::take(&mut self.queued_rebuilds)
+    }
 }
 
 impl Default for Environment {
@@ -149,10 +225,10 @@ pub trait Resource: AnyDebug {}
 /// This context value can be read using the [`with_context`] view with the same
 /// `Context` type parameter within child.
 ///
-/// Note that it is not currently fully supported to mutate `Resource` values.
-/// In particular, if these values are read inside memoising views (such as
-/// [`memoize`](crate::memoize)), the values won't be correctly updated.
-/// We intend to address this limitation in the future.
+/// `Resource` values can be mutated when handling a message, using [`on_action_with_context`]
+/// or [`Environment::mark_changed`].
+/// The `with_context` views reading the value are then sent a [`Rebuild`] message, so that they
+/// are updated even inside memoizing views (such as [`memoize`](crate::memoize)).
 ///
 /// This is analogous to `Context.Provider` in React.
 pub fn provides<State, Action, Context, InitialContext, ChildView, Ctx>(
@@ -345,7 +421,6 @@ where
         core::mem::swap(&mut slot.item, &mut view_state.this_state);
 
         // TODO: Any need for a message directly to this view?
-        // TODO: When the context/environment is available in messages, add the context value here.
         let ret = self
             .child
             .message(&mut view_state.child_state, message, element, app_state);
@@ -374,10 +449,10 @@ where
 /// This view will read the resource value from the closest ancestor `provides`.
 /// If there is no such ancestor, this view will panic when it is built (or rebuilt).
 ///
-/// Note that it is not currently fully supported to mutate `Resource` values.
-/// In particular, if these values are read inside memoising views (such as
-/// [`memoize`](crate::memoize)), the values won't be correctly updated.
-/// We intend to address this limitation in the future.
+/// `Resource` values can be mutated when handling a message, using [`on_action_with_context`]
+/// or [`Environment::mark_changed`].
+/// The `with_context` views reading the value are then sent a [`Rebuild`] message, so that they
+/// are updated even inside memoizing views (such as [`memoize`](crate::memoize)).
 ///
 /// This is analogous to `Context.Consumer` in React.
 pub fn with_context<State, Action, Context, Child, ChildView, Ctx>(
@@ -414,7 +489,7 @@ pub struct WithContextState<ChildState, ChildView> {
     prev: ChildView,
     child_state: ChildState,
     environment_slot: u32,
-    listener_index: Option<usize>,
+    listener_index: usize,
 }
 
 // Use a distinctive number here, to be able to catch bugs.
@@ -469,47 +544,16 @@ where
                     core::any::type_name::<Context>()
                 );
             };
-            let context = value
-                .value
-                .downcast_mut::<Context>()
-                .expect("Environment's slots should have the correct types.");
-
-            let mut first_empty = None;
-            let mut needs_storing = true;
             // We store the path to this reader as a listener.
             // This is required so that we can be alerted of any changes, so that any parent
             // memoizing (or similar) views would correctly handle our value changing.
-            // N.B. This is strictly only needed if:
-            // 1) There actually is such a parent view
-            // 2) The path for rebuilding only needs to be the path to the closest such parent
-            //
-            // The future changes required to enable that are already partially accounted for here (i.e. checking
-            // if the current listening path is already included).
-            //
-            // Note also that there is currently no way to trigger these views!
-            for (idx, item) in value.change_listeners.iter().enumerate() {
-                if let Some(item) = item {
-                    if **item == *path {
-                        needs_storing = false;
-                        break;
-                    }
-                } else {
-                    first_empty.get_or_insert(idx);
-                }
-            }
+            // N.B. This is strictly only needed if there actually is such a parent view.
+            let listener_index = value.add_listener(path);
 
-            let listener_index = if needs_storing {
-                if let Some(first_empty) = first_empty {
-                    value.change_listeners[first_empty] = Some(path);
-                    Some(first_empty)
-                } else {
-                    let idx = value.change_listeners.len();
-                    value.change_listeners.push(Some(path));
-                    Some(idx)
-                }
-            } else {
-                None
-            };
+            let context = value
+                .value
+                .downcast_mut::<Context>()
+                .expect("Environment's slots should have the correct types.");
 
             let child_view = (self.child)(context, State::reborrow_mut(&mut app_state));
             let (child_element, child_state) = child_view.build(ctx, State::reborrow_mut(&mut app_state));
@@ -566,10 +610,11 @@ where
         ctx: &mut Ctx,
         element: Mut<'_, Self::Element>,
     ) {
-        if let Some(_listener_idx) = view_state.listener_index {
-            // TODO: Potentially garbage collect the listener.
-            // Note that it wouldn't be correct to just remove it here, because
-            // there could be multiple listeners under the same "memoization-style" view.
+        // Stop listening, so that the value doesn't try to rebuild a view which no longer exists.
+        let env = ctx.environment();
+        let slot = &mut env.slots[usize::try_from(view_state.environment_slot).unwrap()];
+        if let Some(value) = slot.item.as_mut() {
+            value.remove_listener(view_state.listener_index);
         }
         ctx.with_id(WITH_CONTEXT_CHILD, |ctx| {
             // TODO: We will probably want some access to the context in teardown at some point.
@@ -753,8 +798,15 @@ where
             .downcast_mut::<Res>()
             .expect("Environment's slots should have the correct types.");
 
-        prev_res.map(|child_action| {
+        let mut ran = false;
+        let res = prev_res.map(|child_action| {
+            ran = true;
             (self.on_action)(State::reborrow_mut(&mut app_state), resource, child_action)
-        })
+        });
+        if ran {
+            // We can't tell whether the resource was actually modified, so assume that it was.
+            env.queue_listeners(view_state.environment_slot);
+        }
+        res
     }
 }
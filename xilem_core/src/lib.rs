@@ -49,6 +49,7 @@
 extern crate alloc;
 
 pub use anymore;
+pub use xilem_macros::Lenses;
 
 mod element;
 mod element_splice;
@@ -56,6 +57,7 @@ mod environment;
 mod message;
 mod message_context;
 mod message_proxy;
+mod profiling;
 mod view;
 mod view_argument;
 mod view_ctx;
@@ -73,12 +75,17 @@ pub use self::environment::{
 };
 pub use self::message::{DynMessage, MessageResult, SendMessage};
 pub use self::message_context::MessageCtx;
-pub use self::message_proxy::{MessageProxy, ProxyError, RawProxy};
+pub use self::message_proxy::{
+    DeliveryFailure, DeliveryFailureHandler, MessageProxy, ProxyError, ProxyMessageId, RawProxy,
+};
+pub use self::profiling::{
+    RebuildProfiler, RebuildSummary, ViewRebuildStats, profile_rebuild, record_skipped_rebuild,
+};
 pub use self::view::{View, ViewMarker};
 pub use self::view_argument::{Arg, Edit, Read, ViewArgument};
 pub use self::view_ctx::{ViewId, ViewPathTracker};
 pub use self::view_sequence::{Count, ViewSequence};
-pub use self::view_sequences::{WithoutElements, without_elements};
+pub use self::view_sequences::{Keyed, WithoutElements, keyed, without_elements};
 pub use self::views::{
     Fork, Frozen, Lens, MapMessage, MapState, Memoize, OrphanView, RunOnce, fork, frozen, lens,
     map_action, map_message_result, map_state, memoize, one_of, run_once, run_once_raw,
@@ -49,6 +49,14 @@ impl MessageCtx {
         &self.full_id_path[..self.id_path_index]
     }
 
+    /// The [`Environment`] at this view's location in the view tree.
+    ///
+    /// After mutating a provided value, use [`Environment::mark_changed`] so that the views
+    /// which read it are updated.
+    pub fn environment(&mut self) -> &mut Environment {
+        &mut self.environment
+    }
+
     /// Takes the message, downcasting it to the specified type.
     ///
     /// If the message is not of the specified type, returns `None`.
@@ -5,6 +5,7 @@ use alloc::boxed::Box;
 use alloc::sync::Arc;
 use core::fmt::{Debug, Display};
 use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU64, Ordering};
 
 use anymore::AnyDebug;
 
@@ -39,12 +40,38 @@ pub trait RawProxy: Send + Sync + 'static {
     /// This method may error if the driver is no longer running, and in any other
     /// cases directly documented on the context which was used to create this proxy.
     /// It may also fail silently.
-    // TODO: Do we want/need a way to asynchronously report errors back to the caller?
-    //
-    // e.g. an `Option<Arc<dyn FnMut(ProxyError, ProxyMessageId?)>>`?
+    /// Use [`send_tracked_message`](Self::send_tracked_message) to be told about messages
+    /// which couldn't be delivered after this method returned.
     ///
     /// [`View`]: crate::View
     fn send_message(&self, path: Arc<[ViewId]>, message: SendMessage) -> Result<(), ProxyError>;
+
+    /// Sends a `message` to the view at `path` in this driver, reporting to `on_failure`
+    /// if it can't be delivered.
+    ///
+    /// Messages are delivered asynchronously, so the target view might be torn down
+    /// between this method returning and the driver routing the message.
+    /// In that case, the driver calls `on_failure` with a [`DeliveryFailure`] carrying `id`.
+    /// This callback can be called from any thread, and is never called if this method returns an error.
+    ///
+    /// The default implementation forwards to [`send_message`](Self::send_message),
+    /// and so never calls `on_failure`.
+    /// Drivers which can detect routing failures should override it.
+    ///
+    /// # Errors
+    ///
+    /// As in [`send_message`](Self::send_message).
+    fn send_tracked_message(
+        &self,
+        path: Arc<[ViewId]>,
+        message: SendMessage,
+        id: ProxyMessageId,
+        on_failure: DeliveryFailureHandler,
+    ) -> Result<(), ProxyError> {
+        let _ = (id, on_failure);
+        self.send_message(path, message)
+    }
+
     /// Returns the debug formatter for this proxy type.
     fn dyn_debug(&self) -> &dyn Debug;
 }
@@ -55,19 +82,66 @@ impl Debug for dyn RawProxy {
     }
 }
 
+/// An identifier for a message sent through a [`MessageProxy`].
+///
+/// This is used to tell which message a [`DeliveryFailure`] is about.
+/// Identifiers are unique within the process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProxyMessageId(u64);
+
+impl ProxyMessageId {
+    /// Allocates a new, unique, identifier.
+    pub fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns the integer value of this identifier.
+    pub fn to_raw(self) -> u64 {
+        self.0
+    }
+}
+
+/// A message sent through a [`RawProxy`] which reached the driver, but couldn't be routed to its view.
+///
+/// This most often happens because the view was torn down after the message was sent.
+/// The message itself has been dropped.
+#[derive(Debug, Clone)]
+pub struct DeliveryFailure {
+    /// The identifier the message was sent with.
+    pub id: ProxyMessageId,
+    /// The path the message was sent to.
+    pub path: Arc<[ViewId]>,
+}
+
+/// The callback which a driver calls when a message sent with
+/// [`RawProxy::send_tracked_message`] couldn't be delivered.
+pub type DeliveryFailureHandler = Arc<dyn Fn(DeliveryFailure) + Send + Sync>;
+
 /// A way to send a message of an expected type to a specific view.
-#[derive(Debug)]
 pub struct MessageProxy<M: AnyDebug + Send> {
     proxy: Arc<dyn RawProxy>,
     path: Arc<[ViewId]>,
+    on_failure: Option<DeliveryFailureHandler>,
     message: PhantomData<fn(M)>,
 }
 
+impl<M: AnyDebug + Send> Debug for MessageProxy<M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MessageProxy")
+            .field("proxy", &self.proxy)
+            .field("path", &self.path)
+            .field("on_failure", &self.on_failure.is_some())
+            .finish()
+    }
+}
+
 impl<M: AnyDebug + Send> Clone for MessageProxy<M> {
     fn clone(&self) -> Self {
         Self {
             proxy: self.proxy.clone(),
             path: self.path.clone(),
+            on_failure: self.on_failure.clone(),
             message: PhantomData,
         }
     }
@@ -79,10 +153,29 @@ impl<M: AnyDebug + Send> MessageProxy<M> {
         Self {
             proxy,
             path,
+            on_failure: None,
             message: PhantomData,
         }
     }
 
+    /// Builder-style method to be told about messages which couldn't be delivered.
+    ///
+    /// `on_failure` is called (potentially from another thread) when a message sent by this proxy
+    /// reaches the driver after the [`View`] which created the proxy has been torn down.
+    /// The failure carries the [`ProxyMessageId`] returned by [`send`](Self::send).
+    /// To receive failures on a channel instead, send them from within `on_failure`.
+    ///
+    /// Whether failures are detected depends on the driver; see [`RawProxy::send_tracked_message`].
+    ///
+    /// [`View`]: crate::View
+    pub fn on_delivery_failure(
+        mut self,
+        on_failure: impl Fn(DeliveryFailure) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_failure = Some(Arc::new(on_failure));
+        self
+    }
+
     /// Sends `message` to the [`View`] which created this `MessageProxy`
     ///
     /// # Errors
@@ -91,11 +184,34 @@ impl<M: AnyDebug + Send> MessageProxy<M> {
     /// - `Other`: As determined by the Xilem implementation.
     ///
     /// This method is currently not expected to return `ViewExpired`, as it does not block.
+    /// Use [`on_delivery_failure`](Self::on_delivery_failure) to learn about expired views.
     ///
     /// [`View`]: crate::View
     pub fn message(&self, message: M) -> Result<(), ProxyError> {
-        self.proxy
-            .send_message(self.path.clone(), SendMessage::new(message))
+        self.send(message).map(drop)
+    }
+
+    /// Sends `message` to the [`View`] which created this `MessageProxy`, returning its identifier.
+    ///
+    /// The identifier is used in any [`DeliveryFailure`] passed to the
+    /// [`on_delivery_failure`](Self::on_delivery_failure) callback for this message.
+    ///
+    /// # Errors
+    ///
+    /// As in [`message`](Self::message).
+    ///
+    /// [`View`]: crate::View
+    pub fn send(&self, message: M) -> Result<ProxyMessageId, ProxyError> {
+        let id = ProxyMessageId::next();
+        let message = SendMessage::new(message);
+        match &self.on_failure {
+            Some(on_failure) => {
+                self.proxy
+                    .send_tracked_message(self.path.clone(), message, id, on_failure.clone())
+            }
+            None => self.proxy.send_message(self.path.clone(), message),
+        }?;
+        Ok(id)
     }
 }
 
@@ -107,7 +223,8 @@ pub enum ProxyError {
     DriverFinished(SendMessage),
     /// The [`View`] the message was being routed to is no longer in the view tree.
     ///
-    /// This likely requires async error handling to happen.
+    /// As messages are delivered asynchronously, this is usually reported through
+    /// [`MessageProxy::on_delivery_failure`] instead.
     // See comment above `SendMessage` about possible future.
     ///
     /// [`View`]: crate::View
@@ -0,0 +1,237 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Opt-in instrumentation of view rebuilds.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::fmt::{Debug, Display};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::time::Duration;
+
+use hashbrown::HashMap;
+
+use crate::ViewPathTracker;
+
+/// The number of [`RebuildProfiler`]s which exist.
+///
+/// When there are none, recording a rebuild doesn't need to look up the environment's profiler,
+/// which keeps the cost of profiling being available low for the apps which don't use it.
+static LIVE_PROFILERS: AtomicUsize = AtomicUsize::new(0);
+
+fn is_profiling() -> bool {
+    LIVE_PROFILERS.load(Ordering::Relaxed) > 0
+}
+
+/// Records how often each type of view is rebuilt, and how long that takes.
+///
+/// This is used to find which views are costly to rebuild, and which could benefit
+/// from being wrapped in [`memoize`](crate::memoize).
+/// It is enabled by giving it to [`Environment::set_rebuild_profiler`](crate::Environment::set_rebuild_profiler),
+/// after which rebuilds are recorded until [`take_summary`](Self::take_summary) is called.
+///
+/// Rebuilds are recorded for the views which are items of a [`ViewSequence`](crate::ViewSequence)
+/// (such as the children of a container), and for the children of memoizing views
+/// (such as [`memoize`](crate::memoize), [`frozen`](crate::frozen) and `Arc<impl View>`).
+/// Views which rebuild their children by calling [`View::rebuild`] directly, and aren't
+/// memoizing, have the time spent in those children included in their own time.
+/// Custom views can record their children using [`profile_rebuild`] and [`record_skipped_rebuild`].
+pub struct RebuildProfiler {
+    clock: Box<dyn Fn() -> Duration>,
+    views: HashMap<TypeId, ViewRebuildStats>,
+    /// For each recorded rebuild in progress, the time spent in the recorded rebuilds nested in it.
+    nested_time: Vec<Duration>,
+}
+
+impl Debug for RebuildProfiler {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RebuildProfiler")
+            .field("views", &self.views.len())
+            .field("depth", &self.nested_time.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for RebuildProfiler {
+    fn drop(&mut self) {
+        LIVE_PROFILERS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl RebuildProfiler {
+    /// Creates a profiler which measures time using `clock`.
+    ///
+    /// `clock` should return the time elapsed since an arbitrary fixed point,
+    /// which would be `|| start.elapsed()` for an `Instant` called `start`.
+    /// This crate doesn't depend on `std`, so can't provide this itself.
+    pub fn new(clock: impl Fn() -> Duration + 'static) -> Self {
+        LIVE_PROFILERS.fetch_add(1, Ordering::Relaxed);
+        Self {
+            clock: Box::new(clock),
+            views: HashMap::new(),
+            nested_time: Vec::new(),
+        }
+    }
+
+    /// Returns the statistics recorded since the last call, and clears them.
+    ///
+    /// Drivers should call this after each rebuild of the view tree.
+    pub fn take_summary(&mut self) -> RebuildSummary {
+        let mut views: Vec<_> = self.views.drain().map(|(_, stats)| stats).collect();
+        views.sort_by(|a, b| {
+            b.self_time
+                .cmp(&a.self_time)
+                .then(b.rebuilds.cmp(&a.rebuilds))
+                .then(a.type_name.cmp(b.type_name))
+        });
+        RebuildSummary { views }
+    }
+
+    fn stats_for<V: ?Sized + 'static>(&mut self) -> &mut ViewRebuildStats {
+        self.views
+            .entry(TypeId::of::<V>())
+            .or_insert_with(|| ViewRebuildStats {
+                type_name: core::any::type_name::<V>(),
+                ..ViewRebuildStats::default()
+            })
+    }
+}
+
+/// Runs `rebuild`, which rebuilds a view of type `V`, recording it in the environment's
+/// [`RebuildProfiler`] if there is one.
+///
+/// The time spent in any nested recorded rebuilds is excluded from `V`'s
+/// [`self_time`](ViewRebuildStats::self_time).
+pub fn profile_rebuild<V: ?Sized + 'static, Context: ViewPathTracker, R>(
+    ctx: &mut Context,
+    rebuild: impl FnOnce(&mut Context) -> R,
+) -> R {
+    if !is_profiling() {
+        return rebuild(ctx);
+    }
+    let Some(profiler) = ctx.environment().rebuild_profiler() else {
+        return rebuild(ctx);
+    };
+    let start = (profiler.clock)();
+    profiler.nested_time.push(Duration::ZERO);
+
+    let result = rebuild(ctx);
+
+    // The profiler could have been removed by a child.
+    let Some(profiler) = ctx.environment().rebuild_profiler() else {
+        return result;
+    };
+    let elapsed = (profiler.clock)().saturating_sub(start);
+    let nested = profiler.nested_time.pop().unwrap_or_default();
+    if let Some(parent_nested) = profiler.nested_time.last_mut() {
+        *parent_nested += elapsed;
+    }
+    let stats = profiler.stats_for::<V>();
+    stats.rebuilds += 1;
+    stats.total_time += elapsed;
+    stats.self_time += elapsed.saturating_sub(nested);
+    result
+}
+
+/// Records in the environment's [`RebuildProfiler`] (if any) that a view of
+/// type `V` wasn't rebuilt, because it was memoized.
+pub fn record_skipped_rebuild<V: ?Sized + 'static>(ctx: &mut impl ViewPathTracker) {
+    if is_profiling()
+        && let Some(profiler) = ctx.environment().rebuild_profiler()
+    {
+        profiler.stats_for::<V>().skipped += 1;
+    }
+}
+
+/// The rebuilds of one type of view, as recorded by a [`RebuildProfiler`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ViewRebuildStats {
+    /// The name of the view's type, as given by [`core::any::type_name`].
+    pub type_name: &'static str,
+    /// How many times views of this type were rebuilt.
+    pub rebuilds: u32,
+    /// How many times views of this type weren't rebuilt, because they were memoized.
+    pub skipped: u32,
+    /// The time spent rebuilding views of this type, including their recorded children.
+    ///
+    /// If views of this type are nested in each other, the time of the inner
+    /// views is counted several times.
+    pub total_time: Duration,
+    /// The time spent rebuilding views of this type, excluding their recorded children.
+    pub self_time: Duration,
+}
+
+impl ViewRebuildStats {
+    /// The name of the view's type, without its module path or generic parameters.
+    pub fn short_type_name(&self) -> &'static str {
+        let name = self.type_name;
+        let name = name.split_once('<').map_or(name, |(name, _)| name);
+        name.rsplit_once("::").map_or(name, |(_, name)| name)
+    }
+}
+
+/// The rebuilds recorded by a [`RebuildProfiler`] in one cycle, as returned by
+/// [`RebuildProfiler::take_summary`].
+///
+/// The `Display` implementation formats this as a table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RebuildSummary {
+    /// The statistics of each type of view, from the highest [`self_time`](ViewRebuildStats::self_time)
+    /// to the lowest.
+    pub views: Vec<ViewRebuildStats>,
+}
+
+impl RebuildSummary {
+    /// Returns `true` if no rebuilds were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    /// Returns the statistics for the views with the given type name, if any were recorded.
+    ///
+    /// `type_name` can either be the full name, or the [short name](ViewRebuildStats::short_type_name).
+    /// If several types have the same short name, only the first is returned.
+    pub fn get(&self, type_name: &str) -> Option<&ViewRebuildStats> {
+        self.views
+            .iter()
+            .find(|stats| stats.type_name == type_name)
+            .or_else(|| {
+                self.views
+                    .iter()
+                    .find(|stats| stats.short_type_name() == type_name)
+            })
+    }
+
+    /// The number of views which were rebuilt.
+    pub fn total_rebuilds(&self) -> u32 {
+        self.views.iter().map(|stats| stats.rebuilds).sum()
+    }
+
+    /// The number of views which weren't rebuilt, because they were memoized.
+    pub fn total_skipped(&self) -> u32 {
+        self.views.iter().map(|stats| stats.skipped).sum()
+    }
+}
+
+impl Display for RebuildSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(
+            f,
+            "{:>12} {:>12} {:>8} {:>8}  view",
+            "self", "total", "rebuilt", "skipped"
+        )?;
+        for stats in &self.views {
+            writeln!(
+                f,
+                "{:>12} {:>12} {:>8} {:>8}  {}",
+                alloc::format!("{:?}", stats.self_time),
+                alloc::format!("{:?}", stats.total_time),
+                stats.rebuilds,
+                stats.skipped,
+                stats.short_type_name(),
+            )?;
+        }
+        Ok(())
+    }
+}
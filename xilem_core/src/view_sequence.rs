@@ -5,7 +5,7 @@
 
 use crate::{
     AppendVec, Arg, ElementSplice, MessageCtx, MessageResult, SuperElement, View, ViewArgument,
-    ViewElement, ViewMarker, ViewPathTracker,
+    ViewElement, ViewMarker, ViewPathTracker, profile_rebuild,
 };
 
 /// Classes that a [`ViewSequence`] can be a member of, grouped based on the number
@@ -199,7 +199,9 @@ where
         // Mutate the item we added in `seq_build`
         elements.mutate(|this_element| {
             Element::with_downcast(this_element, |element| {
-                self.rebuild(prev, seq_state, ctx, element, app_state);
+                profile_rebuild::<V, _, _>(ctx, |ctx| {
+                    self.rebuild(prev, seq_state, ctx, element, app_state);
+                });
             });
         });
     }
@@ -0,0 +1,302 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use crate::{
+    AppendVec, Arg, Count, ElementSplice, MessageCtx, MessageResult, ViewArgument, ViewElement,
+    ViewId, ViewPathTracker, ViewSequence,
+};
+
+/// The [`ViewSequence`] for [`keyed`], see its documentation for more context.
+#[derive(Debug)]
+pub struct Keyed<K, Seq> {
+    children: Vec<(K, Seq)>,
+}
+
+/// A sequence of views which are matched with their previous versions by key, rather than by index.
+///
+/// The sequence for `Vec<V>` compares the new and old vectors position by position, so inserting
+/// an item at the front of the list rebuilds every item after it with the view of its predecessor.
+/// Widget state which isn't driven by the view (such as focus, scroll position or text selection)
+/// then stays at the old position instead of following its item.
+///
+/// This sequence instead pairs each child with the previous child which had the same key.
+/// That child's elements are moved into place and rebuilt, and keep their identity;
+/// children with a new key are built, and children whose key is gone are torn down.
+/// If the elements can't be moved (see [`ElementSplice::move_to_current`]), the children
+/// which are in the way are torn down and built again instead.
+/// Messages sent to a child keep being routed to it for as long as its key is present.
+///
+/// Keys should be unique within the sequence.
+/// If several children have the same key, only the first one is matched,
+/// and the others are built from scratch.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_core::docs::{DocsViewSequence as WidgetViewSequence, stateless_component as component};
+/// use xilem_core::keyed;
+///
+/// struct Todo {
+///     id: u64,
+///     text: String,
+/// }
+///
+/// fn todo_list(todos: &[Todo]) -> impl WidgetViewSequence<()> + use<> {
+///     keyed(todos, |todo| todo.id, |_todo| component())
+/// }
+/// ```
+pub fn keyed<T, K, Seq>(
+    items: impl IntoIterator<Item = T>,
+    key: impl Fn(&T) -> K,
+    view: impl Fn(T) -> Seq,
+) -> Keyed<K, Seq> {
+    Keyed {
+        children: items
+            .into_iter()
+            .map(|item| (key(&item), view(item)))
+            .collect(),
+    }
+}
+
+/// The state used to implement `ViewSequence` for [`Keyed`].
+///
+/// Each child gets a unique [`ViewId`] when it is built, which it keeps until it is torn down.
+#[doc(hidden)]
+#[expect(
+    unnameable_types,
+    reason = "Implementation detail, public because of trait visibility rules"
+)]
+#[derive(Debug)]
+pub struct KeyedState<InnerState> {
+    /// The state of each child, in the same order as the children.
+    children: Vec<KeyedChild<InnerState>>,
+    /// The routing id given to the next child which is built.
+    next_id: u64,
+}
+
+#[derive(Debug)]
+struct KeyedChild<InnerState> {
+    id: ViewId,
+    /// The number of elements this child contributes.
+    count: usize,
+    state: InnerState,
+}
+
+impl<InnerState> KeyedState<InnerState> {
+    fn next_id(&mut self) -> ViewId {
+        let id = ViewId::new(self.next_id);
+        self.next_id += 1;
+        id
+    }
+}
+
+impl<State, Action, Context, Element, K, Seq> ViewSequence<State, Action, Context, Element>
+    for Keyed<K, Seq>
+where
+    State: ViewArgument,
+    Seq: ViewSequence<State, Action, Context, Element>,
+    Context: ViewPathTracker,
+    Element: ViewElement,
+    K: Hash + Eq + 'static,
+{
+    type SeqState = KeyedState<Seq::SeqState>;
+
+    const ELEMENTS_COUNT: Count = Seq::ELEMENTS_COUNT.multiple();
+
+    fn seq_build(
+        &self,
+        ctx: &mut Context,
+        elements: &mut AppendVec<Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> Self::SeqState {
+        let mut seq_state = KeyedState {
+            children: Vec::with_capacity(self.children.len()),
+            next_id: 0,
+        };
+        for (_, seq) in &self.children {
+            let id = seq_state.next_id();
+            let start = elements.index();
+            let state = ctx.with_id(id, |ctx| {
+                seq.seq_build(ctx, elements, State::reborrow_mut(&mut app_state))
+            });
+            seq_state.children.push(KeyedChild {
+                id,
+                count: elements.index() - start,
+                state,
+            });
+        }
+        seq_state
+    }
+
+    fn seq_rebuild(
+        &self,
+        prev: &Self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        let prev_len = prev.children.len();
+
+        // Pair each new child with the old child which had the same key.
+        // Removing the entry once it's matched means duplicate keys are only matched once.
+        let mut old_indices: HashMap<&K, usize> = HashMap::with_capacity(prev_len);
+        for (index, (key, _)) in prev.children.iter().enumerate().rev() {
+            old_indices.insert(key, index);
+        }
+        let matches: Vec<Option<usize>> = self
+            .children
+            .iter()
+            .map(|(key, _)| old_indices.remove(key))
+            .collect();
+        let mut is_matched = alloc::vec![false; prev_len];
+        for index in matches.iter().flatten() {
+            is_matched[*index] = true;
+        }
+
+        // The old children whose elements haven't been moved, rebuilt or deleted yet.
+        // These are always in their original order, directly after the current index.
+        let mut old_states: Vec<Option<KeyedChild<Seq::SeqState>>> =
+            seq_state.children.drain(..).map(Some).collect();
+        let mut next_old = 0;
+
+        for ((_, seq), old_index) in self.children.iter().zip(matches) {
+            // Delete the unmatched old children which are in the way.
+            while let Some(old) = old_states.get_mut(next_old) {
+                if let Some(child) = old
+                    && !is_matched[next_old]
+                {
+                    ctx.with_id(child.id, |ctx| {
+                        prev.children[next_old]
+                            .1
+                            .seq_teardown(&mut child.state, ctx, elements);
+                    });
+                    *old = None;
+                } else if old.is_some() {
+                    break;
+                }
+                next_old += 1;
+            }
+
+            // The old child may have been torn down already, if it was in the way of a move.
+            let old = old_index.and_then(|index| Some((index, old_states[index].take()?)));
+            let Some((old_index, mut child)) = old else {
+                let id = seq_state.next_id();
+                let start = elements.index();
+                let state = elements.with_scratch(|elements| {
+                    ctx.with_id(id, |ctx| {
+                        seq.seq_build(ctx, elements, State::reborrow_mut(&mut app_state))
+                    })
+                });
+                seq_state.children.push(KeyedChild {
+                    id,
+                    count: elements.index() - start,
+                    state,
+                });
+                continue;
+            };
+
+            if old_index != next_old {
+                // Move this child's elements in front of those of the old children which come before it.
+                // Moving the last element each time keeps the elements in order.
+                let offset: usize = old_states[next_old..old_index]
+                    .iter()
+                    .flatten()
+                    .map(|child| child.count)
+                    .sum();
+                let moved =
+                    (0..child.count).all(|_| elements.move_to_current(offset + child.count - 1));
+                if !moved {
+                    // The elements can't be moved, so tear down the old children in the way instead.
+                    // If they are matched later on, they are built again.
+                    for (index, old) in old_states
+                        .iter_mut()
+                        .enumerate()
+                        .take(old_index)
+                        .skip(next_old)
+                    {
+                        if let Some(mut in_the_way) = old.take() {
+                            ctx.with_id(in_the_way.id, |ctx| {
+                                prev.children[index].1.seq_teardown(
+                                    &mut in_the_way.state,
+                                    ctx,
+                                    elements,
+                                );
+                            });
+                        }
+                    }
+                }
+            }
+            let start = elements.index();
+            ctx.with_id(child.id, |ctx| {
+                seq.seq_rebuild(
+                    &prev.children[old_index].1,
+                    &mut child.state,
+                    ctx,
+                    elements,
+                    State::reborrow_mut(&mut app_state),
+                );
+            });
+            child.count = elements.index() - start;
+            seq_state.children.push(child);
+        }
+
+        // Delete the old children which weren't matched.
+        for (old_index, old) in old_states.iter_mut().enumerate().skip(next_old) {
+            if let Some(child) = old {
+                ctx.with_id(child.id, |ctx| {
+                    prev.children[old_index]
+                        .1
+                        .seq_teardown(&mut child.state, ctx, elements);
+                });
+            }
+        }
+    }
+
+    fn seq_teardown(
+        &self,
+        seq_state: &mut Self::SeqState,
+        ctx: &mut Context,
+        elements: &mut impl ElementSplice<Element>,
+    ) {
+        for ((_, seq), child) in self.children.iter().zip(&mut seq_state.children) {
+            ctx.with_id(child.id, |ctx| {
+                seq.seq_teardown(&mut child.state, ctx, elements);
+            });
+        }
+    }
+
+    fn seq_message(
+        &self,
+        seq_state: &mut Self::SeqState,
+        message: &mut MessageCtx,
+        elements: &mut impl ElementSplice<Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        let start = message
+            .take_first()
+            .expect("Id path has elements for Keyed");
+        let Some(index) = seq_state
+            .children
+            .iter()
+            .position(|child| child.id == start)
+        else {
+            // The child with this key has been removed.
+            return MessageResult::Stale;
+        };
+        let skip = seq_state.children[..index]
+            .iter()
+            .map(|child| child.count)
+            .sum();
+        elements.skip(skip);
+        let child = &mut seq_state.children[index];
+        self.children[index]
+            .1
+            .seq_message(&mut child.state, message, elements, app_state)
+    }
+}
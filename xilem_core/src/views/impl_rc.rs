@@ -6,7 +6,10 @@ use alloc::sync::Arc;
 use core::ops::Deref;
 
 use crate::message::MessageResult;
-use crate::{Arg, MessageCtx, Mut, View, ViewArgument, ViewMarker, ViewPathTracker};
+use crate::{
+    Arg, MessageCtx, Mut, View, ViewArgument, ViewMarker, ViewPathTracker, profile_rebuild,
+    record_skipped_rebuild,
+};
 
 #[expect(
     unnameable_types,
@@ -58,8 +61,12 @@ where
     ) {
         #![expect(clippy::use_self, reason = "`Rc::ptr_eq` is the canonical form")]
         if core::mem::take(&mut view_state.dirty) || !Rc::ptr_eq(self, prev) {
-            self.deref()
-                .rebuild(prev, &mut view_state.view_state, ctx, element, app_state);
+            profile_rebuild::<V, _, _>(ctx, |ctx| {
+                self.deref()
+                    .rebuild(prev, &mut view_state.view_state, ctx, element, app_state);
+            });
+        } else {
+            record_skipped_rebuild::<V>(ctx);
         }
     }
 
@@ -126,8 +133,12 @@ where
     ) {
         #![expect(clippy::use_self, reason = "`Arc::ptr_eq` is the canonical form")]
         if core::mem::take(&mut view_state.dirty) || !Arc::ptr_eq(self, prev) {
-            self.deref()
-                .rebuild(prev, &mut view_state.view_state, ctx, element, app_state);
+            profile_rebuild::<V, _, _>(ctx, |ctx| {
+                self.deref()
+                    .rebuild(prev, &mut view_state.view_state, ctx, element, app_state);
+            });
+        } else {
+            record_skipped_rebuild::<V>(ctx);
         }
     }
 
@@ -45,6 +45,8 @@ impl<CF, V, F, ParentState, ChildState, Action, Context> Debug
 ///
 /// It's a more specialized/simpler alternative to [`map_state`](crate::map_state)
 ///
+/// The `map` function can be generated for each field of a struct by deriving [`Lenses`](crate::Lenses).
+///
 /// # Examples
 ///
 /// In code, the date picker example might look like:
@@ -33,6 +33,8 @@ where
 ///
 /// See also [`lens`](crate::lens), for an alternative with a similar purpose.
 ///
+/// Functions which can be used as `f` can be generated for each field of a struct by deriving [`Lenses`](crate::Lenses).
+///
 /// # Examples
 ///
 /// (From the Xilem implementation)
@@ -5,7 +5,10 @@ use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::mem::size_of;
 
-use crate::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker, ViewPathTracker};
+use crate::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker, ViewPathTracker,
+    profile_rebuild, record_skipped_rebuild,
+};
 
 /// A view which supports Memoization.
 ///
@@ -129,14 +132,18 @@ where
     ) {
         if core::mem::take(&mut view_state.dirty) || prev.data != self.data {
             let view = (self.init_view)(&self.data);
-            view.rebuild(
-                &view_state.view,
-                &mut view_state.view_state,
-                ctx,
-                element,
-                app_state,
-            );
+            profile_rebuild::<V, _, _>(ctx, |ctx| {
+                view.rebuild(
+                    &view_state.view,
+                    &mut view_state.view_state,
+                    ctx,
+                    element,
+                    app_state,
+                );
+            });
             view_state.view = view;
+        } else {
+            record_skipped_rebuild::<V>(ctx);
         }
     }
 
@@ -251,14 +258,18 @@ where
     ) {
         if core::mem::take(&mut view_state.dirty) {
             let view = (self.init_view)();
-            view_state.view.rebuild(
-                &view_state.view,
-                &mut view_state.view_state,
-                ctx,
-                element,
-                app_state,
-            );
+            profile_rebuild::<V, _, _>(ctx, |ctx| {
+                view_state.view.rebuild(
+                    &view_state.view,
+                    &mut view_state.view_state,
+                    ctx,
+                    element,
+                    app_state,
+                );
+            });
             view_state.view = view;
+        } else {
+            record_skipped_rebuild::<V>(ctx);
         }
     }
 
@@ -87,13 +87,43 @@ pub(super) struct Action {
 pub(super) struct SequenceView<Seq> {
     id: u32,
     seq: Seq,
+    /// Whether to use a [`KeyedTracker`] rather than a [`SeqTracker`], and if so whether it can move elements.
+    keyed: Option<bool>,
 }
 
 pub(super) fn sequence<Seq>(id: u32, seq: Seq) -> SequenceView<Seq>
 where
     Seq: ViewSequence<(), Action, TestCtx, TestElement>,
 {
-    SequenceView { id, seq }
+    SequenceView {
+        id,
+        seq,
+        keyed: None,
+    }
+}
+
+/// A sequence whose children can be inserted and moved anywhere, as is needed by keyed sequences.
+pub(super) fn keyed_sequence<Seq>(id: u32, seq: Seq) -> SequenceView<Seq>
+where
+    Seq: ViewSequence<(), Action, TestCtx, TestElement>,
+{
+    SequenceView {
+        id,
+        seq,
+        keyed: Some(true),
+    }
+}
+
+/// Like [`keyed_sequence`], but using the default [`ElementSplice::move_to_current`], which can't move elements.
+pub(super) fn unmovable_keyed_sequence<Seq>(id: u32, seq: Seq) -> SequenceView<Seq>
+where
+    Seq: ViewSequence<(), Action, TestCtx, TestElement>,
+{
+    SequenceView {
+        id,
+        seq,
+        keyed: Some(false),
+    }
 }
 
 impl<Seq> ViewMarker for SequenceView<Seq> {}
@@ -134,13 +164,28 @@ where
             from: prev.id,
             to: self.id,
         });
-        let mut elements = SeqTracker {
-            inner: element.children.as_mut().unwrap(),
-            ix: 0,
-            scratch: &mut view_state.1,
-        };
-        self.seq
-            .seq_rebuild(&prev.seq, &mut view_state.0, ctx, &mut elements, app_state);
+        let inner = element.children.as_mut().unwrap();
+        let scratch = &mut view_state.1;
+        if let Some(can_move) = self.keyed {
+            let mut elements = KeyedTracker {
+                tracker: SeqTracker {
+                    inner,
+                    ix: 0,
+                    scratch,
+                },
+                can_move,
+            };
+            self.seq
+                .seq_rebuild(&prev.seq, &mut view_state.0, ctx, &mut elements, app_state);
+        } else {
+            let mut elements = SeqTracker {
+                inner,
+                ix: 0,
+                scratch,
+            };
+            self.seq
+                .seq_rebuild(&prev.seq, &mut view_state.0, ctx, &mut elements, app_state);
+        }
     }
 
     fn teardown(
@@ -317,3 +362,49 @@ impl ElementSplice<TestElement> for SeqTracker<'_> {
         ret
     }
 }
+
+/// An [`ElementSplice`] which inserts elements at the current index, rather than at the end.
+///
+/// [`SeqTracker`] is enough for the sequences which only grow or shrink at the end,
+/// but [`Keyed`](xilem_core::Keyed) sequences also insert and move elements in the middle.
+pub(super) struct KeyedTracker<'a> {
+    tracker: SeqTracker<'a>,
+    can_move: bool,
+}
+
+impl ElementSplice<TestElement> for KeyedTracker<'_> {
+    fn with_scratch<R>(&mut self, f: impl FnOnce(&mut AppendVec<TestElement>) -> R) -> R {
+        let tracker = &mut self.tracker;
+        let ret = f(tracker.scratch);
+        for element in tracker.scratch.drain() {
+            tracker.inner.active.insert(tracker.ix, element);
+            tracker.ix += 1;
+        }
+        ret
+    }
+    fn insert(&mut self, element: TestElement) {
+        self.tracker.inner.active.insert(self.tracker.ix, element);
+        self.tracker.ix += 1;
+    }
+    fn mutate<R>(&mut self, f: impl FnOnce(Mut<'_, TestElement>) -> R) -> R {
+        self.tracker.mutate(f)
+    }
+    fn skip(&mut self, n: usize) {
+        self.tracker.skip(n);
+    }
+    fn index(&self) -> usize {
+        self.tracker.index()
+    }
+    fn delete<R>(&mut self, f: impl FnOnce(Mut<'_, TestElement>) -> R) -> R {
+        self.tracker.delete(f)
+    }
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        if !self.can_move {
+            return false;
+        }
+        let tracker = &mut self.tracker;
+        let element = tracker.inner.active.remove(tracker.ix + offset);
+        tracker.inner.active.insert(tracker.ix, element);
+        true
+    }
+}
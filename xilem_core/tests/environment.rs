@@ -0,0 +1,88 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for updating the readers of [`provides`] values.
+
+mod common;
+use common::*;
+use xilem_core::{
+    DynMessage, MessageResult, Rebuild, Resource, View, ViewPathTracker, memoize,
+    on_action_with_context, provides, with_context,
+};
+
+#[derive(Debug)]
+struct Counter(u32);
+
+impl Resource for Counter {}
+
+/// A counter which is read inside a memoized view, and incremented by a sibling.
+fn counter_app() -> impl View<(), Action, TestCtx, Element = TestElement> {
+    provides(
+        |()| Counter(0),
+        sequence(
+            0,
+            (
+                memoize((), |()| {
+                    with_context(|counter: &mut Counter, ()| OperationView::<0>(counter.0))
+                }),
+                on_action_with_context(
+                    |(), counter: &mut Counter, action: Action| {
+                        counter.0 += 1;
+                        action
+                    },
+                    OperationView::<0>(100),
+                ),
+            ),
+        ),
+    )
+}
+
+#[test]
+fn memoized_reader_is_rebuilt() {
+    let view = counter_app();
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+
+    let children = element.children.as_ref().unwrap();
+    let reader_path = children.active[0].view_path.clone();
+    let incrementer_path = children.active[1].view_path.clone();
+    ctx.with_message_context(incrementer_path, DynMessage::new(()), |ctx| {
+        let result = view.message(&mut state, ctx, &mut element, ());
+        assert_action(result, 100);
+    });
+
+    // The `with_context` view is the parent of the reading view.
+    let queued = ctx.environment().take_queued_rebuilds();
+    assert_eq!(queued.len(), 1);
+    assert_eq!(*queued[0], reader_path[..reader_path.len() - 1]);
+    assert!(ctx.environment().take_queued_rebuilds().is_empty());
+
+    ctx.with_message_context(queued[0].to_vec(), DynMessage::new(Rebuild), |ctx| {
+        let result = view.message(&mut state, ctx, &mut element, ());
+        assert!(matches!(result, MessageResult::RequestRebuild));
+    });
+
+    view.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+    assert_eq!(
+        element.children.as_ref().unwrap().active[0].operations,
+        &[Operation::Build(0), Operation::Rebuild { from: 0, to: 1 }]
+    );
+}
+
+#[test]
+fn unchanged_reader_is_not_rebuilt() {
+    let view = counter_app();
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+
+    view.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+    assert!(ctx.environment().take_queued_rebuilds().is_empty());
+    assert_eq!(
+        element.children.as_ref().unwrap().active[0].operations,
+        &[Operation::Build(0)]
+    );
+}
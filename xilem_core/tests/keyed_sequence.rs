@@ -0,0 +1,207 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`SequenceView`] with [`keyed`] sequences.
+
+mod common;
+use common::*;
+use xilem_core::{DynMessage, Keyed, MessageResult, View, keyed};
+
+fn record_ops(id: u32) -> OperationView<0> {
+    OperationView(id)
+}
+
+/// A keyed sequence where each view's id is its key.
+fn keyed_ops(keys: &[u32]) -> Keyed<u32, OperationView<0>> {
+    keyed(keys.iter().copied(), |key| *key, record_ops)
+}
+
+/// The id each active child was first built with, in order.
+fn active_ids(element: &TestElement) -> Vec<u32> {
+    element
+        .children
+        .as_ref()
+        .unwrap()
+        .active
+        .iter()
+        .map(|child| match child.operations[0] {
+            Operation::Build(id) => id,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+#[test]
+fn insert_at_front() {
+    let view = keyed_sequence(0, keyed_ops(&[1, 2, 3]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [1, 2, 3]);
+
+    let view2 = keyed_sequence(0, keyed_ops(&[0, 1, 2, 3]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [0, 1, 2, 3]);
+
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.deleted.is_empty());
+    assert_eq!(seq_children.active[0].operations, &[Operation::Build(0)]);
+    // The existing children are rebuilt with their own previous view, not their predecessor's.
+    for (child, id) in seq_children.active[1..].iter().zip(1..) {
+        assert_eq!(
+            child.operations,
+            &[
+                Operation::Build(id),
+                Operation::Rebuild { from: id, to: id }
+            ]
+        );
+    }
+}
+
+#[test]
+fn reorder_and_remove() {
+    let view = keyed_sequence(0, keyed_ops(&[1, 2, 3, 4]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+
+    let view2 = keyed_sequence(0, keyed_ops(&[4, 2, 5, 1]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [4, 2, 5, 1]);
+
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(seq_children.deleted.len(), 1);
+    let (_, deleted) = &seq_children.deleted[0];
+    assert_eq!(
+        deleted.operations,
+        &[Operation::Build(3), Operation::Teardown(3)]
+    );
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(4), Operation::Rebuild { from: 4, to: 4 }]
+    );
+    assert_eq!(seq_children.active[2].operations, &[Operation::Build(5)]);
+
+    view2.teardown(&mut state, &mut ctx, &mut element);
+    ctx.assert_empty();
+    let seq_children = element.children.as_ref().unwrap();
+    assert!(seq_children.active.is_empty());
+    assert_eq!(seq_children.deleted.len(), 5);
+}
+
+#[test]
+fn reorder_without_moves() {
+    let view = unmovable_keyed_sequence(0, keyed_ops(&[1, 2, 3, 4]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+
+    let view2 = unmovable_keyed_sequence(0, keyed_ops(&[4, 2, 5, 1]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [4, 2, 5, 1]);
+
+    // The elements can't be moved, so the children in front of 4 are torn down,
+    // and those which are still present are built again.
+    let seq_children = element.children.as_ref().unwrap();
+    let deleted: Vec<_> = seq_children
+        .deleted
+        .iter()
+        .map(|(_, child)| child.operations.clone())
+        .collect();
+    assert_eq!(
+        deleted,
+        [1, 2, 3].map(|id| vec![Operation::Build(id), Operation::Teardown(id)])
+    );
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(4), Operation::Rebuild { from: 4, to: 4 }]
+    );
+    for child in &seq_children.active[1..] {
+        assert_eq!(child.operations.len(), 1);
+    }
+}
+
+#[test]
+fn multiple_elements_per_child() {
+    let children = |keys: &[u32]| {
+        keyed(
+            keys.iter().copied(),
+            |key| *key,
+            |key| (record_ops(key * 10), record_ops(key * 10 + 1)),
+        )
+    };
+    let view = keyed_sequence(0, children(&[1, 2, 3]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [10, 11, 20, 21, 30, 31]);
+
+    let view2 = keyed_sequence(0, children(&[3, 1, 2]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [30, 31, 10, 11, 20, 21]);
+
+    let view3 = keyed_sequence(0, children(&[2, 4, 3]));
+    view3.rebuild(&view2, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+    assert_eq!(active_ids(&element), [20, 21, 40, 41, 30, 31]);
+    assert_eq!(element.children.as_ref().unwrap().deleted.len(), 2);
+}
+
+#[test]
+fn messages_follow_keys() {
+    let view = keyed_sequence(0, keyed_ops(&[1, 2]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+
+    let seq_children = element.children.as_ref().unwrap();
+    let first_path = seq_children.active[0].view_path.to_vec();
+    let second_path = seq_children.active[1].view_path.to_vec();
+
+    let view2 = keyed_sequence(0, keyed_ops(&[0, 2]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+
+    // The child with key 2 moved, but messages still reach it.
+    ctx.with_message_context(second_path, DynMessage::new(()), |ctx| {
+        let result = view2.message(&mut state, ctx, &mut element, ());
+        assert_action(result, 2);
+    });
+    // The child with key 1 is gone, and the new child doesn't reuse its path.
+    ctx.with_message_context(first_path, DynMessage::new(()), |ctx| {
+        let result = view2.message(&mut state, ctx, &mut element, ());
+        assert!(matches!(result, MessageResult::Stale));
+    });
+    let new_path = element.children.as_ref().unwrap().active[0]
+        .view_path
+        .to_vec();
+    ctx.with_message_context(new_path, DynMessage::new(()), |ctx| {
+        let result = view2.message(&mut state, ctx, &mut element, ());
+        assert_action(result, 0);
+    });
+}
+
+#[test]
+fn duplicate_keys() {
+    let view = keyed_sequence(0, keyed_ops(&[1, 1]));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+
+    let view2 = keyed_sequence(0, keyed_ops(&[1, 1]));
+    view2.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+
+    // Only the first child is matched, the second one is replaced.
+    let seq_children = element.children.as_ref().unwrap();
+    assert_eq!(
+        seq_children.active[0].operations,
+        &[Operation::Build(1), Operation::Rebuild { from: 1, to: 1 }]
+    );
+    assert_eq!(seq_children.active[1].operations, &[Operation::Build(1)]);
+    assert_eq!(seq_children.deleted.len(), 1);
+}
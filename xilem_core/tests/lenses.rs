@@ -0,0 +1,143 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the functions generated by `#[derive(Lenses)]`.
+
+use xilem_core::Lenses;
+
+/// Checks that `project` has the signature which `lens` and `map_state` expect.
+fn project<Parent, Child>(
+    parent: &mut Parent,
+    project: impl for<'a> Fn(&'a mut Parent, &'a ()) -> &'a mut Child,
+) -> &mut Child {
+    project(parent, &())
+}
+
+#[derive(Lenses, Default)]
+struct AppState {
+    count: i32,
+    name: String,
+    selected: Option<u32>,
+    #[lens(name = "renamed")]
+    original: bool,
+    #[lens(skip)]
+    #[expect(dead_code, reason = "Only used to check that no lens is generated")]
+    skipped: (),
+    r#type: u8,
+}
+
+#[derive(Lenses)]
+struct Pair<T>(T, Option<T>);
+
+#[derive(Lenses)]
+enum Single {
+    Only(i32),
+}
+
+#[derive(Lenses)]
+enum Page {
+    Home(AppState),
+    UserSettings { name: String },
+    Loading,
+}
+
+#[derive(Lenses)]
+enum Connection {
+    HTTPServer(u16),
+    TLSHandshakeFailed {
+        reason: String,
+    },
+    // `super` can't be a raw identifier, so this needs another name.
+    #[lens(name = "superuser")]
+    Super(u32),
+    Type(u8),
+}
+
+#[test]
+fn struct_fields() {
+    let mut state = AppState::default();
+    *project(&mut state, AppState::count) += 2;
+    project(&mut state, AppState::name).push_str("xilem");
+    *project(&mut state, AppState::renamed) = true;
+    *project(&mut state, AppState::r#type) = 3;
+    assert_eq!(state.count, 2);
+    assert_eq!(state.name, "xilem");
+    assert!(state.original);
+    assert_eq!(state.r#type, 3);
+}
+
+#[test]
+fn option_fields() {
+    let mut state = AppState {
+        selected: Some(1),
+        ..AppState::default()
+    };
+    *project(&mut state, AppState::selected_some) += 1;
+    assert_eq!(state.selected, Some(2));
+    *project(&mut state, AppState::selected) = None;
+    assert_eq!(state.selected, None);
+}
+
+#[test]
+#[should_panic(expected = "`AppState::selected` should be `Some` when this lens is used")]
+fn option_field_none() {
+    let mut state = AppState::default();
+    project(&mut state, AppState::selected_some);
+}
+
+#[test]
+fn tuple_struct() {
+    let mut pair = Pair(1, Some(2));
+    *project(&mut pair, Pair::_0) += 10;
+    *project(&mut pair, Pair::_1_some) += 20;
+    assert_eq!(pair.0, 11);
+    assert_eq!(pair.1, Some(22));
+}
+
+#[test]
+fn enum_variants() {
+    let mut page = Page::Home(AppState::default());
+    project(&mut page, Page::home).count = 5;
+    assert!(matches!(page, Page::Home(AppState { count: 5, .. })));
+
+    let mut page = Page::UserSettings {
+        name: String::new(),
+    };
+    project(&mut page, Page::user_settings).push_str("xilem");
+    assert!(matches!(page, Page::UserSettings { name } if name == "xilem"));
+}
+
+#[test]
+fn single_variant_enum() {
+    let mut single = Single::Only(1);
+    *project(&mut single, Single::only) += 1;
+    assert!(matches!(single, Single::Only(2)));
+}
+
+#[test]
+#[should_panic(expected = "`Page` should be `Home` when this lens is used")]
+fn enum_other_variant() {
+    let mut page = Page::Loading;
+    project(&mut page, Page::home);
+}
+
+#[test]
+fn enum_variant_names() {
+    let mut connection = Connection::HTTPServer(80);
+    *project(&mut connection, Connection::http_server) += 8000;
+    assert!(matches!(connection, Connection::HTTPServer(8080)));
+
+    let mut connection = Connection::TLSHandshakeFailed {
+        reason: String::new(),
+    };
+    project(&mut connection, Connection::tls_handshake_failed).push_str("expired");
+    assert!(matches!(connection, Connection::TLSHandshakeFailed { reason } if reason == "expired"));
+
+    let mut connection = Connection::Super(0);
+    *project(&mut connection, Connection::superuser) = 1;
+    assert!(matches!(connection, Connection::Super(1)));
+
+    let mut connection = Connection::Type(0);
+    *project(&mut connection, Connection::r#type) = 2;
+    assert!(matches!(connection, Connection::Type(2)));
+}
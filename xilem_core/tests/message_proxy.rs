@@ -0,0 +1,198 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for reporting undeliverable [`MessageProxy`] messages.
+
+mod common;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use common::*;
+use xilem_core::{
+    DeliveryFailure, DeliveryFailureHandler, MessageProxy, ProxyError, ProxyMessageId, RawProxy,
+    SendMessage, View, ViewId,
+};
+
+struct QueuedMessage {
+    path: Arc<[ViewId]>,
+    message: SendMessage,
+    on_failure: Option<(ProxyMessageId, DeliveryFailureHandler)>,
+}
+
+/// A proxy which records the messages sent through it.
+///
+/// Once the queue is `None`, the "driver" has finished.
+/// Routing the messages and reporting failures is up to the driver, which is tested in `xilem`.
+struct TestProxy {
+    queue: Mutex<Option<Vec<QueuedMessage>>>,
+}
+
+impl TestProxy {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            queue: Mutex::new(Some(Vec::new())),
+        })
+    }
+
+    fn push(&self, message: QueuedMessage) -> Result<(), ProxyError> {
+        match &mut *self.queue.lock().unwrap() {
+            Some(queue) => {
+                queue.push(message);
+                Ok(())
+            }
+            None => Err(ProxyError::DriverFinished(message.message)),
+        }
+    }
+
+    fn take(&self) -> Vec<QueuedMessage> {
+        std::mem::take(self.queue.lock().unwrap().as_mut().unwrap())
+    }
+}
+
+impl Debug for TestProxy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TestProxy").finish_non_exhaustive()
+    }
+}
+
+impl RawProxy for TestProxy {
+    fn send_message(&self, path: Arc<[ViewId]>, message: SendMessage) -> Result<(), ProxyError> {
+        self.push(QueuedMessage {
+            path,
+            message,
+            on_failure: None,
+        })
+    }
+
+    fn send_tracked_message(
+        &self,
+        path: Arc<[ViewId]>,
+        message: SendMessage,
+        id: ProxyMessageId,
+        on_failure: DeliveryFailureHandler,
+    ) -> Result<(), ProxyError> {
+        self.push(QueuedMessage {
+            path,
+            message,
+            on_failure: Some((id, on_failure)),
+        })
+    }
+
+    fn dyn_debug(&self) -> &dyn Debug {
+        self
+    }
+}
+
+/// A proxy to the child at `index` of `element`, which records its delivery failures.
+fn proxy_to_child(
+    raw: &Arc<TestProxy>,
+    element: &TestElement,
+    index: usize,
+) -> (MessageProxy<u32>, Arc<Mutex<Vec<DeliveryFailure>>>) {
+    let path = element.children.as_ref().unwrap().active[index]
+        .view_path
+        .clone();
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let proxy = MessageProxy::new(raw.clone(), path.into()).on_delivery_failure({
+        let failures = failures.clone();
+        move |failure| failures.lock().unwrap().push(failure)
+    });
+    (proxy, failures)
+}
+
+fn build() -> TestElement {
+    let view = sequence(0, vec![OperationView::<0>(1), OperationView(2)]);
+    let mut ctx = TestCtx::default();
+    let (element, _state) = view.build(&mut ctx, ());
+    ctx.assert_empty();
+    element
+}
+
+#[test]
+fn tracked_messages_carry_their_handler() {
+    let element = build();
+    let raw = TestProxy::new();
+    let (proxy, failures) = proxy_to_child(&raw, &element, 1);
+    let first = proxy.send(10).unwrap();
+    let second = proxy.send(20).unwrap();
+    assert_ne!(first, second);
+
+    let queued = raw.take();
+    assert_eq!(queued.len(), 2);
+    for (queued, (id, value)) in queued.into_iter().zip([(first, 10), (second, 20)]) {
+        assert_eq!(
+            &*queued.path,
+            &*element.children.as_ref().unwrap().active[1].view_path
+        );
+        assert_eq!(*queued.message.downcast::<u32>().unwrap(), value);
+        let (queued_id, on_failure) = queued.on_failure.unwrap();
+        assert_eq!(queued_id, id);
+        // Calling the handler is how a driver reports the failure.
+        on_failure(DeliveryFailure {
+            id: queued_id,
+            path: queued.path,
+        });
+    }
+    let failures = failures.lock().unwrap();
+    assert_eq!(
+        failures
+            .iter()
+            .map(|failure| failure.id)
+            .collect::<Vec<_>>(),
+        [first, second]
+    );
+}
+
+#[test]
+fn untracked_messages_have_no_handler() {
+    let element = build();
+    let raw = TestProxy::new();
+    let path = element.children.as_ref().unwrap().active[1]
+        .view_path
+        .clone();
+    let proxy = MessageProxy::<u32>::new(raw.clone(), path.into());
+    proxy.message(1).unwrap();
+
+    let queued = raw.take();
+    assert_eq!(queued.len(), 1);
+    assert!(queued[0].on_failure.is_none());
+}
+
+/// A proxy which only implements [`RawProxy::send_message`].
+#[derive(Debug, Default)]
+struct UntrackedProxy {
+    sent: Mutex<Vec<Arc<[ViewId]>>>,
+}
+
+impl RawProxy for UntrackedProxy {
+    fn send_message(&self, path: Arc<[ViewId]>, _: SendMessage) -> Result<(), ProxyError> {
+        self.sent.lock().unwrap().push(path);
+        Ok(())
+    }
+
+    fn dyn_debug(&self) -> &dyn Debug {
+        self
+    }
+}
+
+#[test]
+fn tracking_falls_back_to_send_message() {
+    let raw = Arc::new(UntrackedProxy::default());
+    let path: Arc<[ViewId]> = [ViewId::new(1)].into();
+    let proxy = MessageProxy::<u32>::new(raw.clone(), path.clone())
+        .on_delivery_failure(|_| panic!("The default implementation doesn't report failures"));
+    proxy.send(1).unwrap();
+    assert_eq!(*raw.sent.lock().unwrap(), [path]);
+}
+
+#[test]
+fn finished_driver_errors_synchronously() {
+    let element = build();
+    let raw = TestProxy::new();
+    let (proxy, failures) = proxy_to_child(&raw, &element, 0);
+    *raw.queue.lock().unwrap() = None;
+
+    let result = proxy.send(1);
+    assert!(matches!(result, Err(ProxyError::DriverFinished(_))));
+    assert!(failures.lock().unwrap().is_empty());
+}
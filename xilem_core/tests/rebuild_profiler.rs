@@ -0,0 +1,108 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for [`RebuildProfiler`].
+
+mod common;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use common::*;
+use xilem_core::{RebuildProfiler, View, ViewPathTracker, memoize};
+
+/// A profiler whose clock advances by a millisecond each time it's read.
+fn profiler() -> RebuildProfiler {
+    let now = Rc::new(Cell::new(Duration::ZERO));
+    RebuildProfiler::new(move || {
+        let time = now.get();
+        now.set(time + Duration::from_millis(1));
+        time
+    })
+}
+
+#[test]
+fn counts_rebuilds_and_skips() {
+    let view = |count: u32| {
+        sequence(
+            0,
+            (
+                OperationView::<0>(count),
+                memoize(7, |data| OperationView::<1>(*data)),
+            ),
+        )
+    };
+    let mut ctx = TestCtx::default();
+    let v1 = view(1);
+    let (mut element, mut state) = v1.build(&mut ctx, ());
+    ctx.environment().set_rebuild_profiler(Some(profiler()));
+
+    let v2 = view(2);
+    v2.rebuild(&v1, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+
+    let summary = ctx.environment().rebuild_profiler().unwrap().take_summary();
+    let operation = summary.get(core::any::type_name::<OperationView<0>>());
+    assert_eq!(operation.map(|stats| stats.rebuilds), Some(1));
+    assert_eq!(operation.map(|stats| stats.skipped), Some(0));
+    // The memoized view wasn't rebuilt, as its data didn't change.
+    let memoized = summary
+        .get(core::any::type_name::<OperationView<1>>())
+        .unwrap();
+    assert_eq!(memoized.rebuilds, 0);
+    assert_eq!(memoized.skipped, 1);
+    assert_eq!(summary.get("Memoize").map(|stats| stats.rebuilds), Some(1));
+    assert_eq!(summary.total_rebuilds(), 2);
+    assert_eq!(summary.total_skipped(), 1);
+
+    // The summary is reset after each cycle.
+    let summary = ctx.environment().rebuild_profiler().unwrap().take_summary();
+    assert!(summary.is_empty());
+}
+
+#[test]
+fn nested_time_is_excluded() {
+    let view = |count: u32| {
+        sequence(
+            0,
+            (memoize(count, |count| {
+                sequence(1, (OperationView::<0>(*count), OperationView::<0>(*count)))
+            }),),
+        )
+    };
+    let mut ctx = TestCtx::default();
+    let v1 = view(1);
+    let (mut element, mut state) = v1.build(&mut ctx, ());
+    ctx.environment().set_rebuild_profiler(Some(profiler()));
+
+    let v2 = view(2);
+    v2.rebuild(&v1, &mut state, &mut ctx, &mut element, ());
+    ctx.assert_empty();
+
+    let summary = ctx.environment().rebuild_profiler().unwrap().take_summary();
+    // Each read of the clock takes a millisecond, and each recorded rebuild reads it twice.
+    let operation = summary.get("OperationView").unwrap();
+    assert_eq!(operation.rebuilds, 2);
+    assert_eq!(operation.total_time, Duration::from_millis(2));
+    assert_eq!(operation.self_time, Duration::from_millis(2));
+    // The inner sequence's own time is that of the clock reads between its children.
+    let inner_sequence = summary.get("SequenceView").unwrap();
+    assert_eq!(inner_sequence.rebuilds, 1);
+    assert_eq!(inner_sequence.total_time, Duration::from_millis(5));
+    assert_eq!(inner_sequence.self_time, Duration::from_millis(3));
+    let memoize = summary.get("Memoize").unwrap();
+    assert_eq!(memoize.total_time, Duration::from_millis(7));
+    assert_eq!(memoize.self_time, Duration::from_millis(2));
+    // The views with the most time of their own come first.
+    assert_eq!(summary.views[0].short_type_name(), "SequenceView");
+    assert_eq!(summary.views[1].short_type_name(), "OperationView");
+}
+
+#[test]
+fn disabled_by_default() {
+    let view = sequence(0, (OperationView::<0>(1),));
+    let mut ctx = TestCtx::default();
+    let (mut element, mut state) = view.build(&mut ctx, ());
+    view.rebuild(&view, &mut state, &mut ctx, &mut element, ());
+    assert!(ctx.environment().rebuild_profiler().is_none());
+}
@@ -0,0 +1,61 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests that recording rebuilds is cheap when there is no [`RebuildProfiler`].
+//!
+//! This is separate from the other profiler tests, as whether profiling is
+//! enabled is tracked for the whole process.
+
+use std::time::Duration;
+
+use xilem_core::{
+    Environment, RebuildProfiler, ViewId, ViewPathTracker, profile_rebuild, record_skipped_rebuild,
+};
+
+/// A context which counts how often its environment is accessed.
+#[derive(Default)]
+struct CountingCtx {
+    path: Vec<ViewId>,
+    environment: Environment,
+    lookups: usize,
+}
+
+impl ViewPathTracker for CountingCtx {
+    fn environment(&mut self) -> &mut Environment {
+        self.lookups += 1;
+        &mut self.environment
+    }
+    fn push_id(&mut self, id: ViewId) {
+        self.path.push(id);
+    }
+    fn pop_id(&mut self) {
+        self.path.pop();
+    }
+    fn view_path(&mut self) -> &[ViewId] {
+        &self.path
+    }
+}
+
+#[test]
+fn no_lookups_without_profiler() {
+    let mut ctx = CountingCtx::default();
+    profile_rebuild::<u32, _, _>(&mut ctx, |_| {});
+    record_skipped_rebuild::<u32>(&mut ctx);
+    assert_eq!(ctx.lookups, 0);
+
+    ctx.environment
+        .set_rebuild_profiler(Some(RebuildProfiler::new(|| Duration::ZERO)));
+    profile_rebuild::<u32, _, _>(&mut ctx, |_| {});
+    record_skipped_rebuild::<u32>(&mut ctx);
+    assert_ne!(ctx.lookups, 0);
+    let summary = ctx.environment.rebuild_profiler().unwrap().take_summary();
+    assert_eq!(summary.views.len(), 1);
+    assert_eq!(summary.views[0].rebuilds, 1);
+    assert_eq!(summary.views[0].skipped, 1);
+
+    // Once the profiler is removed, the environment isn't accessed anymore.
+    ctx.environment.set_rebuild_profiler(None);
+    ctx.lookups = 0;
+    profile_rebuild::<u32, _, _>(&mut ctx, |_| {});
+    assert_eq!(ctx.lookups, 0);
+}
@@ -0,0 +1,299 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DeriveInput, Fields, GenericArgument, Ident, LitStr, PathArguments, Type,
+    Visibility,
+};
+
+/// The options set by `#[lens(...)]` attributes on a field or variant.
+#[derive(Default)]
+struct LensOptions {
+    skip: bool,
+    name: Option<Ident>,
+}
+
+impl LensOptions {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("lens")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    options.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("name") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    options.name = Some(to_ident(&name.value(), &name)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip` or `name = \"...\"`"))
+                }
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+/// Creates an identifier for `name`, escaping it if it's a keyword.
+///
+/// Errors point at `origin`, which is where the name comes from.
+fn to_ident(name: &str, origin: &impl ToTokens) -> syn::Result<Ident> {
+    let span = origin.span();
+    if syn::parse_str::<Ident>(name).is_ok() {
+        Ok(Ident::new(name, span))
+    } else if syn::parse_str::<Ident>(&format!("r#{name}")).is_ok() {
+        Ok(Ident::new_raw(name, span))
+    } else {
+        // Names such as `1x`, and the keywords which can't be raw identifiers, such as `super`.
+        Err(syn::Error::new_spanned(
+            origin,
+            format!(
+                "`{name}` can't be the name of a lens; choose another with `#[lens(name = \"...\")]`"
+            ),
+        ))
+    }
+}
+
+/// Converts a `CamelCase` variant name to `snake_case`.
+///
+/// Acronyms are kept together, so `HTTPServer` becomes `http_server`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    let mut chars = name.chars().peekable();
+    let mut prev: Option<char> = None;
+    while let Some(c) = chars.next() {
+        if c.is_uppercase() {
+            let word_start = match prev {
+                Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+                // The last letter of an acronym followed by a word, like the `S` of `HTTPServer`.
+                Some(prev) if prev.is_uppercase() => chars.peek().is_some_and(|c| c.is_lowercase()),
+                _ => false,
+            };
+            if word_start {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+        prev = Some(c);
+    }
+    snake
+}
+
+/// Returns `T` if `ty` is syntactically `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// A single generated projection function.
+fn projection(
+    vis: &Visibility,
+    name: &Ident,
+    doc: &str,
+    target: &Type,
+    body: TokenStream,
+) -> TokenStream {
+    quote! {
+        #[doc = #doc]
+        #vis fn #name<'lens>(state: &'lens mut Self, (): &'lens ()) -> &'lens mut #target {
+            #body
+        }
+    }
+}
+
+pub(crate) fn derive_lenses_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let ty = &input.ident;
+    let mut functions = Vec::new();
+
+    match &input.data {
+        Data::Struct(data) => {
+            for (index, field) in data.fields.iter().enumerate() {
+                let options = LensOptions::parse(&field.attrs)?;
+                if options.skip {
+                    continue;
+                }
+                let member = match &field.ident {
+                    Some(ident) => quote!(#ident),
+                    None => {
+                        let index = syn::Index::from(index);
+                        quote!(#index)
+                    }
+                };
+                let field_name = match &field.ident {
+                    Some(ident) => ident.to_string().trim_start_matches("r#").to_owned(),
+                    None => format!("{index}"),
+                };
+                let name = options.name.unwrap_or_else(|| match &field.ident {
+                    Some(ident) => ident.clone(),
+                    None => format_ident!("_{index}"),
+                });
+
+                let doc = format!(" Projects a `{ty}` into its `{field_name}` field.");
+                functions.push(projection(
+                    &field.vis,
+                    &name,
+                    &doc,
+                    &field.ty,
+                    quote!(&mut state.#member),
+                ));
+
+                if let Some(inner) = option_inner(&field.ty) {
+                    let some_name =
+                        format_ident!("{}_some", name.to_string().trim_start_matches("r#"));
+                    let doc = format!(
+                        " Projects a `{ty}` into the value in its `{field_name}` field.\n\n # Panics\n\n If `{field_name}` is `None`."
+                    );
+                    let message =
+                        format!("`{ty}::{field_name}` should be `Some` when this lens is used");
+                    functions.push(projection(
+                        &field.vis,
+                        &some_name,
+                        &doc,
+                        inner,
+                        quote!(state.#member.as_mut().expect(#message)),
+                    ));
+                }
+            }
+        }
+        Data::Enum(data) => {
+            for variant in &data.variants {
+                let options = LensOptions::parse(&variant.attrs)?;
+                if options.skip {
+                    continue;
+                }
+                let (field_ty, pattern) = match &variant.fields {
+                    Fields::Named(fields) if fields.named.len() == 1 => {
+                        let field = &fields.named[0];
+                        let ident = field.ident.as_ref().unwrap();
+                        (&field.ty, quote!({ #ident: value }))
+                    }
+                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        (&fields.unnamed[0].ty, quote!((value)))
+                    }
+                    _ => {
+                        if options.name.is_some() {
+                            return Err(syn::Error::new_spanned(
+                                &variant.ident,
+                                "lenses can only be generated for variants with exactly one field",
+                            ));
+                        }
+                        continue;
+                    }
+                };
+                let variant_ident = &variant.ident;
+                let name = match options.name {
+                    Some(name) => name,
+                    None => to_ident(&snake_case(&variant_ident.to_string()), variant_ident)?,
+                };
+                let doc = format!(
+                    " Projects a `{ty}` into the value in its `{variant_ident}` variant.\n\n # Panics\n\n If the value is a different variant."
+                );
+                let message = format!("`{ty}` should be `{variant_ident}` when this lens is used");
+                // A wildcard arm would be unreachable if this is the only variant.
+                let other_variants =
+                    (data.variants.len() > 1).then(|| quote!(_ => panic!(#message),));
+                functions.push(projection(
+                    &input.vis,
+                    &name,
+                    &doc,
+                    field_ty,
+                    quote! {
+                        match state {
+                            Self::#variant_ident #pattern => value,
+                            #other_variants
+                        }
+                    },
+                ));
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "`Lenses` can't be derived for unions",
+            ));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        #[allow(dead_code, reason = "Not every lens is used")]
+        impl #impl_generics #ty #ty_generics #where_clause {
+            #(#functions)*
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snake_case_splits_acronyms() {
+        assert_eq!(snake_case("Home"), "home");
+        assert_eq!(snake_case("UserSettings"), "user_settings");
+        assert_eq!(snake_case("HTTPServer"), "http_server");
+        assert_eq!(snake_case("ParseURL"), "parse_url");
+        assert_eq!(snake_case("IOError2"), "io_error2");
+        assert_eq!(snake_case("Version2Beta"), "version2_beta");
+    }
+
+    #[test]
+    fn invalid_names_are_errors() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Relation {
+                Super(u8),
+                Sub(u8),
+            }
+        };
+        let err = derive_lenses_impl(&input).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("`super` can't be the name of a lens")
+        );
+
+        for name in ["1x", "Self", "crate", ""] {
+            let input: DeriveInput = syn::parse_quote! {
+                struct State {
+                    #[lens(name = #name)]
+                    value: u8,
+                }
+            };
+            let err = derive_lenses_impl(&input).unwrap_err();
+            assert!(
+                err.to_string()
+                    .starts_with(&format!("`{name}` can't be the name of a lens")),
+                "{err}"
+            );
+        }
+
+        // Other keywords are escaped.
+        let input: DeriveInput = syn::parse_quote! {
+            enum Token {
+                Type(u8),
+            }
+        };
+        let lenses = derive_lenses_impl(&input).unwrap().to_string();
+        assert!(lenses.contains("fn r#type"), "{lenses}");
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Derive macros for Xilem Core.
+//!
+//! This crate is re-exported by `xilem_core`, and shouldn't be used directly.
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+mod lenses;
+
+/// Generates functions which project a mutable reference to a type into one of its parts.
+///
+/// These functions have the signature expected by `lens` and `map_state`,
+/// so that a component can be given part of the app's state without writing a closure.
+///
+/// For structs, an associated function with the same name is generated for each field.
+/// Fields of tuple structs are named after their index, such as `_0`.
+/// Fields with an `Option` type also get a function with a `_some` suffix,
+/// which projects into the contained value.
+///
+/// For enums, an associated function is generated for each variant which has exactly one field,
+/// named after the variant in `snake_case` (so `HTTPServer` becomes `http_server`).
+/// Variants whose name would be a keyword which can't be escaped, such as `Super`,
+/// must be given another name with `#[lens(name = "...")]`.
+///
+/// The projections into an `Option` or an enum variant panic if the value doesn't
+/// match, so they should only be used for views which are only created when it does
+/// (for instance, in the `Some` branch of a `match` in the app logic).
+///
+/// The generated functions have the same visibility as the field they project into,
+/// or as the enum for variants.
+///
+/// # Attributes
+///
+/// - `#[lens(skip)]` on a field or variant doesn't generate any functions for it.
+/// - `#[lens(name = "other_name")]` on a field or variant renames the generated function.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_core::docs::{DocsView as WidgetView, some_component_generic};
+/// use xilem_core::{Edit, Lenses, lens};
+///
+/// #[derive(Lenses)]
+/// struct AppState {
+///     count: i32,
+///     selected: Option<u32>,
+/// }
+///
+/// fn counter(count: &mut i32) -> impl WidgetView<Edit<i32>> + use<> {
+///     // ...
+/// # some_component_generic(count)
+/// }
+///
+/// fn selection(selected: &mut u32) -> impl WidgetView<Edit<u32>> + use<> {
+///     // ...
+/// # some_component_generic(selected)
+/// }
+///
+/// fn counter_logic(state: &mut AppState) -> impl WidgetView<Edit<AppState>> + use<> {
+///     lens(counter, AppState::count)
+/// }
+///
+/// fn selection_logic(state: &mut AppState) -> Option<impl WidgetView<Edit<AppState>> + use<>> {
+///     // `AppState::selected_some` panics if `selected` is `None`.
+///     state.selected.is_some().then(|| lens(selection, AppState::selected_some))
+/// }
+/// ```
+#[proc_macro_derive(Lenses, attributes(lens))]
+pub fn derive_lenses(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    lenses::derive_lenses_impl(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,198 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::animation::{Animation, Interpolate, Tween};
+use masonry::properties::{Dimensions, Transition};
+use masonry::widgets::{self, AnimationFrame};
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A view which animates a value towards `target` whenever `target` changes.
+///
+/// On every animation frame until the value reaches `target`,
+/// `on_frame` is called with the app's state and the current value.
+/// It usually stores the value in the app state, which causes a rebuild.
+/// The value moves according to `transition`, starting from its value when `target` changed.
+///
+/// The value isn't animated when the view is first built: it starts at `target`,
+/// and `on_frame` isn't called until `target` changes.
+///
+/// See the documentation on the underlying [`Animator`](widgets::Animator) for more information.
+///
+/// # Example
+///
+/// A panel which slides in when opened:
+///
+/// ```rust,no_run
+/// # use xilem_masonry as xilem;
+/// # use xilem::{WidgetView, core::Edit, view::{animate, label, transformed}};
+/// # use xilem::masonry::{kurbo::Vec2, properties::Transition};
+///
+/// struct State {
+///     panel_open: bool,
+///     panel_offset: f64,
+/// }
+///
+/// # fn my_component(state: &mut State) -> impl WidgetView<Edit<State>> {
+/// animate(
+///     if state.panel_open { 0. } else { -300. },
+///     Transition::ms(250),
+///     |state: &mut State, offset| state.panel_offset = offset,
+///     transformed(label("Panel")).translate(Vec2::new(state.panel_offset, 0.)),
+/// )
+/// # }
+/// ```
+pub fn animate<State, Action, V, T, F>(
+    target: T,
+    transition: Transition,
+    on_frame: F,
+    inner: V,
+) -> Animate<V, T, F, State, Action>
+where
+    V: WidgetView<State, Action>,
+    T: Interpolate + Clone + PartialEq + 'static,
+    F: Fn(Arg<'_, State>, T) -> Action,
+    State: ViewArgument,
+    Animate<V, T, F, State, Action>: WidgetView<State, Action>,
+{
+    Animate {
+        inner,
+        target,
+        transition,
+        on_frame,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`animate`].
+///
+/// See `animate` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Animate<V, T, F, State, Action = ()> {
+    inner: V,
+    target: T,
+    transition: Transition,
+    on_frame: F,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1360298711 in decimal.
+const ANIMATE_CONTENT_VIEW_ID: ViewId = ViewId::new(0x511455d7);
+
+impl<V, T, F, State, Action> ViewMarker for Animate<V, T, F, State, Action> {}
+impl<V, T, F, State, Action> View<State, Action, ViewCtx> for Animate<V, T, F, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+    T: Interpolate + Clone + PartialEq + 'static,
+    F: Fn(Arg<'_, State>, T) -> Action + 'static,
+{
+    type Element = Pod<widgets::Animator>;
+    type ViewState = (Tween<T>, V::ViewState);
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(ANIMATE_CONTENT_VIEW_ID, |ctx| {
+            self.inner.build(ctx, app_state)
+        });
+        let tween = Tween::stable(self.target.clone()).with_easing(self.transition.easing);
+        (
+            ctx.with_action_widget(|_| {
+                let widget = widgets::Animator::new(child.new_widget);
+                Pod::new_with_props(widget, Dimensions::MAX)
+            }),
+            (tween, child_state),
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (tween, view_state): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if self.target != prev.target {
+            tween.set_easing(self.transition.easing);
+            tween.move_to(self.target.clone(), self.transition.duration);
+            // We start even if the duration is zero, so that `on_frame` gets the new value.
+            widgets::Animator::start(&mut element);
+        }
+        ctx.with_id(ANIMATE_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::rebuild(
+                &self.inner,
+                &prev.inner,
+                view_state,
+                ctx,
+                widgets::Animator::child_mut(&mut element).downcast(),
+                app_state,
+            );
+        });
+    }
+
+    fn teardown(
+        &self,
+        (_, view_state): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(ANIMATE_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::teardown(
+                &self.inner,
+                view_state,
+                ctx,
+                widgets::Animator::child_mut(&mut element).downcast(),
+            );
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (tween, view_state): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(ANIMATE_CONTENT_VIEW_ID) => self.inner.message(
+                view_state,
+                message,
+                widgets::Animator::child_mut(&mut element).downcast(),
+                State::reborrow_mut(&mut app_state),
+            ),
+            None => match message.take_message::<AnimationFrame>() {
+                Some(frame) => {
+                    let status = tween.advance(frame.elapsed_ns);
+                    if status.is_completed() {
+                        widgets::Animator::stop(&mut element);
+                    }
+                    MessageResult::Action((self.on_frame)(app_state, tween.value()))
+                }
+                None => {
+                    tracing::error!(
+                        "Wrong message type in Animate::message: {message:?} expected {}",
+                        type_name::<AnimationFrame>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(?message, "Got unexpected id path in `Animate::message`.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
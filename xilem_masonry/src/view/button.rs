@@ -4,8 +4,8 @@
 use std::any::type_name;
 use std::marker::PhantomData;
 
-use masonry::core::ArcStr;
 pub use masonry::core::PointerButton;
+use masonry::core::{ArcStr, RichText};
 use masonry::widgets::{self, ButtonPress};
 
 use crate::core::{
@@ -114,7 +114,7 @@ pub fn text_button<State: ViewArgument, Action>(
     + 'static,
     Label,
 > {
-    button(label(text), callback)
+    button(label(RichText::new(text)), callback)
 }
 
 /// A button which calls `callback` when pressed with any mouse button, providing
@@ -0,0 +1,192 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::any::type_name;
+use std::marker::PhantomData;
+
+use masonry::layers::{MenuItem, MenuItemSelected};
+use masonry::widgets;
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A view which opens a context menu with the given `items` for its child.
+///
+/// The menu is opened with a secondary click on the child, or with the `ContextMenu` key
+/// or `Shift+F10` while the child has focus.
+/// `on_select` is called with the app's state and the [`MenuItemSelected`] describing
+/// which item was chosen.
+///
+/// See the documentation on the underlying [`ContextMenuArea`](widgets::ContextMenuArea)
+/// for more information.
+///
+/// # Example
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::layers::MenuItem;
+/// use xilem::view::{context_menu, label};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     word_wrap: bool,
+///     copies: u32,
+/// }
+///
+/// # fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+/// let items = [
+///     MenuItem::new("Copy"),
+///     MenuItem::separator(),
+///     MenuItem::checkable("Word wrap", state.word_wrap),
+/// ];
+/// context_menu(label("Right-click me"), items, |state: &mut State, selected| {
+///     match selected.path[..] {
+///         [0] => state.copies += 1,
+///         [2] => state.word_wrap = selected.checked.unwrap_or_default(),
+///         _ => {}
+///     }
+/// })
+/// # }
+/// ```
+pub fn context_menu<State, Action, V, F>(
+    inner: V,
+    items: impl IntoIterator<Item = MenuItem>,
+    on_select: F,
+) -> ContextMenu<V, F, State, Action>
+where
+    V: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>, MenuItemSelected) -> Action,
+    State: ViewArgument,
+    ContextMenu<V, F, State, Action>: WidgetView<State, Action>,
+{
+    ContextMenu {
+        inner,
+        items: items.into_iter().collect(),
+        on_select,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`context_menu`].
+///
+/// See `context_menu` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct ContextMenu<V, F, State, Action = ()> {
+    inner: V,
+    items: Vec<MenuItem>,
+    on_select: F,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1307894221 in decimal.
+const CONTEXT_MENU_CONTENT_VIEW_ID: ViewId = ViewId::new(0x4df4d1cd);
+
+impl<V, F, State, Action> ViewMarker for ContextMenu<V, F, State, Action> {}
+impl<V, F, State, Action> View<State, Action, ViewCtx> for ContextMenu<V, F, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: 'static,
+    V: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>, MenuItemSelected) -> Action,
+{
+    type Element = Pod<widgets::ContextMenuArea>;
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(CONTEXT_MENU_CONTENT_VIEW_ID, |ctx| {
+            self.inner.build(ctx, app_state)
+        });
+        (
+            ctx.with_action_widget(|ctx| {
+                ctx.create_pod(widgets::ContextMenuArea::new(
+                    child.new_widget,
+                    self.items.iter().cloned(),
+                ))
+            }),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        if prev.items != self.items {
+            widgets::ContextMenuArea::set_items(&mut element, self.items.iter().cloned());
+        }
+        ctx.with_id(CONTEXT_MENU_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::rebuild(
+                &self.inner,
+                &prev.inner,
+                view_state,
+                ctx,
+                widgets::ContextMenuArea::child_mut(&mut element).downcast(),
+                app_state,
+            );
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(CONTEXT_MENU_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::teardown(
+                &self.inner,
+                view_state,
+                ctx,
+                widgets::ContextMenuArea::child_mut(&mut element).downcast(),
+            );
+        });
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(CONTEXT_MENU_CONTENT_VIEW_ID) => self.inner.message(
+                view_state,
+                message,
+                widgets::ContextMenuArea::child_mut(&mut element).downcast(),
+                State::reborrow_mut(&mut app_state),
+            ),
+            None => match message.take_message::<MenuItemSelected>() {
+                Some(selected) => MessageResult::Action((self.on_select)(app_state, *selected)),
+                None => {
+                    tracing::error!(
+                        "Wrong message type in ContextMenu::message: {message:?} expected {}",
+                        type_name::<MenuItemSelected>()
+                    );
+                    MessageResult::Stale
+                }
+            },
+            _ => {
+                tracing::warn!(
+                    ?message,
+                    "Got unexpected id path in `ContextMenu::message`."
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::ArcStr;
+use masonry::widgets::{self, DropdownSelected};
+
+use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+use crate::{Pod, ViewCtx};
+
+/// An element which shows the `selected` option out of `options`,
+/// and lets the user pick another one from a popup list.
+///
+/// `on_select` is called with the index of the chosen option.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::dropdown;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     fruit: usize,
+/// }
+///
+/// # fn view(app_state: &mut State) -> impl WidgetView<Edit<State>> {
+/// let fruits = ["Apple", "Banana", "Cherry"];
+/// dropdown(Some(app_state.fruit), fruits, |app_state: &mut State, idx: usize| {
+///     app_state.fruit = idx;
+/// })
+/// # }
+/// ```
+pub fn dropdown<F, State, Action>(
+    selected: Option<usize>,
+    options: impl IntoIterator<Item = impl Into<ArcStr>>,
+    on_select: F,
+) -> Dropdown<State, Action, F>
+where
+    F: Fn(Arg<'_, State>, usize) -> Action + Send + 'static,
+    State: ViewArgument,
+{
+    Dropdown {
+        selected,
+        options: options.into_iter().map(Into::into).collect(),
+        placeholder: ArcStr::from(""),
+        on_select,
+        disabled: false,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`dropdown`].
+///
+/// See `dropdown` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Dropdown<State, Action, F> {
+    selected: Option<usize>,
+    options: Vec<ArcStr>,
+    placeholder: ArcStr,
+    on_select: F,
+    disabled: bool,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> Dropdown<State, Action, F> {
+    /// Sets the text shown when no option is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<ArcStr>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the disabled state of the widget.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl<State, Action, F> ViewMarker for Dropdown<State, Action, F> {}
+impl<F, State, Action> View<State, Action, ViewCtx> for Dropdown<State, Action, F>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: Fn(Arg<'_, State>, usize) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::Dropdown>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let element = ctx.with_action_widget(|ctx| {
+            let mut pod = ctx.create_pod(
+                widgets::Dropdown::new(self.options.iter().cloned(), self.selected)
+                    .with_placeholder(self.placeholder.clone()),
+            );
+            pod.new_widget.options.disabled = self.disabled;
+            pod
+        });
+        (element, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.disabled != self.disabled {
+            element.ctx.set_disabled(self.disabled);
+        }
+        if prev.options != self.options {
+            widgets::Dropdown::set_options(&mut element, self.options.iter().cloned());
+        }
+        if prev.placeholder != self.placeholder {
+            widgets::Dropdown::set_placeholder(&mut element, self.placeholder.clone());
+        }
+        if prev.selected != self.selected {
+            widgets::Dropdown::set_selected(&mut element, self.selected);
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        _element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        debug_assert!(
+            message.remaining_path().is_empty(),
+            "id path should be empty in Dropdown::message"
+        );
+        match message.take_message::<DropdownSelected>() {
+            Some(selected) => MessageResult::Action((self.on_select)(app_state, selected.0)),
+            None => {
+                tracing::error!("Wrong message type in Dropdown::message, got {message:?}.");
+                MessageResult::Stale
+            }
+        }
+    }
+}
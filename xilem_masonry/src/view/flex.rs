@@ -414,6 +414,13 @@ impl ElementSplice<FlexElement> for FlexSplice<'_, '_> {
         ret
     }
 
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        if offset > 0 {
+            widgets::Flex::move_child(&mut self.element, self.idx + offset, self.idx);
+        }
+        true
+    }
+
     fn skip(&mut self, n: usize) {
         self.idx += n;
     }
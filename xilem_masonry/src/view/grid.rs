@@ -282,6 +282,13 @@ impl ElementSplice<GridElement> for GridSplice<'_, '_> {
         widgets::Grid::remove(&mut self.element, self.idx);
         ret
     }
+
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        if offset > 0 {
+            widgets::Grid::move_child(&mut self.element, self.idx + offset, self.idx);
+        }
+        true
+    }
 }
 
 /// `GridSequence` is what allows an input to the grid that contains all the grid elements.
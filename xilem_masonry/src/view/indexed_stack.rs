@@ -283,6 +283,13 @@ impl ElementSplice<IndexedStackElement> for IndexedStackSplice<'_, '_> {
         widgets::IndexedStack::remove(&mut self.element, self.idx);
         ret
     }
+
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        if offset > 0 {
+            widgets::IndexedStack::move_child(&mut self.element, self.idx + offset, self.idx);
+        }
+        true
+    }
 }
 
 /// `IndexedStackSequence` is what allows an input to the indexed stack that contains all the stack elements.
@@ -1,7 +1,7 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use masonry::core::{ArcStr, StyleProperty};
+use masonry::core::{RichText, StyleProperty};
 use masonry::parley::style::{FontStack, FontWeight};
 use masonry::parley::{FontFamily, GenericFamily};
 use masonry::widgets;
@@ -10,6 +10,12 @@ use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewM
 use crate::{Pod, TextAlign, ViewCtx};
 
 /// A non-interactive text element.
+///
+/// The text can be [rich text](RichText), with styles applied to ranges of it.
+/// Links in the text are shown, but clicking them does nothing;
+/// use [`prose`](super::prose) with [`on_link`](super::Prose::on_link) for text with
+/// working links.
+///
 /// # Example
 ///
 /// ```
@@ -32,7 +38,7 @@ use crate::{Pod, TextAlign, ViewCtx};
 ///     .color(palette::css::RED)
 /// # }
 /// ```
-pub fn label(label: impl Into<ArcStr>) -> Label {
+pub fn label(label: impl Into<RichText>) -> Label {
     Label {
         label: label.into(),
         text_alignment: TextAlign::default(),
@@ -42,12 +48,12 @@ pub fn label(label: impl Into<ArcStr>) -> Label {
     }
 }
 
-/// The [`View`] created by [`label`] from a text which `impl Into<`[`ArcStr`]`>`.
+/// The [`View`] created by [`label`] from a text which `impl Into<`[`RichText`]`>`.
 ///
 /// See `label` documentation for more context.
 #[must_use = "View values do nothing unless provided to Xilem."]
 pub struct Label {
-    label: ArcStr,
+    label: RichText,
     text_alignment: TextAlign,
     text_size: f32,
     weight: FontWeight,
@@ -87,7 +93,7 @@ impl Label {
 
 impl<T> From<T> for Label
 where
-    T: Into<ArcStr>,
+    T: Into<RichText>,
 {
     fn from(text: T) -> Self {
         label(text)
@@ -101,12 +107,16 @@ impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for Label {
 
     fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
         let pod = ctx.create_pod(
-            widgets::Label::new(self.label.clone())
+            widgets::Label::from_rich_text(self.label.clone())
                 .with_text_alignment(self.text_alignment)
                 .with_style(StyleProperty::FontSize(self.text_size))
                 .with_style(StyleProperty::FontWeight(self.weight))
                 .with_style(StyleProperty::FontStack(self.font.clone())),
         );
+        if self.label.has_links() {
+            // Clicks on links are ignored, but they must still be routed to this view.
+            ctx.record_action_source(pod.new_widget.id());
+        }
         (pod, ())
     }
 
@@ -114,12 +124,17 @@ impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for Label {
         &self,
         prev: &Self,
         (): &mut Self::ViewState,
-        _ctx: &mut ViewCtx,
+        ctx: &mut ViewCtx,
         mut element: Mut<'_, Self::Element>,
         _: Arg<'_, State>,
     ) {
         if prev.label != self.label {
-            widgets::Label::set_text(&mut element, self.label.clone());
+            widgets::Label::set_rich_text(&mut element, self.label.clone());
+            match (prev.label.has_links(), self.label.has_links()) {
+                (false, true) => ctx.record_action_source(element.ctx.widget_id()),
+                (true, false) => ctx.teardown_action_source(element.reborrow_mut()),
+                _ => {}
+            }
         }
         if prev.text_alignment != self.text_alignment {
             widgets::Label::set_text_alignment(&mut element, self.text_alignment);
@@ -135,7 +150,16 @@ impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for Label {
         }
     }
 
-    fn teardown(&self, (): &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {}
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        element: Mut<'_, Self::Element>,
+    ) {
+        if self.label.has_links() {
+            ctx.teardown_action_source(element);
+        }
+    }
 
     fn message(
         &self,
@@ -144,6 +168,9 @@ impl<State: ViewArgument, Action> View<State, Action, ViewCtx> for Label {
         _element: Mut<'_, Self::Element>,
         _app_state: Arg<'_, State>,
     ) -> MessageResult<Action> {
+        if message.take_message::<widgets::LinkClicked>().is_some() {
+            return MessageResult::Nop;
+        }
         tracing::error!(
             ?message,
             "Message arrived in Label::message, but Label doesn't consume any messages, this is a bug"
@@ -3,53 +3,67 @@
 
 //! Views for the widgets which are built-in to Masonry. These are the primitives your Xilem app's view tree will generally be constructed from.
 
+mod animate;
 mod button;
 mod canvas;
 mod checkbox;
+mod context_menu;
+mod dropdown;
 mod flex;
 mod grid;
 mod image;
 mod indexed_stack;
 mod label;
+mod number_input;
 mod portal;
 mod progress_bar;
 mod prop;
 mod prose;
 mod resize_observer;
+mod selection_scope;
 mod sized_box;
 mod slider;
 mod spinner;
 mod split;
 mod switch;
+mod table;
 mod task;
 mod text_input;
 mod transform;
+mod tree_view;
 mod variable_label;
 mod virtual_scroll;
 mod worker;
 mod zstack;
 
+pub use self::animate::*;
 pub use self::button::*;
 pub use self::canvas::*;
 pub use self::checkbox::*;
+pub use self::context_menu::*;
+pub use self::dropdown::*;
 pub use self::flex::*;
 pub use self::grid::*;
 pub use self::image::*;
 pub use self::indexed_stack::*;
 pub use self::label::*;
+pub use self::number_input::*;
 pub use self::portal::*;
 pub use self::progress_bar::*;
 pub use self::prop::*;
 pub use self::prose::*;
 pub use self::resize_observer::*;
+pub use self::selection_scope::*;
 pub use self::sized_box::*;
 pub use self::slider::*;
 pub use self::spinner::*;
 pub use self::split::*;
 pub use self::switch::*;
+pub use self::table::*;
 pub use self::task::*;
 pub use self::text_input::*;
 pub use self::transform::*;
+pub use self::tree_view::*;
 pub use self::variable_label::*;
 pub use self::virtual_scroll::*;
 pub use self::worker::*;
@@ -0,0 +1,303 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::core::{NewWidget, WidgetMut};
+use masonry::widgets::{self, ButtonPress, TextAction};
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// Creates a text input for entering a number, with buttons to step the number up and down.
+///
+/// The user can only type text which could become a number.
+/// When the text is a number between `min` and `max` (inclusive), `on_changed` is called
+/// with that number. Otherwise, the input is shown in its invalid state.
+/// Pressing one of the buttons calls `on_changed` with `value` moved by `step`,
+/// clamped to the range.
+///
+/// The value currently *must* be stored in your app's state,
+/// in the same way as for [`text_input`](super::text_input).
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// # use xilem::view::number_input;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct State {
+///     quantity: f64,
+/// }
+///
+/// fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+///     number_input(state.quantity, |state: &mut State, quantity| {
+///         state.quantity = quantity;
+///     })
+///     .min(0.)
+///     .max(99.)
+/// }
+/// ```
+pub fn number_input<State, Action, F>(value: f64, on_changed: F) -> NumberInput<State, Action, F>
+where
+    State: ViewArgument,
+    F: Fn(Arg<'_, State>, f64) -> Action + Send + Sync + 'static,
+    NumberInput<State, Action, F>: WidgetView<State, Action>,
+{
+    NumberInput {
+        value,
+        on_changed,
+        min: f64::NEG_INFINITY,
+        max: f64::INFINITY,
+        step: 1.,
+        spin_buttons: true,
+        disabled: false,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`number_input`].
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct NumberInput<State, Action, F> {
+    value: f64,
+    on_changed: F,
+    min: f64,
+    max: f64,
+    step: f64,
+    spin_buttons: bool,
+    disabled: bool,
+    phantom: PhantomData<fn(State) -> Action>,
+}
+
+impl<State, Action, F> NumberInput<State, Action, F> {
+    /// Sets the smallest valid value.
+    ///
+    /// If this is zero or more, the user can't type a minus sign.
+    /// If this is greater than [`max`](Self::max), no typed value is valid,
+    /// and the buttons set the value to `max`.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the largest valid value.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the amount by which the buttons change the value.
+    ///
+    /// The default is 1.
+    /// The value is displayed with as many decimals as the step.
+    pub fn step(mut self, step: f64) -> Self {
+        if step > 0.0 {
+            self.step = step;
+        }
+        self
+    }
+
+    /// Sets whether to show the buttons which step the value.
+    ///
+    /// The default is true.
+    pub fn spin_buttons(mut self, spin_buttons: bool) -> Self {
+        self.spin_buttons = spin_buttons;
+        self
+    }
+
+    /// Sets whether the number input is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn format(&self, value: f64) -> String {
+        let step = self.step.to_string();
+        let decimals = step
+            .split_once('.')
+            .map_or(0, |(_, decimals)| decimals.len());
+        format!("{value:.decimals$}")
+    }
+
+    /// Moves `value` into the valid range.
+    ///
+    /// Unlike [`f64::clamp`], this doesn't panic if the range is empty or a bound is NaN.
+    /// A NaN bound is ignored, and if `min` is greater than `max`, this returns `max`.
+    fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+
+    /// Parses `text`, returning `None` if it isn't a number in the valid range.
+    fn parse(&self, text: &str) -> Option<f64> {
+        text.parse::<f64>()
+            .ok()
+            .filter(|value| (self.min..=self.max).contains(value))
+    }
+}
+
+/// Returns whether `text` is a decimal number, or could become one by typing more characters.
+fn is_number_prefix(text: &str, allow_negative: bool) -> bool {
+    let digits = match text.strip_prefix('-') {
+        Some(digits) if allow_negative => digits,
+        Some(_) => return false,
+        None => text,
+    };
+    let mut seen_point = false;
+    digits.chars().all(|c| match c {
+        '0'..='9' => true,
+        '.' if !seen_point => {
+            seen_point = true;
+            true
+        }
+        _ => false,
+    })
+}
+
+fn set_text(element: &mut WidgetMut<'_, widgets::SpinBox>, text: &str) {
+    let mut input = widgets::SpinBox::input_mut(element);
+    widgets::TextInput::set_invalid(&mut input, false);
+    let mut text_area = widgets::TextInput::text_mut(&mut input);
+    widgets::TextArea::reset_text(&mut text_area, text);
+}
+
+// Use distinctive numbers here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 1183546797 in decimal.
+const DECREMENT_VIEW_ID: ViewId = ViewId::new(0x468b7fad);
+/// This is a randomly generated 32 bit number - 2718316457 in decimal.
+const INCREMENT_VIEW_ID: ViewId = ViewId::new(0xa20678a9);
+
+impl<State, Action, F> ViewMarker for NumberInput<State, Action, F> {}
+impl<State, Action, F> View<State, Action, ViewCtx> for NumberInput<State, Action, F>
+where
+    State: ViewArgument,
+    Action: 'static,
+    F: Fn(Arg<'_, State>, f64) -> Action + Send + Sync + 'static,
+{
+    type Element = Pod<widgets::SpinBox>;
+    type ViewState = ();
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let allow_negative = self.min < 0.;
+        let text_area = widgets::TextArea::new_editable(&self.format(self.value))
+            .with_filter(move |text| is_number_prefix(text, allow_negative));
+        let text_input = widgets::TextInput::from_text_area(NewWidget::new(text_area));
+
+        // Ensure that the actions from the *inner* TextArea and buttons get routed correctly.
+        ctx.record_action_source(text_input.area_pod().id());
+        let spin_box =
+            widgets::SpinBox::new(NewWidget::new(text_input)).with_show_buttons(self.spin_buttons);
+        ctx.with_id(DECREMENT_VIEW_ID, |ctx| {
+            ctx.record_action_source(spin_box.decrement_pod().id());
+        });
+        ctx.with_id(INCREMENT_VIEW_ID, |ctx| {
+            ctx.record_action_source(spin_box.increment_pod().id());
+        });
+
+        let mut pod = ctx.create_pod(spin_box);
+        pod.new_widget.options.disabled = self.disabled;
+        (pod, ())
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        (): &mut Self::ViewState,
+        _: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        _: Arg<'_, State>,
+    ) {
+        if prev.disabled != self.disabled {
+            element.ctx.set_disabled(self.disabled);
+        }
+        if prev.spin_buttons != self.spin_buttons {
+            widgets::SpinBox::set_show_buttons(&mut element, self.spin_buttons);
+        }
+        if prev.min != self.min {
+            let allow_negative = self.min < 0.;
+            let mut input = widgets::SpinBox::input_mut(&mut element);
+            let mut text_area = widgets::TextInput::text_mut(&mut input);
+            widgets::TextArea::set_filter(&mut text_area, move |text| {
+                is_number_prefix(text, allow_negative)
+            });
+        }
+
+        // As in `text_input`, we compare to the element's text rather than the previous value,
+        // so that we don't reformat the number while the user is typing it.
+        // Text which isn't a valid number is kept, so that the user can keep editing it.
+        if prev.value != self.value {
+            let text = {
+                let mut input = widgets::SpinBox::input_mut(&mut element);
+                let text_area = widgets::TextInput::text_mut(&mut input);
+                text_area.widget.text().to_string()
+            };
+            if self.parse(&text) != Some(self.value) {
+                set_text(&mut element, &self.format(self.value));
+            }
+        }
+    }
+
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        {
+            let mut input = widgets::SpinBox::input_mut(&mut element);
+            ctx.teardown_action_source(widgets::TextInput::text_mut(&mut input));
+        }
+        ctx.teardown_action_source(widgets::SpinBox::decrement_mut(&mut element));
+        ctx.teardown_action_source(widgets::SpinBox::increment_mut(&mut element));
+    }
+
+    fn message(
+        &self,
+        (): &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        let direction = match message.take_first() {
+            None => 0.,
+            Some(DECREMENT_VIEW_ID) => -1.,
+            Some(INCREMENT_VIEW_ID) => 1.,
+            Some(_) => {
+                tracing::warn!(?message, "Got unexpected id path in NumberInput::message");
+                return MessageResult::Stale;
+            }
+        };
+
+        if direction != 0. {
+            if message.take_message::<ButtonPress>().is_none() {
+                tracing::error!(?message, "Wrong message type in NumberInput::message");
+                return MessageResult::Stale;
+            }
+            let value = self.clamp(self.value + direction * self.step);
+            set_text(&mut element, &self.format(value));
+            return MessageResult::Action((self.on_changed)(app_state, value));
+        }
+
+        match message.take_message::<TextAction>() {
+            Some(action) => match *action {
+                TextAction::Changed(text) => {
+                    let value = self.parse(&text);
+                    let mut input = widgets::SpinBox::input_mut(&mut element);
+                    widgets::TextInput::set_invalid(&mut input, value.is_none());
+                    match value {
+                        Some(value) => MessageResult::Action((self.on_changed)(app_state, value)),
+                        None => MessageResult::Nop,
+                    }
+                }
+                _ => MessageResult::Nop,
+            },
+            None => {
+                tracing::error!(?message, "Wrong message type in NumberInput::message");
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -1,27 +1,60 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use std::marker::PhantomData;
+use std::ops::Range;
 
-use masonry::core::{ArcStr, NewWidget, Properties, StyleProperty};
+use masonry::core::{ArcStr, NewWidget, Properties, RichText, StyleProperty};
 use masonry::parley::FontWeight;
 use masonry::properties::{ContentColor, DisabledContentColor, LineBreaking};
 use masonry::widgets;
 
 use crate::core::{Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewMarker};
+
+type LinkCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, ArcStr) -> Action + Send + Sync + 'static>;
 use crate::{Color, Pod, TextAlign, ViewCtx};
 
 /// A view which displays selectable text.
-pub fn prose<State, Action>(content: impl Into<ArcStr>) -> Prose<State, Action> {
+///
+/// The text can be [rich text](RichText) with colors and links, which can be
+/// handled using [`on_link`](Prose::on_link).
+/// Spans which would change the layout of the text, such as font sizes, aren't supported.
+///
+/// # Examples
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// # use xilem::view::prose;
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+/// use xilem::masonry::core::RichText;
+///
+/// struct State {
+///     opened: Vec<String>,
+/// }
+///
+/// fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+///     let mut text = RichText::builder();
+///     text.push("See the ")
+///         .push_link("documentation", "https://docs.rs/xilem");
+///     prose(text.build()).on_link(|state: &mut State, target| {
+///         state.opened.push(target.to_string());
+///     })
+/// }
+/// ```
+pub fn prose<State: ViewArgument, Action>(content: impl Into<RichText>) -> Prose<State, Action> {
     Prose {
         content: content.into(),
+        on_link: None,
+        highlights: Vec::new(),
+        active_highlight: None,
+        find_bar: false,
         text_color: None,
         disabled_text_color: None,
         text_alignment: TextAlign::default(),
         text_size: masonry::theme::TEXT_SIZE_NORMAL,
         line_break_mode: LineBreaking::WordWrap,
         weight: FontWeight::NORMAL,
-        phantom: PhantomData,
     }
 }
 
@@ -31,14 +64,20 @@ pub fn prose<State, Action>(content: impl Into<ArcStr>) -> Prose<State, Action>
 /// Note that setting [`text_alignment`](Prose::text_alignment) on the result
 /// will be meaningless.
 #[doc(alias = "span")]
-pub fn inline_prose<State, Action>(content: impl Into<ArcStr>) -> Prose<State, Action> {
+pub fn inline_prose<State: ViewArgument, Action>(
+    content: impl Into<RichText>,
+) -> Prose<State, Action> {
     prose(content).line_break_mode(LineBreaking::Overflow)
 }
 
 /// The [`View`] created by [`prose`] or [`inline_prose`].
 #[must_use = "View values do nothing unless provided to Xilem."]
-pub struct Prose<State, Action> {
-    content: ArcStr,
+pub struct Prose<State: ViewArgument, Action> {
+    content: RichText,
+    on_link: Option<LinkCallback<State, Action>>,
+    highlights: Vec<Range<usize>>,
+    active_highlight: Option<usize>,
+    find_bar: bool,
 
     text_color: Option<Color>,
     disabled_text_color: Option<Color>,
@@ -46,12 +85,47 @@ pub struct Prose<State, Action> {
     text_size: f32,
     line_break_mode: LineBreaking,
     weight: FontWeight,
-    phantom: PhantomData<fn(State) -> Action>,
     // TODO: disabled: bool,
     // TODO: add more attributes of `masonry::widgets::Prose`
 }
 
-impl<State, Action> Prose<State, Action> {
+impl<State: ViewArgument, Action> Prose<State, Action> {
+    /// Sets a callback which is called with the target of a link when it is clicked.
+    ///
+    /// Without this callback, clicking a link does nothing.
+    pub fn on_link<F>(mut self, on_link: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, ArcStr) -> Action + Send + Sync + 'static,
+    {
+        self.on_link = Some(Box::new(on_link));
+        self
+    }
+
+    /// Sets byte ranges of the text to highlight, such as search matches.
+    ///
+    /// See [`TextArea::with_highlights`](widgets::TextArea::with_highlights) for details.
+    pub fn highlights(mut self, highlights: Vec<Range<usize>>) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
+    /// Sets which of the [highlights](Self::highlights) is active.
+    ///
+    /// When this changes after the view is built, the active highlight is scrolled into view.
+    pub fn active_highlight(mut self, active_highlight: Option<usize>) -> Self {
+        self.active_highlight = active_highlight;
+        self
+    }
+
+    /// Sets whether the user can open a find bar with <kbd>Ctrl</kbd>+<kbd>F</kbd>.
+    ///
+    /// The find bar replaces the [highlights](Self::highlights) whilst it is open.
+    /// See [`widgets::Prose`] for details.
+    pub fn find_bar(mut self, find_bar: bool) -> Self {
+        self.find_bar = find_bar;
+        self
+    }
+
     /// Set the text's color.
     ///
     /// This overwrites the default `ContentColor` property for the inner `TextArea` widget.
@@ -98,17 +172,20 @@ fn line_break_clips(linebreaking: LineBreaking) -> bool {
     matches!(linebreaking, LineBreaking::Clip | LineBreaking::WordWrap)
 }
 
-impl<State, Action> ViewMarker for Prose<State, Action> {}
+impl<State: ViewArgument, Action> ViewMarker for Prose<State, Action> {}
 impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx> for Prose<State, Action> {
     type Element = Pod<widgets::Prose>;
     type ViewState = ();
 
     fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
-        let text_area = widgets::TextArea::new_immutable(&self.content)
+        let text_area = widgets::TextArea::new_immutable("")
+            .with_rich_text(self.content.clone())
             .with_text_alignment(self.text_alignment)
             .with_style(StyleProperty::FontSize(self.text_size))
             .with_style(StyleProperty::FontWeight(self.weight))
-            .with_word_wrap(self.line_break_mode == LineBreaking::WordWrap);
+            .with_word_wrap(self.line_break_mode == LineBreaking::WordWrap)
+            .with_highlights(self.highlights.clone())
+            .with_active_highlight(self.active_highlight);
 
         // TODO - Replace this with properties on the Prose view
         // once we implement property inheritance or something like it.
@@ -120,10 +197,13 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx> for Pros
             props.insert(DisabledContentColor(ContentColor { color }));
         }
         let text_area = NewWidget::new_with_props(text_area, props);
+        // Ensure that the link actions from the *inner* TextArea get routed correctly.
+        ctx.record_action_source(text_area.id());
 
         let pod = ctx.create_pod(
             widgets::Prose::from_text_area(text_area)
-                .with_clip(line_break_clips(self.line_break_mode)),
+                .with_clip(line_break_clips(self.line_break_mode))
+                .with_find_bar(self.find_bar),
         );
         (pod, ())
     }
@@ -136,6 +216,9 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx> for Pros
         mut element: Mut<'_, Self::Element>,
         _: Arg<'_, State>,
     ) {
+        if prev.find_bar != self.find_bar {
+            widgets::Prose::set_find_bar(&mut element, self.find_bar);
+        }
         let mut text_area = widgets::Prose::text_mut(&mut element);
 
         // TODO - Replace this with properties on the Prose view
@@ -155,7 +238,14 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx> for Pros
         }
 
         if prev.content != self.content {
-            widgets::TextArea::reset_text(&mut text_area, &self.content);
+            widgets::TextArea::set_rich_text(&mut text_area, self.content.clone());
+        }
+        // Replacing the text clears the highlights.
+        if prev.content != self.content || prev.highlights != self.highlights {
+            widgets::TextArea::set_highlights(&mut text_area, self.highlights.clone());
+            widgets::TextArea::set_active_highlight(&mut text_area, self.active_highlight);
+        } else if prev.active_highlight != self.active_highlight {
+            widgets::TextArea::set_active_highlight(&mut text_area, self.active_highlight);
         }
         if prev.text_alignment != self.text_alignment {
             widgets::TextArea::set_text_alignment(&mut text_area, self.text_alignment);
@@ -179,19 +269,37 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx> for Pros
         }
     }
 
-    fn teardown(&self, (): &mut Self::ViewState, _: &mut ViewCtx, _: Mut<'_, Self::Element>) {}
+    fn teardown(
+        &self,
+        (): &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.teardown_action_source(widgets::Prose::text_mut(&mut element));
+    }
 
     fn message(
         &self,
         _view_state: &mut Self::ViewState,
         message: &mut MessageCtx,
         _element: Mut<'_, Self::Element>,
-        _app_state: Arg<'_, State>,
+        app_state: Arg<'_, State>,
     ) -> MessageResult<Action> {
-        tracing::error!(
-            ?message,
-            "Message arrived in Prose::message, but Prose doesn't consume any messages, this is a bug"
+        debug_assert!(
+            message.remaining_path().is_empty(),
+            "id path should be empty in Prose::message"
         );
-        MessageResult::Stale
+        match message.take_message::<widgets::TextAction>() {
+            Some(action) => match (*action, &self.on_link) {
+                (widgets::TextAction::LinkClicked(target), Some(on_link)) => {
+                    MessageResult::Action(on_link(app_state, target))
+                }
+                _ => MessageResult::Nop,
+            },
+            None => {
+                tracing::error!(?message, "Wrong message type in Prose::message");
+                MessageResult::Stale
+            }
+        }
     }
 }
@@ -0,0 +1,142 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomData;
+
+use masonry::widgets;
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+/// A view which lets the user select text across the labels and prose inside it.
+///
+/// Dragging the pointer over the text selects it, and `Ctrl+C` copies the selected text
+/// of each view, one per line.
+///
+/// See the documentation on the underlying [`SelectionScope`](widgets::SelectionScope)
+/// for more information.
+///
+/// # Example
+///
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::view::{flex_col, prose, selection_scope};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// # fn view(posts: &mut Vec<String>) -> impl WidgetView<Edit<Vec<String>>> {
+/// selection_scope(flex_col(
+///     posts.iter().map(|post| prose(post.clone())).collect::<Vec<_>>(),
+/// ))
+/// # }
+/// ```
+pub fn selection_scope<State, Action, V>(inner: V) -> SelectionScope<V, State, Action>
+where
+    V: WidgetView<State, Action>,
+    State: ViewArgument,
+{
+    SelectionScope {
+        inner,
+        phantom: PhantomData,
+    }
+}
+
+/// The [`View`] created by [`selection_scope`].
+///
+/// See `selection_scope` documentation for more context.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct SelectionScope<V, State, Action = ()> {
+    inner: V,
+    phantom: PhantomData<fn() -> (State, Action)>,
+}
+
+// Use a distinctive number here, to be able to catch bugs.
+/// This is a randomly generated 32 bit number - 2915100983 in decimal.
+const SELECTION_SCOPE_CONTENT_VIEW_ID: ViewId = ViewId::new(0xadc0e937);
+
+impl<V, State, Action> ViewMarker for SelectionScope<V, State, Action> {}
+impl<V, State, Action> View<State, Action, ViewCtx> for SelectionScope<V, State, Action>
+where
+    State: ViewArgument,
+    Action: 'static,
+    V: WidgetView<State, Action>,
+{
+    type Element = Pod<widgets::SelectionScope>;
+    type ViewState = V::ViewState;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let (child, child_state) = ctx.with_id(SELECTION_SCOPE_CONTENT_VIEW_ID, |ctx| {
+            self.inner.build(ctx, app_state)
+        });
+        (
+            ctx.create_pod(widgets::SelectionScope::new(child.new_widget)),
+            child_state,
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) {
+        ctx.with_id(SELECTION_SCOPE_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::rebuild(
+                &self.inner,
+                &prev.inner,
+                view_state,
+                ctx,
+                widgets::SelectionScope::child_mut(&mut element).downcast(),
+                app_state,
+            );
+        });
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        ctx.with_id(SELECTION_SCOPE_CONTENT_VIEW_ID, |ctx| {
+            View::<State, Action, _>::teardown(
+                &self.inner,
+                view_state,
+                ctx,
+                widgets::SelectionScope::child_mut(&mut element).downcast(),
+            );
+        });
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        match message.take_first() {
+            Some(SELECTION_SCOPE_CONTENT_VIEW_ID) => self.inner.message(
+                view_state,
+                message,
+                widgets::SelectionScope::child_mut(&mut element).downcast(),
+                app_state,
+            ),
+            _ => {
+                tracing::warn!(
+                    ?message,
+                    "Got unexpected id path in `SelectionScope::message`."
+                );
+                MessageResult::Stale
+            }
+        }
+    }
+}
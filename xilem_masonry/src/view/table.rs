@@ -0,0 +1,433 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use masonry::core::{Widget, WidgetPod};
+use masonry::util::debug_panic;
+use masonry::widgets::{self, SelectionMode, SortDirection, TableAction, TableColumn};
+use private::TableState;
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+type SortCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, usize, SortDirection) -> Action + Send + Sync + 'static>;
+type SelectionCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, Vec<usize>) -> Action + Send + Sync + 'static>;
+
+/// A table View with sortable, resizable and reorderable columns, for Masonry's [`Table`](widgets::Table).
+///
+/// Like [`virtual_scroll`](crate::view::virtual_scroll), only the rows near the visible area
+/// are loaded, and whenever this view is rebuilt, all of the loaded cells are rebuilt.
+/// The cell creation function is a "component" context, which means that changing the app's
+/// state in this function will *not* cause a rebuild or rerunning of the app logic.
+///
+/// Arguments:
+/// - `columns` are the columns of the table.
+/// - `row_count` is the number of rows.
+/// - `cell` is the component for the cells.
+///   It is provided with the app's state, the index of the row and the index of the column.
+///
+/// Sorting the data is left to the app: use [`on_sort`](Table::on_sort) to be notified
+/// when the user clicks the header of a sortable column, and [`sort`](Table::sort) to
+/// show which column the rows are sorted by.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use xilem::masonry::widgets::TableColumn;
+/// use xilem::view::{label, table};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct Fruit {
+///     name: String,
+///     count: u32,
+/// }
+///
+/// # fn view(fruits: &mut Vec<Fruit>) -> impl WidgetView<Edit<Vec<Fruit>>> {
+/// let columns = [TableColumn::new("Name", 120.), TableColumn::new("Count", 60.)];
+/// table(columns, fruits.len(), |fruits: &mut Vec<Fruit>, row, column| {
+///     let fruit = &fruits[row];
+///     match column {
+///         0 => label(fruit.name.clone()),
+///         _ => label(fruit.count.to_string()),
+///     }
+/// })
+/// .on_sort(|fruits: &mut Vec<Fruit>, column, direction| {
+///     match column {
+///         0 => fruits.sort_by(|a, b| a.name.cmp(&b.name)),
+///         _ => fruits.sort_by_key(|fruit| fruit.count),
+///     }
+///     if direction == xilem::masonry::widgets::SortDirection::Descending {
+///         fruits.reverse();
+///     }
+/// })
+/// # }
+/// ```
+pub fn table<State, Action, Cell, F>(
+    columns: impl IntoIterator<Item = TableColumn>,
+    row_count: usize,
+    cell: F,
+) -> Table<State, Action, Cell, F>
+where
+    Cell: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>, usize, usize) -> Cell + 'static,
+    State: ViewArgument,
+{
+    Table {
+        phantom: PhantomData,
+        columns: columns.into_iter().collect(),
+        row_count,
+        cell,
+        row_height: None,
+        selection_mode: SelectionMode::default(),
+        selection: None,
+        sort: None,
+        on_sort: None,
+        on_selection_changed: None,
+    }
+}
+
+/// The view type for [`table`].
+///
+/// See its documentation for details.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct Table<State: ViewArgument, Action, Cell, F> {
+    phantom: PhantomData<fn() -> (WidgetPod<dyn Widget>, Action, Cell)>,
+    columns: Vec<TableColumn>,
+    row_count: usize,
+    cell: F,
+    row_height: Option<f64>,
+    selection_mode: SelectionMode,
+    selection: Option<Vec<usize>>,
+    sort: Option<(usize, SortDirection)>,
+    on_sort: Option<SortCallback<State, Action>>,
+    on_selection_changed: Option<SelectionCallback<State, Action>>,
+}
+
+impl<State: ViewArgument, Action, Cell, F> Table<State, Action, Cell, F> {
+    /// Sets the height of every row, in logical pixels.
+    pub fn row_height(mut self, row_height: f64) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Sets how many rows the user can select.
+    pub fn selection_mode(mut self, selection_mode: SelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    /// Sets the selected rows.
+    ///
+    /// If this isn't set, the selection is only changed by the user.
+    pub fn selection(mut self, selection: impl IntoIterator<Item = usize>) -> Self {
+        self.selection = Some(selection.into_iter().collect());
+        self
+    }
+
+    /// Sets the column whose header shows a sort indicator, and its direction.
+    ///
+    /// If this isn't set, the sort indicator is only changed by the user.
+    pub fn sort(mut self, column: usize, direction: SortDirection) -> Self {
+        self.sort = Some((column, direction));
+        self
+    }
+
+    /// Sets the callback called when the user clicks the header of a sortable column.
+    ///
+    /// The callback is provided with the index of the column and the requested direction.
+    pub fn on_sort<Callback>(mut self, on_sort: Callback) -> Self
+    where
+        Callback: Fn(Arg<'_, State>, usize, SortDirection) -> Action + Send + Sync + 'static,
+    {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the callback called when the user changes the selection.
+    ///
+    /// The callback is provided with the sorted indices of the selected rows.
+    pub fn on_selection_changed<Callback>(mut self, on_selection_changed: Callback) -> Self
+    where
+        Callback: Fn(Arg<'_, State>, Vec<usize>) -> Action + Send + Sync + 'static,
+    {
+        self.on_selection_changed = Some(Box::new(on_selection_changed));
+        self
+    }
+}
+
+mod private {
+    use std::collections::HashMap;
+
+    use masonry::widgets::TableLoadRows;
+
+    #[expect(
+        unnameable_types,
+        reason = "Not meaningful public API; required to be public due to design of View trait"
+    )]
+    pub struct TableState<View, State> {
+        pub(super) pending_load: Option<TableLoadRows>,
+        pub(super) rows: HashMap<usize, Vec<CellState<View, State>>>,
+    }
+
+    pub(super) struct CellState<View, State> {
+        pub(super) view: View,
+        pub(super) state: State,
+    }
+}
+
+/// Create the view id used for rows and cells.
+const fn view_id_for_index(idx: usize) -> ViewId {
+    ViewId::new(idx as u64)
+}
+
+/// Get the index stored in the view id.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "View ids are only ever created from a `usize`"
+)]
+const fn index_for_view_id(id: ViewId) -> usize {
+    id.routing_id() as usize
+}
+
+impl<State, Action, Cell, F> ViewMarker for Table<State, Action, Cell, F> where State: ViewArgument {}
+impl<State, Action, Cell, F> View<State, Action, ViewCtx> for Table<State, Action, Cell, F>
+where
+    State: ViewArgument,
+    Action: 'static,
+    Cell: WidgetView<State, Action>,
+    F: Fn(Arg<'_, State>, usize, usize) -> Cell + 'static,
+{
+    type Element = Pod<widgets::Table>;
+
+    type ViewState = TableState<Cell, Cell::ViewState>;
+
+    fn build(&self, ctx: &mut ViewCtx, _: Arg<'_, State>) -> (Self::Element, Self::ViewState) {
+        let mut widget = widgets::Table::new(self.columns.iter().cloned(), self.row_count)
+            .with_selection_mode(self.selection_mode)
+            .with_sort(self.sort);
+        if let Some(row_height) = self.row_height {
+            widget = widget.with_row_height(row_height);
+        }
+        let pod = Pod::new(widget);
+        ctx.record_action_source(pod.new_widget.id());
+        (
+            pod,
+            TableState {
+                pending_load: None,
+                rows: HashMap::default(),
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        if prev.columns != self.columns {
+            widgets::Table::set_columns(&mut element, self.columns.iter().cloned());
+        }
+        if prev.row_count != self.row_count {
+            widgets::Table::set_row_count(&mut element, self.row_count);
+        }
+        if prev.row_height != self.row_height
+            && let Some(row_height) = self.row_height
+        {
+            widgets::Table::set_row_height(&mut element, row_height);
+        }
+        if prev.selection_mode != self.selection_mode {
+            widgets::Table::set_selection_mode(&mut element, self.selection_mode);
+        }
+        if prev.selection != self.selection
+            && let Some(selection) = &self.selection
+        {
+            widgets::Table::set_selection(&mut element, selection.iter().copied());
+        }
+        if prev.sort != self.sort {
+            widgets::Table::set_sort(&mut element, self.sort);
+        }
+
+        let pending_load = view_state.pending_load.take();
+        if let Some(load) = &pending_load {
+            widgets::Table::will_handle_load(&mut element, load);
+            // Teardown the old rows
+            for idx in load.old_active.clone() {
+                if load.target.contains(&idx) {
+                    continue;
+                }
+                let Some(mut cells) = view_state.rows.remove(&idx) else {
+                    debug_panic!(
+                        "Tried to remove row {idx} from table {load:?}, but it wasn't already present."
+                    );
+                    continue;
+                };
+                ctx.with_id(view_id_for_index(idx), |ctx| {
+                    let mut row = widgets::Table::row_mut(&mut element, idx);
+                    for (column, cell) in cells.iter_mut().enumerate() {
+                        ctx.with_id(view_id_for_index(column), |ctx| {
+                            let mut cell_element = widgets::TableRow::cell_mut(&mut row, column);
+                            cell.view.teardown(
+                                &mut cell.state,
+                                ctx,
+                                widgets::TableCell::child_mut(&mut cell_element).downcast(),
+                            );
+                        });
+                    }
+                });
+                widgets::Table::remove_row(&mut element, idx);
+            }
+            // Build all new rows.
+            for idx in load.target.clone() {
+                if view_state.rows.contains_key(&idx) {
+                    continue;
+                }
+                let cells = ctx.with_id(view_id_for_index(idx), |ctx| {
+                    (0..self.columns.len())
+                        .map(|column| {
+                            let view =
+                                (self.cell)(State::reborrow_mut(&mut app_state), idx, column);
+                            ctx.with_id(view_id_for_index(column), |ctx| {
+                                let (element, state) =
+                                    view.build(ctx, State::reborrow_mut(&mut app_state));
+                                (element, private::CellState { view, state })
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                });
+                let (elements, cells): (Vec<_>, Vec<_>) = cells.into_iter().unzip();
+                widgets::Table::add_row(
+                    &mut element,
+                    idx,
+                    elements
+                        .into_iter()
+                        .map(|element| element.new_widget.erased()),
+                );
+                view_state.rows.insert(idx, cells);
+            }
+        }
+
+        // Rebuild all the other rows.
+        for (&idx, cells) in &mut view_state.rows {
+            if pending_load
+                .as_ref()
+                .is_some_and(|load| !load.old_active.contains(&idx))
+            {
+                // This row was just built.
+                continue;
+            }
+            ctx.with_id(view_id_for_index(idx), |ctx| {
+                let mut row = widgets::Table::row_mut(&mut element, idx);
+                for (column, cell) in cells.iter_mut().enumerate() {
+                    let next_view = (self.cell)(State::reborrow_mut(&mut app_state), idx, column);
+                    ctx.with_id(view_id_for_index(column), |ctx| {
+                        let mut cell_element = widgets::TableRow::cell_mut(&mut row, column);
+                        next_view.rebuild(
+                            &cell.view,
+                            &mut cell.state,
+                            ctx,
+                            widgets::TableCell::child_mut(&mut cell_element).downcast(),
+                            State::reborrow_mut(&mut app_state),
+                        );
+                        cell.view = next_view;
+                    });
+                }
+            });
+        }
+        debug_assert_eq!(
+            element.widget.len(),
+            view_state.rows.len(),
+            "Table: Row added outside of the control of Xilem."
+        );
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        for (&idx, cells) in &mut view_state.rows {
+            ctx.with_id(view_id_for_index(idx), |ctx| {
+                let mut row = widgets::Table::row_mut(&mut element, idx);
+                for (column, cell) in cells.iter_mut().enumerate() {
+                    ctx.with_id(view_id_for_index(column), |ctx| {
+                        let mut cell_element = widgets::TableRow::cell_mut(&mut row, column);
+                        cell.view.teardown(
+                            &mut cell.state,
+                            ctx,
+                            widgets::TableCell::child_mut(&mut cell_element).downcast(),
+                        );
+                    });
+                }
+            });
+        }
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        if let Some(first) = message.take_first() {
+            let idx = index_for_view_id(first);
+            let Some(column) = message.take_first().map(index_for_view_id) else {
+                tracing::error!("Message sent to a row instead of a cell in `Table::message`");
+                return MessageResult::Stale;
+            };
+            let Some(cell) = view_state
+                .rows
+                .get_mut(&idx)
+                .and_then(|cells| cells.get_mut(column))
+            else {
+                tracing::error!("Message sent to unloaded view in `Table::message`: {message:?}");
+                return MessageResult::Stale;
+            };
+            let mut row = widgets::Table::row_mut(&mut element, idx);
+            let mut cell_element = widgets::TableRow::cell_mut(&mut row, column);
+            return cell.view.message(
+                &mut cell.state,
+                message,
+                widgets::TableCell::child_mut(&mut cell_element).downcast(),
+                app_state,
+            );
+        }
+        match message.take_message::<TableAction>().map(|action| *action) {
+            Some(TableAction::LoadRows(load)) => {
+                // TODO: We should be able to rebuild here (we have the element)
+                // but we currently can't make a `ViewCtx`
+                view_state.pending_load = Some(load);
+                MessageResult::RequestRebuild
+            }
+            Some(TableAction::Sort { column, direction }) => match &self.on_sort {
+                Some(on_sort) => MessageResult::Action(on_sort(app_state, column, direction)),
+                None => MessageResult::Nop,
+            },
+            Some(TableAction::SelectionChanged(selection)) => match &self.on_selection_changed {
+                Some(on_selection_changed) => {
+                    MessageResult::Action(on_selection_changed(app_state, selection))
+                }
+                None => MessageResult::Nop,
+            },
+            Some(TableAction::ColumnResized { .. } | TableAction::ColumnsReordered(_)) => {
+                MessageResult::Nop
+            }
+            None => {
+                tracing::error!(?message, "Wrong message type in Table::message");
+                MessageResult::Stale
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::Arc;
+
 use masonry::core::{ArcStr, NewWidget, Properties};
 use masonry::parley::StyleProperty;
 use masonry::parley::style::{FontStack, FontWeight};
@@ -22,6 +24,11 @@ use crate::{InsertNewline, Pod, TextAlign, ViewCtx, WidgetView as _};
 type Callback<State, Action> =
     Box<dyn Fn(Arg<'_, State>, String) -> Action + Send + Sync + 'static>;
 
+type SelectionCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, usize, usize) -> Action + Send + Sync + 'static>;
+
+type TextPredicate = Arc<dyn Fn(&str) -> bool + Send + Sync + 'static>;
+
 /// A view which displays editable text.
 ///
 /// The text input's current text currently *must* be stored in your app's state, and so
@@ -89,6 +96,7 @@ where
         contents,
         on_changed: Box::new(on_changed),
         on_enter: None,
+        on_selection_changed: None,
         text_color: None,
         disabled_text_color: None,
         placeholder: ArcStr::default(),
@@ -97,6 +105,11 @@ where
         weight: FontWeight::NORMAL,
         font: FontStack::List(std::borrow::Cow::Borrowed(&[])),
         insert_newline: InsertNewline::default(),
+        secure: false,
+        revealed: false,
+        filter: None,
+        max_length: None,
+        validate: None,
         disabled: false,
         // Since we don't support setting the word wrapping, we can default to
         // not clipping
@@ -110,6 +123,7 @@ pub struct TextInput<State: ViewArgument, Action> {
     contents: String,
     on_changed: Callback<State, Action>,
     on_enter: Option<Callback<State, Action>>,
+    on_selection_changed: Option<SelectionCallback<State, Action>>,
     text_color: Option<Color>,
     disabled_text_color: Option<Color>,
     placeholder: ArcStr,
@@ -118,6 +132,11 @@ pub struct TextInput<State: ViewArgument, Action> {
     weight: FontWeight,
     font: FontStack<'static>,
     insert_newline: InsertNewline,
+    secure: bool,
+    revealed: bool,
+    filter: Option<TextPredicate>,
+    max_length: Option<usize>,
+    validate: Option<TextPredicate>,
     disabled: bool,
     clip: bool,
     // TODO: add more attributes of `masonry::widgets::TextInput`
@@ -224,6 +243,143 @@ impl<State: ViewArgument, Action: 'static> TextInput<State, Action> {
         self
     }
 
+    /// Set a callback that will be run when the user changes the selection or moves the caret.
+    ///
+    /// The callback receives the anchor and focus of the selection, as byte offsets in the text.
+    /// See [`TextAction::SelectionChanged`] for details.
+    pub fn on_selection_changed<F>(mut self, on_selection_changed: F) -> Self
+    where
+        F: Fn(Arg<'_, State>, usize, usize) -> Action + Send + Sync + 'static,
+    {
+        self.on_selection_changed = Some(Box::new(on_selection_changed));
+        self
+    }
+
+    /// Set whether the text is a secret, such as a password.
+    ///
+    /// Secure text is masked, can't be copied to the clipboard, and isn't kept in the undo history.
+    /// See [`TextArea::with_secure`](widgets::TextArea::with_secure) for details.
+    ///
+    /// # Examples
+    ///
+    /// A password input with a toggle to show the password:
+    ///
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// # use xilem::view::{checkbox, flex_col, text_input};
+    /// # use xilem::WidgetView;
+    /// # use xilem::core::Edit;
+    ///
+    /// struct State {
+    ///     password: String,
+    ///     show_password: bool,
+    /// }
+    ///
+    /// fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+    ///     flex_col((
+    ///         text_input(state.password.clone(), |state: &mut State, input: String| {
+    ///             state.password = input;
+    ///         })
+    ///         .secure(true)
+    ///         .revealed(state.show_password),
+    ///         checkbox("Show password", state.show_password, |state: &mut State, show| {
+    ///             state.show_password = show;
+    ///         }),
+    ///     ))
+    /// }
+    /// ```
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Set whether the text of a [secure](Self::secure) text input is shown instead of masked.
+    ///
+    /// The default value is false.
+    pub fn revealed(mut self, revealed: bool) -> Self {
+        self.revealed = revealed;
+        self
+    }
+
+    /// Set a filter which decides whether an edit by the user is accepted.
+    ///
+    /// The filter is called with the text as it would be after the edit,
+    /// and the edit is discarded if it returns false.
+    /// Changes to the contents made by the app aren't filtered.
+    /// See [`TextArea::with_filter`](widgets::TextArea::with_filter) for details.
+    ///
+    /// # Examples
+    ///
+    /// An input which only accepts digits:
+    ///
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// # use xilem::view::text_input;
+    /// # use xilem::WidgetView;
+    /// # use xilem::core::Edit;
+    ///
+    /// struct State {
+    ///     pin: String,
+    /// }
+    ///
+    /// fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+    ///     text_input(state.pin.clone(), |state: &mut State, input: String| {
+    ///         state.pin = input;
+    ///     })
+    ///     .filter(|text| text.chars().all(|c| c.is_ascii_digit()))
+    ///     .max_length(6)
+    /// }
+    /// ```
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Set the maximum number of characters the user can enter.
+    ///
+    /// Characters are counted as grapheme clusters.
+    /// Insertions which would go over the limit are truncated.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set a callback which decides whether the contents are valid.
+    ///
+    /// Unlike [`filter`](Self::filter), this doesn't prevent any edit.
+    /// Instead, the text input is shown in its invalid state while this returns false,
+    /// and reports the contents as invalid to assistive technologies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// # use xilem::view::text_input;
+    /// # use xilem::WidgetView;
+    /// # use xilem::core::Edit;
+    ///
+    /// struct State {
+    ///     email: String,
+    /// }
+    ///
+    /// fn view(state: &mut State) -> impl WidgetView<Edit<State>> {
+    ///     text_input(state.email.clone(), |state: &mut State, input: String| {
+    ///         state.email = input;
+    ///     })
+    ///     .validate(|text| text.is_empty() || text.contains('@'))
+    /// }
+    /// ```
+    pub fn validate<F>(mut self, validate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.validate = Some(Arc::new(validate));
+        self
+    }
+
     /// Set the disabled state of the widget.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -247,6 +403,19 @@ impl<State: ViewArgument, Action: 'static> TextInput<State, Action> {
     }
 }
 
+impl<State: ViewArgument, Action> TextInput<State, Action> {
+    fn is_valid(&self) -> bool {
+        self.validate
+            .as_ref()
+            .is_none_or(|validate| validate(&self.contents))
+    }
+}
+
+fn area_filter(filter: &TextPredicate) -> impl Fn(&str) -> bool + 'static {
+    let filter = filter.clone();
+    move |text| filter(text)
+}
+
 impl<State: ViewArgument, Action> ViewMarker for TextInput<State, Action> {}
 impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
     for TextInput<State, Action>
@@ -259,9 +428,16 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
         let text_area = widgets::TextArea::new_editable(&self.contents)
             .with_text_alignment(self.text_alignment)
             .with_insert_newline(self.insert_newline)
+            .with_secure(self.secure)
+            .with_revealed(self.revealed)
+            .with_max_length(self.max_length)
             .with_style(StyleProperty::FontSize(self.text_size))
             .with_style(StyleProperty::FontWeight(self.weight))
             .with_style(StyleProperty::FontStack(self.font.clone()));
+        let text_area = match &self.filter {
+            Some(filter) => text_area.with_filter(area_filter(filter)),
+            None => text_area,
+        };
 
         // TODO - Replace this with properties on the TextInput view
         // once we implement property inheritance or something like it.
@@ -277,7 +453,8 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
             widgets::TextInput::from_text_area(NewWidget::new_with_props(text_area, props))
                 .with_text_alignment(self.text_alignment)
                 .with_clip(self.clip)
-                .with_placeholder(self.placeholder.clone());
+                .with_placeholder(self.placeholder.clone())
+                .with_invalid(!self.is_valid());
 
         // Ensure that the actions from the *inner* TextArea get routed correctly.
         let id = text_input.area_pod().id();
@@ -327,6 +504,11 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
             widgets::TextInput::set_text_alignment(&mut element, self.text_alignment);
         }
 
+        let invalid = !self.is_valid();
+        if element.widget.is_invalid() != invalid {
+            widgets::TextInput::set_invalid(&mut element, invalid);
+        }
+
         let mut text_area = widgets::TextInput::text_mut(&mut element);
 
         // Unlike the other properties, we don't compare to the previous value;
@@ -336,6 +518,8 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
         // without calling `set_text`.
 
         // This is probably not the right behaviour, but determining what is the right behaviour is hard
+        // `reset_text` records the change in the undo history, so the user can still undo
+        // their edits from before the programmatic update.
         if text_area.widget.text() != &self.contents {
             widgets::TextArea::reset_text(&mut text_area, &self.contents);
         }
@@ -358,6 +542,22 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
         if prev.insert_newline != self.insert_newline {
             widgets::TextArea::set_insert_newline(&mut text_area, self.insert_newline);
         }
+        if prev.secure != self.secure {
+            widgets::TextArea::set_secure(&mut text_area, self.secure);
+        }
+        if prev.revealed != self.revealed {
+            widgets::TextArea::set_revealed(&mut text_area, self.revealed);
+        }
+        if prev.max_length != self.max_length {
+            widgets::TextArea::set_max_length(&mut text_area, self.max_length);
+        }
+        // Filters are usually closures which are recreated on every rebuild,
+        // so we can't tell whether they changed.
+        match &self.filter {
+            Some(filter) => widgets::TextArea::set_filter(&mut text_area, area_filter(filter)),
+            None if prev.filter.is_some() => widgets::TextArea::clear_filter(&mut text_area),
+            None => {}
+        }
     }
 
     fn teardown(
@@ -393,6 +593,19 @@ impl<State: ViewArgument, Action: 'static> View<State, Action, ViewCtx>
                     tracing::error!("Textbox::message: on_enter is not set");
                     MessageResult::Stale
                 }
+                TextAction::SelectionChanged { anchor, focus } => {
+                    match &self.on_selection_changed {
+                        Some(on_selection_changed) => {
+                            MessageResult::Action(on_selection_changed(app_state, anchor, focus))
+                        }
+                        None => MessageResult::Nop,
+                    }
+                }
+                // Editable text areas don't have links.
+                TextAction::LinkClicked(_)
+                | TextAction::Composing { .. }
+                | TextAction::CompositionEnded
+                | TextAction::DecorationHovered(_) => MessageResult::Nop,
             },
             None => {
                 tracing::error!(?message, "Wrong message type in TextInput::message");
@@ -0,0 +1,492 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+use masonry::core::{Widget, WidgetMut, WidgetPod};
+use masonry::util::debug_panic;
+use masonry::widgets::{self, TreeAction, TreeItem, VirtualScrollAction};
+use private::TreeViewState;
+
+use crate::core::{
+    Arg, MessageCtx, MessageResult, Mut, View, ViewArgument, ViewId, ViewMarker, ViewPathTracker,
+};
+use crate::{Pod, ViewCtx, WidgetView};
+
+type ExpandCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, u64, bool) -> Action + Send + Sync + 'static>;
+type KeyCallback<State, Action> =
+    Box<dyn Fn(Arg<'_, State>, u64) -> Action + Send + Sync + 'static>;
+
+/// A tree View with expandable items, for Masonry's [`TreeView`](widgets::TreeView).
+///
+/// Each item is identified by a `u64` key.
+/// The items of the tree are provided lazily: `children` is only called for expanded items,
+/// and `item` only for the items in rows near the visible area.
+/// Whenever this view is rebuilt, both are called again for these items.
+/// Like in [`virtual_scroll`](crate::view::virtual_scroll), these functions are "component"
+/// contexts, which means that changing the app's state in them will *not* cause a rebuild or
+/// rerunning of the app logic.
+///
+/// Arguments:
+/// - `roots` are the top-level items.
+/// - `children` is provided with the app's state and the key of an expanded item, and returns its children.
+/// - `item` is the component for the content of the rows.
+///   It is provided with the app's state and the key of the item.
+///
+/// Which items are expanded and which one is selected is decided by the app: use
+/// [`expanded`](TreeView::expanded) and [`selected`](TreeView::selected) to set them, and
+/// [`on_expand`](TreeView::on_expand) and [`on_select`](TreeView::on_select) to be notified
+/// when the user wants to change them.
+///
+/// # Example
+/// ```
+/// # use xilem_masonry as xilem;
+/// use std::collections::HashSet;
+///
+/// use xilem::masonry::widgets::TreeItem;
+/// use xilem::view::{label, tree_view};
+/// # use xilem::WidgetView;
+/// # use xilem::core::Edit;
+///
+/// struct Outline {
+///     titles: Vec<String>,
+///     children: Vec<Vec<u64>>,
+///     expanded: HashSet<u64>,
+///     selected: Option<u64>,
+/// }
+///
+/// impl Outline {
+///     fn item(&self, key: u64) -> TreeItem {
+///         TreeItem {
+///             key,
+///             has_children: !self.children[key as usize].is_empty(),
+///         }
+///     }
+/// }
+///
+/// # fn view(outline: &mut Outline) -> impl WidgetView<Edit<Outline>> {
+/// tree_view(
+///     [outline.item(0)],
+///     |outline: &mut Outline, key| {
+///         let children = &outline.children[key as usize];
+///         children.iter().map(|child| outline.item(*child)).collect()
+///     },
+///     |outline: &mut Outline, key| label(outline.titles[key as usize].clone()),
+/// )
+/// .expanded(outline.expanded.iter().copied())
+/// .selected(outline.selected)
+/// .on_expand(|outline: &mut Outline, key, expanded| {
+///     if expanded {
+///         outline.expanded.insert(key);
+///     } else {
+///         outline.expanded.remove(&key);
+///     }
+/// })
+/// .on_select(|outline: &mut Outline, key| outline.selected = Some(key))
+/// # }
+/// ```
+pub fn tree_view<State, Action, Item, Children, F>(
+    roots: impl IntoIterator<Item = TreeItem>,
+    children: Children,
+    item: F,
+) -> TreeView<State, Action, Item, Children, F>
+where
+    Item: WidgetView<State, Action>,
+    Children: Fn(Arg<'_, State>, u64) -> Vec<TreeItem> + 'static,
+    F: Fn(Arg<'_, State>, u64) -> Item + 'static,
+    State: ViewArgument,
+{
+    TreeView {
+        phantom: PhantomData,
+        roots: roots.into_iter().collect(),
+        children,
+        item,
+        expanded: HashSet::new(),
+        selected: None,
+        on_expand: None,
+        on_select: None,
+        on_activate: None,
+    }
+}
+
+/// The view type for [`tree_view`].
+///
+/// See its documentation for details.
+#[must_use = "View values do nothing unless provided to Xilem."]
+pub struct TreeView<State: ViewArgument, Action, Item, Children, F> {
+    phantom: PhantomData<fn() -> (WidgetPod<dyn Widget>, Action, Item)>,
+    roots: Vec<TreeItem>,
+    children: Children,
+    item: F,
+    expanded: HashSet<u64>,
+    selected: Option<u64>,
+    on_expand: Option<ExpandCallback<State, Action>>,
+    on_select: Option<KeyCallback<State, Action>>,
+    on_activate: Option<KeyCallback<State, Action>>,
+}
+
+impl<State: ViewArgument, Action, Item, Children, F> TreeView<State, Action, Item, Children, F> {
+    /// Sets the keys of the expanded items.
+    pub fn expanded(mut self, expanded: impl IntoIterator<Item = u64>) -> Self {
+        self.expanded = expanded.into_iter().collect();
+        self
+    }
+
+    /// Sets the key of the selected item.
+    pub fn selected(mut self, selected: Option<u64>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the callback called when the user expands or collapses an item.
+    ///
+    /// The callback is provided with the key of the item and whether it should be expanded.
+    pub fn on_expand<Callback>(mut self, on_expand: Callback) -> Self
+    where
+        Callback: Fn(Arg<'_, State>, u64, bool) -> Action + Send + Sync + 'static,
+    {
+        self.on_expand = Some(Box::new(on_expand));
+        self
+    }
+
+    /// Sets the callback called when the user selects an item.
+    pub fn on_select<Callback>(mut self, on_select: Callback) -> Self
+    where
+        Callback: Fn(Arg<'_, State>, u64) -> Action + Send + Sync + 'static,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the callback called when the user activates the selected item, by pressing `Enter`.
+    pub fn on_activate<Callback>(mut self, on_activate: Callback) -> Self
+    where
+        Callback: Fn(Arg<'_, State>, u64) -> Action + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Box::new(on_activate));
+        self
+    }
+}
+
+mod private {
+    use std::collections::HashMap;
+
+    use masonry::widgets::{TreeItem, VirtualScrollAction};
+
+    #[expect(
+        unnameable_types,
+        reason = "Not meaningful public API; required to be public due to design of View trait"
+    )]
+    pub struct TreeViewState<View, State> {
+        pub(super) pending_action: Option<VirtualScrollAction>,
+        /// The children last given to each expanded item.
+        pub(super) children: HashMap<u64, Vec<TreeItem>>,
+        pub(super) rows: HashMap<i64, RowState<View, State>>,
+    }
+
+    pub(super) struct RowState<View, State> {
+        /// The key of the item shown by the view.
+        pub(super) key: u64,
+        pub(super) view: View,
+        pub(super) state: State,
+    }
+}
+
+/// Create the view id used for the content of an item.
+const fn view_id_for_key(key: u64) -> ViewId {
+    ViewId::new(key)
+}
+
+/// Runs `f` on the content of the row at `idx`.
+fn with_content<R>(
+    element: &mut WidgetMut<'_, widgets::TreeView>,
+    idx: i64,
+    f: impl FnOnce(WidgetMut<'_, dyn Widget>) -> R,
+) -> R {
+    let mut scroll = widgets::TreeView::scroll_mut(element);
+    let mut row = widgets::VirtualScroll::child_mut(&mut scroll, idx);
+    let mut row = row.downcast::<widgets::TreeRow>();
+    f(widgets::TreeRow::content_mut(&mut row))
+}
+
+impl<State, Action, Item, Children, F> TreeView<State, Action, Item, Children, F>
+where
+    State: ViewArgument,
+    Item: WidgetView<State, Action>,
+    Children: Fn(Arg<'_, State>, u64) -> Vec<TreeItem> + 'static,
+    F: Fn(Arg<'_, State>, u64) -> Item + 'static,
+{
+    /// Provides the children of the expanded items to a new widget, starting from `items`.
+    fn build_expanded(
+        &self,
+        items: &[TreeItem],
+        mut widget: widgets::TreeView,
+        children_map: &mut HashMap<u64, Vec<TreeItem>>,
+        app_state: &mut Arg<'_, State>,
+    ) -> widgets::TreeView {
+        for item in items {
+            if !item.has_children || !self.expanded.contains(&item.key) {
+                continue;
+            }
+            let children = (self.children)(State::reborrow_mut(app_state), item.key);
+            widget = widget
+                .with_children(item.key, children.iter().copied())
+                .with_expanded(item.key);
+            widget = self.build_expanded(&children, widget, children_map, app_state);
+            children_map.insert(item.key, children);
+        }
+        widget
+    }
+
+    /// Provides the children of the expanded items, starting from `items`.
+    fn expand_items(
+        &self,
+        items: &[TreeItem],
+        view_state: &mut TreeViewState<Item, Item::ViewState>,
+        element: &mut WidgetMut<'_, widgets::TreeView>,
+        app_state: &mut Arg<'_, State>,
+    ) {
+        for item in items {
+            if !item.has_children || !self.expanded.contains(&item.key) {
+                continue;
+            }
+            let children = (self.children)(State::reborrow_mut(app_state), item.key);
+            if !element.widget.children_loaded(item.key)
+                || view_state.children.get(&item.key) != Some(&children)
+            {
+                widgets::TreeView::set_children(element, Some(item.key), children.iter().copied());
+            }
+            if !element.widget.is_expanded(item.key) {
+                widgets::TreeView::set_expanded(element, item.key, true);
+            }
+            self.expand_items(&children, view_state, element, app_state);
+            view_state.children.insert(item.key, children);
+        }
+    }
+}
+
+impl<State, Action, Item, Children, F> ViewMarker for TreeView<State, Action, Item, Children, F> where
+    State: ViewArgument
+{
+}
+impl<State, Action, Item, Children, F> View<State, Action, ViewCtx>
+    for TreeView<State, Action, Item, Children, F>
+where
+    State: ViewArgument,
+    Action: 'static,
+    Item: WidgetView<State, Action>,
+    Children: Fn(Arg<'_, State>, u64) -> Vec<TreeItem> + 'static,
+    F: Fn(Arg<'_, State>, u64) -> Item + 'static,
+{
+    type Element = Pod<widgets::TreeView>;
+
+    type ViewState = TreeViewState<Item, Item::ViewState>;
+
+    fn build(
+        &self,
+        ctx: &mut ViewCtx,
+        mut app_state: Arg<'_, State>,
+    ) -> (Self::Element, Self::ViewState) {
+        let mut children = HashMap::default();
+        let widget =
+            widgets::TreeView::new(self.roots.iter().copied()).with_selected(self.selected);
+        let widget = self.build_expanded(&self.roots, widget, &mut children, &mut app_state);
+        let pod = Pod::new(widget);
+        ctx.record_action_source(pod.new_widget.id());
+        ctx.record_action_source(pod.new_widget.widget.scroll_id());
+        (
+            pod,
+            TreeViewState {
+                pending_action: None,
+                children,
+                rows: HashMap::default(),
+            },
+        )
+    }
+
+    fn rebuild(
+        &self,
+        prev: &Self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+        mut app_state: Arg<'_, State>,
+    ) {
+        if prev.roots != self.roots {
+            widgets::TreeView::set_children(&mut element, None, self.roots.iter().copied());
+        }
+        // Collapse the items which aren't expanded anymore, then expand the others.
+        for key in prev.expanded.difference(&self.expanded) {
+            if element.widget.is_expanded(*key) {
+                widgets::TreeView::set_expanded(&mut element, *key, false);
+            }
+        }
+        view_state
+            .children
+            .retain(|key, _| self.expanded.contains(key));
+        self.expand_items(&self.roots, view_state, &mut element, &mut app_state);
+        if element.widget.selected() != self.selected {
+            widgets::TreeView::set_selected(&mut element, self.selected);
+        }
+
+        // TODO: This code should be moved into `Self::message` once it becomes possible to
+        // make a build/rebuild/teardown context there.
+        let pending_action = view_state.pending_action.take();
+        if let Some(action) = &pending_action {
+            widgets::TreeView::will_handle_action(&mut element, action);
+            // Teardown the old rows
+            for idx in action.old_active.clone() {
+                if action.target.contains(&idx) {
+                    continue;
+                }
+                let Some(mut row) = view_state.rows.remove(&idx) else {
+                    debug_panic!(
+                        "Tried to remove row {idx} from tree view {action:?}, but it wasn't already present."
+                    );
+                    continue;
+                };
+                ctx.with_id(view_id_for_key(row.key), |ctx| {
+                    with_content(&mut element, idx, |mut content| {
+                        row.view.teardown(&mut row.state, ctx, content.downcast());
+                    });
+                });
+                widgets::TreeView::remove_row(&mut element, idx);
+            }
+            // Build all new rows.
+            for idx in action.target.clone() {
+                if view_state.rows.contains_key(&idx) {
+                    continue;
+                }
+                // Rows past the last item are requested in rare cases, and shown empty.
+                let key = element.widget.row_key(idx).unwrap_or_default();
+                let view = (self.item)(State::reborrow_mut(&mut app_state), key);
+                let (content, state) = ctx.with_id(view_id_for_key(key), |ctx| {
+                    view.build(ctx, State::reborrow_mut(&mut app_state))
+                });
+                widgets::TreeView::add_row(&mut element, idx, content.new_widget.erased());
+                view_state
+                    .rows
+                    .insert(idx, private::RowState { key, view, state });
+            }
+        }
+
+        // Rebuild all the other rows, replacing the content of the rows which now show another item.
+        for (&idx, row) in &mut view_state.rows {
+            if pending_action
+                .as_ref()
+                .is_some_and(|action| !action.old_active.contains(&idx))
+            {
+                // This row was just built.
+                continue;
+            }
+            let Some(key) = element.widget.row_key(idx) else {
+                // This row will be removed by the next action.
+                continue;
+            };
+            let next_view = (self.item)(State::reborrow_mut(&mut app_state), key);
+            if key == row.key {
+                ctx.with_id(view_id_for_key(key), |ctx| {
+                    with_content(&mut element, idx, |mut content| {
+                        next_view.rebuild(
+                            &row.view,
+                            &mut row.state,
+                            ctx,
+                            content.downcast(),
+                            State::reborrow_mut(&mut app_state),
+                        );
+                    });
+                });
+            } else {
+                ctx.with_id(view_id_for_key(row.key), |ctx| {
+                    with_content(&mut element, idx, |mut content| {
+                        row.view.teardown(&mut row.state, ctx, content.downcast());
+                    });
+                });
+                let (content, state) = ctx.with_id(view_id_for_key(key), |ctx| {
+                    next_view.build(ctx, State::reborrow_mut(&mut app_state))
+                });
+                widgets::TreeView::set_row_content(&mut element, idx, content.new_widget.erased());
+                row.key = key;
+                row.state = state;
+            }
+            row.view = next_view;
+        }
+        debug_assert_eq!(
+            element.widget.len(),
+            view_state.rows.len(),
+            "TreeView: Row added outside of the control of Xilem."
+        );
+    }
+
+    fn teardown(
+        &self,
+        view_state: &mut Self::ViewState,
+        ctx: &mut ViewCtx,
+        mut element: Mut<'_, Self::Element>,
+    ) {
+        for (&idx, row) in &mut view_state.rows {
+            ctx.with_id(view_id_for_key(row.key), |ctx| {
+                with_content(&mut element, idx, |mut content| {
+                    row.view.teardown(&mut row.state, ctx, content.downcast());
+                });
+            });
+        }
+        ctx.teardown_action_source(widgets::TreeView::scroll_mut(&mut element));
+        ctx.teardown_action_source(element);
+    }
+
+    fn message(
+        &self,
+        view_state: &mut Self::ViewState,
+        message: &mut MessageCtx,
+        mut element: Mut<'_, Self::Element>,
+        app_state: Arg<'_, State>,
+    ) -> MessageResult<Action> {
+        if let Some(first) = message.take_first() {
+            let key = first.routing_id();
+            let Some((&idx, row)) = view_state.rows.iter_mut().find(|(_, row)| row.key == key)
+            else {
+                tracing::error!(
+                    "Message sent to unloaded view in `TreeView::message`: {message:?}"
+                );
+                return MessageResult::Stale;
+            };
+            return with_content(&mut element, idx, |mut content| {
+                row.view
+                    .message(&mut row.state, message, content.downcast(), app_state)
+            });
+        }
+        if let Some(action) = message.take_message::<VirtualScrollAction>() {
+            // TODO: We should be able to rebuild here (we have the element)
+            // but we currently can't make a `ViewCtx`
+            view_state.pending_action = Some(*action);
+            return MessageResult::RequestRebuild;
+        }
+        let (callback, key) = match message.take_message::<TreeAction>().map(|action| *action) {
+            Some(TreeAction::Expand(key)) => {
+                return match &self.on_expand {
+                    Some(on_expand) => MessageResult::Action(on_expand(app_state, key, true)),
+                    None => MessageResult::Nop,
+                };
+            }
+            Some(TreeAction::Collapse(key)) => {
+                return match &self.on_expand {
+                    Some(on_expand) => MessageResult::Action(on_expand(app_state, key, false)),
+                    None => MessageResult::Nop,
+                };
+            }
+            Some(TreeAction::Select(key)) => (&self.on_select, key),
+            Some(TreeAction::Activate(key)) => (&self.on_activate, key),
+            None => {
+                tracing::error!(?message, "Wrong message type in TreeView::message");
+                return MessageResult::Stale;
+            }
+        };
+        match callback {
+            Some(callback) => MessageResult::Action(callback(app_state, key)),
+            None => MessageResult::Nop,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2024 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use masonry::core::ArcStr;
+use masonry::core::{ArcStr, RichText};
 use masonry::parley::style::{FontStack, FontWeight};
 use masonry::properties::Dimensions;
 use masonry::widgets;
@@ -15,7 +15,7 @@ use crate::{Pod, TextAlign, ViewCtx};
 /// A view for displaying non-editable text, with a variable [weight](masonry::parley::style::FontWeight).
 pub fn variable_label(text: impl Into<ArcStr>) -> VariableLabel {
     VariableLabel {
-        label: label(text),
+        label: label(RichText::new(text)),
         target_weight: FontWeight::NORMAL,
         over_millis: 0.,
     }
@@ -414,4 +414,11 @@ impl ElementSplice<ZStackElement> for ZStackSplice<'_, '_> {
         widgets::ZStack::remove(&mut self.element, self.idx);
         ret
     }
+
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        if offset > 0 {
+            widgets::ZStack::move_child(&mut self.element, self.idx + offset, self.idx);
+        }
+        true
+    }
 }
@@ -1,7 +1,7 @@
 // Copyright 2025 the Xilem Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use masonry::core::{FromDynWidget, HasProperty, Property, Widget};
+use masonry::core::{FromDynWidget, HasProperty, Property, StyleClasses, Widget};
 use masonry::kurbo::Affine;
 
 use crate::core::{View, ViewArgument, ViewSequence};
@@ -84,6 +84,32 @@ pub trait WidgetView<State: ViewArgument, Action = ()>:
             phantom: std::marker::PhantomData,
         }
     }
+
+    /// Set the [style classes](StyleClasses) of this view's widget.
+    ///
+    /// Style classes select which [style rules](masonry::core::Selector) of the default
+    /// properties apply to the widget, so that a design system can be applied centrally.
+    ///
+    /// This overrides previously set classes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xilem_masonry as xilem;
+    /// use xilem::{view::text_button, WidgetView};
+    ///
+    /// # fn view<State: xilem::core::ViewArgument>() -> impl WidgetView<State> + use<State> {
+    /// text_button("delete", |_| {}).class(["primary", "danger"])
+    /// # }
+    ///
+    /// ```
+    fn class(self, classes: impl Into<StyleClasses>) -> Prop<StyleClasses, Self, State, Action>
+    where
+        State: ViewArgument,
+        Action: 'static,
+        Self: Sized,
+    {
+        self.prop(classes.into())
+    }
 }
 
 impl<V, State, Action, W> WidgetView<State, Action> for V
@@ -0,0 +1,110 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the [`animate`] view.
+
+mod common;
+
+use common::ViewHarness;
+use masonry::animation::Easing;
+use masonry::properties::Transition;
+use masonry::util::Duration;
+use masonry::widgets::Animator;
+use xilem_core::Edit;
+use xilem_masonry::WidgetView;
+use xilem_masonry::view::{animate, label};
+
+#[derive(Default)]
+struct State {
+    target: f64,
+    frames: Vec<f64>,
+}
+
+fn linear(ms: u64) -> Transition {
+    Transition::new(Duration::from_millis(ms), Easing::Linear)
+}
+
+fn app_logic(state: &mut State) -> impl WidgetView<Edit<State>, Widget = Animator> + use<> {
+    animate(
+        state.target,
+        linear(100),
+        |state: &mut State, value| state.frames.push(value),
+        label("Animated"),
+    )
+}
+
+#[test]
+fn doesnt_animate_on_build() {
+    let mut harness = ViewHarness::new(State::default(), app_logic);
+    assert_eq!(harness.animate_ms(16), 0);
+    assert!(!harness.harness.root_widget().is_running());
+    assert!(harness.state.frames.is_empty());
+}
+
+#[test]
+fn animates_to_new_target() {
+    let mut harness = ViewHarness::new(State::default(), app_logic);
+    harness.state.target = 100.;
+    harness.rebuild();
+    assert!(harness.harness.root_widget().is_running());
+
+    assert_eq!(harness.animate_ms(25), 1);
+    assert_eq!(harness.animate_ms(25), 1);
+    assert_eq!(harness.state.frames, [25., 50.]);
+
+    assert_eq!(harness.animate_ms(50), 1);
+    assert_eq!(harness.state.frames.last(), Some(&100.));
+    assert!(!harness.harness.root_widget().is_running());
+
+    // The animator has stopped, so there are no more frames.
+    assert_eq!(harness.animate_ms(16), 0);
+    assert_eq!(harness.state.frames.len(), 3);
+}
+
+#[test]
+fn retarget_starts_from_current_value() {
+    let mut harness = ViewHarness::new(State::default(), app_logic);
+    harness.state.target = 100.;
+    harness.rebuild();
+    harness.animate_ms(50);
+    assert_eq!(harness.state.frames, [50.]);
+
+    harness.state.target = 0.;
+    harness.rebuild();
+    harness.animate_ms(50);
+    assert_eq!(harness.state.frames, [50., 25.]);
+    harness.animate_ms(50);
+    assert_eq!(harness.state.frames, [50., 25., 0.]);
+    assert!(!harness.harness.root_widget().is_running());
+}
+
+#[test]
+fn unchanged_target_doesnt_restart() {
+    let mut harness = ViewHarness::new(State::default(), app_logic);
+    harness.state.target = 100.;
+    harness.rebuild();
+    harness.animate_ms(100);
+    assert!(!harness.harness.root_widget().is_running());
+
+    // Rebuilding with the same target shouldn't start a new animation.
+    harness.rebuild();
+    assert!(!harness.harness.root_widget().is_running());
+    assert_eq!(harness.animate_ms(16), 0);
+}
+
+#[test]
+fn zero_duration_reports_target() {
+    let mut harness = ViewHarness::new(State::default(), |state: &mut State| {
+        animate(
+            state.target,
+            linear(0),
+            |state: &mut State, value| state.frames.push(value),
+            label("Animated"),
+        )
+    });
+    harness.state.target = 10.;
+    harness.rebuild();
+    assert_eq!(harness.animate_ms(16), 1);
+    assert_eq!(harness.state.frames, [10.]);
+    assert!(!harness.harness.root_widget().is_running());
+}
@@ -0,0 +1,140 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A minimal driver which runs Xilem views inside a Masonry [`TestHarness`].
+
+#![allow(
+    dead_code,
+    reason = "This is a utility module, which means that some exposed items aren't used in all instantiations"
+)]
+#![deny(unreachable_pub)]
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use masonry::testing::TestHarness;
+use masonry::theme::default_property_set;
+use xilem_core::{
+    DynMessage, Edit, MessageCtx, MessageResult, ProxyError, RawProxy, SendMessage, ViewId,
+    ViewPathTracker,
+};
+use xilem_masonry::{Pod, ViewCtx, WidgetView};
+
+/// A proxy for tests, which don't run any tasks.
+#[derive(Debug)]
+struct NoProxy;
+
+impl RawProxy for NoProxy {
+    fn send_message(&self, _: Arc<[ViewId]>, _: SendMessage) -> Result<(), ProxyError> {
+        Err(ProxyError::DriverFinished(SendMessage::new(())))
+    }
+
+    fn dyn_debug(&self) -> &dyn Debug {
+        self
+    }
+}
+
+/// Runs the view returned by `logic` in a [`TestHarness`].
+///
+/// Like the real driver, this routes the actions of widgets to the views which created them,
+/// and reruns `logic` when a view returns [`MessageResult::Action`].
+pub(crate) struct ViewHarness<State: 'static, V, Logic>
+where
+    V: WidgetView<Edit<State>>,
+    V::Widget: Sized,
+    Logic: FnMut(&mut State) -> V,
+{
+    pub(crate) harness: TestHarness<V::Widget>,
+    pub(crate) state: State,
+    logic: Logic,
+    view: V,
+    view_state: V::ViewState,
+    ctx: ViewCtx,
+}
+
+impl<State: 'static, V, Logic> ViewHarness<State, V, Logic>
+where
+    V: WidgetView<Edit<State>>,
+    V::Widget: Sized,
+    Logic: FnMut(&mut State) -> V,
+{
+    pub(crate) fn new(mut state: State, mut logic: Logic) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let mut ctx = ViewCtx::new(Arc::new(NoProxy), Arc::new(runtime));
+        let view = logic(&mut state);
+        let (Pod { new_widget }, view_state) = view.build(&mut ctx, &mut state);
+        let harness = TestHarness::create(default_property_set(), new_widget);
+        Self {
+            harness,
+            state,
+            logic,
+            view,
+            view_state,
+            ctx,
+        }
+    }
+
+    /// Reruns the app logic and rebuilds the widget tree.
+    pub(crate) fn rebuild(&mut self) {
+        let next = (self.logic)(&mut self.state);
+        self.harness.edit_root_widget(|root| {
+            next.rebuild(
+                &self.view,
+                &mut self.view_state,
+                &mut self.ctx,
+                root,
+                &mut self.state,
+            );
+        });
+        self.view = next;
+    }
+
+    /// Routes all pending widget actions to their views, returning how many were routed.
+    pub(crate) fn handle_actions(&mut self) -> usize {
+        let mut count = 0;
+        while let Some((action, widget_id)) = self.harness.pop_action_erased() {
+            let id_path = self
+                .ctx
+                .get_id_path(widget_id)
+                .expect("action from a widget without a view")
+                .clone();
+            let mut message = MessageCtx::new(
+                std::mem::take(self.ctx.environment()),
+                id_path,
+                DynMessage(action),
+            );
+            let result = self.harness.edit_root_widget(|root| {
+                self.view
+                    .message(&mut self.view_state, &mut message, root, &mut self.state)
+            });
+            let (env, _, _) = message.finish();
+            *self.ctx.environment() = env;
+            count += 1;
+            match result {
+                MessageResult::Action(()) => self.rebuild(),
+                MessageResult::RequestRebuild => {
+                    self.harness.edit_root_widget(|root| {
+                        self.view.rebuild(
+                            &self.view,
+                            &mut self.view_state,
+                            &mut self.ctx,
+                            root,
+                            &mut self.state,
+                        );
+                    });
+                }
+                MessageResult::Nop => {}
+                MessageResult::Stale => panic!("message was reported as stale"),
+            }
+        }
+        count
+    }
+
+    /// Runs an animation frame, and routes the actions it caused.
+    pub(crate) fn animate_ms(&mut self, ms: u64) -> usize {
+        self.harness.animate_ms(ms);
+        self.handle_actions()
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2026 the Xilem Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the [`number_input`] view.
+
+mod common;
+
+use common::ViewHarness;
+use masonry::widgets::{TextArea, TextInput};
+use xilem_core::Edit;
+use xilem_masonry::view::{NumberInput, number_input};
+
+struct State {
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+}
+
+impl State {
+    fn new(value: f64, min: f64, max: f64) -> Self {
+        Self {
+            value,
+            min,
+            max,
+            step: 1.,
+        }
+    }
+}
+
+type View = NumberInput<Edit<State>, (), fn(&mut State, f64)>;
+type Harness = ViewHarness<State, View, fn(&mut State) -> View>;
+
+fn set_value(state: &mut State, value: f64) {
+    state.value = value;
+}
+
+fn app_logic(state: &mut State) -> View {
+    number_input(state.value, set_value as fn(&mut State, f64))
+        .min(state.min)
+        .max(state.max)
+        .step(state.step)
+}
+
+fn harness(state: State) -> Harness {
+    ViewHarness::new(state, app_logic as fn(&mut State) -> View)
+}
+
+fn increment(harness: &mut Harness) {
+    let id = harness.harness.root_widget().increment_pod().id();
+    harness.harness.mouse_click_on(id);
+    harness.handle_actions();
+}
+
+fn decrement(harness: &mut Harness) {
+    let id = harness.harness.root_widget().decrement_pod().id();
+    harness.harness.mouse_click_on(id);
+    harness.handle_actions();
+}
+
+fn text(harness: &Harness) -> String {
+    let input_id = harness.harness.root_widget().input_pod().id();
+    let input = harness.harness.get_widget_with_id(input_id);
+    let area_id = input.downcast::<TextInput>().unwrap().area_pod().id();
+    let area = harness.harness.get_widget_with_id(area_id);
+    area.downcast::<TextArea<true>>()
+        .unwrap()
+        .text()
+        .to_string()
+}
+
+#[test]
+fn buttons_step_value() {
+    let mut harness = harness(State::new(5., 0., 10.));
+    increment(&mut harness);
+    assert_eq!(harness.state.value, 6.);
+    assert_eq!(text(&harness), "6");
+
+    decrement(&mut harness);
+    decrement(&mut harness);
+    assert_eq!(harness.state.value, 4.);
+    assert_eq!(text(&harness), "4");
+}
+
+#[test]
+fn step_sets_decimals() {
+    let mut harness = harness(State {
+        step: 0.25,
+        ..State::new(1., 0., 10.)
+    });
+    assert_eq!(text(&harness), "1.00");
+    increment(&mut harness);
+    assert_eq!(harness.state.value, 1.25);
+    assert_eq!(text(&harness), "1.25");
+}
+
+#[test]
+fn buttons_clamp_to_range() {
+    let mut harness = harness(State::new(9.5, 0., 10.));
+    increment(&mut harness);
+    assert_eq!(harness.state.value, 10.);
+    increment(&mut harness);
+    assert_eq!(harness.state.value, 10.);
+
+    harness.state.value = 0.5;
+    harness.rebuild();
+    decrement(&mut harness);
+    assert_eq!(harness.state.value, 0.);
+}
+
+#[test]
+fn typing_reports_valid_values() {
+    let mut harness = harness(State::new(1., 0., 100.));
+    let input_id = harness.harness.root_widget().input_pod().id();
+    let area_id = {
+        let input = harness.harness.get_widget_with_id(input_id);
+        input.downcast::<TextInput>().unwrap().area_pod().id()
+    };
+    harness.harness.focus_on(Some(area_id));
+
+    harness.harness.keyboard_type_chars("4");
+    harness.handle_actions();
+    assert_eq!(text(&harness), "41");
+    assert_eq!(harness.state.value, 41.);
+
+    // Out of range values are shown as invalid, and aren't reported.
+    harness.harness.keyboard_type_chars("0");
+    harness.handle_actions();
+    assert_eq!(text(&harness), "401");
+    assert_eq!(harness.state.value, 41.);
+    let input = harness.harness.get_widget_with_id(input_id);
+    assert!(input.downcast::<TextInput>().unwrap().is_invalid());
+}
+
+#[test]
+fn empty_range_doesnt_panic() {
+    let mut harness = harness(State::new(5., 10., 0.));
+    increment(&mut harness);
+    assert_eq!(harness.state.value, 0.);
+    decrement(&mut harness);
+    assert_eq!(harness.state.value, 0.);
+}
+
+#[test]
+fn nan_bounds_are_ignored() {
+    let mut harness = harness(State::new(5., f64::NAN, f64::NAN));
+    increment(&mut harness);
+    assert_eq!(harness.state.value, 6.);
+
+    harness.state.max = 6.;
+    harness.rebuild();
+    increment(&mut harness);
+    assert_eq!(harness.state.value, 6.);
+}
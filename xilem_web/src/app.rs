@@ -7,7 +7,7 @@ use std::rc::Rc;
 use wasm_bindgen::UnwrapThrowExt;
 use xilem_core::Edit;
 
-use crate::core::{AppendVec, MessageCtx, MessageResult, ViewId};
+use crate::core::{AppendVec, MessageCtx, MessageResult, Rebuild, ViewId};
 use crate::elements::DomChildrenSplice;
 use crate::{AnyPod, DomFragment, DynMessage, ViewCtx};
 
@@ -110,6 +110,32 @@ impl<State, Fragment: DomFragment<Edit<State>>, InitFragment: FnMut(&mut State)
             }
         }
     }
+
+    /// Routes `body` to the view at `id_path`.
+    ///
+    /// The app must have been built already.
+    fn dispatch_message(&mut self, id_path: Vec<ViewId>, body: DynMessage) -> MessageResult<()> {
+        let env = std::mem::take(&mut self.ctx.environment);
+        let mut message_context = MessageCtx::new(env, id_path, body);
+        let mut dom_children_splice = DomChildrenSplice::new(
+            &mut self.fragment_append_scratch,
+            &mut self.elements,
+            &mut self.vec_splice_scratch,
+            &self.root,
+            self.ctx.fragment.clone(),
+            false,
+            false,
+        );
+        let message_result = self.fragment.as_mut().unwrap().seq_message(
+            self.fragment_state.as_mut().unwrap(),
+            &mut message_context,
+            &mut dom_children_splice,
+            &mut self.data,
+        );
+        let (env, _path, _message) = message_context.finish();
+        self.ctx.environment = env;
+        message_result
+    }
 }
 
 impl<State, Fragment, InitFragment> AppRunner for App<State, Fragment, InitFragment>
@@ -123,29 +149,12 @@ where
     fn handle_message(&self, message: AppMessage) {
         let mut inner_guard = self.0.borrow_mut();
         let inner = &mut *inner_guard;
-        if let Some(fragment) = &mut inner.fragment {
-            let message_result;
-            {
-                let env = std::mem::take(&mut inner.ctx.environment);
-                let mut message_context =
-                    MessageCtx::new(env, (*message.id_path).into(), message.body);
-                let mut dom_children_splice = DomChildrenSplice::new(
-                    &mut inner.fragment_append_scratch,
-                    &mut inner.elements,
-                    &mut inner.vec_splice_scratch,
-                    &inner.root,
-                    inner.ctx.fragment.clone(),
-                    false,
-                    false,
-                );
-                message_result = fragment.seq_message(
-                    inner.fragment_state.as_mut().unwrap(),
-                    &mut message_context,
-                    &mut dom_children_splice,
-                    &mut inner.data,
-                );
-                let (env, _path, _message) = message_context.finish();
-                inner.ctx.environment = env;
+        if inner.fragment.is_some() {
+            let message_result = inner.dispatch_message(message.id_path.to_vec(), message.body);
+            // Let the views reading any resources which changed know about it,
+            // so that the memoizing views above them are rebuilt.
+            for path in inner.ctx.environment.take_queued_rebuilds() {
+                inner.dispatch_message(path.to_vec(), DynMessage::new(Rebuild));
             }
 
             // Each of those results are currently resulting in a rebuild, that may be subject to change
@@ -156,6 +165,9 @@ where
                 }
             }
 
+            let Some(fragment) = &mut inner.fragment else {
+                unreachable!("Handling a message doesn't remove the fragment");
+            };
             let new_fragment = (inner.app_logic)(&mut inner.data);
             let mut dom_children_splice = DomChildrenSplice::new(
                 &mut inner.fragment_append_scratch,
@@ -224,6 +224,19 @@ impl ElementSplice<AnyPod> for DomChildrenSplice<'_, '_, '_, '_> {
         }
         f(child)
     }
+
+    fn move_to_current(&mut self, offset: usize) -> bool {
+        if offset == 0 {
+            return true;
+        }
+        let (moved, next) = self.children.move_to_next(offset);
+        if !self.parent_was_removed {
+            self.parent
+                .insert_before(moved.node.as_ref(), Some(next.node.as_ref()))
+                .unwrap_throw();
+        }
+        true
+    }
 }
 
 /// Used in all the basic DOM elements as [`View::ViewState`]
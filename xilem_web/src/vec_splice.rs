@@ -56,6 +56,17 @@ impl<'v, 's, T> VecSplice<'v, 's, T> {
         &mut self.v[ix]
     }
 
+    /// Moves the element `offset` elements after the next one, so that it becomes the next one.
+    ///
+    /// Returns the moved element and the element it was moved in front of.
+    pub fn move_to_next(&mut self, offset: usize) -> (&T, &T) {
+        self.clear_tail();
+        let len = self.scratch.len();
+        let value = self.scratch.remove(len - 1 - offset);
+        self.scratch.push(value);
+        (&self.scratch[len - 1], &self.scratch[len - 2])
+    }
+
     fn clear_tail(&mut self) {
         if self.v.len() > self.ix {
             self.scratch.extend(self.v.splice(self.ix.., []).rev());